@@ -450,11 +450,12 @@ _: {
           client-type = "tendermint";
           features = [ "bls" ];
         }
-        # {
-        #   name = "movement";
-        #   dir = "movement";
-        #   client-type = "movement";
-        # }
+        {
+          name = "movement";
+          dir = "movement";
+          client-type = "movement";
+          features = [ "union-movement" ];
+        }
         {
           name = "state-lens-ics23-mpt";
           dir = "state-lens-ics23-mpt";
@@ -480,6 +481,16 @@ _: {
           dir = "parlia";
           client-type = "parlia";
         }
+        {
+          name = "scroll";
+          dir = "scroll";
+          client-type = "scroll";
+        }
+        {
+          name = "linea";
+          dir = "linea";
+          client-type = "linea";
+        }
       ];
 
       # client type => package name
@@ -147,6 +147,17 @@ pub fn execute(
                 IbcUnionMsg::OnChannelOpenAck { .. } | IbcUnionMsg::OnChannelOpenConfirm { .. } => {
                     Ok(Response::default())
                 }
+                // a channel can only be upgraded to the protocol version implemented by this
+                // contract
+                IbcUnionMsg::OnChannelUpgradeInit { version, .. }
+                | IbcUnionMsg::OnChannelUpgradeTry { version, .. } => {
+                    enforce_version(&version, None)?;
+                    Ok(Response::default())
+                }
+                IbcUnionMsg::OnChannelUpgradeAck { .. }
+                | IbcUnionMsg::OnChannelUpgradeConfirm { .. }
+                | IbcUnionMsg::OnChannelUpgradeOpen { .. }
+                | IbcUnionMsg::OnChannelUpgradeCancel { .. } => Ok(Response::default()),
                 IbcUnionMsg::OnRecvPacket {
                     caller,
                     packet,
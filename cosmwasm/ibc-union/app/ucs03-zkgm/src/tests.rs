@@ -3479,3 +3479,42 @@ fn test_on_channel_close_confirm_only_ibc() {
 
     assert_eq!(result, Err(ContractError::OnlyIBCHost));
 }
+
+#[test]
+fn test_on_channel_upgrade_init_ok() {
+    let (mut deps, env, info, _) = init();
+    let result = execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeInit {
+            caller: "".into(),
+            channel_id: ChannelId!(1),
+            version: PROTOCOL_VERSION.to_string(),
+            relayer: "".to_string(),
+        }),
+    );
+
+    assert_eq!(result, Ok(Response::default()));
+}
+
+#[test]
+fn test_on_channel_upgrade_try_invalid_version() {
+    let (mut deps, env, info, _) = init();
+    let result = execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeTry {
+            caller: "".into(),
+            channel_id: ChannelId!(1),
+            version: "im-invalid".to_string(),
+            relayer: "".to_string(),
+        }),
+    );
+
+    assert!(matches!(
+        result,
+        Err(ContractError::InvalidIbcVersion { .. })
+    ));
+}
@@ -8,8 +8,8 @@ use depolama::{QuerierExt, StorageExt, Store};
 use frissitheto::UpgradeError;
 use ibc_union::state::{ClientConsensusStates, ClientImpls, ClientStates, ClientStore, QueryStore};
 use ibc_union_msg::lightclient::{
-    MisbehaviourResponse, QueryMsg, StorageWrites, UpdateStateResponse, VerifyCreationResponse,
-    VerifyCreationResponseEvent,
+    MisbehaviourResponse, QueryMsg, RecoverClientResponse, StorageWrites, UpdateStateResponse,
+    VerifyCreationResponse, VerifyCreationResponseEvent,
 };
 use ibc_union_spec::{ClientId, Status, Timestamp};
 use unionlabs::{
@@ -65,6 +65,8 @@ pub enum IbcClientError<T: IbcClient> {
     InvalidClientMessage(Vec<u8>),
     #[error("caller `{0}` is not a whitelisted relayer")]
     UnauthorizedCaller(String),
+    #[error("client recovery is not supported by this client")]
+    RecoveryNotSupported,
 }
 
 impl<T: IbcClient + 'static> From<IbcClientError<T>> for StdError {
@@ -325,6 +327,21 @@ pub trait IbcClient: Sized + 'static {
         misbehaviour: Self::Misbehaviour,
         relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>>;
+
+    /// Check that the expired or frozen client (`ctx.client_id`) can be recovered with the active
+    /// `substitute_client_state`, and return the client state the subject client is overwritten
+    /// with. The consensus state of the substitute at the latest height of the returned client
+    /// state is copied to the subject client.
+    ///
+    /// Recovery is not supported unless a client explicitly implements this.
+    fn recover_client(
+        ctx: IbcClientCtx<Self>,
+        subject_client_state: Self::ClientState,
+        substitute_client_state: Self::ClientState,
+    ) -> Result<Self::ClientState, IbcClientError<Self>> {
+        let _ = (ctx, subject_client_state, substitute_client_state);
+        Err(IbcClientError::RecoveryNotSupported)
+    }
 }
 
 pub fn init<T: IbcClient>(
@@ -490,6 +507,34 @@ pub fn query<T: IbcClient>(
             })
             .map_err(Into::into)
         }
+        QueryMsg::RecoverClient {
+            client_id,
+            substitute_client_id,
+        } => {
+            let ibc_host = deps.storage.read_item::<IbcHost>()?;
+
+            let subject_client_state =
+                read_client_state::<T>(&*deps.querier, &ibc_host, client_id)?;
+            let substitute_client_state =
+                read_client_state::<T>(&*deps.querier, &ibc_host, substitute_client_id)?;
+
+            let client_state = T::recover_client(
+                IbcClientCtx::new(client_id, ibc_host.clone(), deps, env),
+                subject_client_state,
+                substitute_client_state,
+            )?;
+
+            let height = T::get_latest_height(&client_state);
+            let consensus_state =
+                read_consensus_state::<T>(&*deps.querier, &ibc_host, substitute_client_id, height)?;
+
+            to_json_binary(&RecoverClientResponse {
+                client_state: client_state.encode_as::<T::Encoding>().into(),
+                consensus_state: consensus_state.encode().into(),
+                height,
+            })
+            .map_err(Into::into)
+        }
     }
 }
 
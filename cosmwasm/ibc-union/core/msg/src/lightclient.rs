@@ -9,6 +9,17 @@ pub struct MisbehaviourResponse {
     pub client_state: Bytes,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct RecoverClientResponse {
+    /// The client state to overwrite the subject client's state with
+    pub client_state: Bytes,
+    /// The consensus state to save for the subject client at `height`
+    pub consensus_state: Bytes,
+    /// The latest height of the recovered client
+    pub height: u64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum VerifyCreationResponseEvent {
@@ -88,4 +99,10 @@ pub enum QueryMsg {
         message: Bytes,
         relayer: String,
     },
+    /// Check that `substitute_client_id` can be used to recover `client_id`, returning the state
+    /// to overwrite `client_id` with.
+    RecoverClient {
+        client_id: ClientId,
+        substitute_client_id: ClientId,
+    },
 }
@@ -43,6 +43,52 @@ pub enum IbcUnionMsg {
         channel_id: ChannelId,
         relayer: String,
     },
+    /// An upgrade of the channel to `version` is being proposed. Returning an error rejects the
+    /// upgrade.
+    OnChannelUpgradeInit {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
+    /// The counterparty proposed an upgrade of the channel to `version`. Returning an error rejects
+    /// the upgrade.
+    OnChannelUpgradeTry {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
+    /// The counterparty accepted the upgrade, the channel is now on `version`. Packets can be sent
+    /// again once [`OnChannelUpgradeOpen`](Self::OnChannelUpgradeOpen) is called.
+    OnChannelUpgradeAck {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
+    /// The upgrade has been applied on the counterparty, the channel is now on `version`.
+    OnChannelUpgradeConfirm {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
+    /// The upgrade has been applied on the counterparty and packets can be sent on `version`.
+    OnChannelUpgradeOpen {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
+    /// The upgrade of the channel to `version` was cancelled, the channel stays on its current
+    /// version.
+    OnChannelUpgradeCancel {
+        caller: String,
+        channel_id: ChannelId,
+        version: String,
+        relayer: String,
+    },
     OnIntentRecvPacket {
         caller: String,
         packet: Packet,
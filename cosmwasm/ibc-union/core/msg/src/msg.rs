@@ -1,4 +1,4 @@
-use ibc_union_spec::{Channel, ChannelId, ClientId, ConnectionId, Packet, Timestamp};
+use ibc_union_spec::{Channel, ChannelId, ChannelOrder, ClientId, ConnectionId, Packet, Timestamp};
 use serde::{Deserialize, Serialize};
 use unionlabs_primitives::Bytes;
 
@@ -9,6 +9,41 @@ pub struct InitMsg {
     pub relayers: Vec<String>,
 }
 
+/// The scope of a pause. Pausing a scope also pauses every scope within it, i.e. pausing a client
+/// pauses all connections on that client and all channels on those connections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PauseScope {
+    Global,
+    Client(ClientId),
+    Connection(ConnectionId),
+    Channel(ChannelId),
+}
+
+impl PauseScope {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            PauseScope::Global => "global",
+            PauseScope::Client(_) => "client",
+            PauseScope::Connection(_) => "connection",
+            PauseScope::Channel(_) => "channel",
+        }
+    }
+}
+
+impl core::fmt::Display for PauseScope {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PauseScope::Global => f.write_str("global"),
+            PauseScope::Client(client_id) => write!(f, "client {client_id}"),
+            PauseScope::Connection(connection_id) => write!(f, "connection {connection_id}"),
+            PauseScope::Channel(channel_id) => write!(f, "channel {channel_id}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MsgRegisterClient {
@@ -22,10 +57,21 @@ pub struct MsgRegisterClient {
 pub enum ExecuteMsg {
     AddRelayer(String),
     RemoveRelayer(String),
+    /// Halt all packet and handshake traffic within a scope. Only callable by the relayer admin.
+    Pause(PauseScope),
+    /// Resume traffic within a scope that was previously paused. Traffic that is also paused by
+    /// an enclosing scope stays halted. Only callable by the relayer admin.
+    Unpause(PauseScope),
     RegisterClient(MsgRegisterClient),
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
+    /// Submit evidence of misbehaviour for a client, freezing it. Anyone can submit misbehaviour.
+    SubmitMisbehaviour(MsgSubmitMisbehaviour),
     ForceUpdateClient(MsgForceUpdateClient),
+    /// Recover an expired or frozen client by overwriting its state with the latest state of an
+    /// active substitute client of the same type. Only callable by the relayer admin, and only if
+    /// the light client allows recovering from the substitute.
+    RecoverClient(MsgRecoverClient),
     ConnectionOpenInit(MsgConnectionOpenInit),
     ConnectionOpenTry(MsgConnectionOpenTry),
     ForceConnectionOpenTry(MsgConnectionOpenTry),
@@ -42,6 +88,22 @@ pub enum ExecuteMsg {
     ForceChannelOpenConfirm(MsgChannelOpenConfirm),
     ChannelCloseInit(MsgChannelCloseInit),
     ChannelCloseConfirm(MsgChannelCloseConfirm),
+    /// Propose an upgrade of the version of an open channel to the counterparty. Only the app
+    /// owning the channel or the relayers admin can propose an upgrade. The app owning the channel
+    /// is called with [`OnChannelUpgradeInit`] and can reject the proposed version.
+    ///
+    /// [`OnChannelUpgradeInit`]: crate::module::IbcUnionMsg::OnChannelUpgradeInit
+    ChannelUpgradeInit(MsgChannelUpgradeInit),
+    ChannelUpgradeTry(MsgChannelUpgradeTry),
+    ChannelUpgradeAck(MsgChannelUpgradeAck),
+    ChannelUpgradeConfirm(MsgChannelUpgradeConfirm),
+    /// Complete an upgrade that was proposed on this chain, once it has been applied on the
+    /// counterparty. Packets can't be sent on the channel until then.
+    ChannelUpgradeOpen(MsgChannelUpgradeOpen),
+    /// Cancel the in-flight upgrade of a channel. An upgrade that was proposed on this chain can
+    /// only be cancelled by the app owning the channel or the relayers admin, an upgrade that was accepted on this chain
+    /// can be cancelled by anyone once the counterparty has cancelled it.
+    ChannelUpgradeCancel(MsgChannelUpgradeCancel),
     PacketRecv(MsgPacketRecv),
     PacketAck(MsgPacketAcknowledgement),
     PacketTimeout(MsgPacketTimeout),
@@ -79,6 +141,14 @@ pub struct MsgUpdateClient {
     pub relayer: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgSubmitMisbehaviour {
+    pub client_id: ClientId,
+    pub client_message: Bytes,
+    pub relayer: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MsgForceUpdateClient {
@@ -88,6 +158,15 @@ pub struct MsgForceUpdateClient {
     pub height: u64,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgRecoverClient {
+    /// The expired or frozen client to recover.
+    pub subject: ClientId,
+    /// The active client whose state is copied into the subject.
+    pub substitute: ClientId,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MsgConnectionOpenInit {
@@ -130,6 +209,9 @@ pub struct MsgChannelOpenInit {
     pub connection_id: ConnectionId,
     pub version: String,
     pub relayer: String,
+    /// Ordered channels can only be opened with a counterparty that also runs this contract.
+    #[serde(default)]
+    pub ordering: ChannelOrder,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -141,6 +223,9 @@ pub struct MsgChannelOpenTry {
     pub proof_init: Bytes,
     pub proof_height: u64,
     pub relayer: String,
+    /// Must match the ordering of the counterparty channel.
+    #[serde(default)]
+    pub ordering: ChannelOrder,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -179,6 +264,63 @@ pub struct MsgChannelCloseConfirm {
     pub relayer: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeInit {
+    pub channel_id: ChannelId,
+    pub proposed_version: String,
+    pub relayer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeTry {
+    pub channel_id: ChannelId,
+    pub proposed_version: String,
+    pub upgrade_sequence: u64,
+    pub proof_init: Bytes,
+    pub proof_height: u64,
+    pub relayer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeAck {
+    pub channel_id: ChannelId,
+    pub proof_try: Bytes,
+    pub proof_height: u64,
+    pub relayer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeConfirm {
+    pub channel_id: ChannelId,
+    pub proof_ack: Bytes,
+    pub proof_height: u64,
+    pub relayer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeOpen {
+    pub channel_id: ChannelId,
+    pub proof_channel: Bytes,
+    pub proof_height: u64,
+    pub relayer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MsgChannelUpgradeCancel {
+    pub channel_id: ChannelId,
+    #[serde(default)]
+    pub proof_error: Bytes,
+    #[serde(default)]
+    pub proof_height: u64,
+    pub relayer: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MsgPacketRecv {
@@ -26,6 +26,20 @@ pub enum QueryMsg {
     GetChannel { channel_id: ChannelId },
     #[cfg_attr(feature = "cw-orch-interface", returns(std::collections::BTreeSet<u32>))]
     GetChannels { contract: String },
+    #[cfg_attr(feature = "cw-orch-interface", returns(ibc_union_spec::ChannelOrder))]
+    GetChannelOrder { channel_id: ChannelId },
+    /// The sequence that will be assigned to the next packet sent on an ordered channel.
+    #[cfg_attr(feature = "cw-orch-interface", returns(u64))]
+    GetNextSequenceSend { channel_id: ChannelId },
+    /// The sequence of the next packet to be received on an ordered channel.
+    #[cfg_attr(feature = "cw-orch-interface", returns(u64))]
+    GetNextSequenceRecv { channel_id: ChannelId },
+    /// The latest upgrade of a channel, if any. Upgrades in the `ack` state have been applied.
+    #[cfg_attr(feature = "cw-orch-interface", returns(Option<ibc_union_spec::ChannelUpgrade>))]
+    GetChannelUpgrade { channel_id: ChannelId },
+    /// The last upgrade of a channel that was cancelled by this end of the channel, if any.
+    #[cfg_attr(feature = "cw-orch-interface", returns(Option<ibc_union_spec::ChannelUpgrade>))]
+    GetChannelUpgradeError { channel_id: ChannelId },
     #[cfg_attr(feature = "cw-orch-interface", returns(Option<H256>))]
     GetBatchPackets { batch_hash: H256 },
     #[cfg_attr(feature = "cw-orch-interface", returns(Option<H256>))]
@@ -34,4 +48,8 @@ pub enum QueryMsg {
     GetClientImpl { client_id: ClientId },
     #[cfg_attr(feature = "cw-orch-interface", returns(String))]
     GetRegisteredClientType { client_type: String },
+    /// Whether traffic within a scope is halted, either by a pause of the scope itself or of a
+    /// scope enclosing it.
+    #[cfg_attr(feature = "cw-orch-interface", returns(bool))]
+    IsPaused { scope: crate::msg::PauseScope },
 }
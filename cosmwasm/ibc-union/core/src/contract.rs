@@ -7,31 +7,35 @@ use cosmwasm_std::{
     to_json_binary, wasm_execute, Addr, Attribute, Binary, Deps, DepsMut, Env, Event, MessageInfo,
     OverflowError, OverflowOperation, Response, StdError, StdResult, Storage,
 };
-use depolama::{RawStore, StorageExt};
+use depolama::{RawStore, StorageExt, Store};
 use frissitheto::{UpgradeError, UpgradeMsg};
 use ibc_union_msg::{
     lightclient::{
-        QueryMsg as LightClientQuery, UpdateStateResponse, VerifyCreationResponse,
-        VerifyCreationResponseEvent,
+        MisbehaviourResponse, QueryMsg as LightClientQuery, RecoverClientResponse,
+        UpdateStateResponse, VerifyCreationResponse, VerifyCreationResponseEvent,
     },
     module::{ExecuteMsg as ModuleMsg, IbcUnionMsg},
     msg::{
         ExecuteMsg, InitMsg, MsgBatchAcks, MsgBatchSend, MsgChannelCloseConfirm,
         MsgChannelCloseInit, MsgChannelOpenAck, MsgChannelOpenConfirm, MsgChannelOpenInit,
-        MsgChannelOpenTry, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
-        MsgConnectionOpenTry, MsgCreateClient, MsgForceUpdateClient, MsgIntentPacketRecv,
-        MsgMigrateState, MsgPacketAcknowledgement, MsgPacketRecv, MsgPacketTimeout,
-        MsgRegisterClient, MsgSendPacket, MsgUpdateClient, MsgWriteAcknowledgement,
+        MsgChannelOpenTry, MsgChannelUpgradeAck, MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm,
+        MsgChannelUpgradeInit, MsgChannelUpgradeOpen, MsgChannelUpgradeTry, MsgConnectionOpenAck,
+        MsgConnectionOpenConfirm, MsgConnectionOpenInit, MsgConnectionOpenTry, MsgCreateClient,
+        MsgForceUpdateClient, MsgIntentPacketRecv, MsgMigrateState, MsgPacketAcknowledgement,
+        MsgPacketRecv, MsgPacketTimeout, MsgRecoverClient, MsgRegisterClient, MsgSendPacket,
+        MsgSubmitMisbehaviour, MsgUpdateClient, MsgWriteAcknowledgement, PauseScope,
     },
     query::QueryMsg,
 };
 use ibc_union_spec::{
     path::{
-        commit_packets, BatchPacketsPath, BatchReceiptsPath, ChannelPath, ClientStatePath,
-        ConnectionPath, ConsensusStatePath, COMMITMENT_MAGIC, COMMITMENT_MAGIC_ACK,
+        commit_packets, ordered_packet_commitment, ordered_packet_sequence, BatchPacketsPath,
+        BatchReceiptsPath, ChannelPath, ChannelUpgradeErrorPath, ChannelUpgradePath,
+        ClientStatePath, ConnectionPath, ConsensusStatePath, COMMITMENT_MAGIC,
+        COMMITMENT_MAGIC_ACK,
     },
-    Channel, ChannelId, ChannelState, ClientId, Connection, ConnectionId, ConnectionState,
-    MustBeZero, Packet, Status, Timestamp,
+    Channel, ChannelId, ChannelOrder, ChannelState, ChannelUpgrade, ChannelUpgradeState, ClientId,
+    Connection, ConnectionId, ConnectionState, MustBeZero, Packet, Status, Timestamp,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use unionlabs::{
@@ -41,9 +45,12 @@ use unionlabs::{
 
 use crate::{
     state::{
-        ChannelOwner, Channels, ClientConsensusStates, ClientImpls, ClientRegistry, ClientStates,
-        ClientStore, ClientTypes, Commitments, Connections, ContractChannels, NextChannelId,
-        NextClientId, NextConnectionId, QueryStore, WhitelistedRelayers, WhitelistedRelayersAdmin,
+        ChannelInFlightPackets, ChannelOrders, ChannelOwner, ChannelUpgradeErrors, ChannelUpgrades,
+        Channels, ClientConsensusStates, ClientImpls, ClientRegistry, ClientStates, ClientStore,
+        ClientTypes, Commitments, Connections, ContractChannels, NextChannelId, NextClientId,
+        NextConnectionId, NextSequenceRecv, NextSequenceSend, NextUpgradeSequence, Paused,
+        PausedChannels, PausedClients, PausedConnections, QueryStore, WhitelistedRelayers,
+        WhitelistedRelayersAdmin,
     },
     ContractError,
 };
@@ -55,6 +62,8 @@ pub mod events {
         pub const REGISTER: &str = "register_client";
         pub const CREATE: &str = "create_client";
         pub const UPDATE: &str = "update_client";
+        pub const MISBEHAVIOUR: &str = "client_misbehaviour";
+        pub const RECOVER: &str = "recover_client";
     }
     pub mod connection {
         pub const OPEN_INIT: &str = "connection_open_init";
@@ -69,6 +78,12 @@ pub mod events {
         pub const OPEN_CONFIRM: &str = "channel_open_confirm";
         pub const CLOSE_INIT: &str = "channel_close_init";
         pub const CLOSE_CONFIRM: &str = "channel_close_confirm";
+        pub const UPGRADE_INIT: &str = "channel_upgrade_init";
+        pub const UPGRADE_TRY: &str = "channel_upgrade_try";
+        pub const UPGRADE_ACK: &str = "channel_upgrade_ack";
+        pub const UPGRADE_CONFIRM: &str = "channel_upgrade_confirm";
+        pub const UPGRADE_OPEN: &str = "channel_upgrade_open";
+        pub const UPGRADE_CANCEL: &str = "channel_upgrade_cancel";
     }
     pub mod packet {
         pub const SEND: &str = "packet_send";
@@ -80,6 +95,10 @@ pub mod events {
         pub const BATCH_ACKS: &str = "batch_acks";
         pub const WRITE_ACK: &str = "write_ack";
     }
+    pub mod pause {
+        pub const PAUSE: &str = "pause";
+        pub const UNPAUSE: &str = "unpause";
+    }
     pub mod attribute {
         pub const CLIENT_ID: &str = "client_id";
         pub const CONNECTION_ID: &str = "connection_id";
@@ -101,10 +120,15 @@ pub mod events {
         pub const CLIENT_ADDRESS: &str = "client_address";
         pub const COUNTERPARTY_CHAIN_ID: &str = "counterparty_chain_id";
         pub const COUNTERPARTY_CLIENT_ID: &str = "counterparty_client_id";
+        pub const SUBSTITUTE_CLIENT_ID: &str = "substitute_client_id";
         pub const COUNTERPARTY_CONNECTION_ID: &str = "counterparty_connection_id";
         pub const PORT_ID: &str = "port_id";
         pub const COUNTERPARTY_PORT_ID: &str = "counterparty_port_id";
         pub const VERSION: &str = "version";
+        pub const ORDERING: &str = "ordering";
+        pub const SEQUENCE: &str = "sequence";
+        pub const UPGRADE_SEQUENCE: &str = "upgrade_sequence";
+        pub const SCOPE: &str = "scope";
     }
 }
 
@@ -150,6 +174,29 @@ fn ensure_relayer_admin(storage: &mut dyn Storage, sender: &Addr) -> Result<(),
     }
 }
 
+/// Channel upgrades can only be initiated and unilaterally cancelled by the channel's port owner
+/// or the relayer admin.
+fn ensure_port_owner_or_relayer_admin(
+    storage: &dyn Storage,
+    channel_id: ChannelId,
+    port_id: &Addr,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if sender == port_id
+        || storage
+            .maybe_read_item::<WhitelistedRelayersAdmin>()?
+            .is_some_and(|admin| &admin == sender)
+    {
+        Ok(())
+    } else {
+        Err(ContractError::Unauthorized {
+            channel_id,
+            owner: port_id.clone(),
+            caller: sender.clone(),
+        })
+    }
+}
+
 fn ensure_relayer(storage: &mut dyn Storage, sender: &Addr) -> Result<(), ContractError> {
     if storage.read::<WhitelistedRelayers>(sender).is_ok() {
         Ok(())
@@ -206,6 +253,20 @@ pub fn execute(
                 relayer,
             )
         }
+        ExecuteMsg::SubmitMisbehaviour(MsgSubmitMisbehaviour {
+            client_id,
+            client_message,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            submit_misbehaviour(
+                deps.branch(),
+                info,
+                client_id,
+                client_message.to_vec(),
+                relayer,
+            )
+        }
         ExecuteMsg::ForceUpdateClient(MsgForceUpdateClient {
             client_id,
             client_state_bytes,
@@ -238,6 +299,13 @@ pub fn execute(
                     .add_attribute(events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
             ))
         }
+        ExecuteMsg::RecoverClient(MsgRecoverClient {
+            subject,
+            substitute,
+        }) => {
+            ensure_relayer_admin(deps.storage, &info.sender)?;
+            recover_client(deps.branch(), subject, substitute)
+        }
         ExecuteMsg::ConnectionOpenInit(MsgConnectionOpenInit {
             client_id,
             counterparty_client_id,
@@ -332,6 +400,7 @@ pub fn execute(
             connection_id,
             version,
             relayer,
+            ordering,
         }) => {
             let relayer = deps.api.addr_validate(&relayer)?;
             channel_open_init(
@@ -341,6 +410,7 @@ pub fn execute(
                 counterparty_port_id,
                 connection_id,
                 version,
+                ordering,
                 relayer,
             )
         }
@@ -351,6 +421,7 @@ pub fn execute(
             proof_init,
             proof_height,
             relayer,
+            ordering,
         }) => {
             let relayer = deps.api.addr_validate(&relayer)?;
             channel_open_try(
@@ -359,6 +430,7 @@ pub fn execute(
                 port_id,
                 channel,
                 counterparty_version,
+                ordering,
                 proof_init.to_vec(),
                 proof_height,
                 relayer,
@@ -372,6 +444,7 @@ pub fn execute(
             proof_init,
             proof_height,
             relayer,
+            ordering,
         }) => {
             ensure_relayer_admin(deps.storage, &info.sender)?;
             let relayer = deps.api.addr_validate(&relayer)?;
@@ -381,6 +454,7 @@ pub fn execute(
                 port_id,
                 channel,
                 counterparty_version,
+                ordering,
                 proof_init.to_vec(),
                 proof_height,
                 relayer,
@@ -488,6 +562,98 @@ pub fn execute(
                 relayer,
             )
         }
+        ExecuteMsg::ChannelUpgradeInit(MsgChannelUpgradeInit {
+            channel_id,
+            proposed_version,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_init(deps.branch(), info, channel_id, proposed_version, relayer)
+        }
+        ExecuteMsg::ChannelUpgradeTry(MsgChannelUpgradeTry {
+            channel_id,
+            proposed_version,
+            upgrade_sequence,
+            proof_init,
+            proof_height,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_try(
+                deps.branch(),
+                info,
+                channel_id,
+                proposed_version,
+                upgrade_sequence,
+                proof_init.to_vec(),
+                proof_height,
+                relayer,
+            )
+        }
+        ExecuteMsg::ChannelUpgradeAck(MsgChannelUpgradeAck {
+            channel_id,
+            proof_try,
+            proof_height,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_ack(
+                deps.branch(),
+                info,
+                channel_id,
+                proof_try.to_vec(),
+                proof_height,
+                relayer,
+            )
+        }
+        ExecuteMsg::ChannelUpgradeConfirm(MsgChannelUpgradeConfirm {
+            channel_id,
+            proof_ack,
+            proof_height,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_confirm(
+                deps.branch(),
+                info,
+                channel_id,
+                proof_ack.to_vec(),
+                proof_height,
+                relayer,
+            )
+        }
+        ExecuteMsg::ChannelUpgradeOpen(MsgChannelUpgradeOpen {
+            channel_id,
+            proof_channel,
+            proof_height,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_open(
+                deps.branch(),
+                info,
+                channel_id,
+                proof_channel.to_vec(),
+                proof_height,
+                relayer,
+            )
+        }
+        ExecuteMsg::ChannelUpgradeCancel(MsgChannelUpgradeCancel {
+            channel_id,
+            proof_error,
+            proof_height,
+            relayer,
+        }) => {
+            let relayer = deps.api.addr_validate(&relayer)?;
+            channel_upgrade_cancel(
+                deps.branch(),
+                info,
+                channel_id,
+                proof_error.to_vec(),
+                proof_height,
+                relayer,
+            )
+        }
         ExecuteMsg::PacketRecv(MsgPacketRecv {
             packets,
             relayer_msgs,
@@ -623,6 +789,98 @@ pub fn execute(
                     .add_attribute("relayer", relayer),
             ))
         }
+        ExecuteMsg::Pause(scope) => {
+            ensure_relayer_admin(deps.storage, &info.sender)?;
+            set_paused(deps.branch(), scope, true);
+            Ok(Response::new().add_event(pause_event(events::pause::PAUSE, scope)))
+        }
+        ExecuteMsg::Unpause(scope) => {
+            ensure_relayer_admin(deps.storage, &info.sender)?;
+            set_paused(deps.branch(), scope, false);
+            Ok(Response::new().add_event(pause_event(events::pause::UNPAUSE, scope)))
+        }
+    }
+}
+
+fn set_paused(deps: DepsMut, scope: PauseScope, paused: bool) {
+    match (scope, paused) {
+        (PauseScope::Global, true) => deps.storage.write_item::<Paused>(&()),
+        (PauseScope::Global, false) => deps.storage.delete_item::<Paused>(),
+        (PauseScope::Client(client_id), true) => {
+            deps.storage.write::<PausedClients>(&client_id, &())
+        }
+        (PauseScope::Client(client_id), false) => deps.storage.delete::<PausedClients>(&client_id),
+        (PauseScope::Connection(connection_id), true) => {
+            deps.storage.write::<PausedConnections>(&connection_id, &())
+        }
+        (PauseScope::Connection(connection_id), false) => {
+            deps.storage.delete::<PausedConnections>(&connection_id)
+        }
+        (PauseScope::Channel(channel_id), true) => {
+            deps.storage.write::<PausedChannels>(&channel_id, &())
+        }
+        (PauseScope::Channel(channel_id), false) => {
+            deps.storage.delete::<PausedChannels>(&channel_id)
+        }
+    }
+}
+
+#[must_use]
+fn pause_event(ty: &str, scope: PauseScope) -> Event {
+    let event = Event::new(ty).add_attribute(events::attribute::SCOPE, scope.as_str());
+    match scope {
+        PauseScope::Global => event,
+        PauseScope::Client(client_id) => {
+            event.add_attribute(events::attribute::CLIENT_ID, client_id.to_string())
+        }
+        PauseScope::Connection(connection_id) => {
+            event.add_attribute(events::attribute::CONNECTION_ID, connection_id.to_string())
+        }
+        PauseScope::Channel(channel_id) => {
+            event.add_attribute(events::attribute::CHANNEL_ID, channel_id.to_string())
+        }
+    }
+}
+
+/// Returns the scope that halts traffic within `scope`, being either `scope` itself or the first
+/// paused scope enclosing it.
+fn paused_by(deps: Deps, scope: PauseScope) -> Result<Option<PauseScope>, ContractError> {
+    let (paused, parent) = match scope {
+        PauseScope::Global => (deps.storage.maybe_read_item::<Paused>()?.is_some(), None),
+        PauseScope::Client(client_id) => (
+            deps.storage
+                .maybe_read::<PausedClients>(&client_id)?
+                .is_some(),
+            Some(PauseScope::Global),
+        ),
+        PauseScope::Connection(connection_id) => (
+            deps.storage
+                .maybe_read::<PausedConnections>(&connection_id)?
+                .is_some(),
+            Some(PauseScope::Client(
+                deps.storage.read::<Connections>(&connection_id)?.client_id,
+            )),
+        ),
+        PauseScope::Channel(channel_id) => (
+            deps.storage
+                .maybe_read::<PausedChannels>(&channel_id)?
+                .is_some(),
+            Some(PauseScope::Connection(
+                deps.storage.read::<Channels>(&channel_id)?.connection_id,
+            )),
+        ),
+    };
+    match (paused, parent) {
+        (true, _) => Ok(Some(scope)),
+        (false, Some(parent)) => paused_by(deps, parent),
+        (false, None) => Ok(None),
+    }
+}
+
+fn ensure_not_paused(deps: Deps, scope: PauseScope) -> Result<(), ContractError> {
+    match paused_by(deps, scope)? {
+        Some(scope) => Err(ContractError::Paused { scope }),
+        None => Ok(()),
     }
 }
 
@@ -728,8 +986,13 @@ fn batch_send(deps: DepsMut, packets: Vec<Packet>) -> ContractResult {
         return Err(ContractError::NotEnoughPackets);
     }
     let channel_id = packets[0].source_channel_id;
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
     let batch_hash = commit_packets(&packets);
     let batch_commitment_key = BatchPacketsPath::from_packets(&packets).key();
+    let ordered = channel_order(deps.as_ref(), channel_id)?.is_ordered();
+    // on ordered channels, the batch commits to the sequence of the first packet and the packets
+    // must be consecutive, such that the sequence of every packet in the batch is proven
+    let mut first_sequence = None;
     let mut events = Vec::new();
     for packet in packets {
         events.push(
@@ -747,11 +1010,26 @@ fn batch_send(deps: DepsMut, packets: Vec<Packet>) -> ContractResult {
             .maybe_read::<Commitments>(&commitment_key)?
             .ok_or(ContractError::PacketCommitmentNotFound)?;
 
-        if commitment != COMMITMENT_MAGIC {
+        if ordered {
+            let sequence = ordered_packet_sequence(&commitment)
+                .ok_or(ContractError::PacketCommitmentNotFound)?;
+            match first_sequence {
+                None => first_sequence = Some(sequence),
+                Some(first) => {
+                    if sequence != first + (events.len() as u64 - 1) {
+                        return Err(ContractError::BatchNotInSequence);
+                    }
+                }
+            }
+        } else if commitment != COMMITMENT_MAGIC {
             return Err(ContractError::PacketCommitmentNotFound);
         }
     }
-    store_commit(deps, &batch_commitment_key, &COMMITMENT_MAGIC);
+    store_commit(
+        deps,
+        &batch_commitment_key,
+        &first_sequence.map_or(COMMITMENT_MAGIC, ordered_packet_commitment),
+    );
     Ok(Response::new().add_events(events))
 }
 
@@ -760,6 +1038,7 @@ fn batch_acks(deps: DepsMut, packets: Vec<Packet>, acks: Vec<Bytes>) -> Contract
         return Err(ContractError::NotEnoughPackets);
     }
     let channel_id = packets[0].destination_channel_id;
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
     let batch_hash = commit_packets(&packets);
     let batch_commitment_key = BatchReceiptsPath::from_packets(&packets).key();
     let mut events = Vec::new();
@@ -802,7 +1081,17 @@ fn timeout_packet(
     relayer: Addr,
 ) -> ContractResult {
     let source_channel = packet.source_channel_id;
-    let channel = ensure_channel_state(deps.as_ref(), source_channel)?;
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(source_channel))?;
+    let ordered = channel_order(deps.as_ref(), source_channel)?.is_ordered();
+    // a timeout closes an ordered channel, after which the remaining in-flight packets of the
+    // channel can still be timed out
+    let mut channel = deps.storage.read::<Channels>(&source_channel)?;
+    if channel.state != ChannelState::Open && !(ordered && channel.state == ChannelState::Closed) {
+        return Err(ContractError::ChannelInvalidState {
+            got: channel.state,
+            expected: ChannelState::Open,
+        });
+    }
     let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
 
     let proof_timestamp =
@@ -835,7 +1124,31 @@ fn timeout_packet(
     }
 
     let port_id = deps.storage.read::<ChannelOwner>(&source_channel)?;
-    Ok(Response::new()
+
+    let mut response = Response::new();
+    if ordered && channel.state == ChannelState::Open {
+        channel.state = ChannelState::Closed;
+        save_channel(deps.branch(), source_channel, &channel)?;
+        response = response.add_event(
+            Event::new(events::channel::CLOSE_INIT).add_attributes([
+                (events::attribute::PORT_ID, port_id.to_string()),
+                (events::attribute::CHANNEL_ID, source_channel.to_string()),
+                (
+                    events::attribute::COUNTERPARTY_PORT_ID,
+                    hex::encode(&channel.counterparty_port_id),
+                ),
+                (
+                    events::attribute::COUNTERPARTY_CHANNEL_ID,
+                    channel
+                        .counterparty_channel_id
+                        .expect("channel is open; qed;")
+                        .to_string(),
+                ),
+            ]),
+        );
+    }
+
+    Ok(response
         .add_event(
             Event::new(events::packet::TIMEOUT)
                 .add_attributes(packet_to_attr_hash(source_channel, &packet))
@@ -866,6 +1179,7 @@ fn acknowledge_packet(
     let source_channel_id = first.source_channel_id;
 
     let channel = ensure_channel_state(deps.as_ref(), source_channel_id)?;
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(source_channel_id))?;
     let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
 
     let commitment_key = BatchReceiptsPath::from_packets(&packets).key();
@@ -915,7 +1229,7 @@ fn acknowledge_packet(
     Ok(Response::new().add_events(events).add_messages(messages))
 }
 
-fn mark_packet_as_acknowledged(deps: DepsMut, packet: &Packet) -> Result<(), ContractError> {
+fn mark_packet_as_acknowledged(mut deps: DepsMut, packet: &Packet) -> Result<(), ContractError> {
     let commitment_key = BatchPacketsPath::from_packets(&[packet.clone()]).key();
     let commitment = deps
         .storage
@@ -926,11 +1240,19 @@ fn mark_packet_as_acknowledged(deps: DepsMut, packet: &Packet) -> Result<(), Con
         return Err(ContractError::PacketAlreadyAcknowledged);
     }
 
-    if commitment != COMMITMENT_MAGIC {
+    if commitment != COMMITMENT_MAGIC && ordered_packet_sequence(&commitment).is_none() {
         return Err(ContractError::PacketCommitmentNotFound);
     }
 
-    store_commit(deps, &commitment_key, &COMMITMENT_MAGIC_ACK);
+    store_commit(deps.branch(), &commitment_key, &COMMITMENT_MAGIC_ACK);
+
+    // packets sent before the in flight packets were tracked are not counted
+    let in_flight = deps
+        .storage
+        .maybe_read::<ChannelInFlightPackets>(&packet.source_channel_id)?
+        .unwrap_or_default();
+    deps.storage
+        .write::<ChannelInFlightPackets>(&packet.source_channel_id, &in_flight.saturating_sub(1));
 
     Ok(())
 }
@@ -1045,15 +1367,7 @@ fn update_client(
 ) -> Result<Response, ContractError> {
     let client_impl = client_impl(deps.as_ref(), client_id)?;
     let update = {
-        let status = query_light_client::<Status>(
-            deps.as_ref(),
-            client_impl.clone(),
-            LightClientQuery::GetStatus { client_id },
-        )?;
-
-        if !matches!(status, Status::Active) {
-            return Err(ContractError::ClientNotActive { client_id, status });
-        }
+        ensure_client_active(deps.as_ref(), client_impl.clone(), client_id)?;
 
         // Ugly hack to allow for >64K messages (not configurable) to be threaded for the query.
         // See https://github.com/CosmWasm/cosmwasm/blob/e17ecc44cdebc84de1caae648c7a4f4b56846f8f/packages/vm/src/imports.rs#L47
@@ -1116,11 +1430,154 @@ fn update_client(
     )
 }
 
+fn submit_misbehaviour(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    client_id: ClientId,
+    client_message: Vec<u8>,
+    relayer: Addr,
+) -> Result<Response, ContractError> {
+    let client_impl = client_impl(deps.as_ref(), client_id)?;
+
+    // a frozen client can't be frozen again, and an expired client can't be used anyways
+    ensure_client_active(deps.as_ref(), client_impl.clone(), client_id)?;
+
+    // the light client verifies the misbehaviour, and returns the frozen client state
+    let MisbehaviourResponse { client_state } = query_light_client::<MisbehaviourResponse>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::Misbehaviour {
+            caller: info.sender.into(),
+            client_id,
+            message: client_message.into(),
+            relayer: relayer.into(),
+        },
+    )?;
+
+    store_commit(
+        deps.branch(),
+        &ClientStatePath { client_id }.key(),
+        &commit(&client_state),
+    );
+    deps.storage
+        .write::<ClientStates>(&client_id, &client_state.to_vec().into());
+
+    let client_type = deps.storage.read::<ClientTypes>(&client_id)?;
+
+    Ok(
+        Response::new().add_event(Event::new(events::client::MISBEHAVIOUR).add_attributes([
+            (events::attribute::CLIENT_ID, client_id.to_string()),
+            (events::attribute::CLIENT_TYPE, client_type),
+        ])),
+    )
+}
+
+fn recover_client(
+    mut deps: DepsMut,
+    subject: ClientId,
+    substitute: ClientId,
+) -> Result<Response, ContractError> {
+    if subject == substitute {
+        return Err(ContractError::RecoverClientWithItself);
+    }
+
+    let subject_client_type = deps.storage.read::<ClientTypes>(&subject)?;
+    let substitute_client_type = deps.storage.read::<ClientTypes>(&substitute)?;
+    if subject_client_type != substitute_client_type {
+        return Err(ContractError::RecoverClientTypeMismatch {
+            subject_client_type,
+            substitute_client_type,
+        });
+    }
+
+    let client_impl = client_impl(deps.as_ref(), subject)?;
+
+    // only clients that can no longer be updated need to be recovered
+    let status = query_light_client::<Status>(
+        deps.as_ref(),
+        client_impl.clone(),
+        LightClientQuery::GetStatus { client_id: subject },
+    )?;
+    if matches!(status, Status::Active) {
+        return Err(ContractError::CannotRecoverActiveClient { client_id: subject });
+    }
+
+    ensure_client_active(deps.as_ref(), client_impl.clone(), substitute)?;
+
+    // same as ibc-go, the subject can only be moved forward
+    let subject_height = query_light_client::<u64>(
+        deps.as_ref(),
+        client_impl.clone(),
+        LightClientQuery::GetLatestHeight { client_id: subject },
+    )?;
+    let substitute_height = query_light_client::<u64>(
+        deps.as_ref(),
+        client_impl.clone(),
+        LightClientQuery::GetLatestHeight {
+            client_id: substitute,
+        },
+    )?;
+    if substitute_height <= subject_height {
+        return Err(ContractError::RecoverClientSubstituteHeightTooLow {
+            subject_height,
+            substitute_height,
+        });
+    }
+
+    // the light client checks that the substitute is compatible with the subject, and returns
+    // the state to recover the subject with
+    let RecoverClientResponse {
+        client_state,
+        consensus_state,
+        height,
+    } = query_light_client::<RecoverClientResponse>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::RecoverClient {
+            client_id: subject,
+            substitute_client_id: substitute,
+        },
+    )?;
+
+    store_commit(
+        deps.branch(),
+        &ClientStatePath { client_id: subject }.key(),
+        &commit(&client_state),
+    );
+    store_commit(
+        deps.branch(),
+        &ConsensusStatePath {
+            client_id: subject,
+            height,
+        }
+        .key(),
+        &commit(&consensus_state),
+    );
+
+    deps.storage
+        .write::<ClientStates>(&subject, &client_state.to_vec().into());
+    deps.storage
+        .write::<ClientConsensusStates>(&(subject, height), &consensus_state.into_vec().into());
+
+    Ok(
+        Response::new().add_event(Event::new(events::client::RECOVER).add_attributes([
+            (events::attribute::CLIENT_ID, subject.to_string()),
+            (
+                events::attribute::SUBSTITUTE_CLIENT_ID,
+                substitute.to_string(),
+            ),
+            (events::attribute::CLIENT_TYPE, subject_client_type),
+            (events::attribute::COUNTERPARTY_HEIGHT, height.to_string()),
+        ])),
+    )
+}
+
 fn connection_open_init(
     mut deps: DepsMut,
     client_id: ClientId,
     counterparty_client_id: ClientId,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Client(client_id))?;
     let connection_id = next_connection_id(deps.branch())?;
     let connection = Connection {
         state: ConnectionState::Init,
@@ -1152,6 +1609,7 @@ fn connection_open_try(
     proof_height: u64,
     verify: bool,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Client(client_id))?;
     let connection_id = next_connection_id(deps.branch())?;
     let connection = Connection {
         state: ConnectionState::TryOpen,
@@ -1211,6 +1669,7 @@ fn connection_open_ack(
     proof_height: u64,
     verify: bool,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Connection(connection_id))?;
     let mut connection = deps.storage.read::<Connections>(&connection_id)?;
     if connection.state != ConnectionState::Init {
         return Err(ContractError::ConnectionInvalidState {
@@ -1274,6 +1733,7 @@ fn connection_open_confirm(
     proof_height: u64,
     verify: bool,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Connection(connection_id))?;
     let mut connection = deps.storage.read::<Connections>(&connection_id)?;
     if connection.state != ConnectionState::TryOpen {
         return Err(ContractError::ConnectionInvalidState {
@@ -1333,6 +1793,7 @@ fn connection_open_confirm(
     ))
 }
 
+#[allow(clippy::too_many_arguments)]
 fn channel_open_init(
     mut deps: DepsMut,
     info: MessageInfo,
@@ -1340,10 +1801,12 @@ fn channel_open_init(
     counterparty_port_id: Bytes,
     connection_id: ConnectionId,
     version: String,
+    ordering: ChannelOrder,
     relayer: Addr,
 ) -> ContractResult {
     let port_id = deps.api.addr_validate(&port_id)?;
     ensure_connection_state(deps.as_ref(), connection_id)?;
+    ensure_not_paused(deps.as_ref(), PauseScope::Connection(connection_id))?;
     let (channel_id, _) = create_channel(
         deps.branch(),
         port_id.clone(),
@@ -1352,6 +1815,7 @@ fn channel_open_init(
         None,
         counterparty_port_id.clone(),
         version.clone(),
+        ordering,
     )?;
     Ok(Response::new()
         .add_event(Event::new(events::channel::OPEN_INIT).add_attributes([
@@ -1363,6 +1827,7 @@ fn channel_open_init(
             ),
             (events::attribute::CONNECTION_ID, connection_id.to_string()),
             (events::attribute::VERSION, version.clone()),
+            (events::attribute::ORDERING, ordering.to_string()),
         ]))
         .add_message(wasm_execute(
             port_id,
@@ -1384,6 +1849,7 @@ fn channel_open_try(
     port_id: String,
     channel: Channel,
     counterparty_version: String,
+    ordering: ChannelOrder,
     proof_init: Vec<u8>,
     proof_height: u64,
     relayer: Addr,
@@ -1396,6 +1862,7 @@ fn channel_open_try(
         });
     }
     let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    ensure_not_paused(deps.as_ref(), PauseScope::Connection(channel.connection_id))?;
     let connection_id = connection
         .counterparty_connection_id
         .expect("connection is open; qed;");
@@ -1423,7 +1890,7 @@ fn channel_open_try(
                 }
                 .key()
                 .into_bytes(),
-                value: commit(expected_channel.abi_encode()).into_bytes(),
+                value: commit(expected_channel.commitment_preimage(ordering)).into_bytes(),
             },
         )?;
     }
@@ -1436,6 +1903,7 @@ fn channel_open_try(
         channel.counterparty_channel_id,
         channel.counterparty_port_id,
         channel.version,
+        ordering,
     )?;
     Ok(Response::new()
         .add_event(Event::new(events::channel::OPEN_TRY).add_attributes([
@@ -1454,6 +1922,7 @@ fn channel_open_try(
                 channel.connection_id.to_string(),
             ),
             ("counterparty_version", counterparty_version.clone()),
+            (events::attribute::ORDERING, ordering.to_string()),
         ]))
         .add_message(wasm_execute(
             port_id,
@@ -1481,6 +1950,7 @@ fn channel_open_ack(
     relayer: Addr,
     verify: bool,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
     let mut channel = deps.storage.read::<Channels>(&channel_id)?;
     if channel.state != ChannelState::Init {
         return Err(ContractError::ChannelInvalidState {
@@ -1513,7 +1983,10 @@ fn channel_open_ack(
                 }
                 .key()
                 .into_bytes(),
-                value: commit(expected_channel.abi_encode()).into_bytes(),
+                value: commit(
+                    expected_channel.commitment_preimage(channel_order(deps.as_ref(), channel_id)?),
+                )
+                .into_bytes(),
             },
         )?;
     }
@@ -1557,6 +2030,7 @@ fn channel_open_confirm(
     relayer: Addr,
     verify: bool,
 ) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
     let mut channel = deps.storage.read::<Channels>(&channel_id)?;
     if channel.state != ChannelState::TryOpen {
         return Err(ContractError::ChannelInvalidState {
@@ -1593,7 +2067,10 @@ fn channel_open_confirm(
                 }
                 .key()
                 .into_bytes(),
-                value: commit(expected_channel.abi_encode()).into_bytes(),
+                value: commit(
+                    expected_channel.commitment_preimage(channel_order(deps.as_ref(), channel_id)?),
+                )
+                .into_bytes(),
             },
         )?;
     }
@@ -1716,16 +2193,14 @@ fn channel_close_confirm(
             }
             .key()
             .into_bytes(),
-            value: commit(expected_channel.abi_encode()).into_bytes(),
+            value: commit(
+                expected_channel.commitment_preimage(channel_order(deps.as_ref(), channel_id)?),
+            )
+            .into_bytes(),
         },
     )?;
     channel.state = ChannelState::Closed;
-    deps.storage.write::<Channels>(&channel_id, &channel);
-    store_commit(
-        deps.branch(),
-        &ChannelPath { channel_id }.key(),
-        &commit(channel.abi_encode()),
-    );
+    save_channel(deps.branch(), channel_id, &channel)?;
     Ok(Response::new()
         .add_event(Event::new(events::channel::CLOSE_CONFIRM).add_attributes([
             (events::attribute::PORT_ID, port_id.to_string()),
@@ -1750,7 +2225,451 @@ fn channel_close_confirm(
         )?))
 }
 
-#[allow(clippy::too_many_arguments)]
+fn channel_upgrade_init(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proposed_version: String,
+    relayer: Addr,
+) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
+    let channel = ensure_channel_state(deps.as_ref(), channel_id)?;
+    ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    ensure_port_owner_or_relayer_admin(deps.storage, channel_id, &port_id, &info.sender)?;
+    if pending_channel_upgrade(deps.as_ref(), channel_id)?.is_some() {
+        return Err(ContractError::ChannelUpgradeInProgress { channel_id });
+    }
+    let upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Init,
+        version: proposed_version,
+        sequence: next_sequence::<NextUpgradeSequence>(deps.branch(), channel_id, 1)?,
+    };
+    save_channel_upgrade(deps.branch(), channel_id, &upgrade);
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_INIT,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeInit {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn channel_upgrade_try(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proposed_version: String,
+    upgrade_sequence: u64,
+    proof_init: Vec<u8>,
+    proof_height: u64,
+    relayer: Addr,
+) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
+    let channel = ensure_channel_state(deps.as_ref(), channel_id)?;
+    let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    if pending_channel_upgrade(deps.as_ref(), channel_id)?.is_some() {
+        return Err(ContractError::ChannelUpgradeInProgress { channel_id });
+    }
+    // the counterparty can't propose an upgrade with a sequence that has already been used on
+    // this end, otherwise the proof of an old upgrade could be replayed
+    let next_upgrade_sequence = deps
+        .storage
+        .maybe_read::<NextUpgradeSequence>(&channel_id)?
+        .unwrap_or(1);
+    if upgrade_sequence < next_upgrade_sequence {
+        return Err(ContractError::ChannelUpgradeSequenceInvalid {
+            got: upgrade_sequence,
+            expected: next_upgrade_sequence,
+        });
+    }
+    // the proposer applies the upgrade as soon as it acknowledges it, so none of the packets sent
+    // from this end on the current version can be in flight by then
+    ensure_channel_flushed(deps.as_ref(), channel_id)?;
+    let counterparty_channel_id = channel
+        .counterparty_channel_id
+        .expect("channel is open; qed;");
+    let expected_upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Init,
+        version: proposed_version,
+        sequence: upgrade_sequence,
+    };
+    let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
+    query_light_client::<()>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::VerifyMembership {
+            client_id: connection.client_id,
+            height: proof_height,
+            proof: proof_init.into(),
+            path: ChannelUpgradePath {
+                channel_id: counterparty_channel_id,
+            }
+            .key()
+            .into_bytes(),
+            value: commit(expected_upgrade.commitment_preimage()).into_bytes(),
+        },
+    )?;
+    let upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Try,
+        ..expected_upgrade
+    };
+    deps.storage.write::<NextUpgradeSequence>(
+        &channel_id,
+        &upgrade_sequence
+            .checked_add(1)
+            .ok_or(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+            )))?,
+    );
+    save_channel_upgrade(deps.branch(), channel_id, &upgrade);
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_TRY,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeTry {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+fn channel_upgrade_ack(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proof_try: Vec<u8>,
+    proof_height: u64,
+    relayer: Addr,
+) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
+    let mut channel = ensure_channel_state(deps.as_ref(), channel_id)?;
+    let upgrade =
+        ensure_channel_upgrade_state(deps.as_ref(), channel_id, ChannelUpgradeState::Init)?;
+    let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    ensure_channel_flushed(deps.as_ref(), channel_id)?;
+    let counterparty_channel_id = channel
+        .counterparty_channel_id
+        .expect("channel is open; qed;");
+    let expected_upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Try,
+        ..upgrade.clone()
+    };
+    let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
+    query_light_client::<()>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::VerifyMembership {
+            client_id: connection.client_id,
+            height: proof_height,
+            proof: proof_try.into(),
+            path: ChannelUpgradePath {
+                channel_id: counterparty_channel_id,
+            }
+            .key()
+            .into_bytes(),
+            value: commit(expected_upgrade.commitment_preimage()).into_bytes(),
+        },
+    )?;
+    channel.version = upgrade.version.clone();
+    save_channel(deps.branch(), channel_id, &channel)?;
+    // the upgrade is kept, such that the counterparty can prove that it has been applied on this
+    // end of the channel. packets can't be sent until the counterparty has applied it as well, see
+    // `channel_upgrade_open`
+    let upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Ack,
+        ..upgrade
+    };
+    save_channel_upgrade(deps.branch(), channel_id, &upgrade);
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_ACK,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeAck {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+fn channel_upgrade_confirm(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proof_ack: Vec<u8>,
+    proof_height: u64,
+    relayer: Addr,
+) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
+    let mut channel = ensure_channel_state(deps.as_ref(), channel_id)?;
+    let upgrade =
+        ensure_channel_upgrade_state(deps.as_ref(), channel_id, ChannelUpgradeState::Try)?;
+    let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    ensure_channel_flushed(deps.as_ref(), channel_id)?;
+    let counterparty_channel_id = channel
+        .counterparty_channel_id
+        .expect("channel is open; qed;");
+    let expected_upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Ack,
+        ..upgrade.clone()
+    };
+    let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
+    query_light_client::<()>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::VerifyMembership {
+            client_id: connection.client_id,
+            height: proof_height,
+            proof: proof_ack.into(),
+            path: ChannelUpgradePath {
+                channel_id: counterparty_channel_id,
+            }
+            .key()
+            .into_bytes(),
+            value: commit(expected_upgrade.commitment_preimage()).into_bytes(),
+        },
+    )?;
+    channel.version = upgrade.version.clone();
+    save_channel(deps.branch(), channel_id, &channel)?;
+    delete_channel_upgrade(deps.branch(), channel_id);
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_CONFIRM,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeConfirm {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+fn channel_upgrade_open(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proof_channel: Vec<u8>,
+    proof_height: u64,
+    relayer: Addr,
+) -> ContractResult {
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(channel_id))?;
+    let channel = ensure_channel_state(deps.as_ref(), channel_id)?;
+    let upgrade =
+        ensure_channel_upgrade_state(deps.as_ref(), channel_id, ChannelUpgradeState::Ack)?;
+    let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    let counterparty_channel_id = channel
+        .counterparty_channel_id
+        .expect("channel is open; qed;");
+    // the counterparty deletes its side of the upgrade once it has applied it, so the upgraded
+    // channel itself is proven
+    let expected_channel = Channel {
+        state: ChannelState::Open,
+        connection_id: connection
+            .counterparty_connection_id
+            .expect("connection is open; qed;"),
+        counterparty_channel_id: Some(channel_id),
+        counterparty_port_id: port_id.as_bytes().to_vec().into(),
+        version: upgrade.version.clone(),
+    };
+    let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
+    query_light_client::<()>(
+        deps.as_ref(),
+        client_impl,
+        LightClientQuery::VerifyMembership {
+            client_id: connection.client_id,
+            height: proof_height,
+            proof: proof_channel.into(),
+            path: ChannelPath {
+                channel_id: counterparty_channel_id,
+            }
+            .key()
+            .into_bytes(),
+            value: commit(
+                expected_channel.commitment_preimage(channel_order(deps.as_ref(), channel_id)?),
+            )
+            .into_bytes(),
+        },
+    )?;
+    delete_channel_upgrade(deps.branch(), channel_id);
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_OPEN,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeOpen {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+fn channel_upgrade_cancel(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    channel_id: ChannelId,
+    proof_error: Vec<u8>,
+    proof_height: u64,
+    relayer: Addr,
+) -> ContractResult {
+    let channel = deps.storage.read::<Channels>(&channel_id)?;
+    let upgrade = pending_channel_upgrade(deps.as_ref(), channel_id)?
+        .ok_or(ContractError::ChannelUpgradeNotFound { channel_id })?;
+    let port_id = deps.storage.read::<ChannelOwner>(&channel_id)?;
+    match upgrade.state {
+        // the counterparty can't have applied the upgrade yet, as that requires this end to
+        // acknowledge it first
+        ChannelUpgradeState::Init => {
+            ensure_port_owner_or_relayer_admin(deps.storage, channel_id, &port_id, &info.sender)?;
+            deps.storage
+                .write::<ChannelUpgradeErrors>(&channel_id, &upgrade);
+            store_commit(
+                deps.branch(),
+                &ChannelUpgradeErrorPath { channel_id }.key(),
+                &commit(upgrade.commitment_preimage()),
+            );
+        }
+        // the counterparty may have already applied the upgrade, so it can only be cancelled on
+        // this end once it has been cancelled on the counterparty
+        ChannelUpgradeState::Try => {
+            let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
+            let counterparty_channel_id = channel
+                .counterparty_channel_id
+                .expect("channel has an upgrade; qed;");
+            let expected_upgrade = ChannelUpgrade {
+                state: ChannelUpgradeState::Init,
+                ..upgrade.clone()
+            };
+            let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
+            query_light_client::<()>(
+                deps.as_ref(),
+                client_impl,
+                LightClientQuery::VerifyMembership {
+                    client_id: connection.client_id,
+                    height: proof_height,
+                    proof: proof_error.into(),
+                    path: ChannelUpgradeErrorPath {
+                        channel_id: counterparty_channel_id,
+                    }
+                    .key()
+                    .into_bytes(),
+                    value: commit(expected_upgrade.commitment_preimage()).into_bytes(),
+                },
+            )?;
+        }
+        // the upgrade has already been applied on both ends, it can only be completed
+        ChannelUpgradeState::Ack => {
+            return Err(ContractError::ChannelUpgradeInvalidState {
+                got: ChannelUpgradeState::Ack,
+                expected: ChannelUpgradeState::Init,
+            })
+        }
+    }
+    delete_channel_upgrade(deps.branch(), channel_id);
+    Ok(Response::new()
+        .add_event(channel_upgrade_event(
+            events::channel::UPGRADE_CANCEL,
+            &port_id,
+            channel_id,
+            &channel,
+            &upgrade,
+        ))
+        .add_message(wasm_execute(
+            port_id,
+            &ModuleMsg::IbcUnionMsg(IbcUnionMsg::OnChannelUpgradeCancel {
+                caller: info.sender.into_string(),
+                channel_id,
+                version: upgrade.version,
+                relayer: relayer.into(),
+            }),
+            vec![],
+        )?))
+}
+
+#[must_use]
+fn channel_upgrade_event(
+    ty: &str,
+    port_id: &Addr,
+    channel_id: ChannelId,
+    channel: &Channel,
+    upgrade: &ChannelUpgrade,
+) -> Event {
+    Event::new(ty).add_attributes([
+        (events::attribute::PORT_ID, port_id.to_string()),
+        (events::attribute::CHANNEL_ID, channel_id.to_string()),
+        (
+            events::attribute::COUNTERPARTY_PORT_ID,
+            hex::encode(&channel.counterparty_port_id),
+        ),
+        (
+            events::attribute::COUNTERPARTY_CHANNEL_ID,
+            channel
+                .counterparty_channel_id
+                .expect("channel is open; qed;")
+                .to_string(),
+        ),
+        (
+            events::attribute::CONNECTION_ID,
+            channel.connection_id.to_string(),
+        ),
+        (events::attribute::VERSION, upgrade.version.clone()),
+        (
+            events::attribute::UPGRADE_SEQUENCE,
+            upgrade.sequence.to_string(),
+        ),
+    ])
+}
+
+#[allow(clippy::too_many_arguments)]
 fn process_receive(
     mut deps: DepsMut,
     env: Env,
@@ -1766,9 +2685,29 @@ fn process_receive(
     let destination_channel_id = first.destination_channel_id;
 
     let channel = ensure_channel_state(deps.as_ref(), destination_channel_id)?;
+    ensure_not_paused(deps.as_ref(), PauseScope::Channel(destination_channel_id))?;
     let connection = ensure_connection_state(deps.as_ref(), channel.connection_id)?;
 
+    let ordered = channel_order(deps.as_ref(), destination_channel_id)?.is_ordered();
+    if ordered && intent {
+        return Err(ContractError::IntentOnOrderedChannel {
+            channel_id: destination_channel_id,
+        });
+    }
+
     if !intent {
+        // on ordered channels, the packets are proven to start at the next expected sequence,
+        // which rejects any packet that is received out of order
+        let commitment_value = if ordered {
+            let sequence = next_sequence::<NextSequenceRecv>(
+                deps.branch(),
+                destination_channel_id,
+                packets.len() as u64,
+            )?;
+            ordered_packet_commitment(sequence)
+        } else {
+            COMMITMENT_MAGIC
+        };
         let proof_commitment_key = BatchPacketsPath::from_packets(&packets).key();
         let client_impl = client_impl(deps.as_ref(), connection.client_id)?;
         query_light_client::<()>(
@@ -1779,7 +2718,7 @@ fn process_receive(
                 height: proof_height,
                 proof: proof.to_vec().into(),
                 path: proof_commitment_key.into_bytes(),
-                value: COMMITMENT_MAGIC.into_bytes(),
+                value: commitment_value.into_bytes(),
             },
         )?;
     }
@@ -1926,6 +2865,11 @@ fn send_packet(
     }
 
     let channel = ensure_channel_state(deps.as_ref(), source_channel_id)?;
+    if pending_channel_upgrade(deps.as_ref(), source_channel_id)?.is_some() {
+        return Err(ContractError::ChannelUpgradeInProgress {
+            channel_id: source_channel_id,
+        });
+    }
     let packet = Packet {
         source_channel_id,
         destination_channel_id: channel
@@ -1948,14 +2892,36 @@ fn send_packet(
         return Err(ContractError::PacketCommitmentAlreadyExist);
     }
 
-    store_commit(deps.branch(), &commitment_key, &COMMITMENT_MAGIC);
+    let mut event = Event::new(events::packet::SEND)
+        .add_attributes(packet_attr_hash)
+        .add_attributes(packet_attrs);
+
+    if channel_order(deps.as_ref(), source_channel_id)?.is_ordered() {
+        let sequence = next_sequence::<NextSequenceSend>(deps.branch(), source_channel_id, 1)?;
+        store_commit(
+            deps.branch(),
+            &commitment_key,
+            &ordered_packet_commitment(sequence),
+        );
+        event = event.add_attribute(events::attribute::SEQUENCE, sequence.to_string());
+    } else {
+        store_commit(deps.branch(), &commitment_key, &COMMITMENT_MAGIC);
+    }
+    let in_flight = deps
+        .storage
+        .maybe_read::<ChannelInFlightPackets>(&source_channel_id)?
+        .unwrap_or_default();
+    deps.storage.write::<ChannelInFlightPackets>(
+        &source_channel_id,
+        &in_flight
+            .checked_add(1)
+            .ok_or(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+            )))?,
+    );
 
     Ok(Response::new()
-        .add_event(
-            Event::new(events::packet::SEND)
-                .add_attributes(packet_attr_hash)
-                .add_attributes(packet_attrs),
-        )
+        .add_event(event)
         .set_data(serialized_packet.as_bytes()))
 }
 
@@ -2001,6 +2967,26 @@ fn next_client_id(deps: DepsMut) -> Result<ClientId, ContractError> {
     Ok(next)
 }
 
+/// Take the next `count` sequences of a channel from the sequence store `S`, returning the first
+/// one.
+fn next_sequence<S: Store<Key = ChannelId, Value = u64>>(
+    deps: DepsMut,
+    channel_id: ChannelId,
+    count: u64,
+) -> Result<u64, ContractError> {
+    let next = deps.storage.maybe_read::<S>(&channel_id)?.unwrap_or(1);
+
+    let v = next
+        .checked_add(count)
+        .ok_or(StdError::overflow(OverflowError::new(
+            OverflowOperation::Add,
+        )))?;
+
+    deps.storage.write::<S>(&channel_id, &v);
+
+    Ok(next)
+}
+
 fn client_impl(deps: Deps, client_id: ClientId) -> Result<Addr, ContractError> {
     Ok(deps.storage.read::<ClientImpls>(&client_id)?)
 }
@@ -2047,6 +3033,7 @@ fn save_connection(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn create_channel(
     mut deps: DepsMut,
     owner: Addr,
@@ -2055,6 +3042,7 @@ fn create_channel(
     counterparty_channel_id: Option<ChannelId>,
     counterparty_port_id: Bytes,
     version: String,
+    ordering: ChannelOrder,
 ) -> Result<(ChannelId, Channel), ContractError> {
     let channel_id = next_channel_id(deps.branch())?;
     let channel = Channel {
@@ -2076,6 +3064,9 @@ fn create_channel(
                 None => BTreeSet::from([channel_id]),
             })
         })?;
+    if ordering.is_ordered() {
+        deps.storage.write::<ChannelOrders>(&channel_id, &ordering);
+    }
     save_channel(deps, channel_id, &channel)?;
     Ok((channel_id, channel))
 }
@@ -2085,15 +3076,80 @@ fn save_channel(
     channel_id: ChannelId,
     channel: &Channel,
 ) -> Result<(), ContractError> {
+    let ordering = channel_order(deps.as_ref(), channel_id)?;
     deps.storage.write::<Channels>(&channel_id, channel);
     store_commit(
         deps,
         &ChannelPath { channel_id }.key(),
-        &commit(channel.abi_encode()),
+        &commit(channel.commitment_preimage(ordering)),
     );
     Ok(())
 }
 
+fn save_channel_upgrade(deps: DepsMut, channel_id: ChannelId, upgrade: &ChannelUpgrade) {
+    deps.storage.write::<ChannelUpgrades>(&channel_id, upgrade);
+    store_commit(
+        deps,
+        &ChannelUpgradePath { channel_id }.key(),
+        &commit(upgrade.commitment_preimage()),
+    );
+}
+
+fn delete_channel_upgrade(deps: DepsMut, channel_id: ChannelId) {
+    deps.storage.delete::<ChannelUpgrades>(&channel_id);
+    deps.storage
+        .delete::<Commitments>(&ChannelUpgradePath { channel_id }.key());
+}
+
+/// The upgrade of a channel that has not been completed on this end yet, if any. Packets can't be
+/// sent on the channel while an upgrade is pending.
+fn pending_channel_upgrade(
+    deps: Deps,
+    channel_id: ChannelId,
+) -> Result<Option<ChannelUpgrade>, ContractError> {
+    Ok(deps.storage.maybe_read::<ChannelUpgrades>(&channel_id)?)
+}
+
+/// Ensure that all the packets sent on the channel have been acknowledged or timed out, such that
+/// none of them can be received on a different version than the one they were sent on.
+fn ensure_channel_flushed(deps: Deps, channel_id: ChannelId) -> Result<(), ContractError> {
+    let in_flight = deps
+        .storage
+        .maybe_read::<ChannelInFlightPackets>(&channel_id)?
+        .unwrap_or_default();
+    if in_flight != 0 {
+        return Err(ContractError::ChannelUpgradeNotFlushed {
+            channel_id,
+            in_flight,
+        });
+    }
+    Ok(())
+}
+
+fn ensure_channel_upgrade_state(
+    deps: Deps,
+    channel_id: ChannelId,
+    expected: ChannelUpgradeState,
+) -> Result<ChannelUpgrade, ContractError> {
+    let upgrade = pending_channel_upgrade(deps, channel_id)?
+        .ok_or(ContractError::ChannelUpgradeNotFound { channel_id })?;
+    if upgrade.state != expected {
+        Err(ContractError::ChannelUpgradeInvalidState {
+            got: upgrade.state,
+            expected,
+        })
+    } else {
+        Ok(upgrade)
+    }
+}
+
+fn channel_order(deps: Deps, channel_id: ChannelId) -> Result<ChannelOrder, ContractError> {
+    Ok(deps
+        .storage
+        .maybe_read::<ChannelOrders>(&channel_id)?
+        .unwrap_or_default())
+}
+
 fn ensure_connection_state(
     deps: Deps,
     connection_id: ConnectionId,
@@ -2194,6 +3250,31 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
             let channel = deps.storage.read::<Channels>(&channel_id)?;
             Ok(to_json_binary(&channel)?)
         }
+        QueryMsg::GetChannelOrder { channel_id } => {
+            deps.storage.read::<Channels>(&channel_id)?;
+            Ok(to_json_binary(&channel_order(deps, channel_id)?)?)
+        }
+        QueryMsg::GetNextSequenceSend { channel_id } => Ok(to_json_binary(
+            &deps
+                .storage
+                .maybe_read::<NextSequenceSend>(&channel_id)?
+                .unwrap_or(1),
+        )?),
+        QueryMsg::GetNextSequenceRecv { channel_id } => Ok(to_json_binary(
+            &deps
+                .storage
+                .maybe_read::<NextSequenceRecv>(&channel_id)?
+                .unwrap_or(1),
+        )?),
+        QueryMsg::GetChannelUpgrade { channel_id } => Ok(to_json_binary(
+            &deps.storage.maybe_read::<ChannelUpgrades>(&channel_id)?,
+        )?),
+        QueryMsg::GetChannelUpgradeError { channel_id } => Ok(to_json_binary(
+            &deps
+                .storage
+                .maybe_read::<ChannelUpgradeErrors>(&channel_id)?,
+        )?),
+        QueryMsg::IsPaused { scope } => Ok(to_json_binary(&paused_by(deps, scope)?.is_some())?),
         QueryMsg::GetConnection { connection_id } => {
             let connection = deps.storage.read::<Connections>(&connection_id)?;
             Ok(to_json_binary(&connection)?)
@@ -2209,6 +3290,21 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
     }
 }
 
+fn ensure_client_active(
+    deps: Deps,
+    client_impl: Addr,
+    client_id: ClientId,
+) -> Result<(), ContractError> {
+    let status =
+        query_light_client::<Status>(deps, client_impl, LightClientQuery::GetStatus { client_id })?;
+
+    if !matches!(status, Status::Active) {
+        return Err(ContractError::ClientNotActive { client_id, status });
+    }
+
+    Ok(())
+}
+
 fn query_light_client<T: DeserializeOwned>(
     deps: Deps,
     client_impl: Addr,
@@ -10,7 +10,10 @@ mod tests;
 
 use cosmwasm_std::{Addr, StdError};
 use frissitheto::UpgradeError;
-use ibc_union_spec::{ChannelId, ChannelState, ClientId, ConnectionState, Status, Timestamp};
+use ibc_union_msg::msg::PauseScope;
+use ibc_union_spec::{
+    ChannelId, ChannelState, ChannelUpgradeState, ClientId, ConnectionState, Status, Timestamp,
+};
 use thiserror::Error;
 use unionlabs::primitives::Bytes;
 
@@ -85,6 +88,8 @@ pub enum ContractError {
         owner: Addr,
         caller: Addr,
     },
+    #[error("{} {scope} is paused", ContractErrorKind::from(self))]
+    Paused { scope: PauseScope },
     #[error("{} packet not received", ContractErrorKind::from(self))]
     PacketNotReceived,
     #[error("{} packet is already acknowledged", ContractErrorKind::from(self))]
@@ -149,11 +154,75 @@ pub enum ContractError {
         ContractErrorKind::from(self)
     )]
     ClientNotActive { client_id: ClientId, status: Status },
+    #[error(
+        "{} client {client_id} is active and cannot be recovered",
+        ContractErrorKind::from(self)
+    )]
+    CannotRecoverActiveClient { client_id: ClientId },
+    #[error(
+        "{} a client cannot be recovered with itself as the substitute",
+        ContractErrorKind::from(self)
+    )]
+    RecoverClientWithItself,
+    #[error(
+        "{} the substitute client type ({substitute_client_type}) does not match the subject client type ({subject_client_type})",
+        ContractErrorKind::from(self)
+    )]
+    RecoverClientTypeMismatch {
+        subject_client_type: String,
+        substitute_client_type: String,
+    },
+    #[error(
+        "{} the substitute client height ({substitute_height}) must be greater than the subject client height ({subject_height})",
+        ContractErrorKind::from(self)
+    )]
+    RecoverClientSubstituteHeightTooLow {
+        subject_height: u64,
+        substitute_height: u64,
+    },
     #[error(
         "{} a batch of packets can contains packets from the same channel only",
         ContractErrorKind::from(self)
     )]
     BatchSameChannelOnly,
+    #[error(
+        "{} a batch of packets on an ordered channel must contain consecutive packets in sequence order",
+        ContractErrorKind::from(self)
+    )]
+    BatchNotInSequence,
+    #[error(
+        "{} intent packets cannot be received on ordered channel {channel_id}",
+        ContractErrorKind::from(self)
+    )]
+    IntentOnOrderedChannel { channel_id: ChannelId },
+    #[error(
+        "{} channel {channel_id} already has an upgrade in progress",
+        ContractErrorKind::from(self)
+    )]
+    ChannelUpgradeInProgress { channel_id: ChannelId },
+    #[error(
+        "{} channel {channel_id} has no upgrade in progress",
+        ContractErrorKind::from(self)
+    )]
+    ChannelUpgradeNotFound { channel_id: ChannelId },
+    #[error(
+        "{} channel upgrade state is invalid: expected {expected}, got {got}",
+        ContractErrorKind::from(self)
+    )]
+    ChannelUpgradeInvalidState {
+        got: ChannelUpgradeState,
+        expected: ChannelUpgradeState,
+    },
+    #[error(
+        "{} channel upgrade sequence {got} is invalid, it must be at least {expected}",
+        ContractErrorKind::from(self)
+    )]
+    ChannelUpgradeSequenceInvalid { got: u64, expected: u64 },
+    #[error(
+        "{} channel {channel_id} can't be upgraded while it has {in_flight} packet(s) in flight",
+        ContractErrorKind::from(self)
+    )]
+    ChannelUpgradeNotFlushed { channel_id: ChannelId, in_flight: u64 },
     #[error("sender is not a whitelisted relayer")]
     OnlyWhitelistedRelayer,
     #[error("sender is not the relayer admin")]
@@ -5,7 +5,9 @@ use depolama::{
     value::{ValueCodecViaEncoding, ValueUnitEncoding},
     KeyCodec, Prefix, Store, ValueCodec,
 };
-use ibc_union_spec::{Channel, ChannelId, ClientId, Connection, ConnectionId};
+use ibc_union_spec::{
+    Channel, ChannelId, ChannelOrder, ChannelUpgrade, ClientId, Connection, ConnectionId,
+};
 use unionlabs::{
     encoding::Bincode,
     primitives::{ByteArrayExt, Bytes, H256},
@@ -59,6 +61,20 @@ macro_rules! bytes_value {
     };
 }
 
+macro_rules! sequence_value {
+    ($ty:ty) => {
+        impl ValueCodec<u64> for $ty {
+            fn encode_value(value: &u64) -> Bytes {
+                value.to_be_bytes().into()
+            }
+
+            fn decode_value(raw: &Bytes) -> StdResult<u64> {
+                read_fixed_bytes(raw).map(u64::from_be_bytes)
+            }
+        }
+    };
+}
+
 macro_rules! addr_value {
     ($ty:ty) => {
         impl ValueCodec<Addr> for $ty {
@@ -106,6 +122,98 @@ impl ValueCodecViaEncoding for Channels {
     type Encoding = Bincode;
 }
 
+// Channels that are not in this store are unordered
+pub enum ChannelOrders {}
+impl Store for ChannelOrders {
+    const PREFIX: Prefix = Prefix::new(b"channel_orders");
+
+    type Key = ChannelId;
+    type Value = ChannelOrder;
+}
+id_key!(ChannelOrders);
+impl ValueCodec<ChannelOrder> for ChannelOrders {
+    fn encode_value(value: &ChannelOrder) -> Bytes {
+        [*value as u8].into()
+    }
+
+    fn decode_value(raw: &Bytes) -> StdResult<ChannelOrder> {
+        read_fixed_bytes(raw).and_then(|[ordering]| {
+            ChannelOrder::try_from(ordering)
+                .map_err(|e| StdError::generic_err(format!("invalid channel order: {}", e.0)))
+        })
+    }
+}
+
+// The sequence of the next packet sent on an ordered channel
+pub enum NextSequenceSend {}
+impl Store for NextSequenceSend {
+    const PREFIX: Prefix = Prefix::new(b"next_sequence_send");
+
+    type Key = ChannelId;
+    type Value = u64;
+}
+id_key!(NextSequenceSend);
+sequence_value!(NextSequenceSend);
+
+// The sequence of the next packet received on an ordered channel
+pub enum NextSequenceRecv {}
+impl Store for NextSequenceRecv {
+    const PREFIX: Prefix = Prefix::new(b"next_sequence_recv");
+
+    type Key = ChannelId;
+    type Value = u64;
+}
+id_key!(NextSequenceRecv);
+sequence_value!(NextSequenceRecv);
+
+// The latest upgrade of a channel
+pub enum ChannelUpgrades {}
+impl Store for ChannelUpgrades {
+    const PREFIX: Prefix = Prefix::new(b"channel_upgrades");
+
+    type Key = ChannelId;
+    type Value = ChannelUpgrade;
+}
+id_key!(ChannelUpgrades);
+impl ValueCodecViaEncoding for ChannelUpgrades {
+    type Encoding = Bincode;
+}
+
+// The last upgrade of a channel that was cancelled by the end that proposed it
+pub enum ChannelUpgradeErrors {}
+impl Store for ChannelUpgradeErrors {
+    const PREFIX: Prefix = Prefix::new(b"channel_upgrade_errors");
+
+    type Key = ChannelId;
+    type Value = ChannelUpgrade;
+}
+id_key!(ChannelUpgradeErrors);
+impl ValueCodecViaEncoding for ChannelUpgradeErrors {
+    type Encoding = Bincode;
+}
+
+// The sequence of the next upgrade of a channel
+pub enum NextUpgradeSequence {}
+impl Store for NextUpgradeSequence {
+    const PREFIX: Prefix = Prefix::new(b"next_upgrade_sequence");
+
+    type Key = ChannelId;
+    type Value = u64;
+}
+id_key!(NextUpgradeSequence);
+sequence_value!(NextUpgradeSequence);
+
+// The number of packets sent on a channel that have not been acknowledged or timed out yet
+pub enum ChannelInFlightPackets {}
+impl Store for ChannelInFlightPackets {
+    const PREFIX: Prefix = Prefix::new(b"channel_in_flight_packets");
+
+    type Key = ChannelId;
+    type Value = u64;
+}
+id_key!(ChannelInFlightPackets);
+sequence_value!(ChannelInFlightPackets);
+
 pub enum ContractChannels {}
 impl Store for ContractChannels {
     const PREFIX: Prefix = Prefix::new(b"contract_channels");
@@ -393,6 +501,54 @@ impl ValueCodecViaEncoding for WhitelistedRelayers {
     type Encoding = ValueUnitEncoding;
 }
 
+// Set if all traffic is paused
+pub enum Paused {}
+impl Store for Paused {
+    const PREFIX: Prefix = Prefix::new(b"paused");
+
+    type Key = ();
+    type Value = ();
+}
+impl ValueCodecViaEncoding for Paused {
+    type Encoding = ValueUnitEncoding;
+}
+
+pub enum PausedClients {}
+impl Store for PausedClients {
+    const PREFIX: Prefix = Prefix::new(b"paused_clients");
+
+    type Key = ClientId;
+    type Value = ();
+}
+id_key!(PausedClients);
+impl ValueCodecViaEncoding for PausedClients {
+    type Encoding = ValueUnitEncoding;
+}
+
+pub enum PausedConnections {}
+impl Store for PausedConnections {
+    const PREFIX: Prefix = Prefix::new(b"paused_connections");
+
+    type Key = ConnectionId;
+    type Value = ();
+}
+id_key!(PausedConnections);
+impl ValueCodecViaEncoding for PausedConnections {
+    type Encoding = ValueUnitEncoding;
+}
+
+pub enum PausedChannels {}
+impl Store for PausedChannels {
+    const PREFIX: Prefix = Prefix::new(b"paused_channels");
+
+    type Key = ChannelId;
+    type Value = ();
+}
+id_key!(PausedChannels);
+impl ValueCodecViaEncoding for PausedChannels {
+    type Encoding = ValueUnitEncoding;
+}
+
 fn read_fixed_bytes<const N: usize>(raw: &Bytes) -> StdResult<[u8; N]> {
     raw.try_into().map_err(|_| {
         StdError::generic_err(format!(
@@ -11,13 +11,14 @@ use ibc_union_msg::{
         MsgConnectionOpenInit, MsgConnectionOpenTry, MsgCreateClient, MsgRegisterClient,
     },
 };
-use ibc_union_spec::{ClientId, ConnectionId};
+use ibc_union_spec::{ChannelOrder, ClientId, ConnectionId};
 
 use super::*;
 
 mod channel;
 mod client;
 mod connection;
+mod pause;
 
 const CLIENT_TYPE: &str = "union";
 const CLIENT_ADDRESS: &str = "unionclient";
@@ -122,6 +123,7 @@ fn channel_open_init(deps: DepsMut) -> Result<Response, ContractError> {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps,
@@ -1,4 +1,5 @@
 use super::*;
 
 mod ibc_channel;
+mod ibc_channel_upgrade;
 mod ibc_packet;
@@ -53,6 +53,7 @@ fn channel_open_init_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     assert!(execute(
         deps.as_mut(),
@@ -183,6 +184,7 @@ fn channel_open_try_ok() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
 
     assert!(execute(
@@ -235,6 +237,7 @@ fn channel_open_try_invalid_state() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
 
     assert!(execute(
@@ -296,6 +299,7 @@ fn channel_open_try_channel_claimed() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -353,6 +357,7 @@ fn channel_open_try_commitment_saved() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -409,6 +414,7 @@ fn channel_open_ack_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -522,6 +528,7 @@ fn channel_open_ack_commitment_saved() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -602,6 +609,7 @@ fn channel_open_confirm_ok() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -668,6 +676,7 @@ fn channel_open_try_invalid_counterparty() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
     assert_eq!(
         execute(
@@ -771,6 +780,7 @@ fn channel_open_confirm_commitment_saved() {
         proof_init: vec![1, 2, 3].into(),
         proof_height: 1,
         relayer: mock_addr(RELAYER).into_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -0,0 +1,504 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, MockQuerier, MockStorage},
+    to_json_binary, OwnedDeps,
+};
+use depolama::StorageExt;
+use ibc_union_msg::{
+    lightclient::VerifyCreationResponse,
+    msg::{
+        InitMsg, MsgChannelUpgradeAck, MsgChannelUpgradeCancel, MsgChannelUpgradeConfirm,
+        MsgChannelUpgradeInit, MsgChannelUpgradeOpen, MsgChannelUpgradeTry,
+        MsgPacketAcknowledgement, MsgSendPacket,
+    },
+};
+use ibc_union_spec::{
+    path::ChannelUpgradePath, ChannelUpgrade, ChannelUpgradeState, MustBeZero, Packet,
+};
+use unionlabs::ethereum::keccak256;
+
+use super::*;
+use crate::{
+    contract::init,
+    state::{ChannelUpgrades, Channels, Commitments},
+};
+
+const UPGRADE_VERSION: &str = "version-2";
+const ADMIN: &str = "unionadmin";
+
+fn setup(deps: DepsMut) {
+    init(
+        deps,
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+}
+
+fn open_channel(mut deps: DepsMut) {
+    register_client(deps.branch()).expect("register client ok");
+    create_client(deps.branch()).expect("create client ok");
+    connection_open_try(deps.branch()).expect("connection open try is ok");
+    connection_open_confirm(deps.branch()).expect("connection open confirm is ok");
+    channel_open_init(deps.branch()).expect("channel open init is ok");
+    channel_open_ack(deps).expect("channel open ack is ok");
+}
+
+fn mock_light_client(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+                counterparty_chain_id: "testchain".to_owned(),
+                client_state_bytes: None,
+                events: vec![],
+                storage_writes: Default::default(),
+            }),
+            LightClientQueryMsg::VerifyMembership { .. } => to_json_binary(&()),
+            LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+            msg => panic!("should not be called: {:?}", msg),
+        }));
+}
+
+fn channel_upgrade_init(deps: DepsMut, sender: &str) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(sender), &[]),
+        ExecuteMsg::ChannelUpgradeInit(MsgChannelUpgradeInit {
+            channel_id: ChannelId!(1),
+            proposed_version: UPGRADE_VERSION.to_owned(),
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+fn channel_upgrade_try(deps: DepsMut, upgrade_sequence: u64) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(RELAYER), &[]),
+        ExecuteMsg::ChannelUpgradeTry(MsgChannelUpgradeTry {
+            channel_id: ChannelId!(1),
+            proposed_version: UPGRADE_VERSION.to_owned(),
+            upgrade_sequence,
+            proof_init: vec![1, 2, 3].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+fn channel_upgrade_ack(deps: DepsMut) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(RELAYER), &[]),
+        ExecuteMsg::ChannelUpgradeAck(MsgChannelUpgradeAck {
+            channel_id: ChannelId!(1),
+            proof_try: vec![1, 2, 3].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+fn channel_upgrade_open(deps: DepsMut) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(RELAYER), &[]),
+        ExecuteMsg::ChannelUpgradeOpen(MsgChannelUpgradeOpen {
+            channel_id: ChannelId!(1),
+            proof_channel: vec![1, 2, 3].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+fn channel_upgrade_cancel(deps: DepsMut, sender: &str) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(sender), &[]),
+        ExecuteMsg::ChannelUpgradeCancel(MsgChannelUpgradeCancel {
+            channel_id: ChannelId!(1),
+            proof_error: vec![1, 2, 3].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+fn send_packet(deps: DepsMut) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::PacketSend(MsgSendPacket {
+            source_channel_id: ChannelId!(1),
+            timeout_timestamp: Timestamp::from_nanos(2000000000000000000),
+            data: vec![1, 2, 3].into(),
+        }),
+    )
+}
+
+fn acknowledge_packet(deps: DepsMut) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::PacketAck(MsgPacketAcknowledgement {
+            packets: vec![Packet {
+                source_channel_id: ChannelId!(1),
+                destination_channel_id: ChannelId!(1),
+                data: vec![1, 2, 3].into(),
+                timeout_height: MustBeZero,
+                timeout_timestamp: Timestamp::from_nanos(2000000000000000000),
+            }],
+            acknowledgements: vec![vec![1, 2, 3].into()],
+            proof: vec![1].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+}
+
+#[test]
+fn channel_upgrade_init_ok() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+
+    let upgrade = ChannelUpgrade {
+        state: ChannelUpgradeState::Init,
+        version: UPGRADE_VERSION.to_owned(),
+        sequence: 1,
+    };
+    assert_eq!(
+        deps.storage
+            .read::<ChannelUpgrades>(&ChannelId!(1))
+            .unwrap(),
+        upgrade
+    );
+    assert_eq!(
+        deps.storage
+            .read::<Commitments>(
+                &ChannelUpgradePath {
+                    channel_id: ChannelId!(1)
+                }
+                .key()
+            )
+            .unwrap(),
+        keccak256(upgrade.commitment_preimage())
+    );
+    // the version is only changed once the counterparty has agreed to the upgrade
+    assert_eq!(
+        deps.storage
+            .read::<Channels>(&ChannelId!(1))
+            .unwrap()
+            .version,
+        VERSION
+    );
+}
+
+#[test]
+fn channel_upgrade_init_in_progress() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+
+    assert_eq!(
+        channel_upgrade_init(deps.as_mut(), SENDER),
+        Err(ContractError::ChannelUpgradeInProgress {
+            channel_id: ChannelId!(1)
+        })
+    );
+}
+
+#[test]
+fn channel_upgrade_init_unauthorized() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    assert_eq!(
+        channel_upgrade_init(deps.as_mut(), RELAYER),
+        Err(ContractError::Unauthorized {
+            channel_id: ChannelId!(1),
+            owner: mock_addr(SENDER),
+            caller: mock_addr(RELAYER),
+        })
+    );
+    assert!(deps
+        .storage
+        .maybe_read::<ChannelUpgrades>(&ChannelId!(1))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn channel_upgrade_init_and_cancel_by_admin() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), ADMIN).expect("channel upgrade init is ok");
+    channel_upgrade_cancel(deps.as_mut(), ADMIN).expect("channel upgrade cancel is ok");
+
+    assert!(deps
+        .storage
+        .maybe_read::<ChannelUpgrades>(&ChannelId!(1))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn channel_upgrade_ack_ok() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+    channel_upgrade_ack(deps.as_mut()).expect("channel upgrade ack is ok");
+
+    assert_eq!(
+        deps.storage
+            .read::<Channels>(&ChannelId!(1))
+            .unwrap()
+            .version,
+        UPGRADE_VERSION
+    );
+    assert_eq!(
+        deps.storage
+            .read::<ChannelUpgrades>(&ChannelId!(1))
+            .unwrap()
+            .state,
+        ChannelUpgradeState::Ack
+    );
+    // the upgrade is pending until it has been applied on the counterparty
+    assert_eq!(
+        channel_upgrade_init(deps.as_mut(), SENDER),
+        Err(ContractError::ChannelUpgradeInProgress {
+            channel_id: ChannelId!(1)
+        })
+    );
+    assert_eq!(
+        send_packet(deps.as_mut()),
+        Err(ContractError::ChannelUpgradeInProgress {
+            channel_id: ChannelId!(1)
+        })
+    );
+    assert_eq!(
+        channel_upgrade_cancel(deps.as_mut(), SENDER),
+        Err(ContractError::ChannelUpgradeInvalidState {
+            got: ChannelUpgradeState::Ack,
+            expected: ChannelUpgradeState::Init,
+        })
+    );
+}
+
+#[test]
+fn channel_upgrade_open_ok() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+    assert_eq!(
+        channel_upgrade_open(deps.as_mut()),
+        Err(ContractError::ChannelUpgradeInvalidState {
+            got: ChannelUpgradeState::Init,
+            expected: ChannelUpgradeState::Ack,
+        })
+    );
+    channel_upgrade_ack(deps.as_mut()).expect("channel upgrade ack is ok");
+    channel_upgrade_open(deps.as_mut()).expect("channel upgrade open is ok");
+
+    assert!(deps
+        .storage
+        .maybe_read::<ChannelUpgrades>(&ChannelId!(1))
+        .unwrap()
+        .is_none());
+    assert!(deps
+        .storage
+        .maybe_read::<Commitments>(
+            &ChannelUpgradePath {
+                channel_id: ChannelId!(1)
+            }
+            .key()
+        )
+        .unwrap()
+        .is_none());
+    send_packet(deps.as_mut()).expect("send packet is ok");
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+}
+
+#[test]
+fn channel_upgrade_send_packet_in_progress() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_try(deps.as_mut(), 1).expect("channel upgrade try is ok");
+
+    assert_eq!(
+        send_packet(deps.as_mut()),
+        Err(ContractError::ChannelUpgradeInProgress {
+            channel_id: ChannelId!(1)
+        })
+    );
+}
+
+#[test]
+fn channel_upgrade_ack_not_flushed() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    send_packet(deps.as_mut()).expect("send packet is ok");
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+
+    assert_eq!(
+        channel_upgrade_ack(deps.as_mut()),
+        Err(ContractError::ChannelUpgradeNotFlushed {
+            channel_id: ChannelId!(1),
+            in_flight: 1
+        })
+    );
+
+    acknowledge_packet(deps.as_mut()).expect("acknowledge packet is ok");
+    channel_upgrade_ack(deps.as_mut()).expect("channel upgrade ack is ok");
+}
+
+#[test]
+fn channel_upgrade_try_not_flushed() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    send_packet(deps.as_mut()).expect("send packet is ok");
+
+    assert_eq!(
+        channel_upgrade_try(deps.as_mut(), 1),
+        Err(ContractError::ChannelUpgradeNotFlushed {
+            channel_id: ChannelId!(1),
+            in_flight: 1
+        })
+    );
+
+    acknowledge_packet(deps.as_mut()).expect("acknowledge packet is ok");
+    channel_upgrade_try(deps.as_mut(), 1).expect("channel upgrade try is ok");
+}
+
+#[test]
+fn channel_upgrade_confirm_ok() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_try(deps.as_mut(), 1).expect("channel upgrade try is ok");
+    assert_eq!(
+        deps.storage
+            .read::<ChannelUpgrades>(&ChannelId!(1))
+            .unwrap()
+            .state,
+        ChannelUpgradeState::Try
+    );
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(RELAYER), &[]),
+        ExecuteMsg::ChannelUpgradeConfirm(MsgChannelUpgradeConfirm {
+            channel_id: ChannelId!(1),
+            proof_ack: vec![1, 2, 3].into(),
+            proof_height: 1,
+            relayer: mock_addr(RELAYER).into_string(),
+        }),
+    )
+    .expect("channel upgrade confirm is ok");
+
+    assert_eq!(
+        deps.storage
+            .read::<Channels>(&ChannelId!(1))
+            .unwrap()
+            .version,
+        UPGRADE_VERSION
+    );
+    assert!(deps
+        .storage
+        .maybe_read::<ChannelUpgrades>(&ChannelId!(1))
+        .unwrap()
+        .is_none());
+    assert!(deps
+        .storage
+        .maybe_read::<Commitments>(
+            &ChannelUpgradePath {
+                channel_id: ChannelId!(1)
+            }
+            .key()
+        )
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn channel_upgrade_try_sequence_reused() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_try(deps.as_mut(), 3).expect("channel upgrade try is ok");
+    channel_upgrade_cancel(deps.as_mut(), RELAYER).expect("channel upgrade cancel is ok");
+
+    assert_eq!(
+        channel_upgrade_try(deps.as_mut(), 3),
+        Err(ContractError::ChannelUpgradeSequenceInvalid {
+            got: 3,
+            expected: 4
+        })
+    );
+}
+
+#[test]
+fn channel_upgrade_cancel_unauthorized() {
+    let mut deps = mock_dependencies();
+    setup(deps.as_mut());
+    mock_light_client(&mut deps);
+    open_channel(deps.as_mut());
+
+    channel_upgrade_init(deps.as_mut(), SENDER).expect("channel upgrade init is ok");
+
+    assert_eq!(
+        channel_upgrade_cancel(deps.as_mut(), RELAYER),
+        Err(ContractError::Unauthorized {
+            channel_id: ChannelId!(1),
+            owner: mock_addr(SENDER),
+            caller: mock_addr(RELAYER),
+        })
+    );
+
+    channel_upgrade_cancel(deps.as_mut(), SENDER).expect("channel upgrade cancel is ok");
+    assert!(deps
+        .storage
+        .maybe_read::<ChannelUpgrades>(&ChannelId!(1))
+        .unwrap()
+        .is_none());
+}
@@ -1,4 +1,8 @@
-use cosmwasm_std::{testing::mock_dependencies, to_json_binary};
+use cosmwasm_std::{
+    testing::{mock_dependencies, MockQuerier, MockStorage},
+    to_json_binary, OwnedDeps,
+};
+use depolama::StorageExt;
 use ibc_union_msg::{
     lightclient::VerifyCreationResponse,
     msg::{
@@ -7,10 +11,17 @@ use ibc_union_msg::{
         MsgWriteAcknowledgement,
     },
 };
-use ibc_union_spec::{MustBeZero, Packet};
+use ibc_union_spec::{
+    path::{ordered_packet_commitment, ordered_packet_sequence, BatchPacketsPath},
+    MustBeZero, Packet,
+};
+use unionlabs::primitives::H256;
 
 use super::*;
-use crate::contract::init;
+use crate::{
+    contract::init,
+    state::{Channels, Commitments, NextSequenceRecv, NextSequenceSend},
+};
 
 #[test]
 fn send_packet_ok() {
@@ -539,6 +550,7 @@ fn acknowledge_packet_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -639,6 +651,7 @@ fn acknowledge_packet_tampered() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -739,6 +752,7 @@ fn acknowledge_packet_not_sent() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -980,6 +994,7 @@ fn write_acknowledgement_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1089,6 +1104,7 @@ fn write_acknowledgement_module_is_not_channel_owner() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1194,6 +1210,7 @@ fn write_acknowledgement_packet_not_received() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1282,6 +1299,7 @@ fn write_acknowledgement_already_exists() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1408,6 +1426,7 @@ fn batch_send_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1525,6 +1544,7 @@ fn batch_send_packet_not_sent() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1620,6 +1640,7 @@ fn batch_acks_ok() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1788,6 +1809,7 @@ fn batch_acks_packet_not_received() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -1884,6 +1906,7 @@ fn batch_acks_tampered_packet() {
         connection_id: ConnectionId!(1),
         version: VERSION.to_owned(),
         relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Unordered,
     };
     execute(
         deps.as_mut(),
@@ -2011,3 +2034,290 @@ fn batch_acks_tampered_packet() {
     )
     .is_err_and(|err| { matches!(err, ContractError::PacketCommitmentNotFound) }))
 }
+
+/// Mocks a light client that only accepts membership proofs of ordered packet commitments for
+/// `sequence`, as if the counterparty committed the proven packets at that sequence.
+fn ordered_query_handler(sequence: u64) -> impl Fn(&WasmQuery) -> QuerierResult + 'static {
+    move |msg| match msg {
+        WasmQuery::Smart { msg, .. } => {
+            let res = match from_json::<LightClientQueryMsg>(msg).unwrap() {
+                LightClientQueryMsg::VerifyCreation { .. } => {
+                    to_json_binary(&VerifyCreationResponse {
+                        counterparty_chain_id: "testchain".to_owned(),
+                        events: vec![],
+                        storage_writes: Default::default(),
+                        client_state_bytes: None,
+                    })
+                }
+                LightClientQueryMsg::VerifyMembership { value, .. } => {
+                    match H256::try_from(&*value)
+                        .ok()
+                        .and_then(|value| ordered_packet_sequence(&value))
+                    {
+                        Some(proven) if proven != sequence => {
+                            return QuerierResult::Ok(cosmwasm_std::ContractResult::Err(
+                                "invalid proof".to_owned(),
+                            ))
+                        }
+                        _ => to_json_binary(&()),
+                    }
+                }
+                LightClientQueryMsg::VerifyNonMembership { .. } => to_json_binary(&()),
+                LightClientQueryMsg::GetTimestamp { .. } => to_json_binary(&100000),
+                LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+                msg => panic!("should not be called: {:?}", msg),
+            };
+            QuerierResult::Ok(cosmwasm_std::ContractResult::Ok(res.unwrap()))
+        }
+        _ => panic!("Only smart queries should be possible now. Adjust this based on your needs."),
+    }
+}
+
+/// Opens the ordered channel 1.
+fn ordered_channel_open(deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier>) {
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    connection_open_try(deps.as_mut()).expect("connection open try is ok");
+    connection_open_confirm(deps.as_mut()).expect("connection open confirm is ok");
+    let msg = MsgChannelOpenInit {
+        port_id: mock_addr(SENDER).to_string(),
+        counterparty_port_id: vec![1].into(),
+        connection_id: ConnectionId!(1),
+        version: VERSION.to_owned(),
+        relayer: mock_addr(RELAYER).to_string(),
+        ordering: ChannelOrder::Ordered,
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::ChannelOpenInit(msg),
+    )
+    .expect("channel open init is ok");
+    channel_open_ack(deps.as_mut()).expect("channel open ack is ok");
+}
+
+fn ordered_packet(data: Vec<u8>, timeout_timestamp: Timestamp) -> Packet {
+    Packet {
+        source_channel_id: ChannelId!(1),
+        destination_channel_id: ChannelId!(1),
+        data: data.into(),
+        timeout_height: MustBeZero,
+        timeout_timestamp,
+    }
+}
+
+#[test]
+fn send_packet_ordered_assigns_sequence() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    deps.querier.update_wasm(ordered_query_handler(1));
+    ordered_channel_open(&mut deps);
+
+    for (data, sequence) in [(vec![1], 1), (vec![2], 2)] {
+        let msg = MsgSendPacket {
+            source_channel_id: ChannelId!(1),
+            timeout_timestamp: Timestamp::from_nanos(2000000000000000000),
+            data: data.clone().into(),
+        };
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&mock_addr(SENDER), &[]),
+            ExecuteMsg::PacketSend(msg),
+        )
+        .expect("send packet ok");
+
+        assert!(res.events[0]
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "sequence" && attr.value == sequence.to_string()));
+        assert_eq!(
+            deps.storage.maybe_read::<Commitments>(
+                &BatchPacketsPath::from_packets(&[ordered_packet(
+                    data,
+                    Timestamp::from_nanos(2000000000000000000)
+                )])
+                .key()
+            ),
+            Ok(Some(ordered_packet_commitment(sequence)))
+        );
+    }
+
+    assert_eq!(deps.storage.read::<NextSequenceSend>(&ChannelId!(1)), Ok(3));
+}
+
+#[test]
+fn recv_packet_ordered_ok() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    deps.querier.update_wasm(ordered_query_handler(1));
+    ordered_channel_open(&mut deps);
+
+    let msg = MsgPacketRecv {
+        packets: vec![ordered_packet(
+            vec![1, 2, 3],
+            Timestamp::from_nanos(2000000000000000000),
+        )],
+        relayer_msgs: vec![vec![1].into()],
+        relayer: mock_addr(RELAYER).to_string(),
+        proof: vec![1, 2, 3].into(),
+        proof_height: 1,
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::PacketRecv(msg),
+    )
+    .expect("recv packet ok");
+
+    assert_eq!(deps.storage.read::<NextSequenceRecv>(&ChannelId!(1)), Ok(2));
+}
+
+#[test]
+fn recv_packet_ordered_out_of_order() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    // the packet was committed at sequence 2, but sequence 1 has not been received yet
+    deps.querier.update_wasm(ordered_query_handler(2));
+    ordered_channel_open(&mut deps);
+
+    let msg = MsgPacketRecv {
+        packets: vec![ordered_packet(
+            vec![1, 2, 3],
+            Timestamp::from_nanos(2000000000000000000),
+        )],
+        relayer_msgs: vec![vec![1].into()],
+        relayer: mock_addr(RELAYER).to_string(),
+        proof: vec![1, 2, 3].into(),
+        proof_height: 1,
+    };
+    assert!(matches!(
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&mock_addr(SENDER), &[]),
+            ExecuteMsg::PacketRecv(msg),
+        ),
+        Err(ContractError::CannotQueryLightClient { .. })
+    ));
+    assert_eq!(
+        deps.storage.maybe_read::<NextSequenceRecv>(&ChannelId!(1)),
+        Ok(None)
+    );
+}
+
+#[test]
+fn recv_intent_packet_ordered() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    deps.querier.update_wasm(ordered_query_handler(1));
+    ordered_channel_open(&mut deps);
+
+    let msg = MsgIntentPacketRecv {
+        packets: vec![ordered_packet(
+            vec![1, 2, 3],
+            Timestamp::from_nanos(2000000000000000000),
+        )],
+        market_maker_msgs: vec![vec![1].into()],
+        market_maker: mock_addr(RELAYER).into_string(),
+    };
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&mock_addr(SENDER), &[]),
+            ExecuteMsg::IntentPacketRecv(msg),
+        ),
+        Err(ContractError::IntentOnOrderedChannel {
+            channel_id: ChannelId!(1)
+        })
+    );
+}
+
+#[test]
+fn timeout_packet_ordered_closes_channel() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    let mut env = mock_env();
+    env.block.time = cosmwasm_std::Timestamp::from_nanos(32);
+    let timeout_timestamp = Timestamp::from_nanos(64);
+
+    deps.querier.update_wasm(ordered_query_handler(1));
+    ordered_channel_open(&mut deps);
+
+    for data in [vec![1], vec![2]] {
+        let msg = MsgSendPacket {
+            source_channel_id: ChannelId!(1),
+            timeout_timestamp,
+            data: data.into(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&mock_addr(SENDER), &[]),
+            ExecuteMsg::PacketSend(msg),
+        )
+        .expect("send packet ok");
+    }
+
+    for data in [vec![1], vec![2]] {
+        let msg = MsgPacketTimeout {
+            packet: ordered_packet(data, timeout_timestamp),
+            proof: vec![1].into(),
+            proof_height: 11,
+            relayer: mock_addr(RELAYER).into_string(),
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&mock_addr(SENDER), &[]),
+            ExecuteMsg::PacketTimeout(msg),
+        )
+        .expect("timeout packet ok");
+
+        // the first timeout closes the channel, the remaining packets can still be timed out
+        assert_eq!(
+            deps.storage
+                .read::<Channels>(&ChannelId!(1))
+                .map(|channel| channel.state),
+            Ok(ChannelState::Closed)
+        );
+    }
+}
@@ -4,14 +4,22 @@ use cosmwasm_std::{
 };
 use depolama::StorageExt;
 use ibc_union_msg::{
-    lightclient::{QueryMsg as LightClientQueryMsg, UpdateStateResponse, VerifyCreationResponse},
-    msg::{ExecuteMsg, InitMsg, MsgUpdateClient},
+    lightclient::{
+        MisbehaviourResponse, QueryMsg as LightClientQueryMsg, RecoverClientResponse,
+        UpdateStateResponse, VerifyCreationResponse,
+    },
+    msg::{ExecuteMsg, InitMsg, MsgRecoverClient, MsgSubmitMisbehaviour, MsgUpdateClient},
 };
 
+use ibc_union_spec::path::{ClientStatePath, ConsensusStatePath};
+use unionlabs::ethereum::keccak256;
+
 use super::*;
 use crate::{
     contract::{events, execute, init},
-    state::{ClientConsensusStates, ClientImpls, ClientRegistry, ClientStates, ClientTypes},
+    state::{
+        ClientConsensusStates, ClientImpls, ClientRegistry, ClientStates, ClientTypes, Commitments,
+    },
     ContractError,
 };
 
@@ -19,6 +27,7 @@ const CLIENT_TYPE: &str = "union";
 const CLIENT_ADDRESS: &str = "unionclient";
 const SENDER: &str = "unionsender";
 const RELAYER: &str = "unionrelayer";
+const ADMIN: &str = "unionadmin";
 
 fn new_client_registered_event(client_type: &str, client_address: &Addr) -> Event {
     Event::new(events::client::REGISTER)
@@ -342,3 +351,394 @@ fn update_client_commitments_saved() {
         vec![3, 2, 1]
     );
 }
+
+fn misbehaviour_query_handler(status: Status) -> impl Fn(&WasmQuery) -> QuerierResult + 'static {
+    wasm_query_handler(move |msg| match msg {
+        LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+            counterparty_chain_id: "testchain".to_owned(),
+            events: vec![],
+            storage_writes: Default::default(),
+            client_state_bytes: None,
+        }),
+        LightClientQueryMsg::Misbehaviour { .. } => to_json_binary(&MisbehaviourResponse {
+            client_state: vec![0, 0, 0].into(),
+        }),
+        LightClientQueryMsg::UpdateState { .. } => to_json_binary(&UpdateStateResponse {
+            height: 2,
+            consensus_state_bytes: vec![3, 2, 1].into(),
+            client_state_bytes: Some(vec![3, 2, 1].into()),
+            storage_writes: Default::default(),
+        }),
+        LightClientQueryMsg::GetStatus { .. } => to_json_binary(&status),
+        LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+        msg => panic!("should not be called: {:?}", msg),
+    })
+}
+
+fn submit_misbehaviour(
+    deps: DepsMut,
+    sender: &str,
+    client_id: ClientId,
+) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(sender), &[]),
+        ExecuteMsg::SubmitMisbehaviour(MsgSubmitMisbehaviour {
+            client_id,
+            client_message: vec![4, 5, 6].into(),
+            relayer: mock_addr(sender).into_string(),
+        }),
+    )
+}
+
+#[test]
+fn submit_misbehaviour_ok() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Active));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    // submitting misbehaviour is permissionless
+    let res = submit_misbehaviour(deps.as_mut(), "fisherman", ClientId!(1))
+        .expect("submit misbehaviour ok");
+
+    assert!(res.events.into_iter().any(|e| e
+        == Event::new(events::client::MISBEHAVIOUR)
+            .add_attribute(events::attribute::CLIENT_ID, "1")
+            .add_attribute(events::attribute::CLIENT_TYPE, CLIENT_TYPE)));
+
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![0, 0, 0]
+    );
+}
+
+#[test]
+fn submit_misbehaviour_commitment_saved() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Active));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    submit_misbehaviour(deps.as_mut(), SENDER, ClientId!(1)).expect("submit misbehaviour ok");
+
+    assert_eq!(
+        deps.storage
+            .read::<Commitments>(
+                &ClientStatePath {
+                    client_id: ClientId!(1)
+                }
+                .key()
+            )
+            .unwrap(),
+        keccak256([0_u8, 0, 0])
+    );
+}
+
+#[test]
+fn submit_misbehaviour_frozen_client() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Active));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    submit_misbehaviour(deps.as_mut(), SENDER, ClientId!(1)).expect("submit misbehaviour ok");
+
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Frozen));
+
+    assert_eq!(
+        submit_misbehaviour(deps.as_mut(), SENDER, ClientId!(1)),
+        Err(ContractError::ClientNotActive {
+            client_id: ClientId!(1),
+            status: Status::Frozen
+        })
+    );
+}
+
+#[test]
+fn update_client_frozen_client() {
+    let mut deps = mock_dependencies();
+
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: None,
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Active));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    submit_misbehaviour(deps.as_mut(), SENDER, ClientId!(1)).expect("submit misbehaviour ok");
+
+    deps.querier
+        .update_wasm(misbehaviour_query_handler(Status::Frozen));
+
+    let msg = ExecuteMsg::UpdateClient(MsgUpdateClient {
+        client_id: ClientId!(1),
+        client_message: vec![3, 2, 1].into(),
+        relayer: mock_addr(RELAYER).into_string(),
+    });
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&mock_addr(SENDER), &[]),
+            msg
+        ),
+        Err(ContractError::ClientNotActive {
+            client_id: ClientId!(1),
+            status: Status::Frozen
+        })
+    );
+
+    // the frozen client state is kept
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![0, 0, 0]
+    );
+}
+
+fn recover_client_query_handler(
+    subject_status: Status,
+    substitute_height: u64,
+) -> impl Fn(&WasmQuery) -> QuerierResult + 'static {
+    wasm_query_handler(move |msg| match msg {
+        LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+            counterparty_chain_id: "testchain".to_owned(),
+            events: vec![],
+            storage_writes: Default::default(),
+            client_state_bytes: None,
+        }),
+        LightClientQueryMsg::RecoverClient { .. } => to_json_binary(&RecoverClientResponse {
+            client_state: vec![4, 5, 6].into(),
+            consensus_state: vec![6, 5, 4].into(),
+            height: 5,
+        }),
+        LightClientQueryMsg::GetStatus { client_id } if client_id == ClientId!(1) => {
+            to_json_binary(&subject_status)
+        }
+        LightClientQueryMsg::GetStatus { .. } => to_json_binary(&Status::Active),
+        LightClientQueryMsg::GetLatestHeight { client_id } if client_id == ClientId!(2) => {
+            to_json_binary(&substitute_height)
+        }
+        LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+        msg => panic!("should not be called: {:?}", msg),
+    })
+}
+
+fn recover_client(
+    deps: DepsMut,
+    sender: &str,
+    subject: ClientId,
+    substitute: ClientId,
+) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(sender), &[]),
+        ExecuteMsg::RecoverClient(MsgRecoverClient {
+            subject,
+            substitute,
+        }),
+    )
+}
+
+#[test]
+fn recover_client_ok() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(recover_client_query_handler(Status::Expired, 5));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    let res = recover_client(deps.as_mut(), ADMIN, ClientId!(1), ClientId!(2))
+        .expect("recover client ok");
+
+    assert!(res.events.into_iter().any(|e| e
+        == Event::new(events::client::RECOVER)
+            .add_attribute(events::attribute::CLIENT_ID, "1")
+            .add_attribute(events::attribute::SUBSTITUTE_CLIENT_ID, "2")
+            .add_attribute(events::attribute::CLIENT_TYPE, CLIENT_TYPE)
+            .add_attribute(events::attribute::COUNTERPARTY_HEIGHT, "5")));
+
+    assert_eq!(
+        deps.storage.read::<ClientStates>(&ClientId!(1)).unwrap(),
+        vec![4, 5, 6]
+    );
+    assert_eq!(
+        deps.storage
+            .read::<ClientConsensusStates>(&(ClientId!(1), 5))
+            .unwrap(),
+        vec![6, 5, 4]
+    );
+    assert_eq!(
+        deps.storage
+            .read::<Commitments>(
+                &ClientStatePath {
+                    client_id: ClientId!(1)
+                }
+                .key()
+            )
+            .unwrap(),
+        keccak256([4_u8, 5, 6])
+    );
+    assert_eq!(
+        deps.storage
+            .read::<Commitments>(
+                &ConsensusStatePath {
+                    client_id: ClientId!(1),
+                    height: 5
+                }
+                .key()
+            )
+            .unwrap(),
+        keccak256([6_u8, 5, 4])
+    );
+}
+
+#[test]
+fn recover_client_only_admin() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(recover_client_query_handler(Status::Frozen, 5));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(deps.as_mut(), SENDER, ClientId!(1), ClientId!(2)),
+        Err(ContractError::OnlyRelayerAdmin)
+    );
+}
+
+#[test]
+fn recover_client_active_subject() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(recover_client_query_handler(Status::Active, 5));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(deps.as_mut(), ADMIN, ClientId!(1), ClientId!(2)),
+        Err(ContractError::CannotRecoverActiveClient {
+            client_id: ClientId!(1)
+        })
+    );
+}
+
+#[test]
+fn recover_client_with_itself() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(recover_client_query_handler(Status::Frozen, 5));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(deps.as_mut(), ADMIN, ClientId!(1), ClientId!(1)),
+        Err(ContractError::RecoverClientWithItself)
+    );
+}
+
+#[test]
+fn recover_client_substitute_not_ahead() {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .unwrap();
+    deps.querier
+        .update_wasm(recover_client_query_handler(Status::Expired, 1));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+
+    assert_eq!(
+        recover_client(deps.as_mut(), ADMIN, ClientId!(1), ClientId!(2)),
+        Err(ContractError::RecoverClientSubstituteHeightTooLow {
+            subject_height: 1,
+            substitute_height: 1
+        })
+    );
+}
@@ -0,0 +1,184 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, MockQuerier, MockStorage},
+    to_json_binary, Deps, OwnedDeps,
+};
+use ibc_union_msg::{
+    lightclient::VerifyCreationResponse,
+    msg::{InitMsg, MsgPacketRecv, PauseScope},
+    query::QueryMsg,
+};
+use ibc_union_spec::{MustBeZero, Packet, Timestamp};
+
+use super::*;
+use crate::contract::{init, query};
+
+const ADMIN: &str = "unionadmin";
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    init(
+        deps.as_mut(),
+        InitMsg {
+            relayers_admin: Some(mock_addr(ADMIN).to_string()),
+            relayers: vec![mock_addr(SENDER).to_string()],
+        },
+    )
+    .expect("init is ok");
+    deps.querier
+        .update_wasm(wasm_query_handler(|msg| match msg {
+            LightClientQueryMsg::VerifyCreation { .. } => to_json_binary(&VerifyCreationResponse {
+                counterparty_chain_id: "testchain".to_owned(),
+                events: vec![],
+                storage_writes: Default::default(),
+                client_state_bytes: None,
+            }),
+            LightClientQueryMsg::VerifyMembership { .. } => to_json_binary(&()),
+            LightClientQueryMsg::GetLatestHeight { .. } => to_json_binary(&1),
+            msg => panic!("should not be called: {:?}", msg),
+        }));
+
+    register_client(deps.as_mut()).expect("register client ok");
+    create_client(deps.as_mut()).expect("create client ok");
+    connection_open_try(deps.as_mut()).expect("connection open try is ok");
+    connection_open_confirm(deps.as_mut()).expect("connection open confirm is ok");
+    channel_open_init(deps.as_mut()).expect("channel open init is ok");
+    channel_open_ack(deps.as_mut()).expect("channel open ack is ok");
+
+    deps
+}
+
+fn set_paused(
+    deps: DepsMut,
+    sender: &str,
+    scope: PauseScope,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(sender), &[]),
+        if paused {
+            ExecuteMsg::Pause(scope)
+        } else {
+            ExecuteMsg::Unpause(scope)
+        },
+    )
+}
+
+fn recv_packet(deps: DepsMut) -> Result<Response, ContractError> {
+    execute(
+        deps,
+        mock_env(),
+        message_info(&mock_addr(SENDER), &[]),
+        ExecuteMsg::PacketRecv(MsgPacketRecv {
+            packets: vec![Packet {
+                source_channel_id: ChannelId!(2),
+                destination_channel_id: ChannelId!(1),
+                data: vec![1, 2, 3].into(),
+                timeout_height: MustBeZero,
+                timeout_timestamp: Timestamp::from_nanos(2000000000000000000),
+            }],
+            relayer_msgs: vec![vec![1].into()],
+            relayer: mock_addr(RELAYER).to_string(),
+            proof: vec![1, 2, 3].into(),
+            proof_height: 1,
+        }),
+    )
+}
+
+fn is_paused(deps: Deps, scope: PauseScope) -> bool {
+    from_json(query(deps, mock_env(), QueryMsg::IsPaused { scope }).unwrap()).unwrap()
+}
+
+#[test]
+fn pause_only_admin() {
+    let mut deps = setup();
+
+    assert_eq!(
+        set_paused(deps.as_mut(), SENDER, PauseScope::Global, true),
+        Err(ContractError::OnlyRelayerAdmin)
+    );
+    assert!(!is_paused(deps.as_ref(), PauseScope::Global));
+}
+
+#[test]
+fn pause_channel_halts_recv() {
+    let mut deps = setup();
+
+    set_paused(
+        deps.as_mut(),
+        ADMIN,
+        PauseScope::Channel(ChannelId!(1)),
+        true,
+    )
+    .expect("pause is ok");
+
+    assert!(is_paused(deps.as_ref(), PauseScope::Channel(ChannelId!(1))));
+    assert!(!is_paused(
+        deps.as_ref(),
+        PauseScope::Connection(ConnectionId!(1))
+    ));
+    assert_eq!(
+        recv_packet(deps.as_mut()),
+        Err(ContractError::Paused {
+            scope: PauseScope::Channel(ChannelId!(1))
+        })
+    );
+
+    set_paused(
+        deps.as_mut(),
+        ADMIN,
+        PauseScope::Channel(ChannelId!(1)),
+        false,
+    )
+    .expect("unpause is ok");
+
+    recv_packet(deps.as_mut()).expect("recv is ok");
+}
+
+#[test]
+fn pause_client_halts_channel() {
+    let mut deps = setup();
+
+    set_paused(deps.as_mut(), ADMIN, PauseScope::Client(ClientId!(1)), true).expect("pause is ok");
+
+    assert!(is_paused(deps.as_ref(), PauseScope::Channel(ChannelId!(1))));
+    assert_eq!(
+        recv_packet(deps.as_mut()),
+        Err(ContractError::Paused {
+            scope: PauseScope::Client(ClientId!(1))
+        })
+    );
+    assert_eq!(
+        connection_open_try(deps.as_mut()),
+        Err(ContractError::Paused {
+            scope: PauseScope::Client(ClientId!(1))
+        })
+    );
+}
+
+#[test]
+fn pause_global_halts_all() {
+    let mut deps = setup();
+
+    set_paused(deps.as_mut(), ADMIN, PauseScope::Global, true).expect("pause is ok");
+    // unpausing an enclosed scope doesn't resume traffic halted by the global pause
+    set_paused(
+        deps.as_mut(),
+        ADMIN,
+        PauseScope::Channel(ChannelId!(1)),
+        false,
+    )
+    .expect("unpause is ok");
+
+    assert_eq!(
+        recv_packet(deps.as_mut()),
+        Err(ContractError::Paused {
+            scope: PauseScope::Global
+        })
+    );
+
+    set_paused(deps.as_mut(), ADMIN, PauseScope::Global, false).expect("unpause is ok");
+
+    recv_packet(deps.as_mut()).expect("recv is ok");
+}
@@ -71,7 +71,9 @@ impl IbcClient for ArbitrumLightClient {
         header: Self::Header,
         _relayer: Addr,
     ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
-        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+        let ClientState::V2(mut client_state) = ctx.read_self_client_state()? else {
+            return Err(Error::ClientStateNotMigrated.into());
+        };
         let l1_consensus_state = ctx
             .read_consensus_state::<EthereumLightClient>(
                 client_state.l1_client_id,
@@ -79,7 +81,7 @@ impl IbcClient for ArbitrumLightClient {
             )
             .map_err(Into::<Error>::into)?;
 
-        arbitrum_verifier::verify_header_v1(&client_state, &header, l1_consensus_state.state_root)
+        arbitrum_verifier::verify_header_v2(&client_state, &header, l1_consensus_state.state_root)
             .map_err(Error::HeaderVerify)?;
 
         let consensus_state = ConsensusState {
@@ -99,7 +101,7 @@ impl IbcClient for ArbitrumLightClient {
 
         if client_state.latest_height < new_latest_height {
             client_state.latest_height = new_latest_height;
-            Ok(state_update.overwrite_client_state(ClientState::V1(client_state)))
+            Ok(state_update.overwrite_client_state(ClientState::V2(client_state)))
         } else {
             Ok(state_update)
         }
@@ -114,13 +116,15 @@ impl IbcClient for ArbitrumLightClient {
         Err(Error::Unimplemented.into())
     }
 
-    fn status(
-        ctx: IbcClientCtx<Self>,
-        ClientState::V1(client_state): &Self::ClientState,
-    ) -> Status {
+    fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
         let _ = ctx;
 
-        if client_state.frozen_height.height() != 0 {
+        let frozen_height = match client_state {
+            ClientState::V1(v1) => v1.frozen_height,
+            ClientState::V2(v2) => v2.frozen_height,
+        };
+
+        if frozen_height.height() != 0 {
             Status::Frozen
         } else {
             Status::Active
@@ -140,11 +144,17 @@ impl IbcClient for ArbitrumLightClient {
         consensus_state.timestamp
     }
 
-    fn get_latest_height(ClientState::V1(client_state): &Self::ClientState) -> u64 {
-        client_state.latest_height
+    fn get_latest_height(client_state: &Self::ClientState) -> u64 {
+        match client_state {
+            ClientState::V1(v1) => v1.latest_height,
+            ClientState::V2(v2) => v2.latest_height,
+        }
     }
 
-    fn get_counterparty_chain_id(ClientState::V1(client_state): &Self::ClientState) -> String {
-        client_state.chain_id.to_string()
+    fn get_counterparty_chain_id(client_state: &Self::ClientState) -> String {
+        match client_state {
+            ClientState::V1(v1) => v1.chain_id.to_string(),
+            ClientState::V2(v2) => v2.chain_id.to_string(),
+        }
     }
 }
@@ -1,10 +1,21 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, Response, StdResult};
+use std::num::NonZeroU32;
+
+use arbitrum_light_client_types::FinalityPolicy;
+use cosmwasm_std::{
+    entry_point, wasm_execute, Binary, Deps, DepsMut, Env, Response, StdError, StdResult,
+};
+use depolama::StorageExt;
 use frissitheto::UpgradeMsg;
 use ibc_union_light_client::{
     msg::{InitMsg, QueryMsg},
+    read_client_state, read_consensus_state,
+    spec::ClientId,
+    state::IbcHost,
     IbcClientError,
 };
+use ibc_union_msg::msg::MsgMigrateState;
 use serde::{Deserialize, Serialize};
+use unionlabs::encoding::{Bincode, EncodeAs, EthAbi};
 
 use crate::client::ArbitrumLightClient;
 
@@ -14,7 +25,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MigrateMsg {}
+pub enum MigrateMsg {
+    V1ToV2 {
+        clients_to_migrate: Vec<ClientId>,
+        #[serde(default)]
+        finality_policy: FinalityPolicy,
+    },
+}
 
 #[entry_point]
 pub fn migrate(
@@ -29,6 +46,62 @@ pub fn migrate(
 
             Ok((res, None))
         },
-        |_deps, _migrate_msg, _current_version| Ok((Response::default(), None)),
+        |deps, migrate_msg, current_version| match (migrate_msg, current_version.get()) {
+            (
+                MigrateMsg::V1ToV2 {
+                    clients_to_migrate,
+                    finality_policy,
+                },
+                current_version @ (1 | 2),
+            ) => {
+                let ibc_host = deps.storage.read_item::<IbcHost>()?;
+                let mut migrate_state_msgs = Vec::new();
+                for client_id in clients_to_migrate {
+                    let arbitrum_light_client_types::ClientState::V1(v1) =
+                        read_client_state(&*deps.querier, &ibc_host, client_id)?
+                    else {
+                        return Err(StdError::generic_err(format!(
+                            "expected client state v1 for v1 to v2 migration, client={}",
+                            client_id
+                        ))
+                        .into());
+                    };
+                    let latest_height = v1.latest_height;
+                    let consensus_state = read_consensus_state::<ArbitrumLightClient>(
+                        &*deps.querier,
+                        &ibc_host,
+                        client_id,
+                        latest_height,
+                    )?;
+                    migrate_state_msgs.push(wasm_execute(
+                        &ibc_host,
+                        &ibc_union_msg::msg::ExecuteMsg::MigrateState(MsgMigrateState {
+                            client_id,
+                            client_state: arbitrum_light_client_types::ClientState::V2(
+                                v1.into_v2(finality_policy),
+                            )
+                            .encode_as::<Bincode>()
+                            .into(),
+                            consensus_state: consensus_state.encode_as::<EthAbi>().into(),
+                            height: latest_height,
+                        }),
+                        vec![],
+                    )?);
+                }
+                Ok((
+                    Response::default().add_messages(migrate_state_msgs),
+                    if current_version == 1 {
+                        Some(NonZeroU32::new(2).expect("impossible"))
+                    } else {
+                        None
+                    },
+                ))
+            }
+            (msg, version) => Err(StdError::generic_err(format!(
+                "unknown migration, message={:?}, version={}",
+                msg, version
+            ))
+            .into()),
+        },
     )
 }
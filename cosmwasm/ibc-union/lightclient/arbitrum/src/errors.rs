@@ -34,6 +34,9 @@ pub enum Error {
     #[error("the operation has not been implemented yet")]
     Unimplemented,
 
+    #[error("the client state must be migrated to the latest version before it can be updated")]
+    ClientStateNotMigrated,
+
     #[error(transparent)]
     EvmIbcClient(#[from] IbcClientError<EthereumLightClient>),
 }
@@ -71,6 +71,7 @@ impl IbcClient for BobLightClient {
         match client_state {
             ClientState::V1(v1) => v1.latest_height,
             ClientState::V2(v2) => v2.latest_height,
+            ClientState::V3(v3) => v3.latest_height,
         }
     }
 
@@ -78,6 +79,7 @@ impl IbcClient for BobLightClient {
         match client_state {
             ClientState::V1(v1) => v1.chain_id.to_string(),
             ClientState::V2(v2) => v2.chain_id.to_string(),
+            ClientState::V3(v3) => v3.chain_id.to_string(),
         }
     }
 
@@ -105,8 +107,8 @@ impl IbcClient for BobLightClient {
         header: Self::Header,
         _relayer: Addr,
     ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
-        let ClientState::V2(mut client_state) = ctx.read_self_client_state()? else {
-            panic!("impossible")
+        let ClientState::V3(mut client_state) = ctx.read_self_client_state()? else {
+            return Err(Error::ClientStateNotMigrated.into());
         };
 
         let l1_consensus_state = ctx
@@ -119,6 +121,14 @@ impl IbcClient for BobLightClient {
         bob_verifier::verify_header(&client_state, &header, l1_consensus_state.state_root)
             .map_err(Into::<Error>::into)?;
 
+        bob_verifier::verify_finality(
+            &client_state,
+            &header,
+            l1_consensus_state.state_root,
+            l1_consensus_state.timestamp.as_secs(),
+        )
+        .map_err(Into::<Error>::into)?;
+
         let update_height = header.l2_header.number.try_into().expect("impossible");
 
         let consensus_state = ConsensusState {
@@ -131,7 +141,7 @@ impl IbcClient for BobLightClient {
 
         if client_state.latest_height < update_height {
             client_state.latest_height = update_height;
-            Ok(state_update.overwrite_client_state(ClientState::V2(client_state)))
+            Ok(state_update.overwrite_client_state(ClientState::V3(client_state)))
         } else {
             Ok(state_update)
         }
@@ -1,5 +1,6 @@
 use std::num::NonZeroU32;
 
+use bob_light_client_types::FinalityPolicy;
 use cosmwasm_std::{
     entry_point, wasm_execute, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
     StdResult,
@@ -39,6 +40,11 @@ pub enum MigrateMsg {
         dispute_game_factory_dispute_game_list_slot: U256,
         fault_dispute_game_code_root_claim_index: u32,
     },
+    V2ToV3 {
+        clients_to_migrate: Vec<ClientId>,
+        #[serde(default)]
+        finality_policy: FinalityPolicy,
+    },
 }
 
 #[entry_point]
@@ -101,7 +107,8 @@ pub fn migrate(
                                 },
                             ))?;
                         }
-                        bob_light_client_types::ClientState::V2(_) => {
+                        bob_light_client_types::ClientState::V2(_)
+                        | bob_light_client_types::ClientState::V3(_) => {
                             return Err(StdError::generic_err(format!(
                                 "unexpected client state v2 for v1 to v2 migration, client={}",
                                 client_id
@@ -119,6 +126,56 @@ pub fn migrate(
                     },
                 ))
             }
+            (
+                MigrateMsg::V2ToV3 {
+                    clients_to_migrate,
+                    finality_policy,
+                },
+                current_version @ (2 | 3),
+            ) => {
+                let ibc_host = deps.storage.read_item::<IbcHost>()?;
+                let mut migrate_state_msgs = Vec::new();
+                for client_id in clients_to_migrate {
+                    let bob_light_client_types::ClientState::V2(v2) =
+                        read_client_state(&*deps.querier, &ibc_host, client_id)?
+                    else {
+                        return Err(StdError::generic_err(format!(
+                            "expected client state v2 for v2 to v3 migration, client={}",
+                            client_id
+                        ))
+                        .into());
+                    };
+                    let latest_height = v2.latest_height;
+                    let consensus_state = read_consensus_state::<BobLightClient>(
+                        &*deps.querier,
+                        &ibc_host,
+                        client_id,
+                        latest_height,
+                    )?;
+                    migrate_state_msgs.push(wasm_execute(
+                        &ibc_host,
+                        &ibc_union_msg::msg::ExecuteMsg::MigrateState(MsgMigrateState {
+                            client_id,
+                            client_state: bob_light_client_types::ClientState::V3(
+                                v2.into_v3(finality_policy),
+                            )
+                            .encode_as::<Bincode>()
+                            .into(),
+                            consensus_state: consensus_state.encode_as::<EthAbi>().into(),
+                            height: latest_height,
+                        }),
+                        vec![],
+                    )?);
+                }
+                Ok((
+                    Response::default().add_messages(migrate_state_msgs),
+                    if current_version == 2 {
+                        Some(NonZeroU32::new(3).expect("impossible"))
+                    } else {
+                        None
+                    },
+                ))
+            }
             (msg, version) => Err(StdError::generic_err(format!(
                 "unknown migration, message={:?}, version={}",
                 msg, version
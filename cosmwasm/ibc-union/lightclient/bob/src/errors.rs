@@ -8,6 +8,9 @@ pub enum Error {
     #[error("unimplemented")]
     Unimplemented,
 
+    #[error("the client state must be migrated to the latest version before it can be updated")]
+    ClientStateNotMigrated,
+
     #[error(transparent)]
     Verify(#[from] bob_verifier::Error),
 
@@ -7,7 +7,7 @@ use ibc_union_light_client::{
 };
 use ics23::ibc_api::SDK_SPECS;
 use tendermint_light_client::verifier::Ed25519Verifier;
-use tendermint_light_client_types::{ConsensusState, Header};
+use tendermint_light_client_types::{ConsensusState, Header, Misbehaviour};
 use tendermint_verifier::types::SignatureVerifier;
 use unionlabs::{
     encoding::Bincode, ethereum::ibc_commitment_key,
@@ -25,8 +25,7 @@ impl IbcClient for EthermintLightClient {
 
     type Header = Header;
 
-    // TODO(aeryz): Change this to appropriate misbehavior type when it is implemented
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -141,12 +140,46 @@ impl IbcClient for EthermintLightClient {
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        Err(Error::from(tendermint_light_client::errors::Error::Unimplemented).into())
+        let mut client_state = ctx.read_self_client_state()?;
+        let consensus_state_a =
+            ctx.read_self_consensus_state(misbehaviour.header_a.trusted_height.height())?;
+        let consensus_state_b =
+            ctx.read_self_consensus_state(misbehaviour.header_b.trusted_height.height())?;
+        match misbehaviour
+            .header_a
+            .validator_set
+            .validators
+            .first()
+            .map(|v| &v.pub_key)
+        {
+            Some(PublicKey::Ed25519(_)) => {
+                tendermint_light_client::client::verify_misbehaviour(
+                    &client_state.tendermint_client_state,
+                    consensus_state_a,
+                    consensus_state_b,
+                    misbehaviour,
+                    ctx.env.block.time,
+                    &SignatureVerifier::new(Ed25519Verifier::new(ctx.deps)),
+                )
+                .map_err(Error::from)?;
+            }
+            _ => {
+                return Err(Error::from(
+                    tendermint_light_client::errors::Error::InvalidValidatorSet,
+                )
+                .into())
+            }
+        }
+
+        client_state.tendermint_client_state.frozen_height =
+            Some(tendermint_light_client::client::FROZEN_HEIGHT);
+
+        Ok(client_state)
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
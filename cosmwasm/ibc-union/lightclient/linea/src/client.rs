@@ -1,285 +1,202 @@
-use cosmwasm_std::{Deps, DepsMut, Env};
-use ethereum_light_client::client::{canonicalize_stored_value, check_commitment_key};
+use cosmwasm_std::{Addr, Empty};
+use ethereum_light_client::client::EthereumLightClient;
 use gnark_mimc::new_mimc_constants_bls12_377;
-use ics008_wasm_client::{
-    storage_utils::{
-        read_client_state, read_consensus_state, save_client_state, save_consensus_state,
-        update_client_state,
-    },
-    IbcClient, IbcClientError, Status, StorageState,
-};
-use linea_light_client_types::{
-    client_state::ClientState, consensus_state::ConsensusState, header::Header,
+use ibc_union_light_client::{
+    spec::{Status, Timestamp},
+    ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
+use linea_light_client_types::{ClientState, ConsensusState, Header};
+use linea_types::{account::ZkAccount, proof::MerkleProof};
 use unionlabs::{
-    cosmwasm::wasm::union::custom_query::{query_consensus_state, UnionCustomQuery},
-    encoding::{DecodeAs, Proto},
-    ethereum::keccak256,
-    ibc::{
-        core::{
-            client::{genesis_metadata::GenesisMetadata, height::Height},
-            commitment::merkle_path::MerklePath,
-        },
-        lightclients::{ethereum, wasm},
-    },
-    linea::{
-        account::ZkAccount,
-        proof::{InclusionProof, NonInclusionProof},
-    },
+    encoding::Bincode,
+    ethereum::ibc_commitment_key,
     primitives::{H256, U256},
 };
 
 use crate::errors::Error;
 
-type WasmClientState = wasm::client_state::ClientState<ClientState>;
-type WasmConsensusState = wasm::consensus_state::ConsensusState<ConsensusState>;
-type WasmL1ConsensusState =
-    wasm::consensus_state::ConsensusState<ethereum::consensus_state::ConsensusState>;
-
 pub struct LineaLightClient;
 
 impl IbcClient for LineaLightClient {
     type Error = Error;
 
-    type CustomQuery = UnionCustomQuery;
-
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = ();
 
     type ClientState = ClientState;
 
     type ConsensusState = ConsensusState;
 
-    type Encoding = Proto;
+    type Encoding = Bincode;
 
-    fn verify_membership(
-        deps: Deps<Self::CustomQuery>,
-        height: Height,
-        _delay_time_period: u64,
-        _delay_block_period: u64,
-        proof: Vec<u8>,
-        mut path: MerklePath,
-        value: ics008_wasm_client::StorageState,
-    ) -> Result<(), IbcClientError<Self>> {
-        let consensus_state: WasmConsensusState =
-            read_consensus_state(deps, &height)?.ok_or(Error::ConsensusStateNotFound(height))?;
-        let client_state: WasmClientState = read_client_state(deps)?;
-
-        let path = path.key_path.pop().ok_or(Error::EmptyIbcPath)?;
-
-        // This storage root is verified during the header update, so we don't need to verify it again.
-        let storage_root = consensus_state.data.ibc_storage_root;
-
-        match value {
-            StorageState::Occupied(value) => {
-                let inclusion_proof = InclusionProof::decode_as::<Proto>(&proof)
-                    .map_err(Error::InclusionProofDecode)?;
-                do_verify_membership(path, storage_root, inclusion_proof, value)?
-            }
-            StorageState::Empty => {
-                let noninclusion_proof = NonInclusionProof::decode_as::<Proto>(&proof)
-                    .map_err(Error::InclusionProofDecode)?;
-                do_verify_non_membership(path, storage_root, noninclusion_proof)?
-            }
-        }
+    type CustomQuery = Empty;
 
-        Ok(())
-    }
+    type StorageProof = MerkleProof;
 
-    fn verify_header(
-        deps: Deps<Self::CustomQuery>,
-        env: Env,
-        header: Self::Header,
+    fn verify_membership(
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
+        value: Vec<u8>,
     ) -> Result<(), IbcClientError<Self>> {
-        let client_state: WasmClientState = read_client_state(deps)?;
-        let l1_consensus_state = query_consensus_state::<WasmL1ConsensusState>(
-            deps,
-            &env,
-            client_state.data.l1_client_id.clone(),
-            header.l1_height,
-        )
-        .map_err(Error::CustomQuery)?;
-        linea_verifier::verify_header(
-            client_state.data,
-            header,
-            l1_consensus_state.data.state_root,
-        )
-        .map_err(Error::Verify)?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        verify_membership(key, consensus_state.ibc_storage_root, storage_proof, value)?;
         Ok(())
     }
 
-    fn verify_misbehaviour(
-        _deps: Deps<Self::CustomQuery>,
-        _env: Env,
-        _misbehaviour: Self::Misbehaviour,
-    ) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
-    }
-
-    fn update_state(
-        mut deps: DepsMut<Self::CustomQuery>,
-        _env: Env,
-        header: Self::Header,
-    ) -> Result<Vec<Height>, IbcClientError<Self>> {
-        let mut client_state: WasmClientState = read_client_state(deps.as_ref())?;
-
-        let updated_height = Height {
-            revision_number: client_state.latest_height.revision_number,
-            revision_height: header.l1_height.revision_height,
-        };
-
-        if client_state.latest_height < header.l1_height {
-            client_state.data.l1_latest_height = updated_height;
-            update_client_state::<Self>(
-                deps.branch(),
-                client_state,
-                updated_height.revision_height,
-            );
-        }
-
-        // Guaranteed to succeed as we previously verified the header
-        let zk_account =
-            ZkAccount::decode(header.l2_ibc_contract_proof.proof.value).expect("impossible");
-
-        let consensus_state = WasmConsensusState {
-            data: ConsensusState {
-                ibc_storage_root: zk_account.storage_root,
-                // must be nanos
-                timestamp: 1_000_000_000
-                    * u64::try_from(header.l2_timestamp_proof.value).expect("impossible"),
-            },
-        };
-        save_consensus_state::<Self>(deps, consensus_state, &updated_height);
-        Ok(vec![updated_height])
-    }
-
-    fn update_state_on_misbehaviour(
-        deps: DepsMut<Self::CustomQuery>,
-        env: Env,
-        _client_message: Vec<u8>,
+    fn verify_non_membership(
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
     ) -> Result<(), IbcClientError<Self>> {
-        let mut client_state: WasmClientState = read_client_state(deps.as_ref())?;
-        client_state.data.frozen_height = Height {
-            revision_number: client_state.latest_height.revision_number,
-            revision_height: env.block.height,
-        };
-        save_client_state::<Self>(deps, client_state);
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        verify_non_membership(key, consensus_state.ibc_storage_root, storage_proof)?;
         Ok(())
     }
 
-    fn check_for_misbehaviour_on_header(
-        _deps: Deps<Self::CustomQuery>,
-        _header: Self::Header,
-    ) -> Result<bool, IbcClientError<Self>> {
-        Ok(false)
+    fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
+        consensus_state.timestamp
     }
 
-    fn check_for_misbehaviour_on_misbehaviour(
-        _deps: Deps<Self::CustomQuery>,
-        _misbehaviour: Self::Misbehaviour,
-    ) -> Result<bool, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn get_latest_height(ClientState::V1(client_state): &Self::ClientState) -> u64 {
+        client_state.latest_height
     }
 
-    fn verify_upgrade_and_update_state(
-        _deps: DepsMut<Self::CustomQuery>,
-        _upgrade_client_state: Self::ClientState,
-        _upgrade_consensus_state: Self::ConsensusState,
-        _proof_upgrade_client: Vec<u8>,
-        _proof_upgrade_consensus_state: Vec<u8>,
-    ) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn get_counterparty_chain_id(ClientState::V1(client_state): &Self::ClientState) -> String {
+        client_state.chain_id.to_string()
     }
 
-    fn migrate_client_store(_deps: DepsMut<Self::CustomQuery>) -> Result<(), IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+    fn status(
+        _ctx: IbcClientCtx<Self>,
+        ClientState::V1(client_state): &Self::ClientState,
+    ) -> Status {
+        if client_state.frozen_height != 0 {
+            Status::Frozen
+        } else {
+            Status::Active
+        }
     }
 
-    fn status(deps: Deps<Self::CustomQuery>, _env: &Env) -> Result<Status, IbcClientError<Self>> {
-        let client_state: WasmClientState = read_client_state(deps)?;
-
-        if client_state.data.frozen_height != Height::default() {
-            return Ok(Status::Frozen);
-        }
+    fn verify_creation(
+        _caller: Addr,
+        _client_state: &Self::ClientState,
+        _consensus_state: &Self::ConsensusState,
+        _relayer: Addr,
+    ) -> Result<ClientCreationResult<Self>, IbcClientError<Self>> {
+        Ok(ClientCreationResult::new())
+    }
 
-        let Some(_) = read_consensus_state::<Self>(deps, &client_state.latest_height)? else {
-            return Ok(Status::Expired);
+    fn verify_header(
+        ctx: IbcClientCtx<Self>,
+        _caller: Addr,
+        header: Self::Header,
+        _relayer: Addr,
+    ) -> Result<StateUpdate<Self>, IbcClientError<Self>> {
+        let ClientState::V1(mut client_state) = ctx.read_self_client_state()?;
+
+        let l1_consensus_state = ctx
+            .read_consensus_state::<EthereumLightClient>(
+                client_state.l1_client_id,
+                header.l1_height,
+            )
+            .map_err(Into::<Error>::into)?;
+
+        linea_verifier::verify_header(&client_state, &header, l1_consensus_state.state_root)
+            .map_err(Into::<Error>::into)?;
+
+        // the account has been verified to be part of the l2 state root above
+        let ibc_contract_account = ZkAccount::decode(&header.l2_ibc_contract_proof.proof.value)
+            .map_err(Error::InvalidL2IbcContractAccount)?;
+
+        let update_height = u64::try_from(header.l2_block_number_proof.value)
+            .map_err(|_| Error::L2BlockNumberOverflow(header.l2_block_number_proof.value))?;
+
+        let timestamp = u64::try_from(header.l2_timestamp_proof.value)
+            .map_err(|_| Error::L2TimestampOverflow(header.l2_timestamp_proof.value))?;
+
+        let consensus_state = ConsensusState {
+            state_root: H256::from(header.l2_state_root_proof.value.to_be_bytes()),
+            ibc_storage_root: ibc_contract_account.storage_root,
+            timestamp: Timestamp::from_secs(timestamp),
         };
 
-        Ok(Status::Active)
-    }
+        let state_update = StateUpdate::new(update_height, consensus_state);
 
-    fn export_metadata(
-        _deps: Deps<Self::CustomQuery>,
-        _env: &Env,
-    ) -> Result<Vec<GenesisMetadata>, IbcClientError<Self>> {
-        Ok(Vec::new())
+        if client_state.latest_height < update_height {
+            client_state.latest_height = update_height;
+            Ok(state_update.overwrite_client_state(ClientState::V1(client_state)))
+        } else {
+            Ok(state_update)
+        }
     }
 
-    fn timestamp_at_height(
-        deps: Deps<Self::CustomQuery>,
-        height: Height,
-    ) -> Result<u64, IbcClientError<Self>> {
-        Ok(read_consensus_state::<Self>(deps, &height)?
-            .ok_or(Error::ConsensusStateNotFound(height))?
-            .data
-            .timestamp)
+    fn misbehaviour(
+        _ctx: IbcClientCtx<Self>,
+        _caller: Addr,
+        _misbehaviour: Self::Misbehaviour,
+        _relayer: Addr,
+    ) -> Result<Self::ClientState, IbcClientError<Self>> {
+        // headers are only accepted if they are finalized on the L1, hence misbehaviour can only be
+        // proven against the client tracking the L1
+        Err(Error::Unimplemented.into())
     }
 }
 
-fn do_verify_membership(
-    path: String,
+/// Verifies that `value` is committed at `key` in the sparse merkle trie storage of the IBC
+/// contract.
+pub fn verify_membership(
+    key: Vec<u8>,
     storage_root: H256,
-    storage_proof: InclusionProof,
-    raw_value: Vec<u8>,
+    storage_proof: MerkleProof,
+    value: Vec<u8>,
 ) -> Result<(), Error> {
-    // TODO: handle error
-    let key = U256::try_from_be_bytes(&storage_proof.key).unwrap();
+    let key = ibc_commitment_key(
+        H256::try_from(&key).map_err(|_| Error::InvalidCommitmentKeyLength(key))?,
+    );
 
-    check_commitment_key(&path, key)?;
+    let value = H256::try_from(&value).map_err(|_| Error::InvalidCommitmentValueLength(value))?;
 
-    // we store the hash of the data, not the data itself to the commitments map
-    let expected_value_hash = keccak256(canonicalize_stored_value(path, raw_value)?);
+    let proof_value = linea_zktrie::verify::verify::<U256>(
+        &new_mimc_constants_bls12_377(),
+        &storage_proof,
+        storage_root,
+        key,
+    )?
+    .ok_or(Error::CommitmentNotFound(key))?;
 
-    // TODO: handle error
-    let proof_value = H256::new(storage_proof.proof.value.clone().try_into().unwrap());
+    let proof_value = H256::from(proof_value.to_be_bytes());
 
-    if expected_value_hash != proof_value {
+    if value != proof_value {
         return Err(Error::StoredValueMismatch {
-            expected: expected_value_hash,
+            expected: value,
             stored: proof_value,
         });
     }
 
-    linea_zktrie::verify::verify_inclusion_and_key::<U256>(
-        &new_mimc_constants_bls12_377(),
-        storage_proof.leaf_index,
-        &storage_proof.proof,
-        storage_root,
-        key,
-    )?;
-
     Ok(())
 }
 
-/// Verifies that no value is committed at `path` in the counterparty light client's storage.
-fn do_verify_non_membership(
-    path: String,
+/// Verifies that no value is committed at `key` in the sparse merkle trie storage of the IBC
+/// contract.
+pub fn verify_non_membership(
+    key: Vec<u8>,
     storage_root: H256,
-    noninclusion_proof: NonInclusionProof,
+    storage_proof: MerkleProof,
 ) -> Result<(), Error> {
-    // TODO: handle error
-    let key = U256::try_from_be_bytes(&noninclusion_proof.key).unwrap();
-
-    check_commitment_key(&path, key)?;
+    let key = ibc_commitment_key(
+        H256::try_from(&key).map_err(|_| Error::InvalidCommitmentKeyLength(key))?,
+    );
 
-    linea_zktrie::verify::verify_noninclusion::<U256>(
+    match linea_zktrie::verify::verify::<U256>(
         &new_mimc_constants_bls12_377(),
-        &noninclusion_proof,
+        &storage_proof,
         storage_root,
         key,
-    )?;
-
-    Ok(())
+    )? {
+        Some(_) => Err(Error::CounterpartyStorageNotNil(key)),
+        None => Ok(()),
+    }
 }
@@ -1,16 +1,18 @@
 use cosmwasm_std::{Addr, Empty};
 use ibc_union_light_client::{
-    spec::Timestamp, ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
+    spec::{Status, Timestamp},
+    ClientCreationResult, IbcClient, IbcClientCtx, IbcClientError, StateUpdate,
 };
-use ibc_union_msg::lightclient::Status;
 use movement_light_client_types::{
     client_state::ClientState, consensus_state::ConsensusState, header::Header,
+    misbehaviour::Misbehaviour,
 };
 use unionlabs::{
     aptos::{
         account::AccountAddress, storage_proof::StorageProof, transaction_info::TransactionInfo,
     },
     encoding::Bincode,
+    ibc::core::client::height::Height,
     primitives::{H256, U256},
 };
 
@@ -32,7 +34,7 @@ impl IbcClient for MovementLightClient {
 
     type Header = Header;
 
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -43,32 +45,49 @@ impl IbcClient for MovementLightClient {
     type Encoding = Bincode;
 
     fn verify_membership(
-        _ctx: IbcClientCtx<Self>,
-        _height: u64,
-        _key: Vec<u8>,
-        _storage_proof: Self::StorageProof,
-        _value: Vec<u8>,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
+        value: Vec<u8>,
     ) -> Result<(), IbcClientError<Self>> {
-        // let client_state = ctx.read_self_client_state()?;
-        // let consensus_state = ctx.read_self_consensus_state(height)?;
-        // verify_membership(
-        //     &key,
-        //     consensus_state.state_root,
-        //     client_state.table_handle,
-        //     storage_proof,
-        //     &value,
-        // )
-        // .map_err(Into::into)
-        Ok(())
+        // the storage proofs are served by the same custom node as the header proofs, see the
+        // note in `verify_header`
+        if !cfg!(feature = "union-movement") {
+            return Err(Error::ProofVerificationDisabled.into());
+        }
+
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        verify_membership(
+            &key,
+            consensus_state.state_root,
+            client_state.table_handle,
+            storage_proof,
+            &value,
+        )
+        .map_err(Into::into)
     }
 
     fn verify_non_membership(
-        _ctx: IbcClientCtx<Self>,
-        _height: u64,
-        _key: Vec<u8>,
-        _storage_proof: Self::StorageProof,
+        ctx: IbcClientCtx<Self>,
+        height: u64,
+        key: Vec<u8>,
+        storage_proof: Self::StorageProof,
     ) -> Result<(), IbcClientError<Self>> {
-        unimplemented!()
+        if !cfg!(feature = "union-movement") {
+            return Err(Error::ProofVerificationDisabled.into());
+        }
+
+        let client_state = ctx.read_self_client_state()?;
+        let consensus_state = ctx.read_self_consensus_state(height)?;
+        verify_non_membership(
+            &key,
+            consensus_state.state_root,
+            client_state.table_handle,
+            storage_proof,
+        )
+        .map_err(Into::into)
     }
 
     fn get_timestamp(consensus_state: &Self::ConsensusState) -> Timestamp {
@@ -109,90 +128,157 @@ impl IbcClient for MovementLightClient {
         _relayer: Addr,
     ) -> Result<StateUpdate<Self>, ibc_union_light_client::IbcClientError<Self>> {
         let client_state = ctx.read_self_client_state()?;
-        // Check if caller is whitelisted
-        if !client_state
-            .whitelisted_relayers
-            .contains(&caller.to_string())
-        {
-            return Err(IbcClientError::UnauthorizedCaller(caller.to_string()));
-        }
 
-        // NOTE(aeryz): FOR AUDITORS and NERDS:
-        // Movement's current REST API's don't provide state and transaction proofs. We added those to our custom
-        // Movement node which we also work on getting them to be upstreamed. Hence, we use the following feature-flag with
-        // a custom setup.
-        // Also see the related PR: https://github.com/movementlabsxyz/movement/pull/645
-
-        #[cfg(feature = "union-movement")]
-        {
-            aptos_verifier::verify_tx_state(
-                &header.tx_proof,
-                *header
-                    .state_proof
-                    .latest_ledger_info()
-                    .commit_info
-                    .executed_state_id
-                    .get(),
-                header.tx_index,
-            )
-            .map_err(Into::<Error>::into)?;
-
-            // TODO(aeryz): make sure the given state_proof_hash_proof.key matches the correct slot
-
-            let l1_consensus_state =
-                ctx.read_consensus_state(client_state.l1_client_id, header.l1_height)?;
-
-            let expected_commitment = BlockCommitment {
-                height: header.new_height.into(),
-                commitment: U256::from_be_bytes(header.state_proof.hash()),
-                // TODO(aeryz): check if hash here is big endian
-                block_id: U256::from_be_bytes(
-                    header
-                        .state_proof
-                        .latest_ledger_info()
-                        .commit_info
-                        .id
-                        .into(),
-                ),
-            };
-
-            evm_storage_verifier::verify_account_storage_root(
-                l1_consensus_state.state_root,
-                &client_state.l1_contract_address,
-                &header.settlement_contract_proof.proof,
-                &header.settlement_contract_proof.storage_root,
-            )
-            .unwrap();
-
-            evm_storage_verifier::verify_storage_proof(
-                header.settlement_contract_proof.storage_root,
-                header.state_proof_hash_proof.key,
-                &rlp::encode(&expected_commitment),
-                &header.state_proof_hash_proof.proof,
-            )
-            .unwrap();
-        }
+        ensure_whitelisted(&client_state, &caller)?;
+
+        verify_header(&ctx, &client_state, &header)?;
+
         update_state(client_state, header).map_err(Into::into)
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        unimplemented!()
+        let mut client_state = ctx.read_self_client_state()?;
+
+        let Misbehaviour { header_a, header_b } = misbehaviour;
+
+        if header_a.new_height != header_b.new_height {
+            return Err(Error::MisbehaviourHeightMismatch {
+                height_a: header_a.new_height,
+                height_b: header_b.new_height,
+            }
+            .into());
+        }
+
+        verify_header(&ctx, &client_state, &header_a)?;
+        verify_header(&ctx, &client_state, &header_b)?;
+
+        if state_root(&header_a)? == state_root(&header_b)? {
+            return Err(Error::MisbehaviourNotFound.into());
+        }
+
+        client_state.frozen_height = Height::new(header_a.new_height);
+
+        Ok(client_state)
     }
 }
 
+fn ensure_whitelisted(
+    client_state: &ClientState,
+    caller: &Addr,
+) -> Result<(), IbcClientError<MovementLightClient>> {
+    if !client_state
+        .whitelisted_relayers
+        .contains(&caller.to_string())
+    {
+        return Err(IbcClientError::UnauthorizedCaller(caller.to_string()));
+    }
+
+    Ok(())
+}
+
+fn verify_header(
+    ctx: &IbcClientCtx<MovementLightClient>,
+    client_state: &ClientState,
+    header: &Header,
+) -> Result<(), IbcClientError<MovementLightClient>> {
+    // the state checkpoint of the proven transaction is used as the state root of the consensus
+    // state, hence it must be the last transaction of the ledger info that is committed to the L1,
+    // otherwise any earlier state could be proven at the new height
+    let version = header.state_proof.latest_ledger_info().commit_info.version;
+    if header.tx_index != version {
+        return Err(Error::TransactionNotLatest {
+            tx_index: header.tx_index,
+            version,
+        }
+        .into());
+    }
+
+    // NOTE(aeryz): FOR AUDITORS and NERDS:
+    // Movement's current REST API's don't provide state and transaction proofs. We added those to our custom
+    // Movement node which we also work on getting them to be upstreamed. Hence, we use the following feature-flag with
+    // a custom setup.
+    // Also see the related PR: https://github.com/movementlabsxyz/movement/pull/645
+    //
+    // Without the feature none of the proofs can be verified, hence every header is rejected
+    // instead of being trusted blindly.
+    if !cfg!(feature = "union-movement") {
+        return Err(Error::ProofVerificationDisabled.into());
+    }
+
+    aptos_verifier::verify_tx_state(
+        &header.tx_proof,
+        *header
+            .state_proof
+            .latest_ledger_info()
+            .commit_info
+            .executed_state_id
+            .get(),
+        header.tx_index,
+    )
+    .map_err(Into::<Error>::into)?;
+
+    // TODO(aeryz): make sure the given state_proof_hash_proof.key matches the correct slot
+
+    let l1_consensus_state = ctx
+        .read_consensus_state::<ethereum_light_client::client::EthereumLightClient>(
+            client_state.l1_client_id,
+            header.l1_height,
+        )
+        .map_err(Into::<Error>::into)?;
+
+    let expected_commitment = BlockCommitment {
+        height: header.new_height.into(),
+        commitment: U256::from_be_bytes(header.state_proof.hash()),
+        // TODO(aeryz): check if hash here is big endian
+        block_id: U256::from_be_bytes(
+            header
+                .state_proof
+                .latest_ledger_info()
+                .commit_info
+                .id
+                .into(),
+        ),
+    };
+
+    evm_storage_verifier::verify_account_storage_root(
+        l1_consensus_state.state_root,
+        &client_state.l1_contract_address,
+        &header.settlement_contract_proof.proof,
+        &header.settlement_contract_proof.storage_root,
+    )
+    .map_err(|_| Error::InvalidStateProof)?;
+
+    evm_storage_verifier::verify_storage_proof(
+        header.settlement_contract_proof.storage_root,
+        header.state_proof_hash_proof.key,
+        &rlp::encode(&expected_commitment),
+        &header.state_proof_hash_proof.proof,
+    )
+    .map_err(|_| Error::InvalidStateProof)?;
+
+    Ok(())
+}
+
+fn state_root(header: &Header) -> Result<H256, Error> {
+    let TransactionInfo::V0(tx_info) = &header.tx_proof.transaction_info;
+
+    tx_info
+        .state_checkpoint_hash
+        .map(|hash| H256::new(*hash.get()))
+        .ok_or(Error::MissingStateCheckpoint)
+}
+
 fn update_state(
     mut client_state: ClientState,
     header: Header,
 ) -> Result<StateUpdate<MovementLightClient>, Error> {
-    let TransactionInfo::V0(tx_info) = header.tx_proof.transaction_info;
-
     let consensus_state = ConsensusState {
-        state_root: H256::new(*tx_info.state_checkpoint_hash.unwrap().get()), // TODO(aeryz): we always need this, no need to make this not an option
+        state_root: state_root(&header)?,
         timestamp: Timestamp::from_nanos(
             header
                 .state_proof
@@ -214,7 +300,6 @@ fn update_state(
     }
 }
 
-// #[cfg(feature = "union-movement")]
 pub fn verify_membership(
     path: &[u8],
     state_root: H256,
@@ -256,6 +341,26 @@ pub fn verify_membership(
     )?)
 }
 
+pub fn verify_non_membership(
+    path: &[u8],
+    state_root: H256,
+    table_handle: AccountAddress,
+    proof: StorageProof,
+) -> Result<(), Error> {
+    if proof.state_value.is_some() {
+        return Err(Error::NonMembershipProofWithValue);
+    }
+
+    let key =
+        aptos_verifier::hash_table_key(&bcs::to_bytes(path).expect("cannot fail"), &table_handle);
+
+    Ok(aptos_verifier::verify_non_existence_proof(
+        proof.proof,
+        state_root.into(),
+        key,
+    )?)
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(rename = "StateValue")]
 enum PersistedStateValue {
@@ -282,12 +387,200 @@ pub enum PersistedStateValueMetadata {
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Binary, ContractResult, SystemResult,
+    };
+    use ethereum_light_client_types::AccountProof;
     use hex_literal::hex;
+    use ibc_union_light_client::spec::ClientId;
     use unionlabs::{
-        encoding::{DecodeAs, Proto},
+        aptos::{
+            ledger_info::LedgerInfoWithSignatures,
+            sparse_merkle_proof::{SparseMerkleLeafNode, SparseMerkleProof},
+            state_proof::StateProof,
+            storage_proof::StateValue,
+            transaction_proof::TransactionInfoWithProof,
+        },
+        encoding::{DecodeAs, EncodeAs, Proto},
         ibc::core::channel::channel::Channel,
+        primitives::H160,
     };
 
+    use super::*;
+
+    const TABLE_HANDLE: AccountAddress = AccountAddress(H256::new(hex!(
+        "be769b7536776eb353a61aa4d26de32ee16844d89d8cc2ede29732e3d19407ea"
+    )));
+
+    const STATE_ROOT: H256 = H256::new(hex!(
+        "02388da3aee85236d64e272fec0b1a6fcd4962986327971faef9ee2951a4ad6a"
+    ));
+
+    /// Proof of the table item at path `ABCD` in `TABLE_HANDLE`, taken from a movement devnet.
+    fn proof() -> SparseMerkleProof {
+        SparseMerkleProof {
+            leaf: Some(SparseMerkleLeafNode {
+                key: hex!("f2d067d8ef7e97deb231d46f40f9f30200e6f1dad495d33e2a7911825a97ad14")
+                    .into(),
+                value_hash: hex!(
+                    "40414333f8109f8cb971c67c9eca3c0049e21e6c5e28551f1a4975c96ab15212"
+                )
+                .into(),
+            }),
+            siblings: [
+                hex!("fafdceaec25fd64517ce3745992467dfac306a5ce59e63255da5b9f58d1417ea"),
+                hex!("4480c449082954642653a4570c7cb2ea2114d79b61621b94f095f25d640b6e27"),
+                hex!("0fc055434d70262945d428a5eda3d8396aa960c65ee8c4e79bd20638a95e7a31"),
+                hex!("731e28eb6655e01b8714aa72f76a0f468c330b46eb9c21816a88e56840896f24"),
+                hex!("b120265e60289e6e44216efd4f3fba86a8de645d3eb7912ff09812024c639b2f"),
+                hex!("d90e0a63c7c3cf7ed000841a85f981d8c6bec4c23353822204c7c9e9c5dee4db"),
+                hex!("30b21a8a3bf202b5fe18e415c299fd3b9985462a6292fffdabd5b32fbc27ba30"),
+                hex!("5350415253455f4d45524b4c455f504c414345484f4c4445525f484153480000"),
+                hex!("5b9096922002407577b4e46e6466aadb15cbb9521fd0e9847d474398ea3736e2"),
+                hex!("5350415253455f4d45524b4c455f504c414345484f4c4445525f484153480000"),
+                hex!("884f8b72a832aa718c6590d0bfeb1ec85b546611b6c07f8df563827974ad8134"),
+            ]
+            .map(Into::into)
+            .to_vec(),
+        }
+    }
+
+    #[test]
+    fn membership_value_mismatch() {
+        let err = verify_membership(
+            b"ABCD",
+            STATE_ROOT,
+            TABLE_HANDLE,
+            StorageProof {
+                state_value: Some(StateValue::V0(vec![1, 2, 3])),
+                proof: proof(),
+            },
+            &[4, 5, 6],
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::ProofValueMismatch(..)));
+    }
+
+    #[test]
+    fn non_membership() {
+        // the key of this path shares the first 11 bits with the key of `ABCD`, hence falls into
+        // the subtree that only contains `ABCD`
+        verify_non_membership(
+            b"ABCD1002",
+            STATE_ROOT,
+            TABLE_HANDLE,
+            StorageProof {
+                state_value: None,
+                proof: proof(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn non_membership_of_existing_path() {
+        let err = verify_non_membership(
+            b"ABCD",
+            STATE_ROOT,
+            TABLE_HANDLE,
+            StorageProof {
+                state_value: None,
+                proof: proof(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::HeaderVerification(_)));
+    }
+
+    #[test]
+    fn non_membership_of_unrelated_path() {
+        let err = verify_non_membership(
+            b"EFGH",
+            STATE_ROOT,
+            TABLE_HANDLE,
+            StorageProof {
+                state_value: None,
+                proof: proof(),
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::HeaderVerification(_)));
+    }
+
+    fn header(new_height: u64, tx_index: u64, version: u64) -> Header {
+        let mut state_proof = StateProof::default();
+        let LedgerInfoWithSignatures::V0(ledger_info) = &mut state_proof.latest_li_w_sigs;
+        ledger_info.ledger_info.commit_info.version = version;
+
+        Header {
+            l1_height: 1,
+            trusted_height: Height::new(1),
+            state_proof,
+            tx_index,
+            tx_proof: TransactionInfoWithProof::default(),
+            state_proof_hash_proof: ethereum_light_client_types::StorageProof {
+                key: U256::ZERO,
+                value: U256::ZERO,
+                proof: vec![],
+            },
+            settlement_contract_proof: AccountProof {
+                storage_root: H256::default(),
+                proof: vec![],
+            },
+            new_height,
+        }
+    }
+
+    #[test]
+    fn misbehaviour_proving_an_earlier_transaction() {
+        let client_state = ClientState {
+            chain_id: "movement".to_owned(),
+            l1_client_id: ClientId!(1),
+            l1_contract_address: H160::default(),
+            l2_contract_address: AccountAddress(H256::default()),
+            table_handle: TABLE_HANDLE,
+            frozen_height: Height::new(0),
+            latest_block_num: 10,
+            whitelisted_relayers: vec![],
+        };
+
+        let mut deps = mock_dependencies();
+        let raw_client_state = Binary::from(client_state.encode_as::<Bincode>());
+        deps.querier
+            .update_wasm(move |_| SystemResult::Ok(ContractResult::Ok(raw_client_state.clone())));
+
+        // header_a proves the state after an earlier transaction than the one the ledger info
+        // committed to the L1 ends at
+        let res = MovementLightClient::misbehaviour(
+            IbcClientCtx::new(
+                ClientId!(2),
+                Addr::unchecked("ibc-host"),
+                deps.as_ref(),
+                mock_env(),
+            ),
+            Addr::unchecked("fisherman"),
+            Misbehaviour {
+                header_a: header(11, 99, 100),
+                header_b: header(11, 100, 100),
+            },
+            Addr::unchecked("relayer"),
+        );
+
+        assert!(matches!(
+            res,
+            Err(IbcClientError::ClientSpecific(
+                Error::TransactionNotLatest {
+                    tx_index: 99,
+                    version: 100
+                }
+            ))
+        ));
+    }
+
     #[test]
     fn test_proto() {
         let channel_end = hex!(
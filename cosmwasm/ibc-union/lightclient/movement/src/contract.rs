@@ -12,7 +12,7 @@ pub fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: ()) -> StdResult<Respo
     panic!("this contract cannot be instantiated directly, but must be migrated from an existing instantiated contract.");
 }
 
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     ibc_union_light_client::query::<MovementLightClient>(deps, env, msg).map_err(Into::into)
 }
@@ -1,10 +1,11 @@
 use cosmwasm_std::StdError;
+use ethereum_light_client::client::EthereumLightClient;
 use ibc_union_light_client::IbcClientError;
 use unionlabs::ibc::core::client::height::Height;
 
 use crate::client::MovementLightClient;
 
-#[derive(thiserror::Error, Debug, PartialEq)]
+#[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("header verification failure ({0})")]
     HeaderVerification(#[from] aptos_verifier::Error),
@@ -16,16 +17,30 @@ pub enum Error {
     ConsensusStateNotFound(Height),
     #[error("membership proof with no value")]
     MembershipProofWithoutValue,
+    #[error("non-membership proof with a value")]
+    NonMembershipProofWithValue,
+    #[error("the proven transaction ({tx_index}) is not the latest transaction of the ledger info ({version})")]
+    TransactionNotLatest { tx_index: u64, version: u64 },
+    #[error("transaction info does not contain a state checkpoint")]
+    MissingStateCheckpoint,
+    #[error("misbehaviour headers must be at the same height ({height_a} != {height_b})")]
+    MisbehaviourHeightMismatch { height_a: u64, height_b: u64 },
+    #[error("the headers do not prove any misbehaviour")]
+    MisbehaviourNotFound,
     #[error("proof value {proof_value} doesn't match the given value {given})", proof_value = serde_utils::to_hex(.0), given = serde_utils::to_hex(.1))]
     ProofValueMismatch(Vec<u8>, Vec<u8>),
     #[error("proof value hash doesn't match the calculated one")]
     ProofValueHashMismatch,
     #[error("proof key hash doesn't match the calculated one")]
     ProofKeyMismatch,
+    #[error("proof verification requires the custom movement node (`union-movement` feature)")]
+    ProofVerificationDisabled,
     #[error("invalid ibc path {0}")]
     InvalidIbcPath(String),
     #[error(transparent)]
     StdError(#[from] StdError),
+    #[error(transparent)]
+    EvmIbcClient(#[from] IbcClientError<EthereumLightClient>),
 }
 
 impl From<Error> for StdError {
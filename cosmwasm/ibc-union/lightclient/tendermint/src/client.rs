@@ -8,7 +8,7 @@ use ibc_union_light_client::{
 };
 use ibc_union_spec::path::IBC_UNION_COSMWASM_COMMITMENT_PREFIX;
 use ics23::ibc_api::SDK_SPECS;
-use tendermint_light_client_types::{ClientState, ConsensusState, Header};
+use tendermint_light_client_types::{ClientState, ConsensusState, Header, Misbehaviour};
 use tendermint_verifier::types::{HostFns, SignatureVerifier};
 use unionlabs::{
     bounded::BoundedI64,
@@ -24,11 +24,15 @@ use unionlabs::{
 use crate::{
     errors::{
         Error, IbcHeightTooLargeForTendermintHeight, InvalidChainId, InvalidHeaderError,
-        MathOverflow, RevisionNumberMismatch, TrustedValidatorsMismatch,
+        MathOverflow, MigrateClientStoreError, RevisionNumberMismatch, TrustedValidatorsMismatch,
     },
     verifier::Ed25519Verifier,
 };
 
+/// The height a client is frozen at once misbehaviour has been submitted. Only the fact that the
+/// height is non-zero is meaningful, see [`TendermintLightClient::status`].
+pub const FROZEN_HEIGHT: Height = Height::new(1);
+
 pub struct TendermintLightClient;
 
 impl IbcClient for TendermintLightClient {
@@ -38,8 +42,7 @@ impl IbcClient for TendermintLightClient {
 
     type Header = Header;
 
-    // TODO(aeryz): Change this to appropriate misbehavior type when it is implemented
-    type Misbehaviour = Header;
+    type Misbehaviour = Misbehaviour;
 
     type ClientState = ClientState;
 
@@ -118,12 +121,54 @@ impl IbcClient for TendermintLightClient {
     }
 
     fn misbehaviour(
-        _ctx: IbcClientCtx<Self>,
+        ctx: IbcClientCtx<Self>,
         _caller: Addr,
-        _misbehaviour: Self::Misbehaviour,
+        misbehaviour: Self::Misbehaviour,
         _relayer: Addr,
     ) -> Result<Self::ClientState, IbcClientError<Self>> {
-        Err(Error::Unimplemented.into())
+        let mut client_state = ctx.read_self_client_state()?;
+        let consensus_state_a =
+            ctx.read_self_consensus_state(misbehaviour.header_a.trusted_height.height())?;
+        let consensus_state_b =
+            ctx.read_self_consensus_state(misbehaviour.header_b.trusted_height.height())?;
+        match misbehaviour
+            .header_a
+            .validator_set
+            .validators
+            .first()
+            .map(|v| &v.pub_key)
+        {
+            #[cfg(feature = "bls")]
+            Some(PublicKey::Bls12_381(_)) => verify_misbehaviour(
+                &client_state,
+                consensus_state_a,
+                consensus_state_b,
+                misbehaviour,
+                ctx.env.block.time,
+                &SignatureVerifier::new(crate::verifier::bls::Bls12Verifier::new(ctx.deps)),
+            )?,
+            Some(PublicKey::Ed25519(_)) => verify_misbehaviour(
+                &client_state,
+                consensus_state_a,
+                consensus_state_b,
+                misbehaviour,
+                ctx.env.block.time,
+                &SignatureVerifier::new(Ed25519Verifier::new(ctx.deps)),
+            )?,
+            _ => return Err(Error::InvalidValidatorSet.into()),
+        }
+
+        client_state.frozen_height = Some(FROZEN_HEIGHT);
+
+        Ok(client_state)
+    }
+
+    fn recover_client(
+        _ctx: IbcClientCtx<Self>,
+        subject_client_state: Self::ClientState,
+        substitute_client_state: Self::ClientState,
+    ) -> Result<Self::ClientState, IbcClientError<Self>> {
+        check_substitute(&subject_client_state, substitute_client_state).map_err(Into::into)
     }
 
     fn status(ctx: IbcClientCtx<Self>, client_state: &Self::ClientState) -> Status {
@@ -276,6 +321,105 @@ pub fn verify_header<V: HostFns>(
     }
 }
 
+/// Verifies that `misbehaviour` is valid evidence of the counterparty chain misbehaving.
+///
+/// Both headers must verify against the client's trusted consensus states, exactly as they would
+/// in a regular update. `header_a` must not be lower than `header_b`; see [`check_misbehaviour`]
+/// for what the pair has to prove.
+pub fn verify_misbehaviour<V: HostFns>(
+    client_state: &ClientState,
+    consensus_state_a: ConsensusState,
+    consensus_state_b: ConsensusState,
+    misbehaviour: Misbehaviour,
+    block_timestamp: cosmwasm_std::Timestamp,
+    signature_verifier: &SignatureVerifier<V>,
+) -> Result<(), Error> {
+    check_misbehaviour(&misbehaviour.header_a, &misbehaviour.header_b)?;
+
+    verify_header(
+        client_state.clone(),
+        consensus_state_a,
+        misbehaviour.header_a,
+        block_timestamp,
+        signature_verifier,
+    )?;
+    verify_header(
+        client_state.clone(),
+        consensus_state_b,
+        misbehaviour.header_b,
+        block_timestamp,
+        signature_verifier,
+    )?;
+
+    Ok(())
+}
+
+/// Check that `substitute` can be used to recover `subject`, and return the client state that
+/// `subject` is recovered to.
+///
+/// Same as ibc-go, only the chain id, trusting period and heights are allowed to differ between
+/// the two clients, and the substitute must be ahead of the subject. The recovered client is
+/// unfrozen.
+pub fn check_substitute(
+    subject: &ClientState,
+    substitute: ClientState,
+) -> Result<ClientState, Error> {
+    if substitute.frozen_height.unwrap_or_default().height() != 0 {
+        return Err(MigrateClientStoreError::SubstituteClientFrozen.into());
+    }
+
+    if subject.trust_level != substitute.trust_level
+        || subject.unbonding_period != substitute.unbonding_period
+        || subject.max_clock_drift != substitute.max_clock_drift
+        || subject.proof_specs != substitute.proof_specs
+        || subject.upgrade_path != substitute.upgrade_path
+        || subject.contract_address != substitute.contract_address
+    {
+        return Err(MigrateClientStoreError::MigrateFieldsChanged.into());
+    }
+
+    if substitute.latest_height <= subject.latest_height {
+        return Err(MigrateClientStoreError::SubstituteHeightNotGreater {
+            subject_height: subject.latest_height,
+            substitute_height: substitute.latest_height,
+        }
+        .into());
+    }
+
+    Ok(ClientState {
+        frozen_height: None,
+        ..substitute
+    })
+}
+
+/// Checks whether two (not yet verified) headers constitute misbehaviour. This is the case if
+/// either:
+///
+/// - both headers are at the same height, but commit to different blocks (duplicate vote), or
+/// - `header_a` is higher than `header_b`, but its timestamp is not after the timestamp of
+///   `header_b` (block time monotonicity violation).
+pub fn check_misbehaviour(header_a: &Header, header_b: &Header) -> Result<(), Error> {
+    let height_a = height_from_header(header_a);
+    let height_b = height_from_header(header_b);
+
+    if height_a < height_b {
+        return Err(Error::InvalidMisbehaviourHeaderSequence { height_a, height_b });
+    }
+
+    let is_misbehaviour = if height_a == height_b {
+        header_a.signed_header.header.calculate_merkle_root()
+            != header_b.signed_header.header.calculate_merkle_root()
+    } else {
+        header_a.signed_header.header.time <= header_b.signed_header.header.time
+    };
+
+    if is_misbehaviour {
+        Ok(())
+    } else {
+        Err(Error::MisbehaviourNotFound)
+    }
+}
+
 pub fn set_total_voting_power(validator_set: &mut ValidatorSet) -> Result<(), MathOverflow> {
     validator_set.total_voting_power =
         validator_set
@@ -413,6 +557,243 @@ pub fn verify_non_membership(
     .map_err(Error::VerifyMembership)
 }
 
+#[cfg(test)]
+mod tests {
+    use std::{fs, num::NonZeroU64};
+
+    use cosmwasm_std::testing::mock_dependencies;
+    use tendermint_light_client_types::Fraction;
+
+    use super::*;
+
+    fn read_header(height: u64) -> Header {
+        serde_json::from_str(&fs::read_to_string(format!("src/test/header_{height}.json")).unwrap())
+            .unwrap()
+    }
+
+    fn mk_client_state() -> ClientState {
+        ClientState {
+            chain_id: "simd-devnet-1".to_owned(),
+            trust_level: Fraction {
+                numerator: 1,
+                denominator: NonZeroU64::new(3).unwrap(),
+            },
+            trusting_period: Duration::new(315576000000, 0).unwrap(),
+            unbonding_period: Duration::new(315576000000, 0).unwrap(),
+            max_clock_drift: Duration::new(100_000_000, 0).unwrap(),
+            frozen_height: None,
+            latest_height: Height::new_with_revision(1, 300),
+            proof_specs: vec![],
+            upgrade_path: vec![],
+            contract_address: H256::default(),
+        }
+    }
+
+    /// The consensus state that the client stores after being updated to `header`.
+    fn mk_consensus_state(header: &Header) -> ConsensusState {
+        ConsensusState {
+            timestamp: header.signed_header.header.time,
+            root: MerkleRoot {
+                hash: (*header.signed_header.header.app_hash.get()).into(),
+            },
+            next_validators_hash: header.signed_header.header.next_validators_hash,
+        }
+    }
+
+    fn block_timestamp(header: &Header) -> cosmwasm_std::Timestamp {
+        cosmwasm_std::Timestamp::from_nanos(header.signed_header.header.time.as_unix_nanos())
+            .plus_seconds(60)
+    }
+
+    /// `header` with a different app hash, as if it were signed for a conflicting block.
+    fn conflicting(mut header: Header) -> Header {
+        header.signed_header.header.app_hash = H256::new([0xAA; 32]);
+        header
+    }
+
+    fn verify(misbehaviour: Misbehaviour) -> Result<(), Error> {
+        let deps = mock_dependencies();
+
+        let consensus_state_a =
+            mk_consensus_state(&read_header(misbehaviour.header_a.trusted_height.height()));
+        let consensus_state_b =
+            mk_consensus_state(&read_header(misbehaviour.header_b.trusted_height.height()));
+        let block_timestamp = block_timestamp(&misbehaviour.header_a);
+
+        verify_misbehaviour(
+            &mk_client_state(),
+            consensus_state_a,
+            consensus_state_b,
+            misbehaviour,
+            block_timestamp,
+            &SignatureVerifier::new(Ed25519Verifier::new(deps.as_ref())),
+        )
+    }
+
+    #[test]
+    fn fixtures_verify() {
+        let deps = mock_dependencies();
+
+        let header = read_header(300);
+        let block_timestamp = block_timestamp(&header);
+
+        verify_header(
+            mk_client_state(),
+            mk_consensus_state(&read_header(297)),
+            header,
+            block_timestamp,
+            &SignatureVerifier::new(Ed25519Verifier::new(deps.as_ref())),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn conflicting_headers_are_misbehaviour() {
+        assert_eq!(
+            check_misbehaviour(&read_header(300), &conflicting(read_header(300))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn time_violation_is_misbehaviour() {
+        let header_b = read_header(297);
+        let mut header_a = read_header(300);
+        header_a.signed_header.header.time = header_b.signed_header.header.time;
+
+        assert_eq!(check_misbehaviour(&header_a, &header_b), Ok(()));
+    }
+
+    #[test]
+    fn identical_headers_are_not_misbehaviour() {
+        assert_eq!(
+            check_misbehaviour(&read_header(300), &read_header(300)),
+            Err(Error::MisbehaviourNotFound)
+        );
+    }
+
+    #[test]
+    fn valid_headers_are_not_misbehaviour() {
+        assert_eq!(
+            verify(Misbehaviour {
+                header_a: read_header(300),
+                header_b: read_header(297),
+            }),
+            Err(Error::MisbehaviourNotFound)
+        );
+    }
+
+    #[test]
+    fn headers_out_of_order() {
+        assert_eq!(
+            verify(Misbehaviour {
+                header_a: read_header(297),
+                header_b: read_header(300),
+            }),
+            Err(Error::InvalidMisbehaviourHeaderSequence {
+                height_a: Height::new_with_revision(1, 297),
+                height_b: Height::new_with_revision(1, 300),
+            })
+        );
+    }
+
+    #[test]
+    fn forged_conflicting_header_is_rejected() {
+        assert!(matches!(
+            verify(Misbehaviour {
+                header_a: read_header(300),
+                header_b: conflicting(read_header(300)),
+            }),
+            Err(Error::TendermintVerify(_))
+        ));
+    }
+
+    #[test]
+    fn recover_client_works() {
+        let mut subject = mk_client_state();
+        subject.frozen_height = Some(FROZEN_HEIGHT);
+
+        let substitute = ClientState {
+            chain_id: "simd-devnet-2".to_owned(),
+            trusting_period: Duration::new(100, 0).unwrap(),
+            latest_height: Height::new_with_revision(2, 10),
+            ..mk_client_state()
+        };
+
+        let recovered = check_substitute(&subject, substitute.clone()).unwrap();
+
+        // we didn't miss updating any fields
+        assert_eq!(recovered, substitute);
+        // client is unfrozen
+        assert_eq!(recovered.frozen_height, None);
+    }
+
+    #[test]
+    fn recover_client_fails_when_invalid_change() {
+        macro_rules! modify_fns {
+            ($param:ident, $($m:expr), + $(,)?) => ([$(|$param: &mut ClientState| $m),+])
+        }
+
+        let modifications = modify_fns! { s,
+            s.trust_level.numerator ^= u64::MAX,
+            s.unbonding_period = Duration::new(s.unbonding_period.seconds().inner() + 1, 0).unwrap(),
+            s.max_clock_drift = Duration::new(s.max_clock_drift.seconds().inner() + 1, 0).unwrap(),
+            s.proof_specs = SDK_SPECS.into(),
+            s.upgrade_path.push(String::new()),
+            s.contract_address = H256::new([1; 32]),
+        };
+
+        for m in modifications {
+            let mut substitute = mk_client_state();
+            m(&mut substitute);
+
+            assert_eq!(
+                check_substitute(&mk_client_state(), substitute),
+                Err(Error::MigrateClientStore(
+                    MigrateClientStoreError::MigrateFieldsChanged
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn recover_client_fails_when_substitute_client_frozen() {
+        let mut substitute = mk_client_state();
+        substitute.frozen_height = Some(FROZEN_HEIGHT);
+
+        assert_eq!(
+            check_substitute(&mk_client_state(), substitute),
+            Err(Error::MigrateClientStore(
+                MigrateClientStoreError::SubstituteClientFrozen
+            ))
+        );
+    }
+
+    #[test]
+    fn recover_client_fails_when_substitute_not_ahead() {
+        for latest_height in [
+            Height::new_with_revision(1, 300),
+            Height::new_with_revision(1, 299),
+            Height::new_with_revision(0, 400),
+        ] {
+            let substitute = ClientState {
+                latest_height,
+                ..mk_client_state()
+            };
+
+            assert_eq!(
+                check_substitute(&mk_client_state(), substitute),
+                Err(Error::MigrateClientStore(
+                    MigrateClientStoreError::SubstituteHeightNotGreater {
+                        subject_height: Height::new_with_revision(1, 300),
+                        substitute_height: latest_height,
+                    }
+                ))
+            );
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use std::fs;
@@ -2,7 +2,7 @@ use ibc_union_light_client::IbcClientError;
 use tendermint_light_client_types::{ClientState, Header};
 use unionlabs::{
     encoding::{DecodeErrorOf, Proto},
-    ibc::core::commitment::merkle_proof::MerkleProof,
+    ibc::core::{client::height::Height, commitment::merkle_proof::MerkleProof},
     primitives::H256,
 };
 
@@ -60,6 +60,12 @@ pub enum Error {
 
     #[error("invalid or empty validator set, supported keys are: bls12381 and ed25519")]
     InvalidValidatorSet,
+
+    #[error("misbehaviour header a ({height_a}) must not be lower than header b ({height_b})")]
+    InvalidMisbehaviourHeaderSequence { height_a: Height, height_b: Height },
+
+    #[error("the headers do not prove any misbehaviour")]
+    MisbehaviourNotFound,
 }
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
@@ -92,6 +98,15 @@ pub enum MigrateClientStoreError {
 
     #[error("forbidden fields have been changed during state migration")]
     MigrateFieldsChanged,
+
+    #[error(
+        "substitute client height ({substitute_height}) must be greater than \
+        the subject client height ({subject_height})"
+    )]
+    SubstituteHeightNotGreater {
+        subject_height: Height,
+        substitute_height: Height,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
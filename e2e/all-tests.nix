@@ -24,6 +24,7 @@
           let
             # full-e2e = import ./full-e2e.nix { inherit e2e pkgs; };
             epoch-staking = import ./epoch-staking.nix { inherit e2e pkgs dbg; };
+            movement = import ./movement.nix { inherit e2e pkgs; };
             upgrades = import ./upgrades.nix {
               inherit e2e pkgs;
               inherit (self'.packages) unionvisor;
@@ -46,6 +47,9 @@
             inherit (upgrades) upgrade-from-genesis;
             # inherit (upgrades) upgrade-with-tokenfactory-state;
 
+            # Tests from ./movement.nix
+            inherit (movement) movement-node-rpc;
+
             virtualisation-works = e2e.mkTest {
               name = "full-dev-setup";
               nodes = {
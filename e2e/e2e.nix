@@ -122,6 +122,32 @@
         };
       };
 
+      movementNode = {
+        wait_for_open_port = 8080;
+        node =
+          { pkgs, ... }:
+          {
+            virtualisation = {
+              diskSize = 8 * 1024;
+              memorySize = 8 * 1024;
+              vlans = [ 1 ];
+            };
+            networking.hostName = "devnetMovement";
+            networking.firewall.allowedTCPPorts = [ 8080 ];
+
+            systemd.services.movement-local-testnet = {
+              wantedBy = [ "multi-user.target" ];
+              serviceConfig = {
+                ExecStart = "${pkgs.lib.meta.getExe self'.packages.movement} node run-local-testnet --force-restart --assume-yes --test-dir /var/lib/movement --no-txn-stream";
+                StateDirectory = "movement";
+                Restart = "on-failure";
+              };
+            };
+
+            environment.systemPackages = with pkgs; [ jq ];
+          };
+      };
+
       galoisNode = {
         wait_for_console_text = "Serving...";
         wait_for_open_port = 9999;
@@ -152,6 +178,7 @@
           unionNode
           unionTestnetGenesisNode
           devnetEthNode
+          movementNode
           ;
 
         # TODO: This is poorly named, it only starts devnet-union and devnet-eth
@@ -0,0 +1,32 @@
+{ e2e, pkgs, ... }:
+let
+  jq = pkgs.lib.meta.getExe pkgs.jq;
+in
+{
+  # NOTE: this only checks that the local node serves the rpc endpoints the voyager movement modules
+  # read from, it does not test relaying.
+  #
+  # TODO: Add a voyager movement relaying test (client creation, updates and packets). This needs
+  # the custom movement node that serves the state and transaction proofs (see the
+  # `union-movement` feature of the movement light client), the L1 settlement contract and a
+  # voyager setup in the e2e harness, none of which exist yet (the union <-> ethereum full-e2e is
+  # disabled as well).
+  movement-node-rpc = e2e.mkTest {
+    name = "movement-node-rpc";
+
+    testScript = ''
+      movement.wait_for_open_port(${toString e2e.movementNode.wait_for_open_port}, timeout=600)
+
+      # match non-zero blocks
+      movement.wait_until_succeeds('[[ $(curl "http://localhost:8080/v1" --fail --silent | ${jq} ".block_height | tonumber > 1") == "true" ]]')
+
+      # the client bootstrap and update modules read the state root from the state checkpoint
+      # of the last transaction of a block, make sure the local node exposes it
+      movement.succeed('last_version=$(curl "http://localhost:8080/v1/blocks/by_height/1" --fail --silent | ${jq} -r ".last_version") && curl "http://localhost:8080/v1/transactions/by_version/$last_version" --fail --silent | ${jq} -e ".state_checkpoint_hash != null"')
+    '';
+
+    nodes = {
+      movement = e2e.movementNode.node;
+    };
+  };
+}
@@ -57,6 +57,14 @@
           type = types.str;
           default = "0.0.0.0:9090";
         };
+        api-addr = mkOption {
+          description = lib.mdDoc ''
+            Address to serve the packet query api on. The api is disabled when not set.
+          '';
+          example = "0.0.0.0:9091";
+          type = types.nullOr types.str;
+          default = null;
+        };
         api-key-file = mkOption {
           description = lib.mdDoc ''
             Path to a file containing the database secret to allow for inserts.
@@ -121,6 +129,7 @@
                   "ethereum"
                   "tendermint"
                   "aptos"
+                  "sui"
                 ];
               };
               options.start_height = mkOption {
@@ -143,6 +152,12 @@
                 default = null;
                 description = "Drain events: stop emitting events, so all events can be read from the stream (default false)";
               };
+              options.ibc_handler_address = mkOption {
+                type = types.nullOr types.str;
+                default = null;
+                description = "Address of the ibc package, of which events are indexed (sui only)";
+                example = "0x1234";
+              };
               options.tx_search_max_page_size = mkOption {
                 type = types.int;
                 description = "Maximum number of transactions to fetch in one page";
@@ -183,6 +198,46 @@
                   }
                 );
               };
+              options.stuck_packet_detector = mkOption {
+                description = "control stuck packet detector behavior";
+                example = {
+                  enabled = true;
+                  threshold_seconds = 900;
+                  webhook_url = "https://alerts.example.com/hubble";
+                };
+                default = null;
+                type = types.nullOr (
+                  types.submodule {
+                    options = {
+                      enabled = mkOption {
+                        type = types.nullOr types.bool;
+                        default = null;
+                        description = "periodically scan for packets that are stuck in a lifecycle stage.";
+                      };
+                      interval_seconds = mkOption {
+                        type = types.nullOr types.int;
+                        default = null;
+                        description = "sleep time (in seconds) between scans.";
+                      };
+                      threshold_seconds = mkOption {
+                        type = types.nullOr types.int;
+                        default = null;
+                        description = "time (in seconds) a packet can stay in a lifecycle stage before it is considered stuck.";
+                      };
+                      lookback_seconds = mkOption {
+                        type = types.nullOr types.int;
+                        default = null;
+                        description = "only packets sent within this window (in seconds) are scanned.";
+                      };
+                      webhook_url = mkOption {
+                        type = types.nullOr types.str;
+                        default = null;
+                        description = "url that receives a POST with the stuck packets when a channel becomes stuck.";
+                      };
+                    };
+                  }
+                );
+              };
             }
           );
         };
@@ -242,6 +297,7 @@
                   natsPasswordArg =
                     if cfg.nats-password-file != null then "--nats-password @${cfg.nats-password-file}" else "";
                   natsConsumerArg = if cfg.nats-consumer != null then "--nats-consumer ${cfg.nats-consumer}" else "";
+                  apiAddrArg = if cfg.api-addr != null then "--api-addr ${cfg.api-addr}" else "";
                 in
                 ''
                   ${pkgs.lib.getExe cfg.package}  \
@@ -252,6 +308,7 @@
                     ${natsConsumerArg} \
                     --log-format ${cfg.log-format} \
                     --metrics-addr ${cfg.metrics-addr} \
+                    ${apiAddrArg} \
                     --indexers '${indexersJson}'
                 '';
             };
@@ -0,0 +1,54 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use tracing::error;
+
+use crate::indexer::api::IndexerError;
+
+mod packet;
+mod postgres;
+
+/// Read api on top of the records written by the indexers. Packets can be looked up by
+/// packet hash, or searched by transaction hash or sender address.
+pub fn router(pg_pool: PgPool) -> Router {
+    Router::new()
+        .route("/v1/packets", get(packet::search_handler))
+        .route("/v1/packets/:packet_hash", get(packet::packet_handler))
+        .with_state(pg_pool)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    #[error("invalid parameter {0}: {1}")]
+    InvalidParameter(String, String),
+    #[error("not found")]
+    NotFound,
+    #[error("database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    #[error("indexer error: {0}")]
+    IndexerError(#[from] IndexerError),
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match &self {
+            ApiError::InvalidParameter(_, _) => (StatusCode::BAD_REQUEST, self.to_string()),
+            ApiError::NotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            // do not expose internals to api consumers
+            ApiError::DatabaseError(_) | ApiError::IndexerError(_) => {
+                error!("api error: {self:?}");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal error".to_string(),
+                )
+            }
+        };
+
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
@@ -0,0 +1,300 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use time::OffsetDateTime;
+use tracing::debug;
+
+use crate::{
+    api::{postgres, ApiError},
+    indexer::event::types::{
+        bytes_as_hex, Acknowledgement, BlockHash, BlockHeight, ChannelId, Maker, MakerMsg,
+        PacketData, PacketHash, TransactionHash, UniversalChainId,
+    },
+};
+
+const DEFAULT_LIMIT: i64 = 10;
+const MAX_LIMIT: i64 = 100;
+
+/// The lifecycle of a packet across the source and destination chain. Stages that have not
+/// (yet) been indexed are absent.
+#[derive(Serialize, Debug)]
+pub struct PacketLifecycle {
+    pub packet_hash: PacketHash,
+    pub send: Option<PacketSend>,
+    pub recv: Option<PacketRecv>,
+    pub write_ack: Option<WriteAck>,
+    pub ack: Option<PacketAck>,
+    pub timeout: Option<PacketTimeout>,
+    pub instructions: Vec<Instruction>,
+    pub elapsed: Elapsed,
+}
+
+/// Location of a lifecycle stage.
+#[derive(Serialize, Debug)]
+pub struct Stage {
+    pub universal_chain_id: Option<UniversalChainId>,
+    pub height: BlockHeight,
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    pub block_hash: BlockHash,
+    pub transaction_hash: TransactionHash,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PacketSend {
+    #[serde(flatten)]
+    pub stage: Stage,
+    pub counterparty_universal_chain_id: Option<UniversalChainId>,
+    pub source_channel_id: ChannelId,
+    pub destination_channel_id: ChannelId,
+    pub timeout_height: String,
+    pub timeout_timestamp: String,
+    pub data: PacketData,
+    /// `None` until the packet is enriched.
+    pub decoded: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PacketRecv {
+    #[serde(flatten)]
+    pub stage: Stage,
+    pub maker: Maker,
+    pub maker_msg: MakerMsg,
+    pub intent: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct WriteAck {
+    #[serde(flatten)]
+    pub stage: Stage,
+    pub acknowledgement: Acknowledgement,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PacketAck {
+    #[serde(flatten)]
+    pub stage: Stage,
+    pub acknowledgement: Acknowledgement,
+    pub maker: Maker,
+}
+
+#[derive(Serialize, Debug)]
+pub struct PacketTimeout {
+    #[serde(flatten)]
+    pub stage: Stage,
+    pub maker: Maker,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Instruction {
+    pub instruction_index: i64,
+    #[serde(with = "bytes_as_hex")]
+    pub instruction_hash: Bytes,
+    pub instruction_type: String,
+    #[serde(with = "bytes_as_hex")]
+    pub path: Bytes,
+    #[serde(with = "bytes_as_hex")]
+    pub salt: Bytes,
+    pub instruction_path: String,
+    pub version: i32,
+    pub opcode: i32,
+    pub operand_sender: Option<String>,
+    pub operand_contract_address: Option<String>,
+}
+
+/// Elapsed time in seconds between lifecycle stages.
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct Elapsed {
+    pub send_to_recv: Option<f64>,
+    pub recv_to_write_ack: Option<f64>,
+    pub write_ack_to_ack: Option<f64>,
+    pub send_to_ack: Option<f64>,
+    pub send_to_timeout: Option<f64>,
+}
+
+impl Elapsed {
+    fn between(from: Option<&Stage>, to: Option<&Stage>) -> Option<f64> {
+        Some((to?.timestamp - from?.timestamp).as_seconds_f64())
+    }
+}
+
+impl PacketLifecycle {
+    fn new(
+        packet_hash: PacketHash,
+        send: Option<PacketSend>,
+        recv: Option<PacketRecv>,
+        write_ack: Option<WriteAck>,
+        ack: Option<PacketAck>,
+        timeout: Option<PacketTimeout>,
+        instructions: Vec<Instruction>,
+    ) -> Self {
+        let send_stage = send.as_ref().map(|send| &send.stage);
+        let recv_stage = recv.as_ref().map(|recv| &recv.stage);
+        let write_ack_stage = write_ack.as_ref().map(|write_ack| &write_ack.stage);
+        let ack_stage = ack.as_ref().map(|ack| &ack.stage);
+        let timeout_stage = timeout.as_ref().map(|timeout| &timeout.stage);
+
+        let elapsed = Elapsed {
+            send_to_recv: Elapsed::between(send_stage, recv_stage),
+            recv_to_write_ack: Elapsed::between(recv_stage, write_ack_stage),
+            write_ack_to_ack: Elapsed::between(write_ack_stage, ack_stage),
+            send_to_ack: Elapsed::between(send_stage, ack_stage),
+            send_to_timeout: Elapsed::between(send_stage, timeout_stage),
+        };
+
+        Self {
+            packet_hash,
+            send,
+            recv,
+            write_ack,
+            ack,
+            timeout,
+            instructions,
+            elapsed,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SearchParams {
+    pub transaction_hash: Option<String>,
+    pub sender: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub async fn packet_handler(
+    State(pg_pool): State<PgPool>,
+    Path(packet_hash): Path<String>,
+) -> Result<Json<PacketLifecycle>, ApiError> {
+    let packet_hash = parse_hex("packet_hash", &packet_hash)?;
+
+    find_lifecycle(&pg_pool, packet_hash)
+        .await?
+        .map(Json)
+        .ok_or(ApiError::NotFound)
+}
+
+pub async fn search_handler(
+    State(pg_pool): State<PgPool>,
+    Query(params): Query<SearchParams>,
+) -> Result<Json<Vec<PacketLifecycle>>, ApiError> {
+    debug!("search: {params:?}");
+
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let packet_hashes = match (params.transaction_hash, params.sender) {
+        (Some(transaction_hash), None) => {
+            let transaction_hash = parse_hex("transaction_hash", &transaction_hash)?;
+
+            postgres::packet_hashes_by_transaction_hash(&pg_pool, &transaction_hash, limit).await?
+        }
+        (None, Some(sender)) => {
+            // hex encoded senders are canonical addresses, other formats are display addresses.
+            let sender_canonical = sender
+                .starts_with("0x")
+                .then(|| parse_hex("sender", &sender))
+                .transpose()?;
+
+            postgres::packet_hashes_by_sender(&pg_pool, sender_canonical.as_deref(), &sender, limit)
+                .await?
+        }
+        _ => {
+            return Err(ApiError::InvalidParameter(
+                "query".to_string(),
+                "expecting either transaction_hash or sender".to_string(),
+            ))
+        }
+    };
+
+    let mut lifecycles = Vec::with_capacity(packet_hashes.len());
+    for packet_hash in packet_hashes {
+        if let Some(lifecycle) = find_lifecycle(&pg_pool, packet_hash).await? {
+            lifecycles.push(lifecycle);
+        }
+    }
+
+    Ok(Json(lifecycles))
+}
+
+async fn find_lifecycle(
+    pg_pool: &PgPool,
+    packet_hash: Vec<u8>,
+) -> Result<Option<PacketLifecycle>, ApiError> {
+    let (send, recv, write_ack, ack, timeout, instructions) = tokio::try_join!(
+        postgres::packet_send(pg_pool, &packet_hash),
+        postgres::packet_recv(pg_pool, &packet_hash),
+        postgres::write_ack(pg_pool, &packet_hash),
+        postgres::packet_ack(pg_pool, &packet_hash),
+        postgres::packet_timeout(pg_pool, &packet_hash),
+        postgres::instructions(pg_pool, &packet_hash),
+    )?;
+
+    if send.is_none() && recv.is_none() && write_ack.is_none() && ack.is_none() && timeout.is_none()
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(PacketLifecycle::new(
+        Bytes::from(packet_hash).into(),
+        send,
+        recv,
+        write_ack,
+        ack,
+        timeout,
+        instructions,
+    )))
+}
+
+fn parse_hex(name: &str, value: &str) -> Result<Vec<u8>, ApiError> {
+    value
+        .strip_prefix("0x")
+        .ok_or_else(|| {
+            ApiError::InvalidParameter(name.to_string(), "missing 0x prefix".to_string())
+        })
+        .and_then(|value| {
+            hex::decode(value)
+                .map_err(|err| ApiError::InvalidParameter(name.to_string(), err.to_string()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(timestamp: OffsetDateTime) -> Stage {
+        Stage {
+            universal_chain_id: Some(UniversalChainId("test.1".to_string())),
+            height: BlockHeight(1),
+            timestamp,
+            block_hash: Bytes::from_static(b"block").into(),
+            transaction_hash: Bytes::from_static(b"tx").into(),
+        }
+    }
+
+    #[test]
+    fn test_elapsed_between_stages() {
+        let send = stage(OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap());
+        let recv = stage(OffsetDateTime::from_unix_timestamp(1_600_000_012).unwrap());
+
+        assert_eq!(Elapsed::between(Some(&send), Some(&recv)), Some(12.0));
+        assert_eq!(Elapsed::between(Some(&send), None), None);
+        assert_eq!(Elapsed::between(None, Some(&recv)), None);
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("hash", "0x0102").unwrap(), vec![1, 2]);
+        assert!(matches!(
+            parse_hex("hash", "0102"),
+            Err(ApiError::InvalidParameter(_, _))
+        ));
+        assert!(matches!(
+            parse_hex("hash", "0xzz"),
+            Err(ApiError::InvalidParameter(_, _))
+        ));
+    }
+}
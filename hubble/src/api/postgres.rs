@@ -0,0 +1,352 @@
+use bytes::Bytes;
+use sqlx::PgPool;
+use time::OffsetDateTime;
+
+use crate::{
+    api::{
+        packet::{Instruction, PacketAck, PacketRecv, PacketSend, PacketTimeout, Stage, WriteAck},
+        ApiError,
+    },
+    indexer::event::types::{BlockHeight, ChannelId},
+};
+
+impl Stage {
+    fn new(
+        universal_chain_id: Option<String>,
+        height: i64,
+        timestamp: OffsetDateTime,
+        block_hash: Vec<u8>,
+        transaction_hash: Vec<u8>,
+    ) -> Result<Self, ApiError> {
+        Ok(Self {
+            universal_chain_id: universal_chain_id.map(Into::into),
+            height: BlockHeight::try_from(height)?,
+            timestamp,
+            block_hash: Bytes::from(block_hash).into(),
+            transaction_hash: Bytes::from(transaction_hash).into(),
+        })
+    }
+}
+
+pub async fn packet_hashes_by_transaction_hash(
+    pg_pool: &PgPool,
+    transaction_hash: &[u8],
+    limit: i64,
+) -> Result<Vec<Vec<u8>>, ApiError> {
+    Ok(sqlx::query!(
+        r#"
+        SELECT DISTINCT packet_hash AS "packet_hash!"
+        FROM (
+            SELECT packet_hash FROM v2_sync.packet_send_sync WHERE transaction_hash = $1
+            UNION ALL
+            SELECT packet_hash FROM v2_sync.packet_recv_sync WHERE transaction_hash = $1
+            UNION ALL
+            SELECT packet_hash FROM v2_sync.intent_packet_recv_sync WHERE transaction_hash = $1
+            UNION ALL
+            SELECT packet_hash FROM v2_sync.write_ack_sync WHERE transaction_hash = $1
+            UNION ALL
+            SELECT packet_hash FROM v2_sync.packet_ack_sync WHERE transaction_hash = $1
+            UNION ALL
+            SELECT packet_hash FROM v2_sync.packet_timeout_sync WHERE transaction_hash = $1
+        ) packets
+        LIMIT $2
+        "#,
+        transaction_hash,
+        limit,
+    )
+    .fetch_all(pg_pool)
+    .await?
+    .into_iter()
+    .map(|record| record.packet_hash)
+    .collect())
+}
+
+/// senders are matched on their canonical (bytes) representation when available and on their
+/// display representation (e.g. bech32) otherwise.
+pub async fn packet_hashes_by_sender(
+    pg_pool: &PgPool,
+    sender_canonical: Option<&[u8]>,
+    sender_display: &str,
+    limit: i64,
+) -> Result<Vec<Vec<u8>>, ApiError> {
+    Ok(sqlx::query!(
+        r#"
+        SELECT packet_hash AS "packet_hash!"
+        FROM v2_sync.packet_send_transfers_sync
+        WHERE sender_canonical = $1 OR sender_display = $2
+        GROUP BY packet_hash
+        ORDER BY MAX(timestamp) DESC
+        LIMIT $3
+        "#,
+        sender_canonical,
+        sender_display,
+        limit,
+    )
+    .fetch_all(pg_pool)
+    .await?
+    .into_iter()
+    .map(|record| record.packet_hash)
+    .collect())
+}
+
+pub async fn packet_send(
+    pg_pool: &PgPool,
+    packet_hash: &[u8],
+) -> Result<Option<PacketSend>, ApiError> {
+    sqlx::query!(
+        r#"
+        SELECT
+            chain.family || '.' || chain.chain_id AS universal_chain_id,
+            send.height AS "height!",
+            send.timestamp AS "timestamp!",
+            send.block_hash AS "block_hash!",
+            send.transaction_hash AS "transaction_hash!",
+            send.source_channel_id AS "source_channel_id!",
+            send.destination_channel_id AS "destination_channel_id!",
+            send.timeout_height::TEXT AS "timeout_height!",
+            send.timeout_timestamp::TEXT AS "timeout_timestamp!",
+            send.data AS "data!",
+            decoded.counterparty_universal_chain_id,
+            decoded.data_decoded
+        FROM v2_sync.packet_send_sync send
+        JOIN config.chains chain ON chain.id = send.internal_chain_id
+        LEFT JOIN v2_sync.packet_send_decoded_sync decoded
+            ON decoded.internal_chain_id = send.internal_chain_id
+            AND decoded.packet_hash = send.packet_hash
+        WHERE send.packet_hash = $1
+        ORDER BY send.timestamp
+        LIMIT 1
+        "#,
+        packet_hash,
+    )
+    .fetch_optional(pg_pool)
+    .await?
+    .map(|record| {
+        Ok(PacketSend {
+            stage: Stage::new(
+                record.universal_chain_id,
+                record.height,
+                record.timestamp,
+                record.block_hash,
+                record.transaction_hash,
+            )?,
+            counterparty_universal_chain_id: record.counterparty_universal_chain_id.map(Into::into),
+            source_channel_id: ChannelId::try_from(record.source_channel_id)?,
+            destination_channel_id: ChannelId::try_from(record.destination_channel_id)?,
+            timeout_height: record.timeout_height,
+            timeout_timestamp: record.timeout_timestamp,
+            data: Bytes::from(record.data).into(),
+            decoded: record.data_decoded,
+        })
+    })
+    .transpose()
+}
+
+/// returns the first receive of the packet, which is either a regular or an intent receive.
+pub async fn packet_recv(
+    pg_pool: &PgPool,
+    packet_hash: &[u8],
+) -> Result<Option<PacketRecv>, ApiError> {
+    sqlx::query!(
+        r#"
+        SELECT
+            chain.family || '.' || chain.chain_id AS universal_chain_id,
+            recv.height AS "height!",
+            recv.timestamp AS "timestamp!",
+            recv.block_hash AS "block_hash!",
+            recv.transaction_hash AS "transaction_hash!",
+            recv.maker AS "maker!",
+            recv.maker_msg AS "maker_msg!",
+            recv.intent AS "intent!"
+        FROM (
+            SELECT
+                internal_chain_id, height, timestamp, block_hash, transaction_hash,
+                maker, maker_msg, FALSE AS intent
+            FROM v2_sync.packet_recv_sync
+            WHERE packet_hash = $1
+            UNION ALL
+            SELECT
+                internal_chain_id, height, timestamp, block_hash, transaction_hash,
+                maker, maker_msg, TRUE AS intent
+            FROM v2_sync.intent_packet_recv_sync
+            WHERE packet_hash = $1
+        ) recv
+        JOIN config.chains chain ON chain.id = recv.internal_chain_id
+        ORDER BY recv.timestamp
+        LIMIT 1
+        "#,
+        packet_hash,
+    )
+    .fetch_optional(pg_pool)
+    .await?
+    .map(|record| {
+        Ok(PacketRecv {
+            stage: Stage::new(
+                record.universal_chain_id,
+                record.height,
+                record.timestamp,
+                record.block_hash,
+                record.transaction_hash,
+            )?,
+            maker: Bytes::from(record.maker).into(),
+            maker_msg: Bytes::from(record.maker_msg).into(),
+            intent: record.intent,
+        })
+    })
+    .transpose()
+}
+
+pub async fn write_ack(pg_pool: &PgPool, packet_hash: &[u8]) -> Result<Option<WriteAck>, ApiError> {
+    sqlx::query!(
+        r#"
+        SELECT
+            chain.family || '.' || chain.chain_id AS universal_chain_id,
+            write_ack.height AS "height!",
+            write_ack.timestamp AS "timestamp!",
+            write_ack.block_hash AS "block_hash!",
+            write_ack.transaction_hash AS "transaction_hash!",
+            write_ack.acknowledgement AS "acknowledgement!"
+        FROM v2_sync.write_ack_sync write_ack
+        JOIN config.chains chain ON chain.id = write_ack.internal_chain_id
+        WHERE write_ack.packet_hash = $1
+        ORDER BY write_ack.timestamp
+        LIMIT 1
+        "#,
+        packet_hash,
+    )
+    .fetch_optional(pg_pool)
+    .await?
+    .map(|record| {
+        Ok(WriteAck {
+            stage: Stage::new(
+                record.universal_chain_id,
+                record.height,
+                record.timestamp,
+                record.block_hash,
+                record.transaction_hash,
+            )?,
+            acknowledgement: Bytes::from(record.acknowledgement).into(),
+        })
+    })
+    .transpose()
+}
+
+pub async fn packet_ack(
+    pg_pool: &PgPool,
+    packet_hash: &[u8],
+) -> Result<Option<PacketAck>, ApiError> {
+    sqlx::query!(
+        r#"
+        SELECT
+            chain.family || '.' || chain.chain_id AS universal_chain_id,
+            ack.height AS "height!",
+            ack.timestamp AS "timestamp!",
+            ack.block_hash AS "block_hash!",
+            ack.transaction_hash AS "transaction_hash!",
+            ack.acknowledgement AS "acknowledgement!",
+            ack.maker AS "maker!"
+        FROM v2_sync.packet_ack_sync ack
+        JOIN config.chains chain ON chain.id = ack.internal_chain_id
+        WHERE ack.packet_hash = $1
+        ORDER BY ack.timestamp
+        LIMIT 1
+        "#,
+        packet_hash,
+    )
+    .fetch_optional(pg_pool)
+    .await?
+    .map(|record| {
+        Ok(PacketAck {
+            stage: Stage::new(
+                record.universal_chain_id,
+                record.height,
+                record.timestamp,
+                record.block_hash,
+                record.transaction_hash,
+            )?,
+            acknowledgement: Bytes::from(record.acknowledgement).into(),
+            maker: Bytes::from(record.maker).into(),
+        })
+    })
+    .transpose()
+}
+
+pub async fn packet_timeout(
+    pg_pool: &PgPool,
+    packet_hash: &[u8],
+) -> Result<Option<PacketTimeout>, ApiError> {
+    sqlx::query!(
+        r#"
+        SELECT
+            chain.family || '.' || chain.chain_id AS universal_chain_id,
+            timeout.height AS "height!",
+            timeout.timestamp AS "timestamp!",
+            timeout.block_hash AS "block_hash!",
+            timeout.transaction_hash AS "transaction_hash!",
+            timeout.maker AS "maker!"
+        FROM v2_sync.packet_timeout_sync timeout
+        JOIN config.chains chain ON chain.id = timeout.internal_chain_id
+        WHERE timeout.packet_hash = $1
+        ORDER BY timeout.timestamp
+        LIMIT 1
+        "#,
+        packet_hash,
+    )
+    .fetch_optional(pg_pool)
+    .await?
+    .map(|record| {
+        Ok(PacketTimeout {
+            stage: Stage::new(
+                record.universal_chain_id,
+                record.height,
+                record.timestamp,
+                record.block_hash,
+                record.transaction_hash,
+            )?,
+            maker: Bytes::from(record.maker).into(),
+        })
+    })
+    .transpose()
+}
+
+/// decoded `ucs03_zkgm_0` instructions, as written by the enricher.
+pub async fn instructions(
+    pg_pool: &PgPool,
+    packet_hash: &[u8],
+) -> Result<Vec<Instruction>, ApiError> {
+    Ok(sqlx::query!(
+        r#"
+        SELECT
+            instruction_index AS "instruction_index!",
+            instruction_hash AS "instruction_hash!",
+            instruction_type AS "instruction_type!",
+            path AS "path!",
+            salt AS "salt!",
+            instruction_path AS "instruction_path!",
+            version AS "version!",
+            opcode AS "opcode!",
+            operand_sender,
+            operand_contract_address
+        FROM v2_sync.packet_send_instructions_search_sync
+        WHERE packet_hash = $1
+        ORDER BY instruction_index
+        "#,
+        packet_hash,
+    )
+    .fetch_all(pg_pool)
+    .await?
+    .into_iter()
+    .map(|record| Instruction {
+        instruction_index: record.instruction_index,
+        instruction_hash: record.instruction_hash.into(),
+        instruction_type: record.instruction_type,
+        path: record.path.into(),
+        salt: record.salt.into(),
+        instruction_path: record.instruction_path,
+        version: record.version,
+        opcode: record.opcode,
+        operand_sender: record.operand_sender,
+        operand_contract_address: record.operand_contract_address,
+    })
+    .collect())
+}
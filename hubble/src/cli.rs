@@ -49,6 +49,10 @@ pub struct Args {
     #[arg(short, long, env = "HUBBLE_METRICS_PORT")]
     pub metrics_addr: Option<SocketAddr>,
 
+    /// Address to serve the packet query api on.
+    #[arg(long, env = "HUBBLE_API_ADDR")]
+    pub api_addr: Option<SocketAddr>,
+
     /// The log format for Hubble.
     #[arg(
         global = true,
@@ -115,6 +119,8 @@ pub enum IndexerConfig {
     Dummy(indexer::dummy::config::Config),
     #[serde(rename = "ethereum")]
     Ethereum(indexer::ethereum::config::Config),
+    #[serde(rename = "sui")]
+    Sui(indexer::sui::config::Config),
     #[serde(rename = "tendermint")]
     Tendermint(indexer::tendermint::config::Config),
 }
@@ -124,6 +130,7 @@ impl IndexerConfig {
         match &self {
             Self::Dummy(cfg) => &cfg.indexer_id,
             Self::Ethereum(cfg) => &cfg.indexer_id,
+            Self::Sui(cfg) => &cfg.indexer_id,
             Self::Tendermint(cfg) => &cfg.indexer_id,
         }
     }
@@ -157,6 +164,14 @@ impl IndexerConfig {
                     .instrument(indexer_span)
                     .await
             }
+            Self::Sui(cfg) => {
+                cfg.build(db, nats)
+                    .instrument(initializer_span)
+                    .await?
+                    .index()
+                    .instrument(indexer_span)
+                    .await
+            }
             Self::Tendermint(cfg) => {
                 cfg.build(db, nats)
                     .instrument(initializer_span)
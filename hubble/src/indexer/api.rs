@@ -155,6 +155,8 @@ pub enum IndexerError {
     WrapperPredictionError(String, String),
     #[error("could not acquire lock for chain {0} block {1} (already held by another process)")]
     LockAcquisitionFailed(UniversalChainId, types::BlockHeight),
+    #[error("webhook error: {0}")]
+    WebhookError(#[from] reqwest::Error),
 }
 
 #[derive(Error, Debug)]
@@ -309,6 +311,8 @@ pub struct BlockReference {
     pub height: BlockHeight,
     pub hash: BlockHash,
     pub timestamp: BlockTimestamp,
+    /// hash of the previous block; used to detect reorgs when the chain provides it.
+    pub parent_hash: Option<BlockHash>,
 }
 
 impl BlockReference {
@@ -317,6 +321,7 @@ impl BlockReference {
             height,
             hash,
             timestamp,
+            parent_hash: None,
         }
     }
 }
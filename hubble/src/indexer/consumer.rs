@@ -285,6 +285,9 @@ fn should_trigger_enrich_reset(kind: RecordKind) -> bool {
         ChannelOpenTry => true,
         ChannelOpenAck => true,
         ChannelOpenConfirm => true,
+        // closing a channel does not change its connection details
+        ChannelCloseInit => false,
+        ChannelCloseConfirm => false,
         // connection details are used in enrichement
         ConnectionOpenInit => true,
         ConnectionOpenTry => true,
@@ -294,15 +297,19 @@ fn should_trigger_enrich_reset(kind: RecordKind) -> bool {
         CreateClient => true,
         // lens-client does not affect enrichment
         CreateLensClient => false,
-        // client updates do not affect enrichment
+        // client updates and status changes do not affect enrichment
         UpdateClient => false,
+        ClientStatusUpdate => false,
         // packet-send is enriched upon insertion
         PacketSend => false,
         // non-send packet events are not enriched
         PacketRecv => false,
+        IntentPacketRecv => false,
         WriteAck => false,
         PacketAck => false,
         PacketTimeout => false,
+        BatchSend => false,
+        BatchAcks => false,
         // not related to packets
         TokenBucketUpdate => false,
         WalletMutationEntry => false,
@@ -5,6 +5,7 @@ use super::dummy::{DummyContext, DummyFetcherClient};
 use crate::indexer::{
     api::IndexerId, event::types::UniversalChainId, nats::NatsConnection, ConsumerConfig,
     EnricherConfig, FinalizerConfig, FixerConfig, Indexer, PublisherConfig,
+    StuckPacketDetectorConfig,
 };
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -17,6 +18,7 @@ pub struct Config {
     pub publisher: PublisherConfig,
     pub consumer: ConsumerConfig,
     pub enricher: EnricherConfig,
+    pub stuck_packet_detector: StuckPacketDetectorConfig,
     pub drain: bool,
 }
 
@@ -38,6 +40,7 @@ impl Config {
             self.publisher,
             self.consumer,
             self.enricher,
+            self.stuck_packet_detector,
             DummyContext { bla: 42 },
             self.drain,
         ))
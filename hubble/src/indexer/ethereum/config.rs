@@ -8,6 +8,7 @@ use crate::indexer::{
     event::types::UniversalChainId,
     nats::NatsConnection,
     ConsumerConfig, EnricherConfig, FinalizerConfig, FixerConfig, Indexer, PublisherConfig,
+    StuckPacketDetectorConfig,
 };
 
 const DEFAULT_CHUNK_SIZE: usize = 200;
@@ -30,6 +31,8 @@ pub struct Config {
     #[serde(default)]
     pub enricher: EnricherConfig,
     #[serde(default)]
+    pub stuck_packet_detector: StuckPacketDetectorConfig,
+    #[serde(default)]
     pub drain: bool,
 }
 
@@ -51,6 +54,7 @@ impl Config {
             self.publisher,
             self.consumer,
             self.enricher,
+            self.stuck_packet_detector,
             EthContext {
                 rpc_urls: self.rpc_urls,
             },
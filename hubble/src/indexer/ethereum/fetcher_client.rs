@@ -48,6 +48,7 @@ impl BlockReferenceProvider for AnyRpcBlock {
                 self.header.timestamp.try_into().unwrap(),
             )
             .map_err(|err| IndexerError::ProviderError(Box::new(err.into())))?,
+            parent_hash: Some(self.header.parent_hash.to_lower_hex()),
         })
     }
 }
@@ -0,0 +1,25 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    ethereum::{fetcher_client::EthFetcherClient, mapping::decoder::Decoder},
+    event::{batch_acks_event::BatchAcksEvent, supported::SupportedBlockEvent},
+};
+
+impl EthFetcherClient {
+    pub fn to_batch_acks(
+        &self,
+        decoder: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_batch_acks - {decoder}");
+
+        Ok(vec![SupportedBlockEvent::BatchAcks {
+            inner: BatchAcksEvent {
+                header: decoder.header()?,
+                channel_id: decoder.event.channel_id()?,
+                packet_hash: decoder.event.packet_hash()?,
+                batch_hash: decoder.event.batch_hash()?,
+            },
+        }])
+    }
+}
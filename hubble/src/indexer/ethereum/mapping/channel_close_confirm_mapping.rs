@@ -0,0 +1,28 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    ethereum::{fetcher_client::EthFetcherClient, mapping::decoder::Decoder},
+    event::{
+        channel_close_confirm_event::ChannelCloseConfirmEvent, supported::SupportedBlockEvent,
+    },
+};
+
+impl EthFetcherClient {
+    pub fn to_channel_close_confirm(
+        &self,
+        decoder: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_channel_close_confirm - {decoder}");
+
+        Ok(vec![SupportedBlockEvent::ChannelCloseConfirm {
+            inner: ChannelCloseConfirmEvent {
+                header: decoder.header()?,
+                channel_id: decoder.event.channel_id()?,
+                port_id: decoder.event.port_id()?,
+                counterparty_channel_id: decoder.event.counterparty_channel_id()?,
+                counterparty_port_id: decoder.event.counterparty_port_id()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,26 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    ethereum::{fetcher_client::EthFetcherClient, mapping::decoder::Decoder},
+    event::{channel_close_init_event::ChannelCloseInitEvent, supported::SupportedBlockEvent},
+};
+
+impl EthFetcherClient {
+    pub fn to_channel_close_init(
+        &self,
+        decoder: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_channel_close_init - {decoder}");
+
+        Ok(vec![SupportedBlockEvent::ChannelCloseInit {
+            inner: ChannelCloseInitEvent {
+                header: decoder.header()?,
+                channel_id: decoder.event.channel_id()?,
+                port_id: decoder.event.port_id()?,
+                counterparty_channel_id: decoder.event.counterparty_channel_id()?,
+                counterparty_port_id: decoder.event.counterparty_port_id()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,28 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    ethereum::{fetcher_client::EthFetcherClient, mapping::decoder::Decoder},
+    event::{
+        client_status_update_event::ClientStatusUpdateEvent, supported::SupportedBlockEvent,
+        types::ClientStatus,
+    },
+};
+
+impl EthFetcherClient {
+    pub fn to_client_status_update(
+        &self,
+        decoder: &Decoder,
+        status: ClientStatus,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_client_status_update - {decoder} ({status:?})");
+
+        Ok(vec![SupportedBlockEvent::ClientStatusUpdate {
+            inner: ClientStatusUpdateEvent {
+                header: decoder.header()?,
+                client_id: decoder.event.client_id()?,
+                status,
+            },
+        }])
+    }
+}
@@ -14,9 +14,9 @@ use crate::{
         event::{
             header::Header,
             types::{
-                Acknowledgement, BlockHash, BlockHeight, CanonicalChainId, Capacity, ChannelId,
-                ChannelVersion, ClientId, ClientType, ConnectionId, Denom, Maker, MakerMsg,
-                PacketData, PacketHash, Path, PortId, RefillRate, TimeoutTimestamp,
+                Acknowledgement, BatchHash, BlockHash, BlockHeight, CanonicalChainId, Capacity,
+                ChannelId, ChannelVersion, ClientId, ClientType, ConnectionId, Denom, Maker,
+                MakerMsg, PacketData, PacketHash, Path, PortId, RefillRate, TimeoutTimestamp,
                 TransactionHash,
             },
         },
@@ -199,6 +199,10 @@ impl SolEvent {
         self.get_packet_hash("packetHash")
     }
 
+    pub fn batch_hash(&self) -> Result<BatchHash, IndexerError> {
+        self.get_batch_hash("batchHash")
+    }
+
     pub fn data(&self) -> Result<PacketData, IndexerError> {
         self.get_packet_data("data")
     }
@@ -291,6 +295,10 @@ impl SolEvent {
         Ok(self.get_bytes(key, "packet-hash")?.into())
     }
 
+    fn get_batch_hash(&self, key: &str) -> Result<BatchHash, IndexerError> {
+        Ok(self.get_bytes(key, "batch-hash")?.into())
+    }
+
     fn get_packet_data(&self, key: &str) -> Result<PacketData, IndexerError> {
         Ok(self.get_bytes(key, "packet-data")?.into())
     }
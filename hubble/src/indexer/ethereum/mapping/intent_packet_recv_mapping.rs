@@ -0,0 +1,26 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    ethereum::{fetcher_client::EthFetcherClient, mapping::decoder::Decoder},
+    event::{intent_packet_recv_event::IntentPacketRecvEvent, supported::SupportedBlockEvent},
+};
+
+impl EthFetcherClient {
+    pub fn to_intent_packet_recv(
+        &self,
+        decoder: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_intent_packet_recv - {decoder}");
+
+        Ok(vec![SupportedBlockEvent::IntentPacketRecv {
+            inner: IntentPacketRecvEvent {
+                header: decoder.header()?,
+                channel_id: decoder.event.channel_id()?,
+                packet_hash: decoder.event.packet_hash()?,
+                maker: decoder.event.maker()?,
+                maker_msg: decoder.event.maker_msg()?,
+            },
+        }])
+    }
+}
@@ -11,13 +11,18 @@ use crate::indexer::{
         fetcher_client::EthFetcherClient,
         mapping::decoder::Decoder,
     },
-    event::supported::SupportedBlockEvent,
+    event::{supported::SupportedBlockEvent, types::ClientStatus},
 };
 
+mod batch_acks_mapping;
+mod batch_send_mapping;
+mod channel_close_confirm_mapping;
+mod channel_close_init_mapping;
 mod channel_open_ack_mapping;
 mod channel_open_confirm_mapping;
 mod channel_open_init_mapping;
 mod channel_open_try_mapping;
+mod client_status_update_mapping;
 mod connection_open_ack_mapping;
 mod connection_open_confirm_mapping;
 mod connection_open_init_mapping;
@@ -26,6 +31,7 @@ mod create_client_mapping;
 mod create_lens_client_mapping;
 mod create_wrapped_token;
 mod decoder;
+mod intent_packet_recv_mapping;
 pub(crate) mod legacy;
 mod packet_ack_mapping;
 mod packet_recv_mapping;
@@ -123,6 +129,8 @@ impl EthFetcherClient {
             "ChannelOpenTry" => self.to_channel_open_try(&log_decoder)?,
             "ChannelOpenAck" => self.to_channel_open_ack(&log_decoder)?,
             "ChannelOpenConfirm" => self.to_channel_open_confirm(&log_decoder)?,
+            "ChannelCloseInit" => self.to_channel_close_init(&log_decoder)?,
+            "ChannelCloseConfirm" => self.to_channel_close_confirm(&log_decoder)?,
             "ConnectionOpenInit" => self.to_connection_open_init(&log_decoder)?,
             "ConnectionOpenTry" => self.to_connection_open_try(&log_decoder)?,
             "ConnectionOpenAck" => self.to_connection_open_ack(&log_decoder)?,
@@ -130,11 +138,15 @@ impl EthFetcherClient {
             "CreateClient" => self.to_create_client(&log_decoder)?,
             "CreateLensClient" => self.to_create_lens_client(&log_decoder)?,
             "UpdateClient" => self.to_update_client(&log_decoder)?,
+            "Misbehaviour" => self.to_client_status_update(&log_decoder, ClientStatus::Frozen)?,
             "PacketSend" => self.to_packet_send(&log_decoder)?,
             "PacketRecv" => self.to_packet_recv(&log_decoder)?,
+            "IntentPacketRecv" => self.to_intent_packet_recv(&log_decoder)?,
             "WriteAck" => self.to_write_ack(&log_decoder)?,
             "PacketAck" => self.to_packet_ack(&log_decoder)?,
             "PacketTimeout" => self.to_packet_timeout(&log_decoder)?,
+            "BatchedPreviouslySent" => self.to_batch_send(&log_decoder)?,
+            "BatchedPreviouslyAcked" => self.to_batch_acks(&log_decoder)?,
             "TokenBucketUpdate" => self.to_token_bucket_update(&log_decoder)?,
             "CreateWrappedToken" => self.to_create_wrapped_token(&log_decoder)?,
             name => {
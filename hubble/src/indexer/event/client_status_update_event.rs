@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+use crate::indexer::event::{
+    header::Header,
+    types::{ClientId, ClientStatus},
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClientStatusUpdateEvent {
+    #[serde(flatten)]
+    pub header: Header,
+    pub client_id: ClientId,
+    pub status: ClientStatus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indexer::event::test_utils::test_helpers::{
+        create_test_header, test_json_format, test_roundtrip_serialization,
+    };
+
+    /// Creates a test ClientStatusUpdateEvent with unique deterministic values
+    fn create_test_event(suffix: u32) -> ClientStatusUpdateEvent {
+        let header = create_test_header(suffix);
+
+        ClientStatusUpdateEvent {
+            header,
+            client_id: ClientId(suffix + 1000),
+            status: ClientStatus::Frozen,
+        }
+    }
+
+    #[test]
+    fn test_json_serialization() {
+        let event = create_test_event(1);
+        test_roundtrip_serialization(&event);
+    }
+
+    #[test]
+    fn test_json_format_stability() {
+        let event = create_test_event(42);
+
+        let expected_json = r#"{
+  "block_hash": "0x424c4f434b5f484153485f3432",
+  "client_id": 1042,
+  "event_index": "42",
+  "height": "10042",
+  "status": "frozen",
+  "timestamp": "2020-09-13T12:27:22Z",
+  "transaction_event_index": "242",
+  "transaction_hash": "0x54585f484153485f3432",
+  "transaction_index": "142",
+  "universal_chain_id": "test-chain-42"
+}"#;
+
+        test_json_format(&event, expected_json);
+    }
+}
@@ -1,7 +1,12 @@
+pub(crate) mod batch_acks_event;
+pub(crate) mod batch_send_event;
+pub(crate) mod channel_close_confirm_event;
+pub(crate) mod channel_close_init_event;
 pub(crate) mod channel_open_ack_event;
 pub(crate) mod channel_open_confirm_event;
 pub(crate) mod channel_open_init_event;
 pub(crate) mod channel_open_try_event;
+pub(crate) mod client_status_update_event;
 pub(crate) mod connection_open_ack_event;
 pub(crate) mod connection_open_confirm_event;
 pub(crate) mod connection_open_init_event;
@@ -11,6 +16,7 @@ pub(crate) mod create_lens_client_event;
 pub(crate) mod create_wrapped_token;
 pub(crate) mod header;
 pub(crate) mod hubble;
+pub(crate) mod intent_packet_recv_event;
 pub(crate) mod packet_ack_event;
 pub(crate) mod packet_recv_event;
 pub(crate) mod packet_send_event;
@@ -3,19 +3,22 @@ use serde_json::Value;
 use time::OffsetDateTime;
 
 use crate::indexer::event::{
-    channel_open_ack_event::ChannelOpenAckEvent,
+    batch_acks_event::BatchAcksEvent, batch_send_event::BatchSendEvent,
+    channel_close_confirm_event::ChannelCloseConfirmEvent,
+    channel_close_init_event::ChannelCloseInitEvent, channel_open_ack_event::ChannelOpenAckEvent,
     channel_open_confirm_event::ChannelOpenConfirmEvent,
     channel_open_init_event::ChannelOpenInitEvent, channel_open_try_event::ChannelOpenTryEvent,
+    client_status_update_event::ClientStatusUpdateEvent,
     connection_open_ack_event::ConnectionOpenAckEvent,
     connection_open_confirm_event::ConnectionOpenConfirmEvent,
     connection_open_init_event::ConnectionOpenInitEvent,
     connection_open_try_event::ConnectionOpenTryEvent, create_client_event::CreateClientEvent,
     create_lens_client_event::CreateLensClientEvent, create_wrapped_token::CreateWrappedTokenEvent,
-    packet_ack_event::PacketAckEvent, packet_recv_event::PacketRecvEvent,
-    packet_send_event::PacketSendEvent, packet_timeout_event::PacketTimeoutEvent,
-    token_bucket_update_event::TokenBucketUpdateEvent, types::BlockHeight,
-    update_client_event::UpdateClientEvent, wallet_mutation_entry_event::WalletMutationEntryEvent,
-    write_ack_event::WriteAckEvent,
+    intent_packet_recv_event::IntentPacketRecvEvent, packet_ack_event::PacketAckEvent,
+    packet_recv_event::PacketRecvEvent, packet_send_event::PacketSendEvent,
+    packet_timeout_event::PacketTimeoutEvent, token_bucket_update_event::TokenBucketUpdateEvent,
+    types::BlockHeight, update_client_event::UpdateClientEvent,
+    wallet_mutation_entry_event::WalletMutationEntryEvent, write_ack_event::WriteAckEvent,
 };
 
 #[warn(clippy::enum_variant_names)]
@@ -99,6 +102,16 @@ pub enum SupportedBlockEvent {
         #[serde(flatten)]
         inner: ChannelOpenConfirmEvent,
     },
+    #[serde(rename = "channel-close-init")]
+    ChannelCloseInit {
+        #[serde(flatten)]
+        inner: ChannelCloseInitEvent,
+    },
+    #[serde(rename = "channel-close-confirm")]
+    ChannelCloseConfirm {
+        #[serde(flatten)]
+        inner: ChannelCloseConfirmEvent,
+    },
     #[serde(rename = "connection-open-init")]
     ConnectionOpenInit {
         #[serde(flatten)]
@@ -134,6 +147,11 @@ pub enum SupportedBlockEvent {
         #[serde(flatten)]
         inner: UpdateClientEvent,
     },
+    #[serde(rename = "client-status-update")]
+    ClientStatusUpdate {
+        #[serde(flatten)]
+        inner: ClientStatusUpdateEvent,
+    },
     #[serde(rename = "packet-send")]
     PacketSend {
         #[serde(flatten)]
@@ -144,6 +162,11 @@ pub enum SupportedBlockEvent {
         #[serde(flatten)]
         inner: PacketRecvEvent,
     },
+    #[serde(rename = "intent-packet-recv")]
+    IntentPacketRecv {
+        #[serde(flatten)]
+        inner: IntentPacketRecvEvent,
+    },
     #[serde(rename = "write-ack")]
     WriteAck {
         #[serde(flatten)]
@@ -159,6 +182,16 @@ pub enum SupportedBlockEvent {
         #[serde(flatten)]
         inner: PacketTimeoutEvent,
     },
+    #[serde(rename = "batch-send")]
+    BatchSend {
+        #[serde(flatten)]
+        inner: BatchSendEvent,
+    },
+    #[serde(rename = "batch-acks")]
+    BatchAcks {
+        #[serde(flatten)]
+        inner: BatchAcksEvent,
+    },
     #[serde(rename = "token-bucket-update")]
     TokenBucketUpdate {
         #[serde(flatten)]
@@ -188,6 +221,8 @@ impl SupportedBlockEvent {
             SupportedBlockEvent::ChannelOpenTry { inner, .. } => inner.header.height,
             SupportedBlockEvent::ChannelOpenAck { inner, .. } => inner.header.height,
             SupportedBlockEvent::ChannelOpenConfirm { inner, .. } => inner.header.height,
+            SupportedBlockEvent::ChannelCloseInit { inner, .. } => inner.header.height,
+            SupportedBlockEvent::ChannelCloseConfirm { inner, .. } => inner.header.height,
             SupportedBlockEvent::ConnectionOpenInit { inner, .. } => inner.header.height,
             SupportedBlockEvent::ConnectionOpenTry { inner, .. } => inner.header.height,
             SupportedBlockEvent::ConnectionOpenAck { inner, .. } => inner.header.height,
@@ -195,11 +230,15 @@ impl SupportedBlockEvent {
             SupportedBlockEvent::CreateClient { inner, .. } => inner.header.height,
             SupportedBlockEvent::CreateLensClient { inner, .. } => inner.header.height,
             SupportedBlockEvent::UpdateClient { inner, .. } => inner.header.height,
+            SupportedBlockEvent::ClientStatusUpdate { inner, .. } => inner.header.height,
             SupportedBlockEvent::PacketSend { inner, .. } => inner.header.height,
             SupportedBlockEvent::PacketRecv { inner, .. } => inner.header.height,
+            SupportedBlockEvent::IntentPacketRecv { inner, .. } => inner.header.height,
             SupportedBlockEvent::WriteAck { inner, .. } => inner.header.height,
             SupportedBlockEvent::PacketAck { inner, .. } => inner.header.height,
             SupportedBlockEvent::PacketTimeout { inner, .. } => inner.header.height,
+            SupportedBlockEvent::BatchSend { inner, .. } => inner.header.height,
+            SupportedBlockEvent::BatchAcks { inner, .. } => inner.header.height,
             SupportedBlockEvent::TokenBucketUpdate { inner, .. } => inner.header.height,
             SupportedBlockEvent::WalletMutationEntry { inner, .. } => inner.header.height,
             SupportedBlockEvent::CreateWrappedToken { inner, .. } => inner.header.height,
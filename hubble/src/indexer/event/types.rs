@@ -189,6 +189,14 @@ impl Display for ClientType {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClientStatus {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "frozen")]
+    Frozen,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash, PartialOrd, Ord, Copy)]
 pub struct BlockHeight(#[serde(with = "flexible_u64")] pub u64);
 
@@ -407,6 +415,15 @@ impl fmt::Display for PacketHash {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchHash(#[serde(with = "bytes_as_hex")] pub bytes::Bytes);
+
+impl From<bytes::Bytes> for BatchHash {
+    fn from(value: bytes::Bytes) -> Self {
+        Self(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PacketData(#[serde(with = "bytes_as_hex")] pub bytes::Bytes);
 
@@ -14,6 +14,7 @@ use crate::indexer::{
         block_status::update_block_status,
         indexer_status::{get_current_height, update_current_height},
     },
+    reorg::ReorgResult,
     HappyRangeFetcher,
 };
 
@@ -172,6 +173,15 @@ impl<T: FetcherClient> Indexer<T> {
                     ));
                 }
 
+                if let ReorgResult::Reorged = self
+                    .handle_reorg(fetcher_client, reference)
+                    .instrument(info_span!("reorg"))
+                    .await?
+                {
+                    debug!("{}: reorg handled => refetch", reference);
+                    return Ok(RunToTipLoopResult::RunAgain);
+                }
+
                 let mut tx = self.pg_pool.begin().await?;
                 let events = block_handle
                     .insert(&mut tx)
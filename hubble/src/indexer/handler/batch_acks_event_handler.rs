@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::batch_acks_event::BatchAcksEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, batch_acks_record::BatchAcksRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, BatchAcksEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        BatchAcksRecord::try_from(self)?.insert(tx).await
+    }
+}
@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::batch_send_event::BatchSendEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, batch_send_record::BatchSendRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, BatchSendEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        BatchSendRecord::try_from(self)?.insert(tx).await
+    }
+}
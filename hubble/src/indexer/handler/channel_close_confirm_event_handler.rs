@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::channel_close_confirm_event::ChannelCloseConfirmEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, channel_close_confirm_record::ChannelCloseConfirmRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, ChannelCloseConfirmEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        ChannelCloseConfirmRecord::try_from(self)?.insert(tx).await
+    }
+}
@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::channel_close_init_event::ChannelCloseInitEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, channel_close_init_record::ChannelCloseInitRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, ChannelCloseInitEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        ChannelCloseInitRecord::try_from(self)?.insert(tx).await
+    }
+}
@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::client_status_update_event::ClientStatusUpdateEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, client_status_update_record::ClientStatusUpdateRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, ClientStatusUpdateEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        ClientStatusUpdateRecord::try_from(self)?.insert(tx).await
+    }
+}
@@ -0,0 +1,18 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::intent_packet_recv_event::IntentPacketRecvEvent,
+    handler::EventContext,
+    record::{change_counter::Changes, intent_packet_recv_record::IntentPacketRecvRecord, ChainContext},
+};
+impl<'a> EventContext<'a, ChainContext, IntentPacketRecvEvent> {
+    pub async fn handle(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("handle({self:?})");
+
+        IntentPacketRecvRecord::try_from(self)?.insert(tx).await
+    }
+}
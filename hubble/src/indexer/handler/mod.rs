@@ -1,9 +1,14 @@
 use std::fmt::{self, Display, Formatter};
 
+pub(crate) mod batch_acks_event_handler;
+pub(crate) mod batch_send_event_handler;
+pub(crate) mod channel_close_confirm_event_handler;
+pub(crate) mod channel_close_init_event_handler;
 pub(crate) mod channel_open_ack_event_handler;
 pub(crate) mod channel_open_confirm_event_handler;
 pub(crate) mod channel_open_init_event_handler;
 pub(crate) mod channel_open_try_event_handler;
+pub(crate) mod client_status_update_event_handler;
 pub(crate) mod connection_open_ack_event_handler;
 pub(crate) mod connection_open_confirm_event_handler;
 pub(crate) mod connection_open_init_event_handler;
@@ -11,6 +16,7 @@ pub(crate) mod connection_open_try_event_handler;
 pub(crate) mod create_client_handler;
 pub(crate) mod create_lens_client_handler;
 pub(crate) mod create_wrapped_token_handler;
+pub(crate) mod intent_packet_recv_event_handler;
 pub(crate) mod packet_ack_event_handler;
 pub(crate) mod packet_recv_event_handler;
 pub(crate) mod packet_send_event_handler;
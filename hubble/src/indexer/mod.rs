@@ -14,6 +14,9 @@ pub mod nats;
 mod postgres;
 mod publisher;
 mod record;
+mod reorg;
+mod stuck_packet_detector;
+pub mod sui;
 pub mod tendermint;
 
 use std::{future::Future, time::Duration};
@@ -26,6 +29,7 @@ use futures::{pin_mut, StreamExt};
 use serde::{Deserialize, Deserializer};
 use tokio::{task::JoinSet, time::sleep};
 use tracing::{error, info, info_span, Instrument};
+use url::Url;
 
 use crate::indexer::{event::types::UniversalChainId, nats::NatsConnection};
 
@@ -47,6 +51,7 @@ pub struct Indexer<T: FetcherClient> {
     pub publisher_config: PublisherConfig,
     pub consumer_config: ConsumerConfig,
     pub enricher_config: EnricherConfig,
+    pub stuck_packet_detector_config: StuckPacketDetectorConfig,
     pub context: T::Context,
     pub drain: bool,
 }
@@ -341,6 +346,81 @@ impl Default for EnricherConfig {
     }
 }
 
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct StuckPacketDetectorConfig {
+    // scan for stuck packets.
+    // default: false
+    #[serde(default)]
+    pub enabled: bool,
+
+    // sleep time (in seconds) between scans.
+    // default: 60 seconds
+    #[serde(
+        rename = "interval_seconds",
+        default = "StuckPacketDetectorConfig::default_interval",
+        deserialize_with = "StuckPacketDetectorConfig::deserialize_seconds"
+    )]
+    pub interval: Duration,
+
+    // time (in seconds) a packet can stay in a lifecycle stage before it is considered
+    // stuck. Packets past their timeout timestamp are stuck immediately.
+    // default: 900 seconds
+    #[serde(
+        rename = "threshold_seconds",
+        default = "StuckPacketDetectorConfig::default_threshold",
+        deserialize_with = "StuckPacketDetectorConfig::deserialize_seconds"
+    )]
+    pub threshold: Duration,
+
+    // only packets sent within this window (in seconds) are scanned.
+    // default: 86400 seconds
+    #[serde(
+        rename = "lookback_seconds",
+        default = "StuckPacketDetectorConfig::default_lookback",
+        deserialize_with = "StuckPacketDetectorConfig::deserialize_seconds"
+    )]
+    pub lookback: Duration,
+
+    // url that receives a POST with the stuck packets when a channel becomes stuck.
+    // default: none
+    #[serde(default)]
+    pub webhook_url: Option<Url>,
+}
+
+impl StuckPacketDetectorConfig {
+    pub fn default_interval() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    pub fn default_threshold() -> Duration {
+        Duration::from_secs(15 * 60)
+    }
+
+    pub fn default_lookback() -> Duration {
+        Duration::from_secs(24 * 60 * 60)
+    }
+
+    fn deserialize_seconds<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = u64::deserialize(deserializer)?;
+        Ok(Duration::from_secs(seconds))
+    }
+}
+
+impl Default for StuckPacketDetectorConfig {
+    fn default() -> Self {
+        StuckPacketDetectorConfig {
+            enabled: false,
+            interval: StuckPacketDetectorConfig::default_interval(),
+            threshold: StuckPacketDetectorConfig::default_threshold(),
+            lookback: StuckPacketDetectorConfig::default_lookback(),
+            webhook_url: None,
+        }
+    }
+}
+
 impl<T> Indexer<T>
 where
     T: FetcherClient,
@@ -358,6 +438,7 @@ where
         publisher_config: PublisherConfig,
         consumer_config: ConsumerConfig,
         enricher_config: EnricherConfig,
+        stuck_packet_detector_config: StuckPacketDetectorConfig,
         context: T::Context,
         drain: bool,
     ) -> Self {
@@ -373,6 +454,7 @@ where
             publisher_config,
             consumer_config,
             enricher_config,
+            stuck_packet_detector_config,
             context,
             drain,
         }
@@ -416,6 +498,12 @@ where
                             .instrument(info_span!("enricher")),
                     );
 
+                    let self_clone = self.clone();
+                    join_set.spawn(
+                        async move { self_clone.run_stuck_packet_detector().await }
+                            .instrument(info_span!("stuck-packet-detector")),
+                    );
+
                     let self_clone = self.clone();
                     join_set.spawn(
                         async move { self_clone.run_publisher().await }
@@ -7,3 +7,4 @@ pub(crate) mod indexer_status;
 pub(crate) mod lock;
 pub(crate) mod nats;
 pub(crate) mod replication_reset;
+pub(crate) mod stuck_packets;
@@ -0,0 +1,88 @@
+use sqlx::Postgres;
+use time::OffsetDateTime;
+
+use crate::indexer::{api::IndexerError, event::types::UniversalChainId, record::PgValue};
+
+pub struct StuckPackets {
+    pub stage: String,
+    pub channel_id: i32,
+    pub count: i64,
+    pub oldest: OffsetDateTime,
+}
+
+/// returns per source channel and lifecycle stage the packets sent on this chain (after
+/// `sent_after`) that did not complete. A packet is stuck in:
+/// - `timeout`: not received and past its timeout timestamp (stuck immediately)
+/// - `recv`: not received since it was sent before `stuck_before`
+/// - `write_ack`: received before `stuck_before`, but not acknowledged by the destination
+/// - `ack`: acknowledgement written before `stuck_before`, but not relayed to the source
+pub async fn stuck_packets(
+    tx: &mut sqlx::Transaction<'_, Postgres>,
+    universal_chain_id: &UniversalChainId,
+    sent_after: OffsetDateTime,
+    stuck_before: OffsetDateTime,
+) -> Result<Vec<StuckPackets>, IndexerError> {
+    Ok(sqlx::query!(
+        r#"
+        WITH stages AS (
+            SELECT
+                send.source_channel_id AS channel_id,
+                CASE
+                    WHEN recv.timestamp IS NULL
+                        AND send.timeout_timestamp > 0
+                        AND to_timestamp((send.timeout_timestamp / 1000000000)::DOUBLE PRECISION) < now()
+                        THEN 'timeout'
+                    WHEN recv.timestamp IS NULL THEN 'recv'
+                    WHEN write_ack.timestamp IS NULL THEN 'write_ack'
+                    ELSE 'ack'
+                END AS stage,
+                CASE
+                    WHEN recv.timestamp IS NULL
+                        AND send.timeout_timestamp > 0
+                        AND to_timestamp((send.timeout_timestamp / 1000000000)::DOUBLE PRECISION) < now()
+                        THEN to_timestamp((send.timeout_timestamp / 1000000000)::DOUBLE PRECISION)
+                    ELSE COALESCE(write_ack.timestamp, recv.timestamp, send.timestamp)
+                END AS since
+            FROM v2_sync.packet_send_sync send
+            LEFT JOIN LATERAL (
+                SELECT MIN(timestamp) AS timestamp
+                FROM (
+                    SELECT timestamp FROM v2_sync.packet_recv_sync WHERE packet_hash = send.packet_hash
+                    UNION ALL
+                    SELECT timestamp FROM v2_sync.intent_packet_recv_sync WHERE packet_hash = send.packet_hash
+                ) recvs
+            ) recv ON TRUE
+            LEFT JOIN LATERAL (
+                SELECT MIN(timestamp) AS timestamp
+                FROM v2_sync.write_ack_sync
+                WHERE packet_hash = send.packet_hash
+            ) write_ack ON TRUE
+            WHERE send.internal_chain_id = (SELECT id FROM config.chains WHERE family || '.' || chain_id = $1)
+            AND send.timestamp > $2
+            AND NOT EXISTS (SELECT 1 FROM v2_sync.packet_ack_sync WHERE packet_hash = send.packet_hash)
+            AND NOT EXISTS (SELECT 1 FROM v2_sync.packet_timeout_sync WHERE packet_hash = send.packet_hash)
+        )
+        SELECT
+            stage AS "stage!",
+            channel_id AS "channel_id!",
+            COUNT(*) AS "count!",
+            MIN(since) AS "oldest!"
+        FROM stages
+        WHERE stage = 'timeout' OR since < $3
+        GROUP BY stage, channel_id
+        "#,
+        universal_chain_id.pg_value()?,
+        sent_after,
+        stuck_before,
+    )
+    .fetch_all(tx.as_mut())
+    .await?
+    .into_iter()
+    .map(|record| StuckPackets {
+        stage: record.stage,
+        channel_id: record.channel_id,
+        count: record.count,
+        oldest: record.oldest,
+    })
+    .collect())
+}
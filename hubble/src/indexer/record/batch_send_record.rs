@@ -0,0 +1,121 @@
+use sqlx::{Postgres, Transaction};
+use time::OffsetDateTime;
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{batch_send_event::BatchSendEvent, types::BlockHeight},
+    handler::EventContext,
+    record::{
+        change_counter::{Changes, HasKind, RecordKind},
+        ChainContext, InternalChainId, PgValue, PgValueExt,
+    },
+};
+
+pub struct BatchSendRecord {
+    pub internal_chain_id: i32,
+    pub block_hash: Vec<u8>,
+    pub height: i64,
+    pub event_index: i64,
+    pub timestamp: OffsetDateTime,
+    pub transaction_hash: Vec<u8>,
+    pub transaction_index: i64,
+    pub transaction_event_index: Option<i64>,
+    pub channel_id: i32,
+    pub packet_hash: Vec<u8>,
+    pub batch_hash: Vec<u8>,
+    pub network: String,
+}
+impl HasKind for BatchSendRecord {
+    fn kind() -> RecordKind {
+        RecordKind::BatchSend
+    }
+}
+
+impl<'a> TryFrom<&'a EventContext<'a, ChainContext, BatchSendEvent>> for BatchSendRecord {
+    type Error = IndexerError;
+
+    fn try_from(
+        value: &'a EventContext<'a, ChainContext, BatchSendEvent>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            internal_chain_id: value.context.internal_chain_id.pg_value()?,
+            block_hash: value.event.header.block_hash.pg_value()?,
+            height: value.event.header.height.pg_value()?,
+            event_index: value.event.header.event_index.pg_value()?,
+            timestamp: value.event.header.timestamp.pg_value()?,
+            transaction_hash: value.event.header.transaction_hash.pg_value()?,
+            transaction_index: value.event.header.transaction_index.pg_value()?,
+            transaction_event_index: value.event.header.transaction_event_index.pg_value()?,
+            channel_id: value.event.channel_id.pg_value()?,
+            packet_hash: value.event.packet_hash.pg_value()?,
+            batch_hash: value.event.batch_hash.pg_value()?,
+            network: value.context.network.pg_value()?,
+        })
+    }
+}
+
+impl BatchSendRecord {
+    pub async fn insert(
+        &self,
+        tx: &mut Transaction<'_, Postgres>,
+    ) -> Result<Changes, IndexerError> {
+        trace!("insert({})", self.height);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO v2_sync.batch_send_sync (
+                internal_chain_id,
+                block_hash,
+                height,
+                event_index,
+                timestamp,
+                transaction_hash,
+                transaction_index,
+                transaction_event_index,
+                channel_id,
+                packet_hash,
+                batch_hash,
+                network
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+            self.internal_chain_id,
+            &self.block_hash[..],
+            self.height,
+            self.event_index,
+            self.timestamp,
+            &self.transaction_hash[..],
+            self.transaction_index,
+            self.transaction_event_index,
+            self.channel_id,
+            &self.packet_hash[..],
+            &self.batch_hash[..],
+            self.network,
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(Changes::with_single_insert::<Self>())
+    }
+
+    pub async fn delete_by_chain_and_height(
+        tx: &mut Transaction<'_, Postgres>,
+        internal_chain_id: InternalChainId,
+        height: BlockHeight,
+    ) -> Result<Changes, IndexerError> {
+        trace!("delete_by_chain_and_height({internal_chain_id}, {height})");
+
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM v2_sync.batch_send_sync
+            WHERE internal_chain_id = $1 AND height = $2
+            "#,
+            internal_chain_id.pg_value()?,
+            height.pg_value()?
+        )
+        .execute(&mut **tx)
+        .await?;
+
+        Ok(Changes::with_deletes::<Self>(result.rows_affected()))
+    }
+}
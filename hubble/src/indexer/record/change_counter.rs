@@ -312,6 +312,8 @@ pub enum RecordKind {
     ChannelOpenTry,
     ChannelOpenAck,
     ChannelOpenConfirm,
+    ChannelCloseInit,
+    ChannelCloseConfirm,
     ConnectionOpenInit,
     ConnectionOpenTry,
     ConnectionOpenAck,
@@ -319,11 +321,15 @@ pub enum RecordKind {
     CreateClient,
     CreateLensClient,
     UpdateClient,
+    ClientStatusUpdate,
     PacketSend,
     PacketRecv,
+    IntentPacketRecv,
     WriteAck,
     PacketAck,
     PacketTimeout,
+    BatchSend,
+    BatchAcks,
     TokenBucketUpdate,
     WalletMutationEntry,
     PacketSendDecoded,
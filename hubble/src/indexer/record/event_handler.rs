@@ -5,11 +5,16 @@ use crate::indexer::{
     api::IndexerError,
     event::{supported::SupportedBlockEvent, types::BlockHeight},
     record::{
+        batch_acks_record::BatchAcksRecord,
+        batch_send_record::BatchSendRecord,
         change_counter::{Changes, LegacyRecord},
+        channel_close_confirm_record::ChannelCloseConfirmRecord,
+        channel_close_init_record::ChannelCloseInitRecord,
         channel_open_ack_record::ChannelOpenAckRecord,
         channel_open_confirm_record::ChannelOpenConfirmRecord,
         channel_open_init_record::ChannelOpenInitRecord,
         channel_open_try_record::ChannelOpenTryRecord,
+        client_status_update_record::ClientStatusUpdateRecord,
         connection_open_ack_record::ConnectionOpenAckRecord,
         connection_open_confirm_record::ConnectionOpenConfirmRecord,
         connection_open_init_record::ConnectionOpenInitRecord,
@@ -18,6 +23,7 @@ use crate::indexer::{
         create_lens_client_record::CreateLensClientRecord,
         create_wrapped_token_record::CreateWrappedTokenRecord,
         create_wrapped_token_relation_record::CreateWrappedTokenRelationRecord,
+        intent_packet_recv_record::IntentPacketRecvRecord,
         packet_ack_record::PacketAckRecord,
         packet_recv_record::PacketRecvRecord,
         packet_send_decoded_record::PacketSendDecodedRecord,
@@ -79,6 +85,12 @@ pub async fn delete_event_data_at_height(
         changes +=
             ChannelOpenConfirmRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
                 .await?;
+        changes +=
+            ChannelCloseInitRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
+                .await?;
+        changes +=
+            ChannelCloseConfirmRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
+                .await?;
         changes +=
             ConnectionOpenInitRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
                 .await?;
@@ -98,16 +110,26 @@ pub async fn delete_event_data_at_height(
                 .await?;
         changes +=
             UpdateClientRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
+        changes +=
+            ClientStatusUpdateRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
+                .await?;
         changes +=
             PacketSendRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
         changes +=
             PacketRecvRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
+        changes +=
+            IntentPacketRecvRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
+                .await?;
         changes +=
             WriteAckRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
         changes +=
             PacketAckRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
         changes +=
             PacketTimeoutRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
+        changes +=
+            BatchSendRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
+        changes +=
+            BatchAcksRecord::delete_by_chain_and_height(tx, internal_chain_id, height).await?;
         changes +=
             TokenBucketUpdateRecord::delete_by_chain_and_height(tx, internal_chain_id, height)
                 .await?;
@@ -305,6 +327,12 @@ async fn handle_block_event(
         SupportedBlockEvent::ChannelOpenConfirm { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
+        SupportedBlockEvent::ChannelCloseInit { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
+        SupportedBlockEvent::ChannelCloseConfirm { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
         SupportedBlockEvent::ConnectionOpenInit { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
@@ -326,12 +354,18 @@ async fn handle_block_event(
         SupportedBlockEvent::UpdateClient { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
+        SupportedBlockEvent::ClientStatusUpdate { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
         SupportedBlockEvent::PacketSend { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
         SupportedBlockEvent::PacketRecv { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
+        SupportedBlockEvent::IntentPacketRecv { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
         SupportedBlockEvent::WriteAck { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
@@ -341,6 +375,12 @@ async fn handle_block_event(
         SupportedBlockEvent::PacketTimeout { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
+        SupportedBlockEvent::BatchSend { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
+        SupportedBlockEvent::BatchAcks { inner } => {
+            chain_context.with_event(inner).handle(tx).await?
+        },
         SupportedBlockEvent::TokenBucketUpdate { inner } => {
             chain_context.with_event(inner).handle(tx).await?
         },
@@ -6,8 +6,8 @@ use time::OffsetDateTime;
 use crate::indexer::{
     api::IndexerError,
     event::types::{
-        Acknowledgement, BlockHash, BlockHeight, BlockTimestamp, CanonicalChainId, Capacity,
-        ChannelId, ChannelVersion, ClientId, ClientType, ConnectionId, ContractAddress, Denom,
+        Acknowledgement, BatchHash, BlockHash, BlockHeight, BlockTimestamp, CanonicalChainId, Capacity,
+        ChannelId, ChannelVersion, ClientId, ClientStatus, ClientType, ConnectionId, ContractAddress, Denom,
         EventIndex, Maker, MakerMsg, MessageHash, MessageSequence, MutationAmount,
         MutationDirection, NatsConsumerSequence, NatsStreamSequence, PacketData, PacketHash, Path,
         PortId, RefillRate, TimeoutTimestamp, TransactionEventIndex, TransactionHash,
@@ -25,12 +25,17 @@ use crate::indexer::{
     },
 };
 
+pub(crate) mod batch_acks_record;
+pub(crate) mod batch_send_record;
 pub(crate) mod change_counter;
+pub(crate) mod channel_close_confirm_record;
+pub(crate) mod channel_close_init_record;
 pub(crate) mod channel_meta_data;
 pub(crate) mod channel_open_ack_record;
 pub(crate) mod channel_open_confirm_record;
 pub(crate) mod channel_open_init_record;
 pub(crate) mod channel_open_try_record;
+pub(crate) mod client_status_update_record;
 pub(crate) mod connection_open_ack_record;
 pub(crate) mod connection_open_confirm_record;
 pub(crate) mod connection_open_init_record;
@@ -40,6 +45,7 @@ pub(crate) mod create_lens_client_record;
 pub(crate) mod create_wrapped_token_record;
 pub(crate) mod create_wrapped_token_relation_record;
 pub(crate) mod event_handler;
+pub(crate) mod intent_packet_recv_record;
 pub(crate) mod packet_ack_record;
 pub(crate) mod packet_recv_record;
 pub(crate) mod packet_send_decoded_record;
@@ -289,6 +295,15 @@ impl PgValue<String> for ClientType {
         Ok(self.0.clone())
     }
 }
+impl PgValue<String> for ClientStatus {
+    fn pg_value(&self) -> Result<String, IndexerError> {
+        Ok(match self {
+            ClientStatus::Active => "active".to_string(),
+            ClientStatus::Frozen => "frozen".to_string(),
+        })
+    }
+}
+
 impl TryFrom<Option<String>> for ClientType {
     type Error = IndexerError;
 
@@ -433,6 +448,11 @@ impl PgValue<Vec<u8>> for PacketHash {
         Ok(self.0.to_vec())
     }
 }
+impl PgValue<Vec<u8>> for BatchHash {
+    fn pg_value(&self) -> Result<Vec<u8>, IndexerError> {
+        Ok(self.0.to_vec())
+    }
+}
 impl PgValue<Vec<u8>> for PacketData {
     fn pg_value(&self) -> Result<Vec<u8>, IndexerError> {
         Ok(self.0.to_vec())
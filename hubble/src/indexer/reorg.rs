@@ -0,0 +1,321 @@
+use color_eyre::eyre::eyre;
+use tracing::{debug, info, warn};
+
+use super::{
+    api::{
+        BlockHandle, BlockHash, BlockHeight, BlockReference, BlockSelection, FetchMode,
+        FetcherClient, IndexerError,
+    },
+    Indexer,
+};
+use crate::{
+    indexer::{
+        event::types::{BlockEvents, Range},
+        postgres::block_status::{get_block_status_hash, update_block_status, BlockStatus},
+    },
+    metrics::REORG_DEPTH,
+};
+
+pub enum ReorgResult {
+    NoReorg,
+    Reorged,
+}
+
+impl<T: FetcherClient> Indexer<T> {
+    /// compares the parent hash of a new block with the hash of the block we indexed at the
+    /// previous height. on a mismatch the orphaned blocks are replaced by the canonical ones and
+    /// a single message with the corrected events is scheduled, so consumers roll back and
+    /// recompute all records (including enrichments) of the orphaned blocks in one transaction.
+    pub async fn handle_reorg(
+        &self,
+        fetcher_client: &T,
+        reference: &BlockReference,
+    ) -> Result<ReorgResult, IndexerError> {
+        let Some(parent_hash) = &reference.parent_hash else {
+            return Ok(ReorgResult::NoReorg);
+        };
+
+        let Some(parent_height) = reference.height.checked_sub(1) else {
+            return Ok(ReorgResult::NoReorg);
+        };
+
+        let mut tx = self.pg_pool.begin().await?;
+
+        // blocks without status are finalized (or indexed before reaching the tip)
+        let Some(parent_status) =
+            get_block_status_hash(&mut tx, self.indexer_id.clone(), parent_height).await?
+        else {
+            return Ok(ReorgResult::NoReorg);
+        };
+
+        if &parent_status.block_hash == parent_hash {
+            return Ok(ReorgResult::NoReorg);
+        }
+
+        warn!(
+            "{reference}: parent hash mismatch (indexed: {}) => reorg",
+            parent_status.block_hash
+        );
+
+        let orphaned = self
+            .find_orphaned_blocks(&mut tx, fetcher_client, parent_height, parent_status)
+            .await?;
+
+        let (Some(first), Some(last)) = (orphaned.first(), orphaned.last()) else {
+            // the provider returned a block that does not build on its own view of the chain
+            return Err(IndexerError::ProviderError(Box::new(eyre!(
+                "{reference}: parent hash mismatch, but no orphaned blocks found"
+            ))));
+        };
+
+        let range = Range {
+            start_inclusive: first.reference().height,
+            end_exclusive: last.reference().height + 1,
+        };
+        let depth = orphaned.len();
+        info!("{range}: orphaned (depth: {depth}) => rollback");
+
+        let mut events = vec![];
+        for block in &orphaned {
+            if let Some(block_events) = block.update(&mut tx).await? {
+                events.extend(block_events.events);
+            }
+        }
+
+        // one message for all orphaned blocks: consumers handle it in a single transaction
+        let message_hash = self
+            .schedule_message(
+                &mut tx,
+                range.clone(),
+                (!events.is_empty()).then(|| BlockEvents::new(events)),
+            )
+            .await?;
+
+        for block in &orphaned {
+            let reference = block.reference();
+            debug!("{reference}: update status");
+
+            update_block_status(
+                &mut tx,
+                self.indexer_id.clone(),
+                reference.height,
+                reference.hash,
+                reference.timestamp,
+                Some(message_hash.clone()),
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        REORG_DEPTH
+            .with_label_values(&[self.universal_chain_id.0.as_str()])
+            .observe(depth as f64);
+
+        info!("{range}: rolled back (depth: {depth})");
+
+        Ok(ReorgResult::Reorged)
+    }
+
+    /// walks back from `height` until the indexed block matches the canonical chain. returns the
+    /// canonical blocks replacing the orphaned ones, ordered by height. fails if the reorg is
+    /// deeper than the non-finalized blocks instead of rolling back only part of it.
+    async fn find_orphaned_blocks(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        fetcher_client: &T,
+        mut height: BlockHeight,
+        mut status: BlockStatus,
+    ) -> Result<Vec<T::BlockHandle>, IndexerError> {
+        let mut orphaned = vec![];
+
+        loop {
+            let block = fetcher_client
+                .fetch_single(BlockSelection::Height(height), FetchMode::Eager)
+                .await?;
+            let reference = block.reference();
+
+            let parent_status = match height.checked_sub(1) {
+                Some(parent_height) => {
+                    get_block_status_hash(tx, self.indexer_id.clone(), parent_height).await?
+                }
+                None => None,
+            };
+
+            match walk_step(
+                &reference,
+                &status.block_hash,
+                parent_status.as_ref().map(|parent| &parent.block_hash),
+            )? {
+                WalkStep::CommonAncestor => {
+                    debug!("{reference}: common ancestor");
+                    break;
+                }
+                WalkStep::Orphaned => {
+                    debug!(
+                        "{reference}: orphaned {} (parent is canonical)",
+                        status.block_hash
+                    );
+                    orphaned.push(block);
+                    break;
+                }
+                WalkStep::OrphanedParent => {
+                    debug!("{reference}: orphaned {}", status.block_hash);
+                    orphaned.push(block);
+
+                    height -= 1;
+                    status = parent_status.expect("orphaned parent is indexed");
+                }
+            }
+        }
+
+        orphaned.reverse();
+
+        Ok(orphaned)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum WalkStep {
+    /// the indexed block is canonical
+    CommonAncestor,
+    /// the indexed block is orphaned, but its parent is canonical
+    Orphaned,
+    /// the indexed block is orphaned, and so is its parent
+    OrphanedParent,
+}
+
+/// compares the canonical block at a height with the indexed block (and its parent) at that
+/// height. fails if the parent of an orphaned block is not tracked anymore, because the blocks
+/// below it can't be verified (and rolled back) in that case.
+fn walk_step(
+    canonical: &BlockReference,
+    indexed_hash: &BlockHash,
+    indexed_parent_hash: Option<&BlockHash>,
+) -> Result<WalkStep, IndexerError> {
+    if &canonical.hash == indexed_hash {
+        return Ok(WalkStep::CommonAncestor);
+    }
+
+    if canonical.height == 0 {
+        return Ok(WalkStep::Orphaned);
+    }
+
+    match indexed_parent_hash {
+        Some(parent_hash) if Some(parent_hash) == canonical.parent_hash.as_ref() => {
+            Ok(WalkStep::Orphaned)
+        }
+        Some(_) => Ok(WalkStep::OrphanedParent),
+        None => Err(IndexerError::InternalError(Box::new(eyre!(
+            "{canonical}: orphaned, but the parent is not tracked => reorg deeper than the non-finalized blocks"
+        )))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use time::OffsetDateTime;
+
+    use super::*;
+
+    fn canonical(height: BlockHeight, hash: &str, parent_hash: &str) -> BlockReference {
+        BlockReference {
+            height,
+            hash: hash.to_string(),
+            timestamp: OffsetDateTime::UNIX_EPOCH,
+            parent_hash: Some(parent_hash.to_string()),
+        }
+    }
+
+    /// walks back like `find_orphaned_blocks`, using in-memory canonical and indexed chains
+    fn orphaned_heights(
+        canonical_chain: &[BlockReference],
+        indexed: &HashMap<BlockHeight, BlockHash>,
+        mut height: BlockHeight,
+    ) -> Result<Vec<BlockHeight>, IndexerError> {
+        let mut orphaned = vec![];
+
+        loop {
+            let reference = &canonical_chain[height as usize];
+            let parent_hash = height
+                .checked_sub(1)
+                .and_then(|parent| indexed.get(&parent));
+
+            match walk_step(reference, &indexed[&height], parent_hash)? {
+                WalkStep::CommonAncestor => break,
+                WalkStep::Orphaned => {
+                    orphaned.push(height);
+                    break;
+                }
+                WalkStep::OrphanedParent => {
+                    orphaned.push(height);
+                    height -= 1;
+                }
+            }
+        }
+
+        orphaned.reverse();
+
+        Ok(orphaned)
+    }
+
+    /// canonical chain: c0 <- c1 <- c2 <- c3 <- c4
+    fn canonical_chain() -> Vec<BlockReference> {
+        vec![
+            canonical(0, "c0", ""),
+            canonical(1, "c1", "c0"),
+            canonical(2, "c2", "c1"),
+            canonical(3, "c3", "c2"),
+            canonical(4, "c4", "c3"),
+        ]
+    }
+
+    fn indexed(hashes: &[(BlockHeight, &str)]) -> HashMap<BlockHeight, BlockHash> {
+        hashes
+            .iter()
+            .map(|(height, hash)| (*height, hash.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_canonical_block_is_common_ancestor() {
+        assert_eq!(
+            walk_step(&canonical(3, "c3", "c2"), &"c3".to_string(), None).unwrap(),
+            WalkStep::CommonAncestor
+        );
+    }
+
+    #[test]
+    fn test_parent_hash_mismatch_walks_to_common_ancestor() {
+        // indexed: c0 <- c1 <- o2 <- o3, the new tip c4 does not build on o3
+        let indexed = indexed(&[(0, "c0"), (1, "c1"), (2, "o2"), (3, "o3")]);
+
+        assert_eq!(
+            orphaned_heights(&canonical_chain(), &indexed, 3).unwrap(),
+            vec![2, 3]
+        );
+    }
+
+    #[test]
+    fn test_single_orphaned_block() {
+        let indexed = indexed(&[(1, "c1"), (2, "c2"), (3, "o3")]);
+
+        assert_eq!(
+            orphaned_heights(&canonical_chain(), &indexed, 3).unwrap(),
+            vec![3]
+        );
+    }
+
+    #[test]
+    fn test_reorg_deeper_than_tracked_blocks_fails() {
+        // the parent of the deepest orphaned block is finalized (no status), but not canonical
+        let indexed = indexed(&[(2, "o2"), (3, "o3")]);
+
+        assert!(matches!(
+            orphaned_heights(&canonical_chain(), &indexed, 3),
+            Err(IndexerError::InternalError(_))
+        ));
+    }
+}
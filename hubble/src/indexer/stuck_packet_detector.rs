@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+
+use reqwest::Client;
+use serde::Serialize;
+use time::OffsetDateTime;
+use tokio::time::sleep;
+use tracing::{debug, info, warn};
+use url::Url;
+
+use super::{
+    api::{FetcherClient, IndexerError},
+    Indexer,
+};
+use crate::{
+    indexer::{
+        event::types::UniversalChainId,
+        postgres::stuck_packets::{stuck_packets, StuckPackets},
+    },
+    metrics::{STUCK_PACKETS, STUCK_PACKETS_OLDEST_AGE},
+};
+
+/// (stage, channel-id) of stuck packets
+type StuckChannels = HashSet<(String, String)>;
+
+/// The stuck packets that were reported in previous iterations of the detector.
+#[derive(Default)]
+struct Reported {
+    /// channels for which the stuck packet metrics are set
+    metrics: StuckChannels,
+    /// channels that the webhook was notified about (or would have been, if none is configured)
+    notified: StuckChannels,
+}
+
+#[derive(Serialize)]
+struct WebhookMessage<'a> {
+    universal_chain_id: &'a UniversalChainId,
+    stuck: Vec<WebhookStuckPackets<'a>>,
+}
+
+#[derive(Serialize)]
+struct WebhookStuckPackets<'a> {
+    stage: &'a str,
+    channel_id: i32,
+    count: i64,
+    oldest_age_seconds: i64,
+}
+
+impl<T: FetcherClient> Indexer<T> {
+    pub async fn run_stuck_packet_detector(&self) -> Result<(), IndexerError> {
+        if self.drain || !self.stuck_packet_detector_config.enabled {
+            return Ok(());
+        }
+
+        let client = Client::builder()
+            .timeout(self.stuck_packet_detector_config.interval)
+            .build()?;
+        let mut reported = Reported::default();
+
+        loop {
+            if let Err(error) = self
+                .run_stuck_packet_detector_loop(&client, &mut reported)
+                .await
+            {
+                warn!("error in stuck packet detector loop: {error} => try again later");
+            }
+
+            debug!(
+                "sleep {}s",
+                self.stuck_packet_detector_config.interval.as_secs()
+            );
+            sleep(self.stuck_packet_detector_config.interval).await;
+        }
+    }
+
+    async fn run_stuck_packet_detector_loop(
+        &self,
+        client: &Client,
+        reported: &mut Reported,
+    ) -> Result<(), IndexerError> {
+        let now = OffsetDateTime::now_utc();
+
+        let mut tx = self.pg_pool.begin().await?;
+        let stuck = stuck_packets(
+            &mut tx,
+            &self.universal_chain_id,
+            now - self.stuck_packet_detector_config.lookback,
+            now - self.stuck_packet_detector_config.threshold,
+        )
+        .await?;
+        tx.commit().await?;
+
+        let current: StuckChannels = stuck
+            .iter()
+            .map(|stuck| (stuck.stage.clone(), stuck.channel_id.to_string()))
+            .collect();
+
+        let universal_chain_id = self.universal_chain_id.0.as_str();
+
+        // channels that are no longer stuck should not keep reporting their last values
+        for (stage, channel_id) in reported.metrics.difference(&current) {
+            debug!("{stage}/{channel_id}: no longer stuck");
+
+            let labels = [universal_chain_id, channel_id.as_str(), stage.as_str()];
+
+            let _ = STUCK_PACKETS.remove_label_values(&labels);
+            let _ = STUCK_PACKETS_OLDEST_AGE.remove_label_values(&labels);
+        }
+
+        for stuck in &stuck {
+            let channel_id = stuck.channel_id.to_string();
+            let labels = [
+                universal_chain_id,
+                channel_id.as_str(),
+                stuck.stage.as_str(),
+            ];
+
+            STUCK_PACKETS.with_label_values(&labels).set(stuck.count);
+            STUCK_PACKETS_OLDEST_AGE
+                .with_label_values(&labels)
+                .set((now - stuck.oldest).as_seconds_f64());
+        }
+
+        reported.metrics.clone_from(&current);
+
+        let newly_stuck = current.difference(&reported.notified).count();
+        info!("stuck: {} (newly stuck: {newly_stuck})", stuck.len());
+
+        if newly_stuck > 0 {
+            if let Some(webhook_url) = &self.stuck_packet_detector_config.webhook_url {
+                if let Err(error) = self.notify_webhook(client, webhook_url, now, &stuck).await {
+                    warn!("error notifying webhook: {error} => retry in next iteration");
+
+                    // only forget channels that are no longer stuck, so that the newly stuck
+                    // channels are notified again in the next iteration
+                    reported
+                        .notified
+                        .retain(|channel| current.contains(channel));
+
+                    return Ok(());
+                }
+            }
+        }
+
+        reported.notified = current;
+
+        Ok(())
+    }
+
+    async fn notify_webhook(
+        &self,
+        client: &Client,
+        webhook_url: &Url,
+        now: OffsetDateTime,
+        stuck: &[StuckPackets],
+    ) -> Result<(), IndexerError> {
+        debug!("notifying webhook");
+
+        client
+            .post(webhook_url.clone())
+            .json(&WebhookMessage {
+                universal_chain_id: &self.universal_chain_id,
+                stuck: stuck
+                    .iter()
+                    .map(|stuck| WebhookStuckPackets {
+                        stage: &stuck.stage,
+                        channel_id: stuck.channel_id,
+                        count: stuck.count,
+                        oldest_age_seconds: (now - stuck.oldest).whole_seconds(),
+                    })
+                    .collect(),
+            })
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use axum::async_trait;
+use futures::{stream::FuturesOrdered, Stream};
+use sqlx::Postgres;
+use sui_sdk::rpc_types::Checkpoint;
+use tracing::{debug, trace};
+
+use crate::indexer::{
+    api::{BlockHandle, BlockRange, BlockReference, BlockSelection, FetchMode, IndexerError},
+    event::{supported::SupportedBlockEvent, types::BlockEvents},
+    sui::{fetcher_client::SuiFetcherClient, provider::RpcProviderId},
+};
+
+#[derive(Clone)]
+pub enum BlockDetails {
+    Lazy(Box<Checkpoint>),
+    Eager(Vec<SupportedBlockEvent>),
+}
+
+#[derive(Clone)]
+pub struct SuiBlockHandle {
+    pub reference: BlockReference,
+    pub details: BlockDetails,
+    pub sui_client: SuiFetcherClient,
+    pub provider_id: RpcProviderId,
+}
+
+impl SuiBlockHandle {
+    async fn get_events(&self) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        Ok(match self.details.clone() {
+            BlockDetails::Eager(events) => events,
+            BlockDetails::Lazy(checkpoint) => {
+                self.sui_client
+                    .fetch_details(&checkpoint, self.provider_id)
+                    .await?
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl BlockHandle for SuiBlockHandle {
+    fn reference(&self) -> BlockReference {
+        self.reference.clone()
+    }
+
+    fn fetch_range(
+        &self,
+        block_range: BlockRange,
+        fetch_mode: FetchMode,
+    ) -> Result<impl Stream<Item = Result<Self, IndexerError>>, IndexerError> {
+        debug!("{}: fetching", block_range);
+
+        Ok(FuturesOrdered::from_iter(
+            block_range.clone().into_iter().map(|height| async move {
+                self.sui_client
+                    .fetch_single_with_provider(
+                        BlockSelection::Height(height),
+                        fetch_mode,
+                        Some(self.provider_id),
+                    )
+                    .await
+            }),
+        ))
+    }
+
+    async fn insert(
+        &self,
+        _tx: &mut sqlx::Transaction<'_, Postgres>,
+    ) -> Result<Option<BlockEvents>, IndexerError> {
+        let reference = self.reference();
+        debug!("{}: inserting", reference);
+
+        let events = self.get_events().await?;
+
+        trace!("{}: insert => events: {:?}", reference, events);
+        debug!("{}: insert => done (events: {})", reference, events.len());
+
+        Ok((!events.is_empty()).then_some(events.into()))
+    }
+
+    async fn update(
+        &self,
+        tx: &mut sqlx::Transaction<'_, Postgres>,
+    ) -> Result<Option<BlockEvents>, IndexerError> {
+        debug!("{}: updating", self.reference());
+
+        self.insert(tx).await
+    }
+}
@@ -0,0 +1,68 @@
+use color_eyre::eyre::Report;
+use sqlx::PgPool;
+use sui_sdk::types::base_types::SuiAddress;
+use url::Url;
+
+use crate::indexer::{
+    api::{BlockHeight, IndexerId},
+    event::types::UniversalChainId,
+    nats::NatsConnection,
+    sui::{context::SuiContext, fetcher_client::SuiFetcherClient},
+    ConsumerConfig, EnricherConfig, FinalizerConfig, FixerConfig, Indexer, PublisherConfig,
+    StuckPacketDetectorConfig,
+};
+
+const DEFAULT_CHUNK_SIZE: usize = 100;
+
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct Config {
+    pub indexer_id: IndexerId,
+    pub universal_chain_id: UniversalChainId,
+    pub start_height: BlockHeight,
+    pub chunk_size: Option<usize>,
+    pub rpc_urls: Vec<Url>,
+    /// the address of the ibc package; only events of this package are indexed.
+    pub ibc_handler_address: SuiAddress,
+    #[serde(default)]
+    pub finalizer: FinalizerConfig,
+    #[serde(default)]
+    pub fixer: FixerConfig,
+    #[serde(default)]
+    pub publisher: PublisherConfig,
+    #[serde(default)]
+    pub consumer: ConsumerConfig,
+    #[serde(default)]
+    pub enricher: EnricherConfig,
+    #[serde(default)]
+    pub stuck_packet_detector: StuckPacketDetectorConfig,
+    #[serde(default)]
+    pub drain: bool,
+}
+
+impl Config {
+    pub async fn build(
+        self,
+        pg_pool: PgPool,
+        nats: Option<NatsConnection>,
+    ) -> Result<Indexer<SuiFetcherClient>, Report> {
+        Ok(Indexer::new(
+            pg_pool,
+            nats,
+            self.indexer_id,
+            self.universal_chain_id,
+            self.start_height,
+            self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
+            self.finalizer,
+            self.fixer,
+            self.publisher,
+            self.consumer,
+            self.enricher,
+            self.stuck_packet_detector,
+            SuiContext {
+                rpc_urls: self.rpc_urls,
+                ibc_handler_address: self.ibc_handler_address,
+            },
+            self.drain,
+        ))
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use sui_sdk::types::base_types::SuiAddress;
+use url::Url;
+
+#[derive(Clone)]
+pub struct SuiContext {
+    pub rpc_urls: Vec<Url>,
+    pub ibc_handler_address: SuiAddress,
+}
+
+impl Display for SuiContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rpc_urls: {}, ibc_handler_address: {}",
+            self.rpc_urls
+                .iter()
+                .enumerate()
+                .map(|(index, url)| format!("{}: {}", index, url.as_str()))
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.ibc_handler_address,
+        )
+    }
+}
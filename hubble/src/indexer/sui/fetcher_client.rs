@@ -0,0 +1,241 @@
+use std::fmt::Display;
+
+use axum::async_trait;
+use color_eyre::eyre::Report;
+use futures::TryFutureExt;
+use sui_sdk::{
+    rpc_types::{Checkpoint, SuiTransactionBlockResponse},
+    types::base_types::SuiAddress,
+};
+use time::OffsetDateTime;
+use tokio::task::JoinSet;
+use tracing::{debug, info, info_span, Instrument};
+
+use crate::{
+    indexer::{
+        api::{
+            BlockHeight, BlockReference, BlockReferenceProvider, BlockSelection, FetchMode,
+            FetcherClient, IndexerError,
+        },
+        event::supported::SupportedBlockEvent,
+        sui::{
+            block_handle::{BlockDetails, SuiBlockHandle},
+            context::SuiContext,
+            provider::{Provider, RpcProviderId},
+        },
+    },
+    postgres::{fetch_chain_id_tx, ChainId},
+};
+
+/// Maximum number of transactions that sui nodes return in a single `multi_get_transactions` call.
+const MAX_TRANSACTIONS_PER_REQUEST: usize = 50;
+
+impl BlockReferenceProvider for Checkpoint {
+    fn block_reference(&self) -> Result<BlockReference, Report> {
+        Ok(BlockReference {
+            height: self.sequence_number,
+            hash: self.digest.to_string(),
+            timestamp: OffsetDateTime::from_unix_timestamp_nanos(
+                i128::from(self.timestamp_ms) * 1_000_000,
+            )
+            .map_err(|err| IndexerError::ProviderError(Box::new(err.into())))?,
+            parent_hash: None,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct SuiFetcherClient {
+    pub chain_id: ChainId,
+    pub provider: Provider,
+    pub ibc_handler_address: SuiAddress,
+}
+
+impl Display for SuiFetcherClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "chain_id: {}", self.chain_id)
+    }
+}
+
+impl SuiFetcherClient {
+    pub async fn fetch_single_with_provider(
+        &self,
+        selection: BlockSelection,
+        mode: FetchMode,
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<SuiBlockHandle, IndexerError> {
+        debug!("{}: fetching", selection);
+
+        let checkpoint = match selection {
+            // checkpoints are final once they are certified, so the latest checkpoint is also the
+            // last finalized one.
+            BlockSelection::LastFinalized => {
+                let latest = self
+                    .provider
+                    .latest_checkpoint_sequence_number(provider_id)
+                    .inspect_err(|e| debug!(?e, "error fetching latest checkpoint"))
+                    .await?;
+
+                self.provider
+                    .checkpoint(latest.response, Some(latest.provider_id))
+                    .await
+                    .map(Some)
+            }
+            BlockSelection::Height(height) => match self
+                .provider
+                .checkpoint(height, provider_id)
+                .inspect_err(|e| debug!(?e, "error fetching checkpoint at {}", height))
+                .await
+            {
+                Ok(result) => Ok(Some(result)),
+                Err(error) => {
+                    self.detect_reading_beyond_tip(error, height, provider_id)
+                        .await
+                }
+            },
+        };
+
+        match checkpoint {
+            Ok(Some(result)) => {
+                debug!(
+                    "{}: fetched (provider id: {:?})",
+                    selection, result.provider_id
+                );
+
+                let checkpoint = result.response;
+
+                Ok(SuiBlockHandle {
+                    reference: checkpoint.block_reference()?,
+                    details: match mode {
+                        FetchMode::Lazy => BlockDetails::Lazy(Box::new(checkpoint)),
+                        FetchMode::Eager => BlockDetails::Eager(
+                            self.fetch_details(&checkpoint, result.provider_id).await?,
+                        ),
+                    },
+                    sui_client: self.clone(),
+                    provider_id: result.provider_id,
+                })
+            }
+            Ok(None) => {
+                info!("{}: does not exist", selection);
+
+                Err(IndexerError::NoBlock(selection))
+            }
+            Err(error) => {
+                info!("{}: error: {}", selection, error);
+
+                Err(error.into())
+            }
+        }
+    }
+
+    /// Sui nodes return an error when requesting a checkpoint that does not exist yet. We compare
+    /// with the latest checkpoint to distinguish that from other errors.
+    async fn detect_reading_beyond_tip<T>(
+        &self,
+        error: sui_sdk::error::Error,
+        height: BlockHeight,
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<Option<T>, sui_sdk::error::Error> {
+        match self
+            .provider
+            .latest_checkpoint_sequence_number(provider_id)
+            .await
+        {
+            Ok(latest) if height > latest.response => {
+                debug!("{height}: no checkpoint: beyond tip {}", latest.response);
+
+                Ok(None) // we're reading beyond the tip
+            }
+            _ => Err(error),
+        }
+    }
+
+    pub async fn fetch_details(
+        &self,
+        checkpoint: &Checkpoint,
+        provider_id: RpcProviderId,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        let block_reference = checkpoint.block_reference()?;
+
+        info!("{}: fetch details", block_reference);
+
+        let mut transactions: Vec<SuiTransactionBlockResponse> =
+            Vec::with_capacity(checkpoint.transactions.len());
+
+        for digests in checkpoint.transactions.chunks(MAX_TRANSACTIONS_PER_REQUEST) {
+            let response = self
+                .provider
+                .transactions_with_events(digests, Some(provider_id))
+                .inspect_err(|e| debug!(?e, "{}: error fetching transactions", block_reference))
+                .await?
+                .response;
+
+            transactions.extend(response);
+        }
+
+        debug!(
+            "{}: fetched transactions: {}",
+            block_reference,
+            transactions.len()
+        );
+
+        let events = self.transform_to_ucs_events(checkpoint, &transactions)?;
+
+        debug!(
+            "{}: fetch => converted (events: {})",
+            block_reference,
+            events.len()
+        );
+
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl FetcherClient for SuiFetcherClient {
+    type BlockHandle = SuiBlockHandle;
+    type Context = SuiContext;
+
+    async fn create(
+        pg_pool: sqlx::PgPool,
+        _join_set: &mut JoinSet<Result<(), IndexerError>>,
+        context: SuiContext,
+    ) -> Result<Self, IndexerError> {
+        let provider = Provider::new(context.rpc_urls).await?;
+
+        info!("fetching chain-id from node");
+        let chain_id = provider
+            .chain_identifier(None)
+            .inspect_err(|e| debug!(?e, "error fetching chain-id: {}", e))
+            .await?
+            .response;
+        info!("fetched chain-id from node: {}", chain_id);
+
+        let indexing_span = info_span!("indexer", chain_id = chain_id);
+        async move {
+            let mut tx = pg_pool.begin().await?;
+
+            let chain_id = fetch_chain_id_tx(&mut tx, chain_id).await?;
+            info!("fetched chain-id from database: {}", chain_id);
+
+            tx.commit().await?;
+
+            Ok(SuiFetcherClient {
+                chain_id,
+                provider,
+                ibc_handler_address: context.ibc_handler_address,
+            })
+        }
+        .instrument(indexing_span)
+        .await
+    }
+
+    async fn fetch_single(
+        &self,
+        selection: BlockSelection,
+        mode: FetchMode,
+    ) -> Result<Self::BlockHandle, IndexerError> {
+        self.fetch_single_with_provider(selection, mode, None).await
+    }
+}
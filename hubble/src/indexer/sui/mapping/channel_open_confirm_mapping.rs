@@ -0,0 +1,27 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{channel_open_confirm_event::ChannelOpenConfirmEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_channel_open_confirm(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_channel_open_confirm - {log}");
+
+        Ok(vec![SupportedBlockEvent::ChannelOpenConfirm {
+            inner: ChannelOpenConfirmEvent {
+                header: log.header()?,
+                connection_id: log.move_event.connection_id()?,
+                channel_id: log.move_event.channel_id()?,
+                port_id: log.move_event.port_id()?,
+                counterparty_port_id: log.move_event.counterparty_port_id()?,
+                counterparty_channel_id: log.move_event.counterparty_channel_id()?,
+            },
+        }])
+    }
+}
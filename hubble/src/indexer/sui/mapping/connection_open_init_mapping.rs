@@ -0,0 +1,25 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{connection_open_init_event::ConnectionOpenInitEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_connection_open_init(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_connection_open_init - {log}");
+
+        Ok(vec![SupportedBlockEvent::ConnectionOpenInit {
+            inner: ConnectionOpenInitEvent {
+                header: log.header()?,
+                connection_id: log.move_event.connection_id()?,
+                client_id: log.move_event.client_id()?,
+                counterparty_client_id: log.move_event.counterparty_client_id()?,
+            },
+        }])
+    }
+}
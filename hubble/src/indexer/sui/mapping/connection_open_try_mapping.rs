@@ -0,0 +1,26 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{connection_open_try_event::ConnectionOpenTryEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_connection_open_try(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_connection_open_try - {log}");
+
+        Ok(vec![SupportedBlockEvent::ConnectionOpenTry {
+            inner: ConnectionOpenTryEvent {
+                header: log.header()?,
+                connection_id: log.move_event.connection_id()?,
+                client_id: log.move_event.client_id()?,
+                counterparty_client_id: log.move_event.counterparty_client_id()?,
+                counterparty_connection_id: log.move_event.counterparty_connection_id()?,
+            },
+        }])
+    }
+}
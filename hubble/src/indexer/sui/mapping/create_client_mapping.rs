@@ -0,0 +1,25 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{create_client_event::CreateClientEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_create_client(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_create_client - {log}");
+
+        Ok(vec![SupportedBlockEvent::CreateClient {
+            inner: CreateClientEvent {
+                header: log.header()?,
+                client_id: log.move_event.client_id()?,
+                client_type: log.move_event.client_type()?,
+                counterparty_chain_id: log.move_event.counterparty_chain_id()?,
+            },
+        }])
+    }
+}
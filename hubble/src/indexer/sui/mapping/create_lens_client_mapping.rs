@@ -0,0 +1,26 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{create_lens_client_event::CreateLensClientEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_create_lens_client(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_create_lens_client - {log}");
+
+        Ok(vec![SupportedBlockEvent::CreateLensClient {
+            inner: CreateLensClientEvent {
+                header: log.header()?,
+                client_id: log.move_event.client_id()?,
+                l1_client_id: log.move_event.l1_client_id()?,
+                l2_client_id: log.move_event.l2_client_id()?,
+                l2_chain_id: log.move_event.l2_chain_id()?,
+            },
+        }])
+    }
+}
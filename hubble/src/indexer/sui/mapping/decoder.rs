@@ -0,0 +1,511 @@
+use std::fmt::Display;
+
+use alloy_primitives::keccak256;
+use alloy_sol_types::{sol, SolValue};
+use bytes::Bytes;
+use itertools::Itertools;
+use serde_json::{Map, Value};
+use sui_sdk::{
+    rpc_types::{Checkpoint, SuiEvent},
+    types::digests::{CheckpointDigest, TransactionDigest},
+};
+use time::OffsetDateTime;
+use tracing::trace;
+
+use crate::{
+    indexer::{
+        api::IndexerError,
+        event::{
+            header::Header,
+            types::{
+                Acknowledgement, BlockHash, BlockHeight, CanonicalChainId, ChannelId,
+                ChannelVersion, ClientId, ClientType, ConnectionId, Maker, MakerMsg, PacketData,
+                PacketHash, PortId, TimeoutTimestamp, TransactionHash,
+            },
+        },
+    },
+    postgres::ChainId,
+};
+
+sol! {
+    // source: sui/ibc/sources/packet.move (ethabi encoded, like the ibc-union Packet)
+    struct Packet {
+        uint32 sourceChannelId;
+        uint32 destinationChannelId;
+        bytes data;
+        uint64 timeoutHeight;
+        uint64 timeoutTimestamp;
+    }
+}
+
+/// A move event emitted by the ibc package, with the fields of its `parsed_json` representation.
+///
+/// In the json representation, `u64` values are encoded as strings, `vector<u8>` values as arrays
+/// of numbers and addresses as 0x-prefixed hex strings.
+pub struct MoveEvent {
+    pub name: String,
+    pub attributes: Map<String, Value>,
+}
+
+impl Display for MoveEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.keys_as_string())
+    }
+}
+
+impl From<&SuiEvent> for MoveEvent {
+    fn from(value: &SuiEvent) -> Self {
+        Self {
+            name: value.type_.name.to_string(),
+            attributes: match &value.parsed_json {
+                Value::Object(attributes) => attributes.clone(),
+                _ => Map::new(),
+            },
+        }
+    }
+}
+
+pub struct Decoder<'a> {
+    pub chain_id: ChainId,
+    pub checkpoint: &'a Checkpoint,
+    pub transaction_index: usize,
+    pub event: &'a SuiEvent,
+    pub move_event: &'a MoveEvent,
+    pub event_index: usize,
+    pub transaction_event_index: usize,
+}
+
+impl<'a> Display for Decoder<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.move_event)
+    }
+}
+
+impl<'a> Decoder<'a> {
+    pub fn header(&'a self) -> Result<Header, IndexerError> {
+        Ok(Header {
+            universal_chain_id: self.chain_id.universal_chain_id.to_string().into(),
+            block_hash: self.checkpoint.digest.into(),
+            height: self.checkpoint.sequence_number.into(),
+            event_index: self.event_index.try_into()?,
+            timestamp: OffsetDateTime::from_unix_timestamp_nanos(
+                i128::from(self.checkpoint.timestamp_ms) * 1_000_000,
+            )
+            .map_err(|_| {
+                IndexerError::CannotMapToEventDomainOutOfRange(
+                    self.move_event.name.clone(),
+                    "timestamp".to_string(),
+                    self.checkpoint.timestamp_ms.to_string(),
+                    "unix timestamp".to_string(),
+                )
+            })?
+            .into(),
+            transaction_hash: self.event.id.tx_digest.into(),
+            transaction_index: self.transaction_index.try_into()?,
+            transaction_event_index: Some(self.transaction_event_index.try_into()?),
+        })
+    }
+
+    /// The sender of the transaction that emitted the event.
+    pub fn sender(&self) -> Maker {
+        Bytes::from(self.event.sender.to_vec()).into()
+    }
+}
+
+impl From<CheckpointDigest> for BlockHash {
+    fn from(value: CheckpointDigest) -> Self {
+        Bytes::copy_from_slice(value.inner()).into()
+    }
+}
+
+impl From<TransactionDigest> for TransactionHash {
+    fn from(value: TransactionDigest) -> Self {
+        Bytes::copy_from_slice(value.inner()).into()
+    }
+}
+
+impl MoveEvent {
+    pub fn client_id(&self) -> Result<ClientId, IndexerError> {
+        self.get_client_id("client_id")
+    }
+
+    pub fn l1_client_id(&self) -> Result<ClientId, IndexerError> {
+        self.get_client_id("l1_client_id")
+    }
+
+    pub fn l2_client_id(&self) -> Result<ClientId, IndexerError> {
+        self.get_client_id("l2_client_id")
+    }
+
+    pub fn client_type(&self) -> Result<ClientType, IndexerError> {
+        self.get_client_type("client_type")
+    }
+
+    pub fn counterparty_chain_id(&self) -> Result<CanonicalChainId, IndexerError> {
+        self.get_chain_id("counterparty_chain_id")
+    }
+
+    pub fn l2_chain_id(&self) -> Result<CanonicalChainId, IndexerError> {
+        self.get_chain_id("l2_chain_id")
+    }
+
+    pub fn counterparty_height(&self) -> Result<BlockHeight, IndexerError> {
+        self.get_height("height")
+    }
+
+    pub fn timeout_height(&self) -> Result<BlockHeight, IndexerError> {
+        self.get_height("timeout_height")
+    }
+
+    pub fn timeout_timestamp(&self) -> Result<TimeoutTimestamp, IndexerError> {
+        self.get_timestamp("timeout_timestamp")
+    }
+
+    pub fn counterparty_client_id(&self) -> Result<ClientId, IndexerError> {
+        self.get_client_id("counterparty_client_id")
+    }
+
+    pub fn connection_id(&self) -> Result<ConnectionId, IndexerError> {
+        self.get_connection_id("connection_id")
+    }
+
+    pub fn counterparty_connection_id(&self) -> Result<ConnectionId, IndexerError> {
+        self.get_connection_id("counterparty_connection_id")
+    }
+
+    pub fn channel_id(&self) -> Result<ChannelId, IndexerError> {
+        self.get_channel_id("channel_id")
+    }
+
+    pub fn source_channel_id(&self) -> Result<ChannelId, IndexerError> {
+        self.get_channel_id("source_channel_id")
+    }
+
+    pub fn destination_channel_id(&self) -> Result<ChannelId, IndexerError> {
+        self.get_channel_id("destination_channel_id")
+    }
+
+    pub fn counterparty_channel_id(&self) -> Result<ChannelId, IndexerError> {
+        self.get_channel_id("counterparty_channel_id")
+    }
+
+    pub fn port_id(&self) -> Result<PortId, IndexerError> {
+        self.get_port_id("port_id")
+    }
+
+    pub fn counterparty_port_id(&self) -> Result<PortId, IndexerError> {
+        self.get_counterparty_port_id("counterparty_port_id")
+    }
+
+    pub fn version(&self) -> Result<ChannelVersion, IndexerError> {
+        self.get_version("version")
+    }
+
+    pub fn packet_hash(&self) -> Result<PacketHash, IndexerError> {
+        self.get_packet_hash("packet_hash")
+    }
+
+    pub fn data(&self) -> Result<PacketData, IndexerError> {
+        self.get_packet_data("data")
+    }
+
+    pub fn acknowledgement(&self) -> Result<Acknowledgement, IndexerError> {
+        self.get_acknowledgement("acknowledgement")
+    }
+
+    pub fn maker(&self) -> Result<Maker, IndexerError> {
+        self.get_maker("maker")
+    }
+
+    pub fn maker_msg(&self) -> Result<MakerMsg, IndexerError> {
+        self.get_maker_msg("maker_msg")
+    }
+
+    pub fn packet(&self) -> Result<MoveEvent, IndexerError> {
+        self.get_struct("packet", "packet")
+    }
+
+    /// The hash of a packet, computed the same way as the ibc package does (keccak256 of the
+    /// ethabi encoded packet array).
+    pub fn hash_of_packet(&self) -> Result<PacketHash, IndexerError> {
+        let packet = Packet {
+            sourceChannelId: self.source_channel_id()?.0,
+            destinationChannelId: self.destination_channel_id()?.0,
+            data: self.data()?.0.to_vec().into(),
+            timeoutHeight: self.timeout_height()?.0,
+            timeoutTimestamp: self.timeout_timestamp()?.0,
+        };
+
+        Ok(Bytes::copy_from_slice(keccak256(vec![packet].abi_encode()).as_slice()).into())
+    }
+
+    fn get_height(&self, key: &str) -> Result<BlockHeight, IndexerError> {
+        Ok(self.get_u64(key, "height")?.into())
+    }
+
+    fn get_timestamp(&self, key: &str) -> Result<TimeoutTimestamp, IndexerError> {
+        Ok(self.get_u64(key, "timestamp")?.into())
+    }
+
+    fn get_client_id(&self, key: &str) -> Result<ClientId, IndexerError> {
+        Ok(self.get_u32(key, "client-id")?.into())
+    }
+
+    fn get_client_type(&self, key: &str) -> Result<ClientType, IndexerError> {
+        Ok(self.get_string(key, "client-type")?.into())
+    }
+
+    fn get_chain_id(&self, key: &str) -> Result<CanonicalChainId, IndexerError> {
+        Ok(self.get_string(key, "chain-id")?.into())
+    }
+
+    fn get_connection_id(&self, key: &str) -> Result<ConnectionId, IndexerError> {
+        Ok(self.get_u32(key, "connection-id")?.into())
+    }
+
+    fn get_channel_id(&self, key: &str) -> Result<ChannelId, IndexerError> {
+        Ok(self.get_u32(key, "channel-id")?.into())
+    }
+
+    /// in sui, port id is the (string) address of the module
+    fn get_port_id(&self, key: &str) -> Result<PortId, IndexerError> {
+        Ok(self.get_bytes_utf8(key, "port-id")?.into())
+    }
+
+    /// in sui, counterparty port id is a byte vector
+    fn get_counterparty_port_id(&self, key: &str) -> Result<PortId, IndexerError> {
+        Ok(self.get_bytes(key, "counterparty-port-id")?.into())
+    }
+
+    fn get_version(&self, key: &str) -> Result<ChannelVersion, IndexerError> {
+        Ok(self.get_string(key, "version")?.into())
+    }
+
+    fn get_packet_hash(&self, key: &str) -> Result<PacketHash, IndexerError> {
+        Ok(self.get_bytes(key, "packet-hash")?.into())
+    }
+
+    fn get_packet_data(&self, key: &str) -> Result<PacketData, IndexerError> {
+        Ok(self.get_bytes(key, "packet-data")?.into())
+    }
+
+    fn get_acknowledgement(&self, key: &str) -> Result<Acknowledgement, IndexerError> {
+        Ok(self.get_bytes(key, "acknowledgement")?.into())
+    }
+
+    fn get_maker(&self, key: &str) -> Result<Maker, IndexerError> {
+        Ok(self.get_bytes(key, "maker")?.into())
+    }
+
+    fn get_maker_msg(&self, key: &str) -> Result<MakerMsg, IndexerError> {
+        Ok(self.get_bytes(key, "maker-msg")?.into())
+    }
+
+    fn get_u32(&self, key: &str, expecting: &str) -> Result<u32, IndexerError> {
+        let value = self.get_u64(key, expecting)?;
+
+        value
+            .try_into()
+            .map_err(|_| self.report_out_of_range(key, &value.to_string(), expecting))
+    }
+
+    /// numbers up to `u32` are json numbers, larger numbers are strings
+    fn get_u64(&self, key: &str, expecting: &str) -> Result<u64, IndexerError> {
+        match self.get_value(key, expecting)? {
+            Value::Number(number) => number
+                .as_u64()
+                .ok_or_else(|| self.report_unexpected_type(key, &number.to_string(), expecting)),
+            Value::String(string) => string
+                .parse()
+                .map_err(|_| self.report_unexpected_type(key, string, expecting)),
+            value => Err(self.report_unexpected_type(key, &value.to_string(), expecting)),
+        }
+    }
+
+    fn get_bytes_utf8(&self, key: &str, expecting: &str) -> Result<Bytes, IndexerError> {
+        Ok(Bytes::from(self.get_string(key, expecting)?))
+    }
+
+    /// byte vectors are arrays of numbers, addresses are 0x-prefixed hex strings
+    fn get_bytes(&self, key: &str, expecting: &str) -> Result<Bytes, IndexerError> {
+        match self.get_value(key, expecting)? {
+            Value::Array(values) => values
+                .iter()
+                .map(|value| value.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                .collect::<Option<Vec<u8>>>()
+                .map(Bytes::from)
+                .ok_or_else(|| {
+                    self.report_unexpected_type(
+                        key,
+                        &Value::from(values.clone()).to_string(),
+                        expecting,
+                    )
+                }),
+            Value::String(string) => {
+                let value_without_0x = string.strip_prefix("0x").unwrap_or(string);
+
+                Ok(Bytes::from(hex::decode(value_without_0x).map_err(
+                    |_| self.report_unexpected_type(key, string, expecting),
+                )?))
+            }
+            value => Err(self.report_unexpected_type(key, &value.to_string(), expecting)),
+        }
+    }
+
+    fn get_string(&self, key: &str, expecting: &str) -> Result<String, IndexerError> {
+        match self.get_value(key, expecting)? {
+            Value::String(string) => Ok(string.clone()),
+            value => Err(self.report_unexpected_type(key, &value.to_string(), expecting)),
+        }
+    }
+
+    fn get_struct(&self, key: &str, expecting: &str) -> Result<MoveEvent, IndexerError> {
+        match self.get_value(key, expecting)? {
+            Value::Object(attributes) => Ok(MoveEvent {
+                name: key.to_string(),
+                attributes: attributes.clone(),
+            }),
+            value => Err(self.report_unexpected_type(key, &value.to_string(), expecting)),
+        }
+    }
+
+    fn get_value(&self, key: &str, expecting: &str) -> Result<&Value, IndexerError> {
+        self.attributes
+            .get(key)
+            .ok_or_else(|| self.report_missing_key(key, expecting))
+    }
+
+    fn report_missing_key(&self, key: &str, expecting: &str) -> IndexerError {
+        trace!(
+            "report_missing_key - {}.{key} (expecting: {expecting}, keys: {})",
+            self.name,
+            self.keys_as_string(),
+        );
+
+        IndexerError::CannotMapToEventDomainMissingKey(
+            self.name.to_string(),
+            key.to_string(),
+            expecting.to_string(),
+        )
+    }
+
+    fn report_unexpected_type(&self, key: &str, value: &str, expecting: &str) -> IndexerError {
+        trace!(
+            "report_unexpected_type - {}.{key} {value:?} (expecting: {expecting}, keys: {})",
+            self.name,
+            self.keys_as_string(),
+        );
+
+        IndexerError::CannotMapToEventDomainUnexpectedType(
+            self.name.to_string(),
+            key.to_string(),
+            format!("{value:?}"),
+            expecting.to_string(),
+        )
+    }
+
+    fn report_out_of_range(&self, key: &str, value: &str, expecting: &str) -> IndexerError {
+        trace!(
+            "report_out_of_range - {}.{key} {value:?} (expecting: {expecting}, keys: {})",
+            self.name,
+            self.keys_as_string(),
+        );
+
+        IndexerError::CannotMapToEventDomainOutOfRange(
+            self.name.to_string(),
+            key.to_string(),
+            format!("{value:?}"),
+            expecting.to_string(),
+        )
+    }
+
+    pub fn keys_as_string(&self) -> String {
+        self.attributes.keys().sorted().join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn move_event(name: &str, parsed_json: Value) -> MoveEvent {
+        MoveEvent {
+            name: name.to_string(),
+            attributes: parsed_json.as_object().unwrap().clone(),
+        }
+    }
+
+    #[test]
+    fn decodes_parsed_json_values() {
+        let event = move_event(
+            "PacketRecv",
+            json!({
+                "channel_id": 5,
+                "packet_hash": [1, 2, 3],
+                "maker": "0x0a0b",
+                "maker_msg": [],
+            }),
+        );
+
+        assert_eq!(event.channel_id().unwrap(), ChannelId(5));
+        assert_eq!(
+            event.packet_hash().unwrap(),
+            PacketHash(Bytes::from_static(&[1, 2, 3]))
+        );
+        assert_eq!(event.maker().unwrap(), Maker(Bytes::from_static(&[10, 11])));
+        assert_eq!(event.maker_msg().unwrap(), MakerMsg(Bytes::new()));
+        assert!(matches!(
+            event.acknowledgement(),
+            Err(IndexerError::CannotMapToEventDomainMissingKey(..))
+        ));
+    }
+
+    #[test]
+    fn decodes_u64_strings() {
+        let event = move_event(
+            "UpdateClient",
+            json!({
+                "client_id": 1,
+                "height": "18446744073709551615",
+            }),
+        );
+
+        assert_eq!(event.counterparty_height().unwrap(), BlockHeight(u64::MAX));
+        assert!(matches!(
+            move_event("UpdateClient", json!({ "client_id": "4294967296" })).client_id(),
+            Err(IndexerError::CannotMapToEventDomainOutOfRange(..))
+        ));
+    }
+
+    #[test]
+    fn hash_of_packet_matches_ibc_union_packet_hash() {
+        let event = move_event(
+            "TimeoutPacket",
+            json!({
+                "source_channel_id": 1,
+                "destination_channel_id": 2,
+                "data": [1, 2, 3],
+                "timeout_height": "0",
+                "timeout_timestamp": "1000",
+            }),
+        );
+
+        let expected = keccak256(
+            (vec![(
+                1_u32,
+                2_u32,
+                alloy_primitives::Bytes::from(vec![1, 2, 3]),
+                0_u64,
+                1000_u64,
+            )],)
+                .abi_encode_params(),
+        );
+
+        assert_eq!(
+            event.hash_of_packet().unwrap(),
+            PacketHash(Bytes::copy_from_slice(expected.as_slice()))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use sui_sdk::rpc_types::{Checkpoint, SuiEvent, SuiTransactionBlockResponse};
+use tracing::{trace, warn};
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{supported::SupportedBlockEvent, types::ClientStatus},
+    sui::{
+        fetcher_client::SuiFetcherClient,
+        mapping::decoder::{Decoder, MoveEvent},
+    },
+};
+
+mod channel_open_ack_mapping;
+mod channel_open_confirm_mapping;
+mod channel_open_init_mapping;
+mod channel_open_try_mapping;
+mod client_status_update_mapping;
+mod connection_open_ack_mapping;
+mod connection_open_confirm_mapping;
+mod connection_open_init_mapping;
+mod connection_open_try_mapping;
+mod create_client_mapping;
+mod create_lens_client_mapping;
+mod decoder;
+mod intent_packet_recv_mapping;
+mod packet_ack_mapping;
+mod packet_recv_mapping;
+mod packet_send_mapping;
+mod packet_timeout_mapping;
+mod update_client_mapping;
+mod write_ack_mapping;
+
+impl SuiFetcherClient {
+    pub fn transform_to_ucs_events(
+        &self,
+        checkpoint: &Checkpoint,
+        transactions: &[SuiTransactionBlockResponse],
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        let mut event_index_in_block: usize = 0;
+
+        transactions
+            .iter()
+            .enumerate()
+            .map(|(transaction_index, transaction)| {
+                // failed transactions do not emit events
+                let events = transaction
+                    .events
+                    .as_ref()
+                    .map(|events| events.data.as_slice())
+                    .unwrap_or_default();
+
+                let result = self.transform_transaction_to_ucs_events(
+                    checkpoint,
+                    transaction_index,
+                    events,
+                    event_index_in_block,
+                );
+
+                event_index_in_block += events.len();
+
+                result
+            })
+            .collect::<Result<Vec<_>, _>>() // Result<Vec<Vec<SupportedBlockEvent>>, IndexerError>
+            .map(|vecs| vecs.into_iter().flatten().collect())
+    }
+
+    fn transform_transaction_to_ucs_events(
+        &self,
+        checkpoint: &Checkpoint,
+        transaction_index: usize,
+        events: &[SuiEvent],
+        event_index_of_first_event_in_transaction: usize,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| event.type_.address == self.ibc_handler_address.into())
+            .map(|(event_index_in_transaction, event)| {
+                let move_event = &event.into();
+
+                let event_decoder = Decoder {
+                    chain_id: self.chain_id,
+                    checkpoint,
+                    transaction_index,
+                    event,
+                    move_event,
+                    event_index: event_index_of_first_event_in_transaction
+                        + event_index_in_transaction,
+                    transaction_event_index: event_index_in_transaction,
+                };
+
+                self.transform_ibc_event_to_ucs_events(&event_decoder)
+            })
+            .collect::<Result<Vec<_>, _>>() // Result<Vec<Vec<SupportedBlockEvent>>, IndexerError>
+            .map(|vecs| vecs.into_iter().flatten().collect())
+    }
+
+    fn transform_ibc_event_to_ucs_events(
+        &self,
+        event_decoder: &Decoder<'_>,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_ibc_event - {event_decoder}");
+
+        Ok(match event_decoder.move_event.name.as_str() {
+            "ChannelOpenInit" => self.to_channel_open_init(event_decoder)?,
+            "ChannelOpenTry" => self.to_channel_open_try(event_decoder)?,
+            "ChannelOpenAck" => self.to_channel_open_ack(event_decoder)?,
+            "ChannelOpenConfirm" => self.to_channel_open_confirm(event_decoder)?,
+            "ConnectionOpenInit" => self.to_connection_open_init(event_decoder)?,
+            "ConnectionOpenTry" => self.to_connection_open_try(event_decoder)?,
+            "ConnectionOpenAck" => self.to_connection_open_ack(event_decoder)?,
+            "ConnectionOpenConfirm" => self.to_connection_open_confirm(event_decoder)?,
+            "CreateClient" => self.to_create_client(event_decoder)?,
+            "CreateLensClient" => self.to_create_lens_client(event_decoder)?,
+            "UpdateClient" => self.to_update_client(event_decoder)?,
+            "SubmitMisbehaviour" => {
+                self.to_client_status_update(event_decoder, ClientStatus::Frozen)?
+            }
+            "PacketSend" => self.to_packet_send(event_decoder)?,
+            "PacketRecv" => self.to_packet_recv(event_decoder)?,
+            "IntentPacketRecv" => self.to_intent_packet_recv(event_decoder)?,
+            "WriteAck" => self.to_write_ack(event_decoder)?,
+            "PacketAck" => self.to_packet_ack(event_decoder)?,
+            "TimeoutPacket" => self.to_packet_timeout(event_decoder)?,
+            name => {
+                warn!("unsupported ibc event: {name} ({event_decoder})");
+                vec![]
+            }
+        })
+    }
+}
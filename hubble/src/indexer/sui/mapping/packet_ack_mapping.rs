@@ -0,0 +1,23 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{packet_ack_event::PacketAckEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_packet_ack(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_packet_ack - {log}");
+
+        Ok(vec![SupportedBlockEvent::PacketAck {
+            inner: PacketAckEvent {
+                header: log.header()?,
+                channel_id: log.move_event.channel_id()?,
+                packet_hash: log.move_event.packet_hash()?,
+                acknowledgement: log.move_event.acknowledgement()?,
+                maker: log.move_event.maker()?,
+            },
+        }])
+    }
+}
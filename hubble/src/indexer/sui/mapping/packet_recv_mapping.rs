@@ -0,0 +1,23 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{packet_recv_event::PacketRecvEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_packet_recv(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_packet_recv - {log}");
+
+        Ok(vec![SupportedBlockEvent::PacketRecv {
+            inner: PacketRecvEvent {
+                header: log.header()?,
+                channel_id: log.move_event.channel_id()?,
+                packet_hash: log.move_event.packet_hash()?,
+                maker: log.move_event.maker()?,
+                maker_msg: log.move_event.maker_msg()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,28 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{packet_send_event::PacketSendEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_packet_send(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_packet_send - {log}");
+
+        let packet = log.move_event.packet()?;
+
+        Ok(vec![SupportedBlockEvent::PacketSend {
+            inner: PacketSendEvent {
+                header: log.header()?,
+                channel_id: log.move_event.channel_id()?,
+                packet_hash: log.move_event.packet_hash()?,
+                source_channel_id: packet.source_channel_id()?,
+                destination_channel_id: packet.destination_channel_id()?,
+                timeout_height: packet.timeout_height()?,
+                timeout_timestamp: packet.timeout_timestamp()?,
+                data: packet.data()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,29 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{packet_timeout_event::PacketTimeoutEvent, supported::SupportedBlockEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    /// The sui `TimeoutPacket` event only contains the packet, so the packet hash is derived from
+    /// the packet and the maker is the sender of the transaction.
+    pub fn to_packet_timeout(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_packet_timeout - {log}");
+
+        let packet = log.move_event.packet()?;
+
+        Ok(vec![SupportedBlockEvent::PacketTimeout {
+            inner: PacketTimeoutEvent {
+                header: log.header()?,
+                channel_id: packet.source_channel_id()?,
+                packet_hash: packet.hash_of_packet()?,
+                maker: log.sender(),
+            },
+        }])
+    }
+}
@@ -0,0 +1,24 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{supported::SupportedBlockEvent, update_client_event::UpdateClientEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_update_client(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_update_client - {log}");
+
+        Ok(vec![SupportedBlockEvent::UpdateClient {
+            inner: UpdateClientEvent {
+                header: log.header()?,
+                client_id: log.move_event.client_id()?,
+                counterparty_height: log.move_event.counterparty_height()?,
+            },
+        }])
+    }
+}
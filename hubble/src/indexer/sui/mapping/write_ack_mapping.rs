@@ -0,0 +1,22 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{supported::SupportedBlockEvent, write_ack_event::WriteAckEvent},
+    sui::{fetcher_client::SuiFetcherClient, mapping::decoder::Decoder},
+};
+
+impl SuiFetcherClient {
+    pub fn to_write_ack(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_write_ack - {log}");
+
+        Ok(vec![SupportedBlockEvent::WriteAck {
+            inner: WriteAckEvent {
+                header: log.header()?,
+                channel_id: log.move_event.channel_id()?,
+                packet_hash: log.move_event.packet_hash()?,
+                acknowledgement: log.move_event.acknowledgement()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,16 @@
+use color_eyre::eyre::Report;
+
+use crate::indexer::api::IndexerError;
+
+mod block_handle;
+pub mod config;
+mod context;
+mod fetcher_client;
+mod mapping;
+mod provider;
+
+impl From<sui_sdk::error::Error> for IndexerError {
+    fn from(error: sui_sdk::error::Error) -> Self {
+        Self::ProviderError(Box::new(Report::from(error)))
+    }
+}
@@ -0,0 +1,123 @@
+use futures::future;
+use sui_sdk::{
+    error::Error,
+    rpc_types::{
+        Checkpoint, CheckpointId, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+    },
+    types::digests::TransactionDigest,
+    SuiClient, SuiClientBuilder,
+};
+use url::Url;
+
+use crate::{
+    indexer::api::{BlockHeight, IndexerError},
+    race_client::{RaceClient, RaceClientId, RaceClientResponse},
+};
+
+#[derive(Clone)]
+pub struct Provider {
+    pub rpc_client: RaceClient<SuiClient>,
+}
+
+#[derive(Clone, Debug, Copy)]
+pub struct RpcProviderId {
+    race_client_id: RaceClientId,
+}
+
+impl From<RpcProviderId> for RaceClientId {
+    fn from(value: RpcProviderId) -> Self {
+        value.race_client_id
+    }
+}
+
+#[derive(Debug)]
+pub struct RpcResult<T> {
+    pub provider_id: RpcProviderId,
+    pub response: T,
+}
+
+impl<T> RpcResult<T> {
+    fn new(race_client_id: RaceClientId, result: T) -> Self {
+        Self {
+            provider_id: RpcProviderId { race_client_id },
+            response: result,
+        }
+    }
+}
+
+impl<T> From<RaceClientResponse<T>> for RpcResult<T> {
+    fn from(value: RaceClientResponse<T>) -> Self {
+        RpcResult::new(value.race_client_id, value.response)
+    }
+}
+
+impl Provider {
+    pub async fn new(rpc_urls: Vec<Url>) -> Result<Self, IndexerError> {
+        Ok(Self {
+            rpc_client: RaceClient::new(
+                future::join_all(
+                    rpc_urls
+                        .into_iter()
+                        .map(|rpc_url| SuiClientBuilder::default().build(rpc_url)),
+                )
+                .await
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?,
+            ),
+        })
+    }
+
+    pub async fn chain_identifier(
+        &self,
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<RpcResult<String>, Error> {
+        self.rpc_client
+            .race(provider_id.map(Into::into), |c| {
+                c.read_api().get_chain_identifier()
+            })
+            .await
+            .map(Into::into)
+    }
+
+    pub async fn latest_checkpoint_sequence_number(
+        &self,
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<RpcResult<BlockHeight>, Error> {
+        self.rpc_client
+            .race(provider_id.map(Into::into), |c| {
+                c.read_api().get_latest_checkpoint_sequence_number()
+            })
+            .await
+            .map(Into::into)
+    }
+
+    pub async fn checkpoint(
+        &self,
+        height: BlockHeight,
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<RpcResult<Checkpoint>, Error> {
+        self.rpc_client
+            .race(provider_id.map(Into::into), |c| {
+                c.read_api()
+                    .get_checkpoint(CheckpointId::SequenceNumber(height))
+            })
+            .await
+            .map(Into::into)
+    }
+
+    pub async fn transactions_with_events(
+        &self,
+        digests: &[TransactionDigest],
+        provider_id: Option<RpcProviderId>,
+    ) -> Result<RpcResult<Vec<SuiTransactionBlockResponse>>, Error> {
+        self.rpc_client
+            .race(provider_id.map(Into::into), |c| {
+                c.read_api().multi_get_transactions_with_options(
+                    digests.to_vec(),
+                    SuiTransactionBlockResponseOptions::new().with_events(),
+                )
+            })
+            .await
+            .map(Into::into)
+    }
+}
@@ -77,6 +77,7 @@ impl BlockReferenceProvider for BlockHeader {
                 self.header.time.as_unix_nanos().into(),
             )
             .map_err(|err| IndexerError::ProviderError(Box::new(err.into())))?,
+            parent_hash: None,
         })
     }
 }
@@ -96,6 +97,7 @@ impl BlockReferenceProvider for BlockMeta {
                 self.header.time.as_unix_nanos().into(),
             )
             .map_err(|err| IndexerError::ProviderError(Box::new(err.into())))?,
+            parent_hash: None,
         })
     }
 }
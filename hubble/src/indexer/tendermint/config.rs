@@ -8,6 +8,7 @@ use crate::indexer::{
     nats::NatsConnection,
     tendermint::{context::TmContext, fetcher_client::TmFetcherClient},
     ConsumerConfig, EnricherConfig, FinalizerConfig, FixerConfig, Indexer, PublisherConfig,
+    StuckPacketDetectorConfig,
 };
 
 const DEFAULT_CHUNK_SIZE: usize = 20;
@@ -32,6 +33,8 @@ pub struct Config {
     #[serde(default)]
     pub enricher: EnricherConfig,
     #[serde(default)]
+    pub stuck_packet_detector: StuckPacketDetectorConfig,
+    #[serde(default)]
     pub testnet: bool,
     #[serde(default)]
     pub drain: bool,
@@ -55,6 +58,7 @@ impl Config {
             self.publisher,
             self.consumer,
             self.enricher,
+            self.stuck_packet_detector,
             TmContext {
                 rpc_urls: self.rpc_urls,
                 tx_search_max_page_size: self
@@ -0,0 +1,22 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{batch_acks_event::BatchAcksEvent, supported::SupportedBlockEvent},
+    tendermint::{fetcher_client::TmFetcherClient, mapping::decoder::Decoder},
+};
+
+impl TmFetcherClient {
+    pub fn to_batch_acks(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_batch_acks - {log}");
+
+        Ok(vec![SupportedBlockEvent::BatchAcks {
+            inner: BatchAcksEvent {
+                header: log.header()?,
+                channel_id: log.event.channel_id()?,
+                packet_hash: log.event.packet_hash()?,
+                batch_hash: log.event.batch_hash()?,
+            },
+        }])
+    }
+}
@@ -0,0 +1,22 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{batch_send_event::BatchSendEvent, supported::SupportedBlockEvent},
+    tendermint::{fetcher_client::TmFetcherClient, mapping::decoder::Decoder},
+};
+
+impl TmFetcherClient {
+    pub fn to_batch_send(&self, log: &Decoder) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_batch_send - {log}");
+
+        Ok(vec![SupportedBlockEvent::BatchSend {
+            inner: BatchSendEvent {
+                header: log.header()?,
+                channel_id: log.event.channel_id()?,
+                packet_hash: log.event.packet_hash()?,
+                batch_hash: log.event.batch_hash()?,
+            },
+        }])
+    }
+}
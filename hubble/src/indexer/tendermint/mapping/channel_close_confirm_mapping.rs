@@ -0,0 +1,28 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{
+        channel_close_confirm_event::ChannelCloseConfirmEvent, supported::SupportedBlockEvent,
+    },
+    tendermint::{fetcher_client::TmFetcherClient, mapping::decoder::Decoder},
+};
+
+impl TmFetcherClient {
+    pub fn to_channel_close_confirm(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_channel_close_confirm - {log}");
+
+        Ok(vec![SupportedBlockEvent::ChannelCloseConfirm {
+            inner: ChannelCloseConfirmEvent {
+                header: log.header()?,
+                channel_id: log.event.channel_id()?,
+                port_id: log.event.port_id()?,
+                counterparty_channel_id: log.event.counterparty_channel_id()?,
+                counterparty_port_id: log.event.counterparty_port_id()?,
+            },
+        }])
+    }
+}
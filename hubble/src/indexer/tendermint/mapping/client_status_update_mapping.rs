@@ -0,0 +1,28 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{
+        client_status_update_event::ClientStatusUpdateEvent, supported::SupportedBlockEvent,
+        types::ClientStatus,
+    },
+    tendermint::{fetcher_client::TmFetcherClient, mapping::decoder::Decoder},
+};
+
+impl TmFetcherClient {
+    pub fn to_client_status_update(
+        &self,
+        log: &Decoder,
+        status: ClientStatus,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_client_status_update - {log} ({status:?})");
+
+        Ok(vec![SupportedBlockEvent::ClientStatusUpdate {
+            inner: ClientStatusUpdateEvent {
+                header: log.header()?,
+                client_id: log.event.client_id()?,
+                status,
+            },
+        }])
+    }
+}
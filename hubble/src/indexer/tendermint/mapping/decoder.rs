@@ -14,10 +14,10 @@ use crate::{
         event::{
             header::Header,
             types::{
-                Acknowledgement, BlockHash, BlockHeight, CanonicalChainId, Capacity, ChannelId,
-                ChannelVersion, ClientId, ClientType, ConnectionId, ContractAddress, Denom, Maker,
-                MakerMsg, MutationAmount, PacketData, PacketHash, Path, PortId, RefillRate,
-                TimeoutTimestamp, TransactionHash, WalletAddress,
+                Acknowledgement, BatchHash, BlockHash, BlockHeight, CanonicalChainId, Capacity,
+                ChannelId, ChannelVersion, ClientId, ClientType, ConnectionId, ContractAddress,
+                Denom, Maker, MakerMsg, MutationAmount, PacketData, PacketHash, Path, PortId,
+                RefillRate, TimeoutTimestamp, TransactionHash, WalletAddress,
             },
         },
         handler::types::{CreateWrappedTokenKind, Metadata},
@@ -190,6 +190,10 @@ impl TmEvent {
         self.get_packet_hash("packet_hash")
     }
 
+    pub fn batch_hash(&self) -> Result<BatchHash, IndexerError> {
+        self.get_batch_hash("batch_hash")
+    }
+
     pub fn data(&self) -> Result<PacketData, IndexerError> {
         self.get_packet_data("packet_data")
     }
@@ -305,6 +309,10 @@ impl TmEvent {
         Ok(self.get_bytes(key, "packet-hash")?.into())
     }
 
+    fn get_batch_hash(&self, key: &str) -> Result<BatchHash, IndexerError> {
+        Ok(self.get_bytes(key, "batch-hash")?.into())
+    }
+
     fn get_packet_data(&self, key: &str) -> Result<PacketData, IndexerError> {
         Ok(self.get_bytes(key, "packet_data")?.into())
     }
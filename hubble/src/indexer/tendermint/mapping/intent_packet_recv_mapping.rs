@@ -0,0 +1,26 @@
+use tracing::trace;
+
+use crate::indexer::{
+    api::IndexerError,
+    event::{intent_packet_recv_event::IntentPacketRecvEvent, supported::SupportedBlockEvent},
+    tendermint::{fetcher_client::TmFetcherClient, mapping::decoder::Decoder},
+};
+
+impl TmFetcherClient {
+    pub fn to_intent_packet_recv(
+        &self,
+        log: &Decoder,
+    ) -> Result<Vec<SupportedBlockEvent>, IndexerError> {
+        trace!("to_intent_packet_recv - {log}");
+
+        Ok(vec![SupportedBlockEvent::IntentPacketRecv {
+            inner: IntentPacketRecvEvent {
+                header: log.header()?,
+                channel_id: log.event.channel_id()?,
+                packet_hash: log.event.packet_hash()?,
+                maker: log.event.maker()?,
+                maker_msg: log.event.maker_msg()?,
+            },
+        }])
+    }
+}
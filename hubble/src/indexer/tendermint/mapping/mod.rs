@@ -3,7 +3,7 @@ use tracing::{trace, warn};
 
 use crate::indexer::{
     api::{BlockReference, IndexerError},
-    event::supported::SupportedBlockEvent,
+    event::{supported::SupportedBlockEvent, types::ClientStatus},
     tendermint::{
         block_handle::{ActiveContracts, BlockHeader},
         fetcher_client::TmFetcherClient,
@@ -11,10 +11,15 @@ use crate::indexer::{
     },
 };
 
+mod batch_acks_mapping;
+mod batch_send_mapping;
+mod channel_close_confirm_mapping;
+mod channel_close_init_mapping;
 mod channel_open_ack_mapping;
 mod channel_open_confirm_mapping;
 mod channel_open_init_mapping;
 mod channel_open_try_mapping;
+mod client_status_update_mapping;
 mod connection_open_ack_mapping;
 mod connection_open_confirm_mapping;
 mod connection_open_init_mapping;
@@ -23,6 +28,7 @@ mod create_client_mapping;
 mod create_lens_client_mapping;
 mod create_wrapped_token_mapping;
 mod decoder;
+mod intent_packet_recv_mapping;
 pub(crate) mod legacy;
 mod packet_ack_mapping;
 mod packet_recv_mapping;
@@ -146,6 +152,8 @@ impl TmFetcherClient {
             "wasm-channel_open_try" => self.to_channel_open_try(event_decoder)?,
             "wasm-channel_open_ack" => self.to_channel_open_ack(event_decoder)?,
             "wasm-channel_open_confirm" => self.to_channel_open_confirm(event_decoder)?,
+            "wasm-channel_close_init" => self.to_channel_close_init(event_decoder)?,
+            "wasm-channel_close_confirm" => self.to_channel_close_confirm(event_decoder)?,
             "wasm-connection_open_init" => self.to_connection_open_init(event_decoder)?,
             "wasm-connection_open_try" => self.to_connection_open_try(event_decoder)?,
             "wasm-connection_open_ack" => self.to_connection_open_ack(event_decoder)?,
@@ -153,11 +161,20 @@ impl TmFetcherClient {
             "wasm-create_client" => self.to_create_client(event_decoder)?,
             "wasm-create_lens_client" => self.to_create_lens_client(event_decoder)?,
             "wasm-update_client" => self.to_update_client(event_decoder)?,
+            "wasm-client_misbehaviour" => {
+                self.to_client_status_update(event_decoder, ClientStatus::Frozen)?
+            }
+            "wasm-recover_client" => {
+                self.to_client_status_update(event_decoder, ClientStatus::Active)?
+            }
             "wasm-packet_send" => self.to_packet_send(event_decoder)?,
             "wasm-packet_recv" => self.to_packet_recv(event_decoder)?,
+            "wasm-intent_packet_recv" => self.to_intent_packet_recv(event_decoder)?,
             "wasm-write_ack" => self.to_write_ack(event_decoder)?,
             "wasm-packet_ack" => self.to_packet_ack(event_decoder)?,
             "wasm-packet_timeout" => self.to_packet_timeout(event_decoder)?,
+            "wasm-batch_send" => self.to_batch_send(event_decoder)?,
+            "wasm-batch_acks" => self.to_batch_acks(event_decoder)?,
             "wasm-token_bucket_update" => self.to_token_bucket_update(event_decoder)?,
             "wasm-create_wrapped_token" => self.to_create_wrapped_token(event_decoder)?,
             name => {
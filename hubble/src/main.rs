@@ -10,6 +10,7 @@ use tokio::task::JoinSet;
 use tracing::{error, info, warn};
 
 mod abi_fetcher;
+mod api;
 mod cli;
 mod github_client;
 mod github_fetcher;
@@ -67,6 +68,18 @@ async fn main() -> color_eyre::eyre::Result<()> {
                 .map_err(Into::into)
         });
     }
+
+    if let Some(addr) = args.api_addr {
+        info!("enabling api");
+        let db = db.clone();
+        set.spawn(async move {
+            axum::Server::bind(&addr)
+                .serve(api::router(db).into_make_service())
+                .await
+                .map_err(Into::into)
+        });
+    }
+
     args.indexers.clone().into_iter().for_each(|indexer| {
         let db: sqlx::Pool<sqlx::Postgres> = db.clone();
         let nats = nats.clone();
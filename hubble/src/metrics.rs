@@ -1,5 +1,7 @@
 use lazy_static::lazy_static;
-use prometheus::{IntCounterVec, Opts, Registry};
+use prometheus::{
+    GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry,
+};
 use reqwest::StatusCode;
 
 lazy_static! {
@@ -25,6 +27,34 @@ lazy_static! {
         &["chain_id"]
     )
     .expect("register TRANSACTION_COLLECTOR");
+    pub static ref STUCK_PACKETS: IntGaugeVec = IntGaugeVec::new(
+        Opts::new("stuck", "Packets stuck in a lifecycle stage")
+            .namespace("hubble")
+            .subsystem("packets"),
+        &["universal_chain_id", "channel_id", "stage"]
+    )
+    .expect("register STUCK_PACKETS");
+    pub static ref STUCK_PACKETS_OLDEST_AGE: GaugeVec = GaugeVec::new(
+        Opts::new(
+            "stuck_oldest_age_seconds",
+            "Time the oldest stuck packet is in its lifecycle stage"
+        )
+        .namespace("hubble")
+        .subsystem("packets"),
+        &["universal_chain_id", "channel_id", "stage"]
+    )
+    .expect("register STUCK_PACKETS_OLDEST_AGE");
+    pub static ref REORG_DEPTH: HistogramVec = HistogramVec::new(
+        HistogramOpts::new(
+            "reorg_depth",
+            "Number of orphaned blocks per detected reorg"
+        )
+        .namespace("hubble")
+        .subsystem("index")
+        .buckets(vec![1.0, 2.0, 3.0, 5.0, 8.0, 13.0, 21.0, 34.0, 64.0]),
+        &["universal_chain_id"]
+    )
+    .expect("register REORG_DEPTH");
 }
 
 pub fn register_custom_metrics() {
@@ -37,6 +67,15 @@ pub fn register_custom_metrics() {
     REGISTRY
         .register(Box::new(TRANSACTION_COLLECTOR.clone()))
         .expect("TRANSACTION_COLLECTOR can be registered");
+    REGISTRY
+        .register(Box::new(STUCK_PACKETS.clone()))
+        .expect("STUCK_PACKETS can be registered");
+    REGISTRY
+        .register(Box::new(STUCK_PACKETS_OLDEST_AGE.clone()))
+        .expect("STUCK_PACKETS_OLDEST_AGE can be registered");
+    REGISTRY
+        .register(Box::new(REORG_DEPTH.clone()))
+        .expect("REORG_DEPTH can be registered");
 }
 
 #[axum::debug_handler]
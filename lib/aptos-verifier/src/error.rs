@@ -24,6 +24,8 @@ pub enum StorageVerificationError {
     ExpectedMembershipVerification,
     #[error("expected non-membership verification")]
     ExpectedNonMembershipVerification,
+    #[error("leaf ({1}) is not in the subtree of the non-existent key ({0})")]
+    LeafNotInSubtree(H256, H256),
     #[error("root hash mismatch (({0}, {1}))")]
     RootHashMismatch(H256, H256),
 }
@@ -23,7 +23,7 @@ use unionlabs::{
 pub(crate) const MAX_ACCUMULATOR_PROOF_DEPTH: usize = 63;
 // "SPARSE_MERKLE_PLACEHOLDER_HASH"
 pub(crate) const SPARSE_MERKLE_PLACEHOLDER_HASH: [u8; 32] =
-    hex!("00005350415253455F4D45524B4C455F504C414345484F4C4445525F48415348");
+    hex!("5350415253455F4D45524B4C455F504C414345484F4C4445525F484153480000");
 
 /// Verifies an element whose hash is `element_hash` and version is `element_version` exists in
 /// the accumulator whose root hash is `expected_root_hash` using the provided proof.
@@ -122,9 +122,56 @@ pub fn verify_existence_proof(
         .into());
     }
 
-    let current_hash = proof.leaf.map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, |leaf| {
-        hash_sparse_merkle_leaf_node(&leaf)
-    });
+    verify_root(&proof, expected_root_hash, element_key)
+}
+
+/// Verifies that `element_key` does not exist in the sparse merkle tree whose root hash is
+/// `expected_root_hash`.
+///
+/// The proof either ends in an empty subtree, or in a leaf for a different key that shares the
+/// path to the subtree with `element_key` (the only leaf that exists in that subtree).
+pub fn verify_non_existence_proof(
+    proof: SparseMerkleProof,
+    expected_root_hash: [u8; 32],
+    element_key: [u8; 32],
+) -> Result<(), Error> {
+    if proof.siblings.len() > 256 {
+        return Err(
+            StorageVerificationError::MaxSiblingsExceeded(256, proof.siblings.len()).into(),
+        );
+    }
+
+    if let Some(leaf) = proof.leaf {
+        if &element_key == leaf.key.get() {
+            return Err(StorageVerificationError::ExpectedNonMembershipVerification.into());
+        }
+
+        let common_prefix_bits_len = BytesBitIterator::new(&element_key)
+            .zip(BytesBitIterator::new(leaf.key.get()))
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        if common_prefix_bits_len < proof.siblings.len() {
+            return Err(StorageVerificationError::LeafNotInSubtree(
+                H256::new(element_key),
+                H256::new(*leaf.key.get()),
+            )
+            .into());
+        }
+    }
+
+    verify_root(&proof, expected_root_hash, element_key)
+}
+
+fn verify_root(
+    proof: &SparseMerkleProof,
+    expected_root_hash: [u8; 32],
+    element_key: [u8; 32],
+) -> Result<(), Error> {
+    let current_hash = proof
+        .leaf
+        .as_ref()
+        .map_or(SPARSE_MERKLE_PLACEHOLDER_HASH, hash_sparse_merkle_leaf_node);
     let actual_root_hash = proof
         .siblings
         .iter()
@@ -235,3 +282,188 @@ impl SparseMerkleInternalNode {
         state.finalize().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use unionlabs::aptos::{
+        transaction_info::{ExecutionStatus, TransactionInfoV0},
+        transaction_proof::{Null, TransactionAccumulatorProof},
+    };
+
+    use super::*;
+
+    // state checkpoint of a movement devnet transaction, see `tx_info_with_proof`
+    const STATE_ROOT: [u8; 32] =
+        hex!("02388da3aee85236d64e272fec0b1a6fcd4962986327971faef9ee2951a4ad6a");
+
+    fn storage_proof() -> SparseMerkleProof {
+        SparseMerkleProof {
+            leaf: Some(SparseMerkleLeafNode {
+                key: hex!("f2d067d8ef7e97deb231d46f40f9f30200e6f1dad495d33e2a7911825a97ad14")
+                    .into(),
+                value_hash: hex!(
+                    "40414333f8109f8cb971c67c9eca3c0049e21e6c5e28551f1a4975c96ab15212"
+                )
+                .into(),
+            }),
+            siblings: [
+                hex!("fafdceaec25fd64517ce3745992467dfac306a5ce59e63255da5b9f58d1417ea"),
+                hex!("4480c449082954642653a4570c7cb2ea2114d79b61621b94f095f25d640b6e27"),
+                hex!("0fc055434d70262945d428a5eda3d8396aa960c65ee8c4e79bd20638a95e7a31"),
+                hex!("731e28eb6655e01b8714aa72f76a0f468c330b46eb9c21816a88e56840896f24"),
+                hex!("b120265e60289e6e44216efd4f3fba86a8de645d3eb7912ff09812024c639b2f"),
+                hex!("d90e0a63c7c3cf7ed000841a85f981d8c6bec4c23353822204c7c9e9c5dee4db"),
+                hex!("30b21a8a3bf202b5fe18e415c299fd3b9985462a6292fffdabd5b32fbc27ba30"),
+                SPARSE_MERKLE_PLACEHOLDER_HASH,
+                hex!("5b9096922002407577b4e46e6466aadb15cbb9521fd0e9847d474398ea3736e2"),
+                SPARSE_MERKLE_PLACEHOLDER_HASH,
+                hex!("884f8b72a832aa718c6590d0bfeb1ec85b546611b6c07f8df563827974ad8134"),
+            ]
+            .map(Into::into)
+            .to_vec(),
+        }
+    }
+
+    fn tx_info_with_proof() -> TransactionInfoWithProof {
+        TransactionInfoWithProof {
+            ledger_info_to_transaction_info_proof: TransactionAccumulatorProof {
+                siblings: [
+                    hex!("38ebb945a351a6701658fe7f5398133ad62777754e3ea44834da0d1e75a87e10"),
+                    hex!("cdb6d7b047d18fdf13f27f13cf1077bc03b1e93b283feeecec55e31176a1f328"),
+                    hex!("835449fd22e856b1f0fdb76d1ff3e493b7c0f8f43b9f66690b4d4d90a0c424ac"),
+                    hex!("af625d6b7281a633d6bdf5cc144186e1ff094e9953165fd1516ab16544776631"),
+                    hex!("d00d20a6fb6874e4c36e5690a4069b94a41f1ae197ae8769d2207f668f016c1c"),
+                    hex!("3c929e62e334cb0ca8dbfd955899aa2bb09e6cc2ce053261689bb69d31c133f4"),
+                    hex!("819a3f1ed1827d33e60b91da7f44736b4c583faec52b8867f45a97c1803b2b66"),
+                    hex!("ea3756c694f6ed5782c91640e5e821604fa39cc55ff85691949d5c93f5c9fb95"),
+                ]
+                .map(Into::into)
+                .to_vec(),
+                phantom: Null,
+            },
+            transaction_info: TransactionInfo::V0(TransactionInfoV0 {
+                gas_used: 0,
+                status: ExecutionStatus::Success,
+                transaction_hash: hex!(
+                    "e77d9016e431a2d367c513ebaf1bc39e291dc9589728e9bf1495fc573cb085ca"
+                )
+                .into(),
+                event_root_hash: hex!(
+                    "414343554d554c41544f525f504c414345484f4c4445525f4841534800000000"
+                )
+                .into(),
+                state_change_hash: hex!(
+                    "afb6e14fe47d850fd0a7395bcfb997ffacf4715e0f895cc162c218e4a7564bc6"
+                )
+                .into(),
+                state_checkpoint_hash: Some(STATE_ROOT.into()),
+                state_cemetery_hash: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn tx_state() {
+        assert_eq!(
+            verify_tx_state(
+                &tx_info_with_proof(),
+                hex!("b4f2928670ff96185bc02ed57168c18443b6735524b580114aff5dc262f6ff3c"),
+                17,
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn tx_state_wrong_index() {
+        assert!(matches!(
+            verify_tx_state(
+                &tx_info_with_proof(),
+                hex!("b4f2928670ff96185bc02ed57168c18443b6735524b580114aff5dc262f6ff3c"),
+                16,
+            ),
+            Err(Error::RootHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn membership() {
+        assert_eq!(verify_membership(storage_proof(), STATE_ROOT), Ok(()));
+    }
+
+    #[test]
+    fn membership_wrong_value() {
+        let proof = storage_proof();
+        let leaf = proof.leaf.unwrap();
+
+        assert_eq!(
+            verify_existence_proof(proof, STATE_ROOT, leaf.key.into(), [0; 32]),
+            Err(StorageVerificationError::LeafValueMismatch(
+                H256::new([0; 32]),
+                H256::new(*leaf.value_hash.get())
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn membership_wrong_root() {
+        assert!(matches!(
+            verify_membership(storage_proof(), [0; 32]),
+            Err(Error::StorageVerification(
+                StorageVerificationError::RootHashMismatch(..)
+            ))
+        ));
+    }
+
+    #[test]
+    fn non_membership_with_leaf() {
+        // shares the first 11 bits (the depth of the proof) with the leaf key
+        let key = hex!("f2c0000000000000000000000000000000000000000000000000000000000000");
+
+        assert_eq!(
+            verify_non_existence_proof(storage_proof(), STATE_ROOT, key),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn non_membership_of_existing_key() {
+        let proof = storage_proof();
+        let key = proof.leaf.unwrap().key.into();
+
+        assert_eq!(
+            verify_non_existence_proof(proof, STATE_ROOT, key),
+            Err(StorageVerificationError::ExpectedNonMembershipVerification.into())
+        );
+    }
+
+    #[test]
+    fn non_membership_leaf_not_in_subtree() {
+        let key = hex!("f200000000000000000000000000000000000000000000000000000000000000");
+
+        assert!(matches!(
+            verify_non_existence_proof(storage_proof(), STATE_ROOT, key),
+            Err(Error::StorageVerification(
+                StorageVerificationError::LeafNotInSubtree(..)
+            ))
+        ));
+    }
+
+    #[test]
+    fn non_membership_empty_subtree() {
+        let proof = SparseMerkleProof {
+            leaf: None,
+            siblings: vec![[0x11; 32].into()],
+        };
+
+        assert_eq!(
+            verify_non_existence_proof(
+                proof,
+                hex!("176d6f1363560c1870b4ff17f9cd8b1a65da0fbcd5c6bf99c66b68e6c36acc33"),
+                [0; 32],
+            ),
+            Ok(())
+        );
+    }
+}
@@ -8,11 +8,17 @@ use alloy::{
     sol_types::SolEvent,
 };
 use arbitrum_types::{
-    slots::{read_latest_node_created, ROLLUP_CORE_LATEST_NODE_CREATED},
+    slots::{
+        read_latest_confirmed, read_latest_node_created, read_node_created_at_block,
+        read_node_first_child_block, read_node_latest_child_number, read_node_prev_num,
+        rollup_core_nodes_slot, NODE_CHILDREN_OFFSET, NODE_PREV_NUM_OFFSET,
+        ROLLUP_CORE_LATEST_NODE_CREATED,
+    },
     NodeCreated,
 };
+use l2_finality_policy::FinalityPolicy;
 use tracing::{debug, instrument, trace};
-use unionlabs::primitives::{H160, H256};
+use unionlabs::primitives::{H160, H256, U256};
 
 #[instrument(skip_all, fields(%l1_height, %l1_contract_address))]
 pub async fn next_node_num_at_l1_height(
@@ -38,24 +44,134 @@ pub async fn next_node_num_at_l1_height(
     Ok(latest_confirmed)
 }
 
-#[instrument(skip_all, fields(%l1_height, %l1_contract_address))]
+/// Find the latest node that satisfies the finality policy as of the provided L1 height, or `None`
+/// if there is no such node.
+///
+/// Nodes are checked with the same rules as the light client; see
+/// `arbitrum_verifier::verify_node_unchallenged`.
+#[instrument(skip_all, fields(%l1_height, %l1_contract_address, ?finality_policy))]
+pub async fn node_num_of_l1_height(
+    l1_provider: impl Provider,
+    l1_contract_address: H160,
+    l1_height: u64,
+    finality_policy: FinalityPolicy,
+) -> Result<Option<u64>, Box<dyn Error>> {
+    let raw_slot = read_slot(
+        &l1_provider,
+        l1_contract_address,
+        ROLLUP_CORE_LATEST_NODE_CREATED.slot(),
+        l1_height,
+    )
+    .await?;
+
+    let latest_confirmed = read_latest_confirmed(raw_slot);
+    let latest_node_created = read_latest_node_created(raw_slot);
+
+    debug!(latest_confirmed, latest_node_created);
+
+    let unchallenged_blocks = match finality_policy {
+        FinalityPolicy::L1Finalized => return Ok(Some(latest_confirmed)),
+        FinalityPolicy::SequencerSoftConfirmation
+        | FinalityPolicy::L1Included {
+            unchallenged_blocks: 0,
+        } => return Ok(Some(latest_node_created)),
+        FinalityPolicy::L1Included {
+            unchallenged_blocks,
+        } => unchallenged_blocks,
+    };
+
+    for node_num in (latest_confirmed..=latest_node_created).rev() {
+        let children = read_slot(
+            &l1_provider,
+            l1_contract_address,
+            rollup_core_nodes_slot(node_num, NODE_CHILDREN_OFFSET),
+            l1_height,
+        )
+        .await?;
+
+        let created_at_block = read_node_created_at_block(children);
+        let age = l1_height.saturating_sub(created_at_block);
+
+        if age < unchallenged_blocks {
+            trace!(node_num, age, "node is too recent");
+            continue;
+        }
+
+        if node_num == latest_confirmed {
+            return Ok(Some(node_num));
+        }
+
+        let prev_num = read_node_prev_num(
+            read_slot(
+                &l1_provider,
+                l1_contract_address,
+                rollup_core_nodes_slot(node_num, NODE_PREV_NUM_OFFSET),
+                l1_height,
+            )
+            .await?,
+        );
+
+        let parent_children = read_slot(
+            &l1_provider,
+            l1_contract_address,
+            rollup_core_nodes_slot(prev_num, NODE_CHILDREN_OFFSET),
+            l1_height,
+        )
+        .await?;
+
+        if read_node_latest_child_number(parent_children) == node_num
+            && read_node_first_child_block(parent_children) == created_at_block
+        {
+            return Ok(Some(node_num));
+        }
+
+        trace!(node_num, prev_num, "node is challenged");
+    }
+
+    Ok(None)
+}
+
+/// Find the L2 block of the latest node that satisfies the finality policy as of the provided L1
+/// height, or `None` if there is no such node.
+#[instrument(skip_all, fields(%l1_height, %l1_contract_address, ?finality_policy))]
 pub async fn finalized_l2_block_of_l1_height(
     l1_provider: impl Provider,
     l2_provider: impl Provider<AnyNetwork>,
     l1_contract_address: H160,
     l1_height: u64,
-) -> Result<AnyRpcBlock, Box<dyn Error>> {
-    // read the next_node_num at l1.execution_height(beacon_slot), then from there filter for `NodeCreated`
-    let next_node_num =
-        next_node_num_at_l1_height(&l1_provider, l1_contract_address, l1_height).await?;
+    finality_policy: FinalityPolicy,
+) -> Result<Option<AnyRpcBlock>, Box<dyn Error>> {
+    let Some(node_num) = node_num_of_l1_height(
+        &l1_provider,
+        l1_contract_address,
+        l1_height,
+        finality_policy,
+    )
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    l2_block_of_node_num(l1_provider, l2_provider, l1_contract_address, node_num)
+        .await
+        .map(Some)
+}
 
+#[instrument(skip_all, fields(%node_num, %l1_contract_address))]
+pub async fn l2_block_of_node_num(
+    l1_provider: impl Provider,
+    l2_provider: impl Provider<AnyNetwork>,
+    l1_contract_address: H160,
+    node_num: u64,
+) -> Result<AnyRpcBlock, Box<dyn Error>> {
+    // filter for the `NodeCreated` event of the node
     let [event]: [Log; 1] = l1_provider
         .get_logs(
             &Filter::new()
                 .select(BlockNumberOrTag::Earliest..=BlockNumberOrTag::Latest)
                 .address::<alloy::primitives::Address>(l1_contract_address.into())
                 .event_signature(NodeCreated::SIGNATURE_HASH)
-                .topic1(alloy::primitives::U256::from(next_node_num)),
+                .topic1(alloy::primitives::U256::from(node_num)),
         )
         .await?
         .try_into()
@@ -63,11 +179,11 @@ pub async fn finalized_l2_block_of_l1_height(
 
     let event: NodeCreated = NodeCreated::decode_log(&event.inner)?.data;
 
-    trace!(next_node_num, "{event:?}");
+    trace!(node_num, "{event:?}");
 
     let block_hash = event.assertion.afterState.globalState.bytes32Vals[0];
 
-    debug!("next node num {next_node_num} is l2 block hash {block_hash}");
+    debug!("node num {node_num} is l2 block hash {block_hash}");
 
     let block = l2_provider
         .get_block(block_hash.into())
@@ -82,6 +198,22 @@ pub async fn finalized_l2_block_of_l1_height(
     Ok(block)
 }
 
+async fn read_slot(
+    l1_provider: impl Provider,
+    l1_contract_address: H160,
+    slot: U256,
+    l1_height: u64,
+) -> Result<U256, Box<dyn Error>> {
+    let raw_slot = l1_provider
+        .get_storage_at(l1_contract_address.into(), slot.into())
+        .block_id(l1_height.into())
+        .await?;
+
+    trace!(%slot, raw_slot = %<H256>::new(raw_slot.to_be_bytes()));
+
+    Ok(raw_slot.into())
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use alloy::{hex, network::AnyNetwork, providers::ProviderBuilder};
@@ -1,4 +1,5 @@
 use ibc_union_spec::ClientId;
+use l2_finality_policy::FinalityPolicy;
 use unionlabs::{
     ibc::core::client::height::Height,
     primitives::{H160, U256},
@@ -13,6 +14,8 @@ use unionlabs::{
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub enum ClientState {
     V1(ClientStateV1),
+    // v1 with a configurable finality policy
+    V2(ClientStateV2),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -38,3 +41,44 @@ pub struct ClientStateV1 {
 
     pub ibc_contract_address: H160,
 }
+
+impl ClientStateV1 {
+    pub fn into_v2(self, finality_policy: FinalityPolicy) -> ClientStateV2 {
+        ClientStateV2 {
+            chain_id: self.chain_id,
+            latest_height: self.latest_height,
+            l1_client_id: self.l1_client_id,
+            l1_contract_address: self.l1_contract_address,
+            frozen_height: self.frozen_height,
+            ibc_contract_address: self.ibc_contract_address,
+            finality_policy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ClientStateV2 {
+    pub chain_id: U256,
+
+    /// Latest height of the L2
+    pub latest_height: u64,
+
+    /// Client id of the client tracking the L1 that the chain this client tracks settles on
+    pub l1_client_id: ClientId,
+
+    /// Rollup contract on the L1
+    pub l1_contract_address: H160,
+
+    pub frozen_height: Height,
+
+    pub ibc_contract_address: H160,
+
+    /// The policy used to decide whether the L2 state in a header is final.
+    pub finality_policy: FinalityPolicy,
+}
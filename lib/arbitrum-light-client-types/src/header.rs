@@ -13,4 +13,26 @@ pub struct Header {
     pub l1_next_node_num_slot_proof: StorageProof,
     pub l1_nodes_slot_proof: StorageProof,
     pub l2_header: L2Header,
+    /// Proofs of the node to update to, if it is not the node determined by the finality policy of
+    /// the client. See [`NodeProof`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub l1_node_proof: Option<NodeProof>,
+}
+
+/// Proofs of a node that is not the latest created node, along with the data required to check
+/// that it has been unchallenged for long enough to satisfy the
+/// [`FinalityPolicy::L1Included`](crate::FinalityPolicy::L1Included) policy of the client.
+///
+/// All proofs are against the storage root of the L1 `RollupCore` contract.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct NodeProof {
+    pub node_num: u64,
+    /// Proof of the slot of `_nodes[node_num]` containing `prevNum`.
+    pub node_prev_num_slot_proof: StorageProof,
+    /// Proof of the slot of `_nodes[node_num]` containing `createdAtBlock`.
+    pub node_created_at_block_slot_proof: StorageProof,
+    /// Proof of the slot of `_nodes[prevNum]` containing `firstChildBlock` and `latestChildNumber`.
+    pub parent_node_children_slot_proof: StorageProof,
 }
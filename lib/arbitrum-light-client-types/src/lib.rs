@@ -3,9 +3,11 @@ pub mod consensus_state;
 pub mod header;
 pub mod l2_header;
 
+pub use l2_finality_policy::FinalityPolicy;
+
 pub use crate::{
-    client_state::{ClientState, ClientStateV1},
+    client_state::{ClientState, ClientStateV1, ClientStateV2},
     consensus_state::ConsensusState,
-    header::Header,
+    header::{Header, NodeProof},
     l2_header::L2Header,
 };
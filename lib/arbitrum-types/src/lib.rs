@@ -32,6 +32,14 @@ pub mod slots {
     /// [`_latestNodeCreated`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/RollupCore.sol#L62
     pub const ROLLUP_CORE_LATEST_NODE_CREATED_SLOT_OFFSET_BYTES: u8 = 16;
 
+    /// The offset into the storage layout of the slot containing [`_latestConfirmed`].
+    ///
+    /// `_latestConfirmed` is packed into the same slot as `_latestNodeCreated`, see
+    /// [`ROLLUP_CORE_LATEST_NODE_CREATED`].
+    ///
+    /// [`_latestConfirmed`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/RollupCore.sol#L60
+    pub const ROLLUP_CORE_LATEST_CONFIRMED_SLOT_OFFSET_BYTES: u8 = 0;
+
     /// The base slot of the [`_nodes`] mapping.
     ///
     /// ```solidity
@@ -50,6 +58,44 @@ pub mod slots {
     /// [`Node`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/Node.sol#L21-L46
     pub const NODE_CONFIRM_DATA_OFFSET: U256 = U256::from_limbs([2, 0, 0, 0]);
 
+    /// The offset of the slot containing the `prevNum` field in the storage layout of the [`Node`]
+    /// struct.
+    ///
+    /// ```solidity
+    /// uint64 prevNum;
+    /// uint64 deadlineBlock;
+    /// uint64 noChildConfirmedBeforeBlock;
+    /// uint64 stakerCount;
+    /// ```
+    ///
+    /// [`Node`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/Node.sol#L21-L46
+    pub const NODE_PREV_NUM_OFFSET: U256 = U256::from_limbs([3, 0, 0, 0]);
+
+    /// The offset into the slot at [`NODE_PREV_NUM_OFFSET`] of the `prevNum` field.
+    pub const NODE_PREV_NUM_SLOT_OFFSET_BYTES: u8 = 0;
+
+    /// The offset of the slot containing the `firstChildBlock`, `latestChildNumber` and
+    /// `createdAtBlock` fields in the storage layout of the [`Node`] struct.
+    ///
+    /// ```solidity
+    /// uint64 childStakerCount;
+    /// uint64 firstChildBlock;
+    /// uint64 latestChildNumber;
+    /// uint64 createdAtBlock;
+    /// ```
+    ///
+    /// [`Node`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/Node.sol#L21-L46
+    pub const NODE_CHILDREN_OFFSET: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+    /// The offset into the slot at [`NODE_CHILDREN_OFFSET`] of the `firstChildBlock` field.
+    pub const NODE_FIRST_CHILD_BLOCK_SLOT_OFFSET_BYTES: u8 = 8;
+
+    /// The offset into the slot at [`NODE_CHILDREN_OFFSET`] of the `latestChildNumber` field.
+    pub const NODE_LATEST_CHILD_NUMBER_SLOT_OFFSET_BYTES: u8 = 16;
+
+    /// The offset into the slot at [`NODE_CHILDREN_OFFSET`] of the `createdAtBlock` field.
+    pub const NODE_CREATED_AT_BLOCK_SLOT_OFFSET_BYTES: u8 = 24;
+
     /// Calculate the slot of the [`confirmData`] field of the `Node` struct stored in the `_nodes`
     /// mapping.
     ///
@@ -62,16 +108,62 @@ pub mod slots {
     ///
     /// [`confirmData`]: https://github.com/OffchainLabs/nitro-contracts/blob/90037b996509312ef1addb3f9352457b8a99d6a6/src/rollup/Node.sol#L27
     pub fn rollup_core_nodes_confirm_data_slot(node_num: u64) -> U256 {
+        rollup_core_nodes_slot(node_num, NODE_CONFIRM_DATA_OFFSET)
+    }
+
+    /// Calculate the slot at `offset` in the storage layout of the `Node` struct stored in the
+    /// `_nodes` mapping.
+    ///
+    /// See [`NODE_PREV_NUM_OFFSET`] and [`NODE_CHILDREN_OFFSET`].
+    pub fn rollup_core_nodes_slot(node_num: u64, offset: U256) -> U256 {
         Slot::StructOffset(
             &Slot::Mapping(
                 &ROLLUP_CORE_NODES_MAPPING_BASE,
                 MappingKey::Uint64(node_num),
             ),
-            NODE_CONFIRM_DATA_OFFSET,
+            offset,
         )
         .slot()
     }
 
+    /// Read the value of the `_latestConfirmed` in the provided storage slot.
+    pub fn read_latest_confirmed(value: U256) -> u64 {
+        read_packed_u64(value, ROLLUP_CORE_LATEST_CONFIRMED_SLOT_OFFSET_BYTES)
+    }
+
+    /// Read the `prevNum` of a node from the slot at [`NODE_PREV_NUM_OFFSET`].
+    pub fn read_node_prev_num(value: U256) -> u64 {
+        read_packed_u64(value, NODE_PREV_NUM_SLOT_OFFSET_BYTES)
+    }
+
+    /// Read the `firstChildBlock` of a node from the slot at [`NODE_CHILDREN_OFFSET`].
+    pub fn read_node_first_child_block(value: U256) -> u64 {
+        read_packed_u64(value, NODE_FIRST_CHILD_BLOCK_SLOT_OFFSET_BYTES)
+    }
+
+    /// Read the `latestChildNumber` of a node from the slot at [`NODE_CHILDREN_OFFSET`].
+    pub fn read_node_latest_child_number(value: U256) -> u64 {
+        read_packed_u64(value, NODE_LATEST_CHILD_NUMBER_SLOT_OFFSET_BYTES)
+    }
+
+    /// Read the `createdAtBlock` of a node from the slot at [`NODE_CHILDREN_OFFSET`].
+    pub fn read_node_created_at_block(value: U256) -> u64 {
+        read_packed_u64(value, NODE_CREATED_AT_BLOCK_SLOT_OFFSET_BYTES)
+    }
+
+    /// Read the `u64` packed at `offset_bytes` in the provided storage slot.
+    ///
+    /// See [`read_latest_node_created`] for how values are packed into a slot.
+    fn read_packed_u64(value: U256, offset_bytes: u8) -> u64 {
+        let start = 32 - (offset_bytes as usize + size_of::<u64>());
+
+        u64::from_be_bytes(
+            value.to_be_bytes()[start..start + size_of::<u64>()]
+                .try_into()
+                .expect("slice is the size of a u64; qed;"),
+        )
+    }
+
     /// Read the value of the `_latestNodeCreated` in the provided storage slot.
     pub fn read_latest_node_created(value: U256) -> u64 {
         u64::from_be_bytes(
@@ -154,7 +246,10 @@ alloy_sol_types::sol! {
 
 #[cfg(test)]
 mod tests {
-    use crate::slots::read_latest_node_created;
+    use crate::slots::{
+        read_latest_confirmed, read_latest_node_created, read_node_created_at_block,
+        read_node_first_child_block, read_node_latest_child_number,
+    };
 
     #[test]
     fn read_latest_node_created_correct_value() {
@@ -165,4 +260,25 @@ mod tests {
             0x000000000000011c,
         );
     }
+
+    #[test]
+    fn read_latest_confirmed_correct_value() {
+        assert_eq!(
+            read_latest_confirmed(solidity_slot::U256::from_be_bytes(hex_literal::hex!(
+                "000000000143dd37000000000000011c00000000000001120000000000000111"
+            ))),
+            0x0000000000000111,
+        );
+    }
+
+    #[test]
+    fn read_node_children_slot_correct_values() {
+        let value = solidity_slot::U256::from_be_bytes(hex_literal::hex!(
+            "000000000143dd0a000000000000011c000000000143dd2b0000000000000000"
+        ));
+
+        assert_eq!(read_node_created_at_block(value), 0x000000000143dd0a);
+        assert_eq!(read_node_latest_child_number(value), 0x000000000000011c);
+        assert_eq!(read_node_first_child_block(value), 0x000000000143dd2b);
+    }
 }
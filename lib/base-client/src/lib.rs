@@ -1,29 +1,112 @@
 use alloy::{providers::Provider, sol};
+use base_verifier::{check_game_status, GameStatus, CLAIM_DATA_LENGTH_SLOT, GAME_STATUS_SLOT};
+use l2_finality_policy::FinalityPolicy;
 use tracing::{debug, instrument};
 use unionlabs::primitives::{H160, U256};
 
 use crate::DisputeGameFactory::gameAtIndexReturn;
 
-#[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy))]
+/// Find the L2 block number of the latest game that satisfies the finality policy as of the
+/// provided L1 block, or `None` if there is no such game.
+#[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy, ?finality_policy))]
 pub async fn finalized_l2_block_number_of_l1_block_number(
     l1_provider: &impl Provider,
     l1_dispute_game_factory_proxy: H160,
     l1_block_number: u64,
-) -> Result<u64, alloy::contract::Error> {
-    let count = latest_game_of_l1_block_number(
-        &l1_provider,
+    finality_policy: FinalityPolicy,
+) -> Result<Option<u64>, alloy::contract::Error> {
+    let Some(game_index) = latest_final_game_of_l1_block_number(
+        l1_provider,
         l1_block_number,
         l1_dispute_game_factory_proxy,
+        finality_policy,
     )
-    .await?;
+    .await?
+    else {
+        return Ok(None);
+    };
 
     finalized_l2_block_of_game_index(
         l1_provider,
         l1_block_number,
         l1_dispute_game_factory_proxy,
-        count - U256::ONE,
+        game_index,
     )
     .await
+    .map(Some)
+}
+
+/// Find the index of the latest game that satisfies the finality policy as of the provided L1
+/// block, or `None` if there is no such game.
+///
+/// Games are checked from newest to oldest, with the same rules as the light client.
+#[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy, ?finality_policy))]
+pub async fn latest_final_game_of_l1_block_number(
+    l1_provider: &impl Provider,
+    l1_block_number: u64,
+    l1_dispute_game_factory_proxy: H160,
+    finality_policy: FinalityPolicy,
+) -> Result<Option<U256>, alloy::contract::Error> {
+    let count = latest_game_of_l1_block_number(
+        &l1_provider,
+        l1_block_number,
+        l1_dispute_game_factory_proxy,
+    )
+    .await?;
+
+    if finality_policy == FinalityPolicy::SequencerSoftConfirmation {
+        return Ok((count != U256::ZERO).then(|| count - U256::ONE));
+    }
+
+    let l1_timestamp = l1_provider
+        .get_block(l1_block_number.into())
+        .await?
+        .expect("block should exist")
+        .header
+        .timestamp;
+
+    let c = DisputeGameFactory::new(l1_dispute_game_factory_proxy.into(), &l1_provider);
+
+    let mut game_index = count;
+
+    while game_index != U256::ZERO {
+        game_index = game_index - U256::ONE;
+
+        let gameAtIndexReturn { proxy_, .. } = c
+            .gameAtIndex(game_index.into())
+            .block(l1_block_number.into())
+            .call()
+            .await?;
+
+        let game_status = GameStatus::from_slot(
+            l1_provider
+                .get_storage_at(proxy_, GAME_STATUS_SLOT.into())
+                .block_id(l1_block_number.into())
+                .await?
+                .into(),
+        );
+
+        let claim_data_length: U256 = l1_provider
+            .get_storage_at(proxy_, CLAIM_DATA_LENGTH_SLOT.into())
+            .block_id(l1_block_number.into())
+            .await?
+            .into();
+
+        debug!(%game_index, %proxy_, ?game_status, %claim_data_length);
+
+        if check_game_status(
+            finality_policy,
+            game_status,
+            claim_data_length,
+            l1_timestamp,
+        )
+        .is_ok()
+        {
+            return Ok(Some(game_index));
+        }
+    }
+
+    Ok(None)
 }
 
 #[instrument(skip_all, fields(%l1_block_number, %l1_dispute_game_factory_proxy))]
@@ -18,6 +18,20 @@ pub struct Header {
     pub l2_ibc_account_proof: AccountProof,
     pub l2_header: L2Header,
     pub output_root_proof: OutputRootProof,
+    /// Proof of the first storage slot of the game, which contains the timestamp it was created
+    /// at and its status.
+    ///
+    /// Required unless the finality policy of the client is
+    /// [`FinalityPolicy::SequencerSoftConfirmation`](crate::FinalityPolicy::SequencerSoftConfirmation).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub game_status_proof: Option<StorageProof>,
+    /// Proof of the length of the `claimData` array of the game, which is the number of claims
+    /// made in the game.
+    ///
+    /// Required unless the finality policy of the client is
+    /// [`FinalityPolicy::SequencerSoftConfirmation`](crate::FinalityPolicy::SequencerSoftConfirmation).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub claim_data_length_proof: Option<StorageProof>,
 }
 
 // https://github.com/ethereum-optimism/optimism/blob/99a53381019d3571359d989671ccf70f8d69dfd9/packages/contracts-bedrock/src/libraries/Types.sol#L25
@@ -3,11 +3,12 @@ use core::fmt::Debug;
 use alloy_sol_types::SolValue;
 use base_light_client_types::{
     header::{L2Header, OutputRootProof},
-    ClientStateV1, Header,
+    ClientStateV2, Header,
 };
 use evm_storage_verifier::{
     verify_account_code_hash, verify_account_storage_root, verify_storage_proof,
 };
+use l2_finality_policy::{FinalityPolicy, L1_SECONDS_PER_BLOCK};
 use unionlabs::{
     ethereum::{keccak256, slot::Slot},
     primitives::{encoding::HexPrefixed, ByteArrayExt, H160, H256, U256},
@@ -34,10 +35,75 @@ pub enum Error {
     InvalidIbcContractStorageRoot,
     #[error("the l2 header is not finalized")]
     HeaderNotFinalized,
+    #[error("the finality policy of the client requires a game status proof")]
+    MissingGameStatusProof,
+    #[error("invalid game account proof")]
+    InvalidGameAccountProof(#[source] evm_storage_verifier::error::Error),
+    #[error("invalid game status proof")]
+    InvalidGameStatusProof(#[source] evm_storage_verifier::error::Error),
+    #[error("the finality policy of the client requires a claim data length proof")]
+    MissingClaimDataLengthProof,
+    #[error("invalid claim data length proof")]
+    InvalidClaimDataLengthProof(#[source] evm_storage_verifier::error::Error),
+    #[error("the game has been challenged")]
+    GameChallenged,
+    #[error(
+        "the game was created {age} l1 blocks ago, but the finality \
+        policy requires it to be unchallenged for {unchallenged_blocks} blocks"
+    )]
+    GameTooRecent { age: u64, unchallenged_blocks: u64 },
+}
+
+/// The slot of the `FaultDisputeGame` containing the following packed values:
+///
+/// ```solidity
+/// Timestamp public createdAt;
+/// Timestamp public resolvedAt;
+/// GameStatus public status;
+/// bool internal initialized;
+/// bool public l2BlockNumberChallenged;
+/// ```
+///
+/// See <https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/FaultDisputeGame.sol>.
+pub const GAME_STATUS_SLOT: U256 = U256::ZERO;
+
+/// The slot of the length of the `claimData` array of a `FaultDisputeGame`. The root claim is the
+/// first element, every move made in the game appends a claim.
+///
+/// See <https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/snapshots/storageLayout/FaultDisputeGame.json>.
+pub const CLAIM_DATA_LENGTH_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+
+/// See <https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/lib/Types.sol>.
+pub const GAME_STATUS_CHALLENGER_WINS: u8 = 1;
+pub const GAME_STATUS_DEFENDER_WINS: u8 = 2;
+
+/// The values stored in the [`GAME_STATUS_SLOT`] of a `FaultDisputeGame`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameStatus {
+    /// The L1 timestamp the game was created at, in seconds.
+    pub created_at: u64,
+    /// The L1 timestamp the game was resolved at, in seconds. Zero if the game is unresolved.
+    pub resolved_at: u64,
+    pub status: u8,
+    pub l2_block_number_challenged: bool,
+}
+
+impl GameStatus {
+    pub fn from_slot(value: U256) -> Self {
+        // values are packed in the slot right-aligned, in declaration order
+        let bz = value.to_be_bytes();
+
+        Self {
+            created_at: u64::from_be_bytes(bz.array_slice::<24, 8>()),
+            resolved_at: u64::from_be_bytes(bz.array_slice::<16, 8>()),
+            status: bz[15],
+            l2_block_number_challenged: bz[13] != 0,
+        }
+    }
 }
 
 pub fn verify_header(
-    client_state: &ClientStateV1,
+    client_state: &ClientStateV2,
     header: &Header,
     l1_state_root: H256,
 ) -> Result<(), Error> {
@@ -64,9 +130,7 @@ pub fn verify_header(
     )
     .map_err(Error::InvalidOutputProposalStorageProof)?;
 
-    let game_id = header.game_proof.value.to_be_bytes();
-    // See https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/lib/LibUDT.sol#L70-L79
-    let game_account_address = H160::<HexPrefixed>::new(game_id.array_slice::<12, 20>());
+    let game_account_address = game_account_address(header.game_proof.value);
     let game_account_code_hash = keccak256(&header.game_account_code);
 
     // 3. Verify that the provided code is what's currently backing the game account.
@@ -120,6 +184,117 @@ pub fn verify_header(
     Ok(())
 }
 
+/// Verify that the game the header is proven against satisfies the finality policy of the client.
+///
+/// This must be called after [`verify_header`], with the same L1 state root. `l1_timestamp` is the
+/// timestamp of the L1 block, in seconds.
+///
+/// 1. Verify that the storage root of the game account is part of the L1 state root.
+/// 2. Verify that the [`GAME_STATUS_SLOT`] is part of the game account storage root.
+/// 3. Verify that the [`CLAIM_DATA_LENGTH_SLOT`] is part of the game account storage root.
+/// 4. Verify that the [`GameStatus`] and the number of claims satisfy the finality policy.
+pub fn verify_finality(
+    client_state: &ClientStateV2,
+    header: &Header,
+    l1_state_root: H256,
+    l1_timestamp: u64,
+) -> Result<(), Error> {
+    if client_state.finality_policy == FinalityPolicy::SequencerSoftConfirmation {
+        return Ok(());
+    }
+
+    let game_status_proof = header
+        .game_status_proof
+        .as_ref()
+        .ok_or(Error::MissingGameStatusProof)?;
+    let claim_data_length_proof = header
+        .claim_data_length_proof
+        .as_ref()
+        .ok_or(Error::MissingClaimDataLengthProof)?;
+
+    // 1.
+    verify_account_storage_root(
+        l1_state_root,
+        &game_account_address(header.game_proof.value),
+        &header.game_account_proof.proof,
+        &header.game_account_proof.storage_root,
+    )
+    .map_err(Error::InvalidGameAccountProof)?;
+
+    // 2.
+    verify_storage_proof(
+        header.game_account_proof.storage_root,
+        GAME_STATUS_SLOT,
+        &rlp::encode(&game_status_proof.value),
+        &game_status_proof.proof,
+    )
+    .map_err(Error::InvalidGameStatusProof)?;
+
+    // 3.
+    verify_storage_proof(
+        header.game_account_proof.storage_root,
+        CLAIM_DATA_LENGTH_SLOT,
+        &rlp::encode(&claim_data_length_proof.value),
+        &claim_data_length_proof.proof,
+    )
+    .map_err(Error::InvalidClaimDataLengthProof)?;
+
+    // 4.
+    check_game_status(
+        client_state.finality_policy,
+        GameStatus::from_slot(game_status_proof.value),
+        claim_data_length_proof.value,
+        l1_timestamp,
+    )
+}
+
+/// Check that a game with the provided [`GameStatus`] and `claim_data_length` (the value of the
+/// [`CLAIM_DATA_LENGTH_SLOT`]) satisfies the finality policy, as of the L1 timestamp
+/// `l1_timestamp` (in seconds).
+pub fn check_game_status(
+    finality_policy: FinalityPolicy,
+    game_status: GameStatus,
+    claim_data_length: U256,
+    l1_timestamp: u64,
+) -> Result<(), Error> {
+    match finality_policy {
+        FinalityPolicy::L1Finalized => {
+            if game_status.status != GAME_STATUS_DEFENDER_WINS {
+                return Err(Error::HeaderNotFinalized);
+            }
+        }
+        FinalityPolicy::L1Included {
+            unchallenged_blocks,
+        } => {
+            // any claim besides the root claim is a counter to the root claim or to one of the
+            // counters, the game has been challenged even though it might not be resolved yet
+            if game_status.status == GAME_STATUS_CHALLENGER_WINS
+                || game_status.l2_block_number_challenged
+                || claim_data_length != U256::ONE
+            {
+                return Err(Error::GameChallenged);
+            }
+
+            let age = l1_timestamp.saturating_sub(game_status.created_at) / L1_SECONDS_PER_BLOCK;
+
+            if !finality_policy.is_old_enough(age) {
+                return Err(Error::GameTooRecent {
+                    age,
+                    unchallenged_blocks,
+                });
+            }
+        }
+        FinalityPolicy::SequencerSoftConfirmation => {}
+    }
+
+    Ok(())
+}
+
+// See https://github.com/ethereum-optimism/optimism/blob/4a7cb8a198a1f027e739d2e51dc170faf02b5d28/packages/contracts-bedrock/src/dispute/lib/LibUDT.sol#L70-L79
+pub fn game_account_address(game_id: U256) -> H160 {
+    H160::<HexPrefixed>::new(game_id.to_be_bytes().array_slice::<12, 20>())
+}
+
 pub fn compute_game_slot(dispute_game_list_slot: U256, index: U256) -> U256 {
     let offset = Slot::Offset(dispute_game_list_slot);
     Slot::Array(&offset, index).slot()
@@ -155,7 +330,7 @@ pub fn verify_l2_header_is_related_to_output_root_proof(
 
 #[cfg(test)]
 mod tests {
-    use base_light_client_types::{ClientStateV1, Header};
+    use base_light_client_types::{ClientStateV2, Header};
     use hex_literal::hex;
     use ibc_union_spec::ClientId;
     use unionlabs::encoding::{DecodeAs, Json};
@@ -177,7 +352,7 @@ mod tests {
 
         BYTECODE_OFFSET = START_INDEX + FIELD_OFFSET = 98 + FIELD_OFFSET
          */
-        let client_state = ClientStateV1 {
+        let client_state = ClientStateV2 {
             chain_id: 8453u32.into(),
             latest_height: 0x1ee1d6b,
             l1_client_id: ClientId!(1),
@@ -186,6 +361,7 @@ mod tests {
             fault_dispute_game_code_root_claim_index: 98 + 20,
             frozen_height: 0,
             ibc_contract_address: hex!("8EfB6B5c4767B09Dc9AA6Af4eAA89F749522BaE2").into(),
+            finality_policy: FinalityPolicy::default(),
         };
 
         let l1_state_root = H256::new(hex!(
@@ -288,6 +464,129 @@ mod tests {
         verify_header(&client_state, &header, l1_state_root).unwrap();
     }
 
+    #[test]
+    fn test_game_status_from_slot() {
+        // createdAt = 0x6868f0a3, resolvedAt = 0x6872c1a3, status = DEFENDER_WINS, initialized = true
+        let game_status = GameStatus::from_slot(U256::from_be_bytes(hex!(
+            "00000000000000000000000000000102000000006872c1a3000000006868f0a3"
+        )));
+
+        assert_eq!(
+            game_status,
+            GameStatus {
+                created_at: 0x6868f0a3,
+                resolved_at: 0x6872c1a3,
+                status: GAME_STATUS_DEFENDER_WINS,
+                l2_block_number_challenged: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_game_status() {
+        let in_progress = GameStatus {
+            created_at: 1_000,
+            resolved_at: 0,
+            status: 0,
+            l2_block_number_challenged: false,
+        };
+
+        assert_eq!(
+            check_game_status(
+                FinalityPolicy::L1Finalized,
+                in_progress,
+                U256::ONE,
+                1_000_000
+            ),
+            Err(Error::HeaderNotFinalized)
+        );
+        assert_eq!(
+            check_game_status(
+                FinalityPolicy::L1Finalized,
+                GameStatus {
+                    status: GAME_STATUS_DEFENDER_WINS,
+                    ..in_progress
+                },
+                U256::ONE,
+                1_000_000
+            ),
+            Ok(())
+        );
+
+        let policy = FinalityPolicy::L1Included {
+            unchallenged_blocks: 10,
+        };
+
+        assert_eq!(
+            check_game_status(
+                policy,
+                in_progress,
+                U256::ONE,
+                1_000 + 9 * L1_SECONDS_PER_BLOCK
+            ),
+            Err(Error::GameTooRecent {
+                age: 9,
+                unchallenged_blocks: 10
+            })
+        );
+        assert_eq!(
+            check_game_status(
+                policy,
+                in_progress,
+                U256::ONE,
+                1_000 + 10 * L1_SECONDS_PER_BLOCK
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            check_game_status(
+                policy,
+                GameStatus {
+                    status: GAME_STATUS_CHALLENGER_WINS,
+                    ..in_progress
+                },
+                U256::ONE,
+                1_000_000
+            ),
+            Err(Error::GameChallenged)
+        );
+        assert_eq!(
+            check_game_status(
+                policy,
+                GameStatus {
+                    l2_block_number_challenged: true,
+                    ..in_progress
+                },
+                U256::ONE,
+                1_000_000
+            ),
+            Err(Error::GameChallenged)
+        );
+        // the root claim has been countered, but the game is still in progress
+        assert_eq!(
+            check_game_status(
+                policy,
+                in_progress,
+                U256::from(2u64),
+                1_000 + 10 * L1_SECONDS_PER_BLOCK
+            ),
+            Err(Error::GameChallenged)
+        );
+
+        assert_eq!(
+            check_game_status(
+                FinalityPolicy::SequencerSoftConfirmation,
+                GameStatus {
+                    status: GAME_STATUS_CHALLENGER_WINS,
+                    ..in_progress
+                },
+                U256::ONE,
+                0
+            ),
+            Ok(())
+        );
+    }
+
     #[test]
     fn test_verify_l2_header_is_related_to_output_root_proof() {
         // Verified against mainnet https://dashboard.tenderly.co/tx/0xddc2095d8d800ddebd22d2717841cb3c7eefa3c76f97316daf6ceb0897a7ab43
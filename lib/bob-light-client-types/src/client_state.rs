@@ -1,4 +1,5 @@
 use ibc_union_spec::ClientId;
+use l2_finality_policy::FinalityPolicy;
 use unionlabs::primitives::{H160, U256};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,6 +10,8 @@ pub enum ClientState {
     V1(ClientStateV1),
     // compatible with v2 bedrock contracts
     V2(ClientStateV2),
+    // v2 with a configurable finality policy
+    V3(ClientStateV3),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -45,3 +48,40 @@ pub struct ClientStateV2 {
     pub frozen_height: u64,
     pub ibc_contract_address: H160,
 }
+
+impl ClientStateV2 {
+    pub fn into_v3(self, finality_policy: FinalityPolicy) -> ClientStateV3 {
+        ClientStateV3 {
+            chain_id: self.chain_id,
+            latest_height: self.latest_height,
+            l1_client_id: self.l1_client_id,
+            dispute_game_factory_address: self.dispute_game_factory_address,
+            dispute_game_factory_dispute_game_list_slot: self
+                .dispute_game_factory_dispute_game_list_slot,
+            fault_dispute_game_code_root_claim_index: self.fault_dispute_game_code_root_claim_index,
+            frozen_height: self.frozen_height,
+            ibc_contract_address: self.ibc_contract_address,
+            finality_policy,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ClientStateV3 {
+    pub chain_id: U256,
+    pub latest_height: u64,
+    /// Client id of the client tracking the L1 that the chain this client tracks settles on
+    pub l1_client_id: ClientId,
+    /// Address of the `DisputeGameFactory`.
+    pub dispute_game_factory_address: H160,
+    /// Slot of the [`_disputeGameList`](https://vscode.blockscan.com/ethereum/0x4bba758f006ef09402ef31724203f316ab74e4a0) within the DisputeGameFactory.
+    pub dispute_game_factory_dispute_game_list_slot: U256,
+    /// Index of the `rootClaim` within the game proxy bytecode.
+    pub fault_dispute_game_code_root_claim_index: u32,
+    pub frozen_height: u64,
+    pub ibc_contract_address: H160,
+    /// The policy used to decide whether the L2 state in a header is final.
+    pub finality_policy: FinalityPolicy,
+}
@@ -2,8 +2,10 @@ pub mod client_state;
 pub mod consensus_state;
 pub mod header;
 
+pub use l2_finality_policy::FinalityPolicy;
+
 pub use crate::{
-    client_state::{ClientState, ClientStateV1, ClientStateV2},
+    client_state::{ClientState, ClientStateV1, ClientStateV2, ClientStateV3},
     consensus_state::ConsensusState,
     header::Header,
 };
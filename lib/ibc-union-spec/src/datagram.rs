@@ -4,7 +4,7 @@ use voyager_primitives::ClientType;
 
 use crate::{
     types::{ChannelId, ClientId, ConnectionId},
-    Channel, Packet,
+    Channel, ChannelOrder, Packet,
 };
 
 /// All datagrams that are a part of the IBC union specification.
@@ -18,6 +18,8 @@ use crate::{
 pub enum Datagram {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
+    Misbehaviour(MsgMisbehaviour),
+    RecoverClient(MsgRecoverClient),
     ConnectionOpenInit(MsgConnectionOpenInit),
     ConnectionOpenTry(MsgConnectionOpenTry),
     ConnectionOpenAck(MsgConnectionOpenAck),
@@ -28,6 +30,12 @@ pub enum Datagram {
     ChannelOpenConfirm(MsgChannelOpenConfirm),
     ChannelCloseInit(MsgChannelCloseInit),
     ChannelCloseConfirm(MsgChannelCloseConfirm),
+    ChannelUpgradeInit(MsgChannelUpgradeInit),
+    ChannelUpgradeTry(MsgChannelUpgradeTry),
+    ChannelUpgradeAck(MsgChannelUpgradeAck),
+    ChannelUpgradeConfirm(MsgChannelUpgradeConfirm),
+    ChannelUpgradeOpen(MsgChannelUpgradeOpen),
+    ChannelUpgradeCancel(MsgChannelUpgradeCancel),
     PacketRecv(MsgPacketRecv),
     PacketAcknowledgement(MsgPacketAcknowledgement),
     PacketTimeout(MsgPacketTimeout),
@@ -43,6 +51,8 @@ impl Datagram {
         match self {
             Self::CreateClient(_) => None,
             Self::UpdateClient(_) => None,
+            Self::Misbehaviour(_) => None,
+            Self::RecoverClient(_) => None,
             Self::ConnectionOpenInit(_) => None,
             Self::ConnectionOpenTry(msg) => Some(Height::new(msg.proof_height)),
             Self::ConnectionOpenAck(msg) => Some(Height::new(msg.proof_height)),
@@ -53,6 +63,12 @@ impl Datagram {
             Self::ChannelOpenConfirm(msg) => Some(Height::new(msg.proof_height)),
             Self::ChannelCloseInit(_msg) => todo!(),
             Self::ChannelCloseConfirm(_msg) => todo!(),
+            Self::ChannelUpgradeInit(_) => None,
+            Self::ChannelUpgradeTry(msg) => Some(Height::new(msg.proof_height)),
+            Self::ChannelUpgradeAck(msg) => Some(Height::new(msg.proof_height)),
+            Self::ChannelUpgradeConfirm(msg) => Some(Height::new(msg.proof_height)),
+            Self::ChannelUpgradeOpen(msg) => Some(Height::new(msg.proof_height)),
+            Self::ChannelUpgradeCancel(msg) => Some(Height::new(msg.proof_height)),
             Self::PacketRecv(msg) => Some(Height::new(msg.proof_height)),
             Self::PacketAcknowledgement(msg) => Some(Height::new(msg.proof_height)),
             Self::PacketTimeout(_msg) => todo!(),
@@ -66,6 +82,8 @@ impl Datagram {
         match self {
             Self::CreateClient(_) => "create_client",
             Self::UpdateClient(_) => "update_client",
+            Self::Misbehaviour(_) => "misbehaviour",
+            Self::RecoverClient(_) => "recover_client",
             Self::ConnectionOpenInit(_) => "connection_open_init",
             Self::ConnectionOpenTry(_) => "connection_open_try",
             Self::ConnectionOpenAck(_) => "connection_open_ack",
@@ -76,6 +94,12 @@ impl Datagram {
             Self::ChannelOpenConfirm(_) => "channel_open_confirm",
             Self::ChannelCloseInit(_) => "channel_close_init",
             Self::ChannelCloseConfirm(_) => "channel_close_confirm",
+            Self::ChannelUpgradeInit(_) => "channel_upgrade_init",
+            Self::ChannelUpgradeTry(_) => "channel_upgrade_try",
+            Self::ChannelUpgradeAck(_) => "channel_upgrade_ack",
+            Self::ChannelUpgradeConfirm(_) => "channel_upgrade_confirm",
+            Self::ChannelUpgradeOpen(_) => "channel_upgrade_open",
+            Self::ChannelUpgradeCancel(_) => "channel_upgrade_cancel",
             Self::PacketRecv(_) => "packet_recv",
             Self::PacketAcknowledgement(_) => "packet_acknowledgement",
             Self::PacketTimeout(_) => "packet_timeout",
@@ -111,6 +135,30 @@ pub struct MsgUpdateClient {
     pub client_message: Bytes,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgMisbehaviour {
+    pub client_id: ClientId,
+    pub client_message: Bytes,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgRecoverClient {
+    pub subject: ClientId,
+    pub substitute: ClientId,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
@@ -177,6 +225,8 @@ pub struct MsgChannelOpenInit {
     pub counterparty_port_id: Bytes,
     pub connection_id: ConnectionId,
     pub version: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordering: ChannelOrder,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -192,6 +242,10 @@ pub struct MsgChannelOpenTry {
     pub counterparty_version: String,
     pub proof_init: Bytes,
     pub proof_height: u64,
+    /// The ordering of the channel, which must be the same as the ordering of the counterparty
+    /// channel.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordering: ChannelOrder,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -240,6 +294,88 @@ pub struct MsgChannelCloseInit {}
 )]
 pub struct MsgChannelCloseConfirm {}
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeInit {
+    pub channel_id: ChannelId,
+    pub proposed_version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeTry {
+    pub channel_id: ChannelId,
+    pub proposed_version: String,
+    pub upgrade_sequence: u64,
+    pub proof_init: Bytes,
+    pub proof_height: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeAck {
+    pub channel_id: ChannelId,
+    pub proof_try: Bytes,
+    pub proof_height: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeConfirm {
+    pub channel_id: ChannelId,
+    pub proof_ack: Bytes,
+    pub proof_height: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeOpen {
+    pub channel_id: ChannelId,
+    /// Proof of the upgraded channel on the counterparty.
+    pub proof_channel: Bytes,
+    pub proof_height: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct MsgChannelUpgradeCancel {
+    pub channel_id: ChannelId,
+    /// Proof of the cancellation of the upgrade by the counterparty. Unused when the upgrade is
+    /// cancelled by the end that proposed it.
+    pub proof_error: Bytes,
+    pub proof_height: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
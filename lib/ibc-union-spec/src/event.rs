@@ -4,7 +4,7 @@ use voyager_primitives::{ClientType, Timestamp};
 
 use crate::{
     types::{packet::MustBeZero, ChannelId, ClientId, ConnectionId},
-    Connection, Packet,
+    ChannelOrder, Connection, Packet,
 };
 
 /// The fully filled out event for IBC union. This will likely not be what is exactly emitted on chain, however *enough* information should be emitted such that this structure can be constructed.
@@ -18,6 +18,7 @@ use crate::{
 pub enum FullEvent {
     CreateClient(CreateClient),
     UpdateClient(UpdateClient),
+    ClientMisbehaviour(ClientMisbehaviour),
 
     ConnectionOpenInit(ConnectionOpenInit),
     ConnectionOpenTry(ConnectionOpenTry),
@@ -32,6 +33,13 @@ pub enum FullEvent {
     ChannelCloseInit(ChannelCloseInit),
     ChannelCloseConfirm(ChannelCloseConfirm),
 
+    ChannelUpgradeInit(ChannelUpgradeInit),
+    ChannelUpgradeTry(ChannelUpgradeTry),
+    ChannelUpgradeAck(ChannelUpgradeAck),
+    ChannelUpgradeConfirm(ChannelUpgradeConfirm),
+    ChannelUpgradeOpen(ChannelUpgradeOpen),
+    ChannelUpgradeCancel(ChannelUpgradeCancel),
+
     PacketSend(PacketSend),
     BatchSend(BatchSend),
     PacketRecv(PacketRecv),
@@ -46,6 +54,7 @@ impl FullEvent {
         match self {
             Self::CreateClient(_) => None,
             Self::UpdateClient(_) => None,
+            Self::ClientMisbehaviour(_) => None,
             Self::ConnectionOpenInit(event) => Some(event.counterparty_client_id),
             Self::ConnectionOpenTry(event) => Some(event.counterparty_client_id),
             Self::ConnectionOpenAck(event) => Some(event.counterparty_client_id),
@@ -56,6 +65,12 @@ impl FullEvent {
             Self::ChannelOpenConfirm(event) => Some(event.connection.counterparty_client_id),
             Self::ChannelCloseInit(_) => todo!(),
             Self::ChannelCloseConfirm(_) => todo!(),
+            Self::ChannelUpgradeInit(event) => Some(event.connection.counterparty_client_id),
+            Self::ChannelUpgradeTry(event) => Some(event.connection.counterparty_client_id),
+            Self::ChannelUpgradeAck(event) => Some(event.connection.counterparty_client_id),
+            Self::ChannelUpgradeConfirm(event) => Some(event.connection.counterparty_client_id),
+            Self::ChannelUpgradeOpen(event) => Some(event.connection.counterparty_client_id),
+            Self::ChannelUpgradeCancel(event) => Some(event.connection.counterparty_client_id),
             Self::PacketSend(event) => Some(event.packet.destination_channel.connection.client_id),
             Self::BatchSend(event) => Some(event.destination_channel.connection.client_id),
             Self::PacketRecv(event) => Some(event.packet.source_channel.connection.client_id),
@@ -72,6 +87,7 @@ impl FullEvent {
         match self {
             Self::CreateClient(_) => "create_client",
             Self::UpdateClient(_) => "update_client",
+            Self::ClientMisbehaviour(_) => "client_misbehaviour",
             Self::ConnectionOpenInit(_) => "connection_open_init",
             Self::ConnectionOpenTry(_) => "connection_open_try",
             Self::ConnectionOpenAck(_) => "connection_open_ack",
@@ -82,6 +98,12 @@ impl FullEvent {
             Self::ChannelOpenConfirm(_) => "channel_open_confirm",
             Self::ChannelCloseInit(_) => "channel_close_init",
             Self::ChannelCloseConfirm(_) => "channel_close_confirm",
+            Self::ChannelUpgradeInit(_) => "channel_upgrade_init",
+            Self::ChannelUpgradeTry(_) => "channel_upgrade_try",
+            Self::ChannelUpgradeAck(_) => "channel_upgrade_ack",
+            Self::ChannelUpgradeConfirm(_) => "channel_upgrade_confirm",
+            Self::ChannelUpgradeOpen(_) => "channel_upgrade_open",
+            Self::ChannelUpgradeCancel(_) => "channel_upgrade_cancel",
             Self::PacketSend(_) => "packet_send",
             Self::BatchSend(_) => "batch_send",
             Self::PacketRecv(_) => "packet_recv",
@@ -118,6 +140,19 @@ pub struct UpdateClient {
     pub height: u64,
 }
 
+/// Misbehaviour was submitted for a client, freezing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ClientMisbehaviour {
+    pub client_type: ClientType,
+    pub client_id: ClientId,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
@@ -186,6 +221,8 @@ pub struct ChannelOpenInit {
     pub counterparty_port_id: Bytes,
     pub connection: Connection,
     pub version: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordering: ChannelOrder,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -254,6 +291,118 @@ pub struct ChannelCloseInit {}
 )]
 pub struct ChannelCloseConfirm {}
 
+/// An upgrade of the channel to `version` was proposed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeInit {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
+/// The upgrade of the channel to `version` proposed by the counterparty was accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeTry {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
+/// The upgrade of the channel was accepted by the counterparty and `version` is now the version of
+/// the channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeAck {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
+/// The upgrade of the channel was applied on the counterparty and `version` is now the version
+/// of the channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeConfirm {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
+/// The upgrade of the channel has been applied on both ends and packets can be sent on
+/// `version` again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeOpen {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
+/// The upgrade of the channel to `version` was cancelled. The version of the channel is
+/// unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeCancel {
+    pub port_id: Bytes,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: Bytes,
+    pub counterparty_channel_id: ChannelId,
+    pub connection: Connection,
+    pub version: String,
+    pub upgrade_sequence: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
@@ -265,6 +414,13 @@ pub struct PacketSend {
     pub packet_data: Bytes,
 
     pub packet: PacketMetadata,
+
+    /// The sequence of the packet, if it was sent on an ordered channel.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub sequence: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,7 +11,7 @@ pub(crate) mod types;
 pub use voyager_primitives::{Duration, Timestamp};
 
 pub use crate::types::{
-    channel::{Channel, ChannelState},
+    channel::{Channel, ChannelOrder, ChannelState, ChannelUpgrade, ChannelUpgradeState},
     connection::{Connection, ConnectionState},
     packet::{MustBeZero, Packet},
     ChannelId, ClientId, ConnectionId, Status,
@@ -64,6 +64,11 @@ impl IbcSpec for IbcUnion {
 pub fn log_event(e: &FullEvent, chain_id: &voyager_primitives::ChainId) {
     use tracing::info;
 
+    use crate::event::{
+        ChannelUpgradeAck, ChannelUpgradeCancel, ChannelUpgradeConfirm, ChannelUpgradeInit,
+        ChannelUpgradeOpen, ChannelUpgradeTry,
+    };
+
     let event = e.name();
 
     match e {
@@ -82,6 +87,13 @@ pub fn log_event(e: &FullEvent, chain_id: &voyager_primitives::ChainId) {
             data.height = e.height,
             "event"
         ),
+        FullEvent::ClientMisbehaviour(e) => info!(
+            event,
+            %chain_id,
+            data.client_id = %e.client_id,
+            data.client_type = %e.client_type,
+            "event"
+        ),
         FullEvent::ConnectionOpenInit(e) => info!(
             event,
             %chain_id,
@@ -174,6 +186,74 @@ pub fn log_event(e: &FullEvent, chain_id: &voyager_primitives::ChainId) {
         ),
         FullEvent::ChannelCloseInit(_e) => info!(event, "event"),
         FullEvent::ChannelCloseConfirm(_e) => info!(event, "event"),
+        FullEvent::ChannelUpgradeInit(ChannelUpgradeInit {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        })
+        | FullEvent::ChannelUpgradeTry(ChannelUpgradeTry {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        })
+        | FullEvent::ChannelUpgradeAck(ChannelUpgradeAck {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        })
+        | FullEvent::ChannelUpgradeConfirm(ChannelUpgradeConfirm {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        })
+        | FullEvent::ChannelUpgradeOpen(ChannelUpgradeOpen {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        })
+        | FullEvent::ChannelUpgradeCancel(ChannelUpgradeCancel {
+            port_id,
+            channel_id,
+            counterparty_port_id,
+            counterparty_channel_id,
+            connection,
+            version,
+            upgrade_sequence,
+        }) => info!(
+            event,
+            %chain_id,
+            data.port_id = %port_id,
+            data.channel_id = %channel_id,
+            data.counterparty_port_id = %counterparty_port_id,
+            data.counterparty_channel_id = %counterparty_channel_id,
+            data.connection.state = ?connection.state,
+            data.connection.client_id = %connection.client_id,
+            data.connection.counterparty_client_id = %connection.counterparty_client_id,
+            data.connection.counterparty_connection_id = connection.counterparty_connection_id.map(|id|id.get()),
+            data.version = %version,
+            data.upgrade_sequence = %upgrade_sequence,
+            "event"
+        ),
         FullEvent::PacketSend(e) => info!(
             event,
             %chain_id,
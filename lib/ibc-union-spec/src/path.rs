@@ -7,7 +7,7 @@ use voyager_primitives::IbcStorePathKey;
 use crate::Packet;
 use crate::{
     types::{ChannelId, ClientId, ConnectionId},
-    Channel, Connection, IbcUnion,
+    Channel, ChannelUpgrade, Connection, IbcUnion,
 };
 
 pub const IBC_UNION_COSMWASM_COMMITMENT_PREFIX: [u8; 1] = [0x00];
@@ -26,6 +26,35 @@ pub const COMMITMENT_MAGIC_ACK: H256 = {
     H256::new(bz)
 };
 
+/// The commitment of a packet (or batch of packets) sent on an ordered channel.
+///
+/// This is [`COMMITMENT_MAGIC`] with the big endian sequence of the (first) packet in the last 8
+/// bytes, such that proving the commitment also proves the sequence of the packet.
+#[must_use]
+pub fn ordered_packet_commitment(sequence: u64) -> H256 {
+    let mut commitment = COMMITMENT_MAGIC;
+    commitment.get_mut()[24..].copy_from_slice(&sequence.to_be_bytes());
+    commitment
+}
+
+/// Extract the sequence from an ordered packet commitment, as created by
+/// [`ordered_packet_commitment`].
+///
+/// Returns `None` if the commitment is not an ordered packet commitment.
+#[must_use]
+pub fn ordered_packet_sequence(commitment: &H256) -> Option<u64> {
+    let mut masked = *commitment;
+    masked.get_mut()[24..].fill(0);
+
+    let sequence = u64::from_be_bytes(
+        commitment.get()[24..]
+            .try_into()
+            .expect("valid slice; qed;"),
+    );
+
+    (masked == COMMITMENT_MAGIC && sequence != 0).then_some(sequence)
+}
+
 pub const COMMITMENT_NULL: H256 = H256::new([0; 32]);
 
 pub const CLIENT_STATE: U256 = U256::from_limbs([0, 0, 0, 0]);
@@ -34,6 +63,8 @@ pub const CONNECTIONS: U256 = U256::from_limbs([2, 0, 0, 0]);
 pub const CHANNELS: U256 = U256::from_limbs([3, 0, 0, 0]);
 pub const PACKETS: U256 = U256::from_limbs([4, 0, 0, 0]);
 pub const PACKET_ACKS: U256 = U256::from_limbs([5, 0, 0, 0]);
+pub const CHANNEL_UPGRADES: U256 = U256::from_limbs([6, 0, 0, 0]);
+pub const CHANNEL_UPGRADE_ERRORS: U256 = U256::from_limbs([7, 0, 0, 0]);
 
 #[cfg(feature = "ethabi")]
 #[must_use]
@@ -60,6 +91,8 @@ pub enum StorePath {
     Channel(ChannelPath),
     BatchReceipts(BatchReceiptsPath),
     BatchPackets(BatchPacketsPath),
+    ChannelUpgrade(ChannelUpgradePath),
+    ChannelUpgradeError(ChannelUpgradeErrorPath),
 }
 
 impl StorePath {
@@ -72,6 +105,8 @@ impl StorePath {
             StorePath::Channel(path) => path.key(),
             StorePath::BatchReceipts(path) => path.key(),
             StorePath::BatchPackets(path) => path.key(),
+            StorePath::ChannelUpgrade(path) => path.key(),
+            StorePath::ChannelUpgradeError(path) => path.key(),
         }
     }
 }
@@ -190,6 +225,65 @@ impl IbcStorePathKey for ChannelPath {
     type Value = Channel;
 }
 
+/// The latest upgrade of a channel, if any.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradePath {
+    pub channel_id: ChannelId,
+}
+
+impl ChannelUpgradePath {
+    #[must_use]
+    pub fn key(&self) -> H256 {
+        Keccak256::new()
+            .chain_update(CHANNEL_UPGRADES.to_be_bytes())
+            .chain_update(U256::from(self.channel_id.get()).to_be_bytes())
+            .finalize()
+            .into()
+    }
+}
+
+impl IbcStorePathKey for ChannelUpgradePath {
+    type Spec = IbcUnion;
+
+    type Value = ChannelUpgrade;
+}
+
+/// The last upgrade of a channel that was cancelled by the end that proposed it. The counterparty
+/// proves this to cancel its side of the upgrade.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgradeErrorPath {
+    pub channel_id: ChannelId,
+}
+
+impl ChannelUpgradeErrorPath {
+    #[must_use]
+    pub fn key(&self) -> H256 {
+        Keccak256::new()
+            .chain_update(CHANNEL_UPGRADE_ERRORS.to_be_bytes())
+            .chain_update(U256::from(self.channel_id.get()).to_be_bytes())
+            .finalize()
+            .into()
+    }
+}
+
+impl IbcStorePathKey for ChannelUpgradeErrorPath {
+    type Spec = IbcUnion;
+
+    type Value = ChannelUpgrade;
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(
@@ -75,6 +75,123 @@ impl Borrow<u8> for &ChannelState {
     }
 }
 
+/// The delivery guarantee of the packets sent over a channel.
+///
+/// Packets on an [`Unordered`](ChannelOrder::Unordered) channel can be received in any order, and a
+/// timed out packet has no effect on the other packets of the channel. Packets on an
+/// [`Ordered`](ChannelOrder::Ordered) channel are assigned a sequence when they are sent, must be
+/// received in that sequence, and a timeout of any packet closes the channel.
+///
+/// Ordered channels are only implemented by the CosmWasm stack. The commitment of an ordered
+/// channel includes its ordering, which the Solidity and Move stacks don't, so the handshake of an
+/// ordered channel with a chain running either of them can't complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+#[repr(u8)]
+pub enum ChannelOrder {
+    #[default]
+    Unordered = 1,
+    Ordered = 2,
+}
+
+impl ChannelOrder {
+    #[must_use]
+    pub const fn is_ordered(&self) -> bool {
+        matches!(self, Self::Ordered)
+    }
+}
+
+impl Display for ChannelOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChannelOrder::Unordered => "unordered",
+            ChannelOrder::Ordered => "ordered",
+        })
+    }
+}
+
+impl TryFrom<u8> for ChannelOrder {
+    type Error = UnknownEnumVariant<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Unordered),
+            2 => Ok(Self::Ordered),
+            _ => Err(UnknownEnumVariant(value)),
+        }
+    }
+}
+
+/// An upgrade of the version of a channel, as stored under
+/// [`ChannelUpgradePath`](crate::path::ChannelUpgradePath).
+///
+/// An upgrade is proposed by one end of the channel ([`ChannelUpgradeState::Init`]) and accepted
+/// by the other end ([`ChannelUpgradeState::Try`]). The proposing end then applies it
+/// ([`ChannelUpgradeState::Ack`]) and keeps it around for the other end to prove, which then
+/// applies it as well. Finally, the proposing end proves the upgraded channel of the other end to
+/// complete the upgrade. The `sequence` is unique per upgrade of a channel, such that proofs of a
+/// previous upgrade can't be reused.
+///
+/// Packets can't be sent on a channel while it has an upgrade, and all the packets that were sent
+/// before must have been acknowledged or timed out before the upgrade is accepted or applied.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+pub struct ChannelUpgrade {
+    pub state: ChannelUpgradeState,
+    pub version: String,
+    pub sequence: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+#[repr(u8)]
+pub enum ChannelUpgradeState {
+    Init = 1,
+    Try = 2,
+    Ack = 3,
+}
+
+impl Display for ChannelUpgradeState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChannelUpgradeState::Init => "init",
+            ChannelUpgradeState::Try => "try",
+            ChannelUpgradeState::Ack => "ack",
+        })
+    }
+}
+
+impl TryFrom<u8> for ChannelUpgradeState {
+    type Error = UnknownEnumVariant<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Init),
+            2 => Ok(Self::Try),
+            3 => Ok(Self::Ack),
+            _ => Err(UnknownEnumVariant(value)),
+        }
+    }
+}
+
 #[cfg(feature = "ethabi")]
 pub mod ethabi {
     use std::borrow::Cow;
@@ -88,6 +205,32 @@ pub mod ethabi {
 
     type SolTuple = (Uint<8>, Uint<32>, Uint<32>, SolBytes, SolString);
 
+    impl Channel {
+        /// The preimage of the commitment of this channel, as stored under
+        /// [`ChannelPath`](crate::path::ChannelPath).
+        ///
+        /// Unordered channels commit to the abi encoding of the channel, which is what they have
+        /// always committed to. Ordered channels additionally commit to the ordering, such that
+        /// the handshake fails if both ends of the channel don't agree on it.
+        #[must_use]
+        pub fn commitment_preimage(&self, ordering: ChannelOrder) -> Vec<u8> {
+            match ordering {
+                ChannelOrder::Unordered => self.abi_encode(),
+                ChannelOrder::Ordered => (self.clone(), ordering as u8).abi_encode(),
+            }
+        }
+    }
+
+    impl ChannelUpgrade {
+        /// The preimage of the commitment of this upgrade, as stored under
+        /// [`ChannelUpgradePath`](crate::path::ChannelUpgradePath) (and
+        /// [`ChannelUpgradeErrorPath`](crate::path::ChannelUpgradeErrorPath) once cancelled).
+        #[must_use]
+        pub fn commitment_preimage(&self) -> Vec<u8> {
+            (self.state as u8, self.version.clone(), self.sequence).abi_encode_params()
+        }
+    }
+
     impl SolValue for Channel {
         type SolType = Self;
     }
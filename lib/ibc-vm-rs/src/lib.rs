@@ -108,6 +108,9 @@ pub enum IbcError {
 
     #[error("committed packet ({comm}) does not match the calculated one ({exp_comm})", comm = serde_utils::to_hex(.0), exp_comm= serde_utils::to_hex(.1))]
     PacketCommitmentMismatch(Vec<u8>, Vec<u8>),
+
+    #[error("packet sequence ({1}) does not match the next expected sequence ({0}) on an ordered channel")]
+    PacketSequenceMismatch(u64, u64),
 }
 
 pub trait IbcHost: Sized {
@@ -7,8 +7,8 @@ use unionlabs::{
         connection::{self, connection_end::ConnectionEnd},
     },
     ics24::{
-        AcknowledgementPath, ChannelEndPath, CommitmentPath, ConnectionPath, NextSequenceSendPath,
-        ReceiptPath,
+        AcknowledgementPath, ChannelEndPath, CommitmentPath, ConnectionPath, NextSequenceAckPath,
+        NextSequenceRecvPath, NextSequenceSendPath, Path, ReceiptPath,
     },
     id::{ChannelId, ClientId, ConnectionId, PortId},
 };
@@ -125,17 +125,40 @@ impl<T: IbcHost> Runnable<T> for RecvPacket {
                     return Err(IbcError::TimedOutPacket.into());
                 }
 
-                // TODO(aeryz): recv start sequence check for replay protection
+                let already_received = match channel.ordering {
+                    Order::Ordered => {
+                        let next_sequence_recv = read_sequence(
+                            host,
+                            NextSequenceRecvPath {
+                                port_id: packet.destination_port.clone(),
+                                channel_id: packet.destination_channel.clone(),
+                            },
+                        );
 
-                match host.read_raw(
-                    &ReceiptPath {
-                        port_id: packet.destination_port.clone(),
-                        channel_id: packet.destination_channel.clone(),
-                        sequence: packet.sequence,
+                        if u64::from(packet.sequence) > next_sequence_recv {
+                            return Err(IbcError::PacketSequenceMismatch(
+                                next_sequence_recv,
+                                packet.sequence.into(),
+                            )
+                            .into());
+                        }
+
+                        u64::from(packet.sequence) < next_sequence_recv
                     }
-                    .into(),
-                ) {
-                    Some(_) => Either::Right((
+                    _ => host
+                        .read_raw(
+                            &ReceiptPath {
+                                port_id: packet.destination_port.clone(),
+                                channel_id: packet.destination_channel.clone(),
+                                sequence: packet.sequence,
+                            }
+                            .into(),
+                        )
+                        .is_some(),
+                };
+
+                match already_received {
+                    true => Either::Right((
                         vec![IbcEvent::RecvPacket(ibc_events::RecvPacket {
                             packet_data_hex: packet.data,
                             packet_timeout_height: packet.timeout_height,
@@ -150,7 +173,7 @@ impl<T: IbcHost> Runnable<T> for RecvPacket {
                         })],
                         IbcVmResponse::Empty,
                     )),
-                    None => {
+                    false => {
                         // TODO(aeryz): known size can be optimized
                         let packet_commitment = packet_commitment(host, &packet);
 
@@ -208,15 +231,25 @@ impl<T: IbcHost> Runnable<T> for RecvPacket {
                 RecvPacket::CallbackCalled { packet, channel },
                 &[IbcResponse::OnRecvPacket { ack }],
             ) => {
-                host.commit_raw(
-                    ReceiptPath {
-                        port_id: packet.destination_port.clone(),
-                        channel_id: packet.destination_channel.clone(),
-                        sequence: packet.sequence,
-                    }
-                    .into(),
-                    vec![1],
-                )?;
+                match channel.ordering {
+                    Order::Ordered => host.commit_raw(
+                        NextSequenceRecvPath {
+                            port_id: packet.destination_port.clone(),
+                            channel_id: packet.destination_channel.clone(),
+                        }
+                        .into(),
+                        (u64::from(packet.sequence) + 1).to_be_bytes().to_vec(),
+                    )?,
+                    _ => host.commit_raw(
+                        ReceiptPath {
+                            port_id: packet.destination_port.clone(),
+                            channel_id: packet.destination_channel.clone(),
+                            sequence: packet.sequence,
+                        }
+                        .into(),
+                        vec![1],
+                    )?,
+                }
 
                 let mut events = vec![IbcEvent::RecvPacket(ibc_events::RecvPacket {
                     packet_data_hex: packet.data.clone(),
@@ -299,6 +332,7 @@ pub enum SendPacket {
     LatestHeightFetched {
         client_id: ClientId,
         connection_id: ConnectionId,
+        ordering: Order,
         source_port: PortId,
         source_channel: ChannelId,
         destination_port: PortId,
@@ -311,6 +345,7 @@ pub enum SendPacket {
     TimestampFetched {
         height: Height,
         connection_id: ConnectionId,
+        ordering: Order,
         source_port: PortId,
         source_channel: ChannelId,
         destination_port: PortId,
@@ -394,6 +429,7 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
                         destination_channel: channel.counterparty.channel_id.unwrap(),
                         data,
                         connection_id: channel.connection_hops[0].clone(),
+                        ordering: channel.ordering,
                     },
                     (
                         connection.client_id,
@@ -413,6 +449,7 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
                     destination_channel,
                     data,
                     connection_id,
+                    ordering,
                 },
                 &[IbcResponse::Status { status }, IbcResponse::LatestHeight { height }],
             ) => {
@@ -430,6 +467,7 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
                         destination_channel,
                         data,
                         connection_id,
+                        ordering,
                     },
                     (client_id, vec![IbcQuery::TimestampAtHeight(height)]).into(),
                 ))
@@ -445,6 +483,7 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
                     destination_channel,
                     data,
                     connection_id,
+                    ordering,
                 },
                 &[IbcResponse::TimestampAtHeight { timestamp }],
             ) => {
@@ -502,7 +541,7 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
                         packet_src_channel: packet.source_channel,
                         packet_dst_port: packet.destination_port,
                         packet_dst_channel: packet.destination_channel,
-                        packet_channel_ordering: Order::Unordered,
+                        packet_channel_ordering: ordering,
                         connection_id,
                     })],
                     IbcVmResponse::SendPacket {
@@ -516,6 +555,13 @@ impl<T: IbcHost> Runnable<T> for SendPacket {
     }
 }
 
+/// Reads a next sequence counter of an ordered channel, which starts at 1.
+fn read_sequence<T: IbcHost>(host: &T, path: impl Into<Path>) -> u64 {
+    host.read_raw(&path.into())
+        .and_then(|raw| raw.try_into().ok())
+        .map_or(1, u64::from_be_bytes)
+}
+
 fn packet_commitment<T: IbcHost>(host: &mut T, packet: &Packet) -> Vec<u8> {
     let mut packet_commitment = Vec::new();
     packet_commitment.extend_from_slice(packet.timeout_timestamp.to_be_bytes().as_slice());
@@ -538,11 +584,13 @@ pub enum Acknowledgement {
         packet: Packet,
         ack: Vec<u8>,
         connection_id: ConnectionId,
+        ordering: Order,
     },
 
     CallbackCalled {
         packet: Packet,
         connection_id: ConnectionId,
+        ordering: Order,
     },
 }
 
@@ -637,7 +685,7 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                             packet_src_channel: packet.source_channel,
                             packet_dst_port: packet.destination_port,
                             packet_dst_channel: packet.destination_channel,
-                            packet_channel_ordering: Order::Unordered,
+                            packet_channel_ordering: channel.ordering,
                             connection_id: channel.connection_hops[0].clone(),
                         })],
                         IbcVmResponse::Empty,
@@ -652,11 +700,30 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                     );
                 }
 
+                if channel.ordering == Order::Ordered {
+                    let next_sequence_ack = read_sequence(
+                        host,
+                        NextSequenceAckPath {
+                            port_id: packet.source_port.clone(),
+                            channel_id: packet.source_channel.clone(),
+                        },
+                    );
+
+                    if u64::from(packet.sequence) != next_sequence_ack {
+                        return Err(IbcError::PacketSequenceMismatch(
+                            next_sequence_ack,
+                            packet.sequence.into(),
+                        )
+                        .into());
+                    }
+                }
+
                 Either::Left((
                     Acknowledgement::MembershipVerified {
                         packet: packet.clone(),
                         connection_id: channel.connection_hops[0].clone(),
                         ack: ack.clone(),
+                        ordering: channel.ordering,
                     },
                     (
                         connection.client_id,
@@ -687,6 +754,7 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                     packet,
                     ack,
                     connection_id,
+                    ordering,
                 },
                 &[IbcResponse::VerifyMembership { valid }],
             ) => {
@@ -698,6 +766,7 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                     Acknowledgement::CallbackCalled {
                         packet: packet.clone(),
                         connection_id,
+                        ordering,
                     },
                     IbcMsg::OnAcknowledgePacket { packet, ack }.into(),
                 ))
@@ -706,6 +775,7 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                 Acknowledgement::CallbackCalled {
                     packet,
                     connection_id,
+                    ordering,
                 },
                 &[IbcResponse::OnAcknowledgePacket { err }],
             ) => {
@@ -722,6 +792,17 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                     .into(),
                 )?;
 
+                if ordering == Order::Ordered {
+                    host.commit_raw(
+                        NextSequenceAckPath {
+                            port_id: packet.source_port.clone(),
+                            channel_id: packet.source_channel.clone(),
+                        }
+                        .into(),
+                        (u64::from(packet.sequence) + 1).to_be_bytes().to_vec(),
+                    )?;
+                }
+
                 Either::Right((
                     vec![IbcEvent::AcknowledgePacket(ibc_events::AcknowledgePacket {
                         packet_timeout_height: packet.timeout_height,
@@ -731,7 +812,7 @@ impl<T: IbcHost> Runnable<T> for Acknowledgement {
                         packet_src_channel: packet.source_channel,
                         packet_dst_port: packet.destination_port,
                         packet_dst_channel: packet.destination_channel,
-                        packet_channel_ordering: Order::Unordered,
+                        packet_channel_ordering: ordering,
                         connection_id,
                     })],
                     IbcVmResponse::Empty,
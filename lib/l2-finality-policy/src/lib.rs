@@ -0,0 +1,80 @@
+/// The L1 slot time, in seconds.
+///
+/// Some settlement contracts (such as the OP stack `FaultDisputeGame`) only record the L1
+/// *timestamp* at which a state commitment was posted. This is used to express the elapsed L1 time
+/// as a number of L1 blocks. Ethereum produces at most one block per slot, so this slightly
+/// overestimates the number of blocks if slots were missed.
+pub const L1_SECONDS_PER_BLOCK: u64 = 12;
+
+/// The policy used to decide which L2 state is considered final.
+///
+/// This is stored in the client state of the light clients tracking L2s that settle on an L1, and
+/// configured on the corresponding finality modules. L2 state can only ever be *verified* once the
+/// state commitment has been posted to the L1; the policy determines how much further the dispute
+/// state of that commitment must have progressed before the light client accepts it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case", deny_unknown_fields)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub enum FinalityPolicy {
+    /// The state commitment must be finalized on the L1, i.e. the dispute game must have resolved
+    /// in favour of the defender (OP stack) or the node must have been confirmed (arbitrum).
+    L1Finalized,
+    /// The state commitment must be included on the L1, and must have been unchallenged for at
+    /// least `unchallenged_blocks` L1 blocks.
+    L1Included { unchallenged_blocks: u64 },
+    /// Sequencer soft-confirmations are considered final.
+    ///
+    /// The finality module reports the latest block produced by the sequencer. The light client
+    /// still requires the state commitment to be included on the L1, but does not check its dispute
+    /// state.
+    SequencerSoftConfirmation,
+}
+
+impl Default for FinalityPolicy {
+    /// Accept any unchallenged state commitment as soon as it is included on the L1.
+    fn default() -> Self {
+        Self::L1Included {
+            unchallenged_blocks: 0,
+        }
+    }
+}
+
+impl FinalityPolicy {
+    /// Whether a state commitment that has been included on the L1 for `age` L1 blocks is old
+    /// enough to satisfy this policy.
+    ///
+    /// Note that this does not check the dispute state of the commitment.
+    #[must_use]
+    pub fn is_old_enough(&self, age: u64) -> bool {
+        match self {
+            Self::L1Included {
+                unchallenged_blocks,
+            } => age >= *unchallenged_blocks,
+            Self::L1Finalized | Self::SequencerSoftConfirmation => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_old_enough() {
+        let policy = FinalityPolicy::L1Included {
+            unchallenged_blocks: 10,
+        };
+
+        assert!(!policy.is_old_enough(9));
+        assert!(policy.is_old_enough(10));
+        assert!(policy.is_old_enough(11));
+
+        assert!(FinalityPolicy::default().is_old_enough(0));
+        assert!(FinalityPolicy::L1Finalized.is_old_enough(0));
+        assert!(FinalityPolicy::SequencerSoftConfirmation.is_old_enough(0));
+    }
+}
@@ -1,19 +1,30 @@
-use unionlabs::{
-    ibc::core::client::height::Height,
-    primitives::{H160, U256},
-};
+use ibc_union_spec::ClientId;
+use unionlabs::primitives::{H160, U256};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ClientState {
-    pub chain_id: u64,
-    // TODO: This should be ClientId
-    pub l1_client_id: String,
-    pub l1_latest_height: Height,
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub enum ClientState {
+    V1(ClientStateV1),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ClientStateV1 {
+    pub chain_id: U256,
+    /// Latest height of the L2
+    pub latest_height: u64,
+    /// Client id of the client tracking the L1 that the chain this client tracks settles on
+    pub l1_client_id: ClientId,
+    /// Address of the `LineaRollup` contract on the L1.
     pub l1_rollup_contract_address: H160,
-    pub l1_rollup_current_l2_timestamp_slot: U256,
+    /// Slot of `currentL2BlockNumber` within the rollup contract.
     pub l1_rollup_current_l2_block_number_slot: U256,
+    /// Slot of `currentTimestamp` within the rollup contract.
+    pub l1_rollup_current_l2_timestamp_slot: U256,
+    /// Slot of the `stateRootHashes` mapping within the rollup contract.
     pub l1_rollup_l2_state_root_hashes_slot: U256,
     pub l2_ibc_contract_address: H160,
-    pub frozen_height: Height,
+    pub frozen_height: u64,
 }
@@ -2,4 +2,8 @@ pub mod client_state;
 pub mod consensus_state;
 pub mod header;
 
-pub use crate::{client_state::ClientState, consensus_state::ConsensusState, header::Header};
+pub use crate::{
+    client_state::{ClientState, ClientStateV1},
+    consensus_state::ConsensusState,
+    header::Header,
+};
@@ -1,3 +1,4 @@
+use ibc_union_spec::ClientId;
 use unionlabs::{
     aptos::account::AccountAddress, ibc::core::client::height::Height, primitives::H160,
 };
@@ -7,7 +8,7 @@ use unionlabs::{
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct ClientState {
     pub chain_id: String,
-    pub l1_client_id: u32,
+    pub l1_client_id: ClientId,
     pub l1_contract_address: H160,
     pub l2_contract_address: AccountAddress,
     pub table_handle: AccountAddress,
@@ -1,5 +1,9 @@
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
+pub mod misbehaviour;
 
-pub use crate::{client_state::ClientState, consensus_state::ConsensusState, header::Header};
+pub use crate::{
+    client_state::ClientState, consensus_state::ConsensusState, header::Header,
+    misbehaviour::Misbehaviour,
+};
@@ -0,0 +1,11 @@
+use crate::header::Header;
+
+/// Evidence of a movement chain misbehaving: two headers that are each proven against the L1
+/// settlement contract, but commit to different state roots at the same L2 height.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Misbehaviour {
+    pub header_a: Header,
+    pub header_b: Header,
+}
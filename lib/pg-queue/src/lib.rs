@@ -7,6 +7,7 @@ use std::{
     future::Future,
     hash::Hash,
     marker::PhantomData,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -22,7 +23,9 @@ use sqlx::{
 use tracing::{debug, debug_span, error, info, info_span, instrument, trace, warn, Instrument};
 use voyager_vm::{
     filter::{FilterResult, Interest, InterestFilter},
+    history::{History, HistoryItem, ItemStatus},
     pass::{Pass, PassResult},
+    schedule::{ScheduleState, Trigger},
     BoxDynError, Captures, EnqueueResult, ItemId, Op, QueueError, QueueMessage,
 };
 
@@ -48,10 +51,13 @@ pub struct PgQueue<T> {
     retryable_error_expo_backoff_multiplier: f64,
 
     metrics: Metrics,
+    lane_depth_reported_at: Arc<Mutex<Option<Instant>>>,
 
     __marker: PhantomData<fn() -> T>,
 }
 
+const LANE_DEPTH_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PgQueueConfig {
@@ -114,16 +120,38 @@ struct QueueRecord {
     item: String,
     created_at: time::OffsetDateTime,
     attempt: i64,
+    schedule: Option<String>,
+    lane: String,
 }
 
 #[derive(Debug, FromRow)]
 struct OptimizeRecord {
     id: i64,
-    #[allow(dead_code)]
     parents: Vec<i64>,
     item: String,
-    #[allow(dead_code)]
     created_at: time::OffsetDateTime,
+    schedule: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct ScheduleRecord {
+    name: String,
+    trigger: Json<Trigger>,
+    next_run_at: i64,
+    last_run_at: Option<i64>,
+    runs: i64,
+    skipped: i64,
+    in_flight: i64,
+}
+
+#[derive(Debug, FromRow)]
+struct HistoryRecord {
+    id: i64,
+    parents: Vec<i64>,
+    item: String,
+    status: String,
+    tag: Option<String>,
+    message: Option<String>,
 }
 
 #[derive(Debug, FromRow, Serialize)]
@@ -141,18 +169,9 @@ impl<T: QueueMessage> PgQueue<T> {
         &self,
         page: i64,
         per_page: i64,
-        mut item_filters: Vec<String>,
-        mut message_filters: Vec<String>,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
     ) -> Result<Vec<FailedRecord<T>>, sqlx::Error> {
-        // default to all-inclusive filter if none are provided
-        if item_filters.is_empty() {
-            item_filters.push("%".to_owned())
-        }
-
-        if message_filters.is_empty() {
-            message_filters.push("%".to_owned())
-        }
-
         sqlx::query(
             r#"
             SELECT
@@ -173,8 +192,8 @@ impl<T: QueueMessage> PgQueue<T> {
                 $4
             "#,
         )
-        .bind(item_filters)
-        .bind(message_filters)
+        .bind(default_filters(item_filters))
+        .bind(default_filters(message_filters))
         .bind(per_page)
         .bind((page - 1) * per_page)
         .map(|row| FailedRecord::<T>::from_row(&row))
@@ -184,6 +203,98 @@ impl<T: QueueMessage> PgQueue<T> {
         .collect()
     }
 
+    /// Count all failed items matching `item_filters` and `message_filters`, using the same
+    /// filter semantics as [`Self::query_failed`].
+    pub async fn count_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT
+                count(*)
+            FROM
+                failed
+            WHERE
+                item::TEXT LIKE ANY($1)
+                AND message LIKE ANY($2)
+            "#,
+        )
+        .bind(default_filters(item_filters))
+        .bind(default_filters(message_filters))
+        .fetch_one(&self.client)
+        .await
+    }
+
+    /// Move all failed items matching `item_filters` and `message_filters` back into the queue.
+    ///
+    /// The items keep their original id and parents, and are ready to be processed immediately.
+    /// Returns the amount of items that were requeued.
+    pub async fn requeue_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<u64, sqlx::Error> {
+        let res = sqlx::query(
+            r#"
+            WITH requeued AS (
+                DELETE FROM
+                    failed
+                WHERE
+                    item::TEXT LIKE ANY($1)
+                    AND message LIKE ANY($2)
+                RETURNING
+                    id,
+                    item,
+                    parents,
+                    created_at,
+                    lane
+            )
+            INSERT INTO
+            queue  (id, item, parents, created_at, lane)
+            SELECT  id, item, parents, created_at, lane FROM requeued
+            "#,
+        )
+        .bind(default_filters(item_filters))
+        .bind(default_filters(message_filters))
+        .execute(&self.client)
+        .await?;
+
+        info!(count = res.rows_affected(), "requeued failed items");
+
+        Ok(res.rows_affected())
+    }
+
+    /// Permanently delete all failed items matching `item_filters` and `message_filters`. Returns
+    /// the amount of items that were discarded.
+    ///
+    /// Note that if both `item_filters` and `message_filters` are empty, this will discard *all*
+    /// failed items.
+    pub async fn discard_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<u64, sqlx::Error> {
+        let res = sqlx::query(
+            r#"
+            DELETE FROM
+                failed
+            WHERE
+                item::TEXT LIKE ANY($1)
+                AND message LIKE ANY($2)
+            "#,
+        )
+        .bind(default_filters(item_filters))
+        .bind(default_filters(message_filters))
+        .execute(&self.client)
+        .await?;
+
+        info!(count = res.rows_affected(), "discarded failed items");
+
+        Ok(res.rows_affected())
+    }
+
     pub async fn query_failed_by_id(
         &self,
         id: i64,
@@ -208,8 +319,110 @@ impl<T: QueueMessage> PgQueue<T> {
         .transpose()
     }
 
+    async fn query_history(
+        &self,
+        id: ItemId,
+        max_depth: u32,
+    ) -> Result<Option<History<T>>, sqlx::Error> {
+        // items is inlined into each of it's references so that lookups by id can use the primary
+        // key indexes of the underlying tables
+        let records = sqlx::query(
+            r#"
+            WITH RECURSIVE
+              items AS NOT MATERIALIZED (
+                SELECT id, parents, item, 'queued' AS status, NULL AS tag, NULL AS message FROM queue
+                UNION ALL
+                SELECT id, parents, item, 'optimize', tag, NULL FROM optimize
+                UNION ALL
+                SELECT id, parents, item, 'done', NULL, NULL FROM done
+                UNION ALL
+                SELECT id, parents, item, 'failed', NULL, message FROM failed
+              ),
+              ancestors (id, depth) AS (
+                SELECT $1::BIGINT, 0
+                UNION
+                SELECT
+                  parent, a.depth + 1
+                FROM
+                  ancestors a
+                  JOIN items i ON i.id = a.id
+                  CROSS JOIN LATERAL UNNEST(i.parents) AS parent
+                WHERE
+                  a.depth < $2
+              ),
+              descendants (id, depth) AS (
+                SELECT $1::BIGINT, 0
+                UNION
+                SELECT
+                  i.id, d.depth + 1
+                FROM
+                  descendants d
+                  JOIN items i ON d.id = ANY(i.parents)
+                WHERE
+                  d.depth < $2
+              )
+            SELECT
+              id,
+              parents,
+              item::TEXT,
+              status,
+              tag::TEXT,
+              message::TEXT
+            FROM
+              items
+            WHERE
+              id IN (
+                SELECT id FROM ancestors
+                UNION
+                SELECT id FROM descendants
+              )
+            ORDER BY
+              id ASC
+            "#,
+        )
+        .bind(id.raw())
+        .bind(i32::try_from(max_depth).unwrap_or(i32::MAX))
+        .try_map(|row| HistoryRecord::from_row(&row))
+        .fetch_all(&self.client)
+        .await?;
+
+        if !records.iter().any(|record| record.id == id.raw()) {
+            return Ok(None);
+        }
+
+        let items = records
+            .into_iter()
+            .map(|record| {
+                let status = match &*record.status {
+                    "queued" => ItemStatus::Queued,
+                    "optimize" => ItemStatus::Optimize {
+                        tag: record.tag.unwrap_or_default(),
+                    },
+                    "done" => ItemStatus::Done,
+                    "failed" => ItemStatus::Failed {
+                        message: record.message.unwrap_or_default(),
+                    },
+                    status => unreachable!("unknown status `{status}`"),
+                };
+
+                Ok(HistoryItem {
+                    id: item_id(record.id)?,
+                    parents: record
+                        .parents
+                        .into_iter()
+                        .map(item_id)
+                        .collect::<Result<_, _>>()?,
+                    status,
+                    item: de(&record.item).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                })
+            })
+            .collect::<Result<_, sqlx::Error>>()?;
+
+        Ok(Some(History { id, items }))
+    }
+
     pub async fn stats(&self) -> Result<Stats, sqlx::Error> {
-        sqlx::query(
+        let stats = sqlx::query(
             r#"
             SELECT
               (
@@ -243,12 +456,125 @@ impl<T: QueueMessage> PgQueue<T> {
                   )
                 FROM
                   t
-              ) optimize
+              ) optimize,
+              (
+                WITH t AS (
+                  SELECT
+                    lane,
+                    count(*)
+                  FROM
+                    queue
+                  WHERE
+                    handle_at < now()
+                  GROUP BY
+                    lane
+                )
+                SELECT
+                  coalesce(
+                    json_object_agg(lane, count),
+                    '{}'::json
+                  )
+                FROM
+                  t
+              ) lanes
             "#,
         )
         .try_map(|row| Stats::from_row(&row))
         .fetch_one(&self.client)
-        .await
+        .await?;
+
+        Ok(Stats {
+            schedules: self.query_schedules().await?,
+            ..stats
+        })
+    }
+
+    async fn query_schedules(&self) -> Result<Vec<ScheduleState>, sqlx::Error> {
+        sqlx::query(
+            r#"
+            SELECT
+              s.name,
+              s.trigger,
+              s.next_run_at,
+              s.last_run_at,
+              s.runs,
+              s.skipped,
+              (
+                (SELECT count(*) FROM queue q WHERE q.schedule = s.name)
+                + (SELECT count(*) FROM optimize o WHERE o.schedule = s.name)
+              ) AS in_flight
+            FROM
+              schedule s
+            ORDER BY
+              s.name ASC
+            "#,
+        )
+        .try_map(|row| ScheduleRecord::from_row(&row))
+        .fetch_all(&self.client)
+        .await?
+        .into_iter()
+        .map(|record| {
+            let unsigned = |n: i64| u64::try_from(n).map_err(|e| sqlx::Error::Decode(Box::new(e)));
+
+            Ok(ScheduleState {
+                name: record.name,
+                trigger: record.trigger.0,
+                next_run_at: unsigned(record.next_run_at)?,
+                last_run_at: record.last_run_at.map(unsigned).transpose()?,
+                runs: unsigned(record.runs)?,
+                skipped: unsigned(record.skipped)?,
+                in_flight: unsigned(record.in_flight)?,
+            })
+        })
+        .collect()
+    }
+
+    /// Record the amount of ready items in each lane in [`Metrics::lane_depth`]. This is called on
+    /// every [`process`](voyager_vm::Queue::process), but only queries the database at most once
+    /// every [`LANE_DEPTH_REPORT_INTERVAL`].
+    async fn report_lane_depths(&self) {
+        let now = Instant::now();
+
+        {
+            let mut last_reported = self.lane_depth_reported_at.lock().expect("poisoned");
+
+            if last_reported
+                .is_some_and(|last| now.duration_since(last) < LANE_DEPTH_REPORT_INTERVAL)
+            {
+                return;
+            }
+
+            *last_reported = Some(now);
+        }
+
+        let res = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT
+              lane,
+              count(*)
+            FROM
+              queue
+            WHERE
+              handle_at < now()
+            GROUP BY
+              lane
+            "#,
+        )
+        .fetch_all(&self.client)
+        .await;
+
+        match res {
+            Ok(depths) => {
+                for (lane, depth) in depths {
+                    self.metrics
+                        .lane_depth
+                        .record(depth.unsigned_abs(), &[KeyValue::new("lane", lane)]);
+                }
+            }
+            Err(error) => {
+                warn!(%error, "error querying lane depths");
+            }
+        }
     }
 
     pub async fn truncate(&self, tables: Tables) -> Result<(), sqlx::Error> {
@@ -309,6 +635,10 @@ pub struct Stats {
     pub total: i64,
     pub ready: i64,
     pub optimize: Json<BTreeMap<String, u64>>,
+    /// The amount of ready items in each lane.
+    pub lanes: Json<BTreeMap<String, u64>>,
+    #[sqlx(skip)]
+    pub schedules: Vec<ScheduleState>,
 }
 
 pub struct Tables {
@@ -352,7 +682,9 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                 parents BIGINT[] DEFAULT '{}',
                 created_at timestamptz NOT NULL DEFAULT now(),
                 handle_at timestamptz NOT NULL DEFAULT now(),
-                attempt INT8 NOT NULL DEFAULT 0
+                attempt INT8 NOT NULL DEFAULT 0,
+                schedule TEXT,
+                lane TEXT NOT NULL DEFAULT 'default'
               );
 
             CREATE TABLE IF NOT EXISTS
@@ -362,7 +694,8 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                 item JSONB NOT NULL,
                 tag text NOT NULL,
                 parents BIGINT[] DEFAULT '{}',
-                created_at timestamptz NOT NULL DEFAULT now()
+                created_at timestamptz NOT NULL DEFAULT now(),
+                schedule TEXT
               );
 
             -- done is unlogged, since we only need "mostly available" data for retroactive debugging etc
@@ -382,7 +715,8 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                 item JSONB NOT NULL,
                 parents BIGINT[] DEFAULT '{}',
                 message TEXT,
-                created_at timestamptz NOT NULL DEFAULT now()
+                created_at timestamptz NOT NULL DEFAULT now(),
+                lane TEXT NOT NULL DEFAULT 'default'
               );
 
             CREATE INDEX IF NOT EXISTS index_queue_id ON queue (id);
@@ -392,6 +726,34 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
             CREATE INDEX IF NOT EXISTS index_queue_handle_at ON queue(handle_at DESC) INCLUDE (id);
 
             CREATE INDEX IF NOT EXISTS optimize_tag_id_idx ON optimize(tag, id);
+
+            CREATE TABLE IF NOT EXISTS
+              schedule (
+                name TEXT PRIMARY KEY,
+                -- the trigger that next_run_at was computed with
+                trigger JSONB NOT NULL,
+                -- unix timestamps, in seconds
+                next_run_at INT8 NOT NULL,
+                last_run_at INT8,
+                runs INT8 NOT NULL DEFAULT 0,
+                skipped INT8 NOT NULL DEFAULT 0
+              );
+
+            -- the schedule column was added after the initial schema
+            ALTER TABLE queue ADD COLUMN IF NOT EXISTS schedule TEXT;
+
+            ALTER TABLE optimize ADD COLUMN IF NOT EXISTS schedule TEXT;
+
+            CREATE INDEX IF NOT EXISTS index_queue_schedule ON queue (schedule) WHERE schedule IS NOT NULL;
+
+            CREATE INDEX IF NOT EXISTS index_optimize_schedule ON optimize (schedule) WHERE schedule IS NOT NULL;
+
+            -- the lane column was added after the initial schema
+            ALTER TABLE queue ADD COLUMN IF NOT EXISTS lane TEXT NOT NULL DEFAULT 'default';
+
+            ALTER TABLE failed ADD COLUMN IF NOT EXISTS lane TEXT NOT NULL DEFAULT 'default';
+
+            CREATE INDEX IF NOT EXISTS index_queue_lane_handle_at ON queue (lane, handle_at ASC) INCLUDE (id);
             "#,
         )
         .try_for_each(|result| async move {
@@ -407,6 +769,7 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
             retryable_error_expo_backoff_max,
             retryable_error_expo_backoff_multiplier,
             metrics: Metrics::new(),
+            lane_depth_reported_at: Arc::new(Mutex::new(None)),
             __marker: PhantomData,
         };
 
@@ -430,71 +793,13 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
     ) -> Result<EnqueueResult, Self::Error> {
         trace!("enqueue");
 
-        let (optimize, ready): (Vec<_>, Vec<_>) =
-            op.normalize()
-                .into_iter()
-                .partition_map(|op| match filter.check_interest(&op) {
-                    FilterResult::Interest(interest) => Either::Left((op, interest)),
-                    FilterResult::NoInterest => Either::Right(op),
-                });
-
         let mut tx = self.client.begin().await?;
 
-        let ready_ids = sqlx::query(
-            "
-            INSERT INTO queue (item)
-            SELECT * FROM UNNEST($1::JSONB[])
-            RETURNING id
-            ",
-        )
-        .bind(ready.into_iter().map(Json).collect::<Vec<_>>())
-        .try_map(|x| Id::from_row(&x))
-        .fetch_all(tx.as_mut())
-        .await?;
-
-        for ready in &ready_ids {
-            debug!(id = ready.id, "enqueued ready item");
-        }
-
-        let optimize_further_ids = sqlx::query(
-            "
-            INSERT INTO optimize (item, tag)
-            SELECT * FROM UNNEST($1::JSONB[], $2::TEXT[])
-            RETURNING id
-            ",
-        )
-        .bind(
-            optimize
-                .iter()
-                .map(|x| Json(x.0.clone()))
-                .collect::<Vec<_>>(),
-        )
-        .bind(
-            optimize
-                .iter()
-                .flat_map(|x| x.1.tags.clone())
-                .collect::<Vec<_>>(),
-        )
-        .try_map(|x| Id::from_row(&x))
-        .fetch_all(tx.as_mut())
-        .await?;
-
-        for ready in &optimize_further_ids {
-            debug!(id = ready.id, "enqueued optimize item");
-        }
+        let res = insert_new(&mut tx, op, filter, None).await?;
 
         tx.commit().await?;
 
-        Ok(EnqueueResult {
-            queue: ready_ids
-                .into_iter()
-                .map(|id| ItemId::new(id.id).expect("invalid id returned from database"))
-                .collect(),
-            optimize: optimize_further_ids
-                .into_iter()
-                .map(|id| ItemId::new(id.id).expect("invalid id returned from database"))
-                .collect(),
-        })
+        Ok(res)
     }
 
     #[instrument(skip_all)]
@@ -511,36 +816,25 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
     {
         trace!("process");
 
+        self.report_lane_depths().await;
+
         let mut tx = self.client.begin().await?;
 
-        let row = sqlx::query(
-            r#"
-            DELETE FROM
-              queue
-            WHERE
-              id = (
-                SELECT
-                  id
-                FROM
-                  queue
-                WHERE
-                  handle_at < now()
-                ORDER BY
-                  handle_at ASC
-                FOR UPDATE
-                  SKIP LOCKED
-                LIMIT 1)
-            RETURNING
-              id,
-              parents,
-              item::text,
-              attempt,
-              created_at
-            "#,
-        )
-        .try_map(|x| QueueRecord::from_row(&x))
-        .fetch_optional(tx.as_mut())
-        .await?;
+        let mut row = None;
+
+        // check each lane in order, falling back to any ready item (i.e. one in a lane that is not
+        // configured)
+        for lane in filter.lane_order().into_iter().map(Some).chain([None]) {
+            row = claim_next(&mut tx, lane).await?;
+
+            if row.is_some() {
+                if let Some(lane) = lane {
+                    filter.lane_served(lane);
+                }
+
+                break;
+            }
+        }
 
         let res = match row {
             Some(record) => {
@@ -599,7 +893,8 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
               id,
               parents,
               item::text,
-              created_at
+              created_at,
+              schedule
             "#,
         )
         .bind(tag)
@@ -615,6 +910,26 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
             return Ok(());
         }
 
+        // keep the optimized items around so that the lineage of the new items can be traced
+        for msg in &msgs {
+            sqlx::query(
+                "
+                INSERT INTO
+                done   (id, parents, item,      created_at)
+                VALUES ($1, $2,      $3::JSONB, $4        )
+                ",
+            )
+            .bind(msg.id)
+            .bind(&msg.parents)
+            .bind(&msg.item)
+            .bind(msg.created_at)
+            .execute(tx.as_mut())
+            .await
+            .map_err(Either::Left)?;
+        }
+
+        let schedules = msgs.iter().map(|r| r.schedule.clone()).collect::<Vec<_>>();
+
         let (ids, msgs) = msgs
             .into_iter()
             .map(|r| {
@@ -666,21 +981,30 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                 .collect::<Vec<_>>()
         };
 
+        // new items are attributed to the schedule of their first parent
+        let get_schedule = |parent_idxs: &[usize]| {
+            parent_idxs
+                .iter()
+                .min()
+                .and_then(|&idx| schedules[idx].clone())
+        };
+
         for (parent_idxs, new_msg, tag) in optimize_further {
             let parents = get_parent_ids(&parent_idxs);
             trace!(parent_idxs = ?&parent_idxs, parents = ?&parents);
 
             let new_row = sqlx::query(
                 "
-                INSERT INTO optimize (item, parents, tag)
+                INSERT INTO optimize (item, parents, tag, schedule)
                 VALUES
-                    ($1::JSONB, $2, $3)
+                    ($1::JSONB, $2, $3, $4)
                 RETURNING id
                 ",
             )
             .bind(Json(new_msg))
             .bind(&parents)
             .bind(tag)
+            .bind(get_schedule(&parent_idxs))
             .try_map(|row| Id::from_row(&row))
             .fetch_one(tx.as_mut())
             .await
@@ -695,6 +1019,7 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
             let normalized_ops = op.normalize();
 
             let parents = get_parent_ids(&parent_idxs);
+            let schedule = get_schedule(&parent_idxs);
             trace!(parent_idxs = ?&parent_idxs, parents = ?&parents);
 
             'block: for op in normalized_ops {
@@ -703,15 +1028,16 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                         for tag in tags {
                             let new_row = sqlx::query(
                                 "
-                                INSERT INTO optimize (item, parents, tag)
+                                INSERT INTO optimize (item, parents, tag, schedule)
                                 VALUES
-                                    ($1::JSONB, $2, $3)
+                                    ($1::JSONB, $2, $3, $4)
                                 RETURNING id
                                 ",
                             )
                             .bind(Json(op.clone()))
                             .bind(&parents)
                             .bind(tag)
+                            .bind(&schedule)
                             .try_map(|row| Id::from_row(&row))
                             .fetch_one(tx.as_mut())
                             .await
@@ -727,21 +1053,25 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
                     FilterResult::NoInterest => {}
                 }
 
-                ready_insert_into_queue.push((parents.clone(), op));
+                ready_insert_into_queue.push((parents.clone(), schedule.clone(), op));
             }
         }
 
-        for (parents, op) in ready_insert_into_queue {
+        for (parents, schedule, op) in ready_insert_into_queue {
+            let lane = filter.lane(&op);
+
             let ready_ids = sqlx::query(
                 "
-                INSERT INTO queue (item, parents)
+                INSERT INTO queue (item, parents, schedule, lane)
                 VALUES
-                    ($2::JSONB, $1)
+                    ($2::JSONB, $1, $3, $4)
                 RETURNING id
                 ",
             )
             .bind(parents)
             .bind(Json(op))
+            .bind(schedule)
+            .bind(lane)
             .try_map(|x| Id::from_row(&x))
             .fetch_all(tx.as_mut())
             .await
@@ -756,6 +1086,193 @@ impl<T: QueueMessage> voyager_vm::Queue<T> for PgQueue<T> {
 
         Ok(())
     }
+
+    async fn history(&self, id: ItemId, max_depth: u32) -> Result<Option<History<T>>, Self::Error> {
+        self.query_history(id, max_depth).await
+    }
+
+    #[instrument(skip_all, fields(%name))]
+    async fn enqueue_scheduled<'a, Filter: InterestFilter<T>>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        op: Op<T>,
+        filter: &'a Filter,
+        next_run_at: u64,
+    ) -> Result<EnqueueResult, Self::Error> {
+        trace!("enqueue scheduled");
+
+        let mut tx = self.client.begin().await?;
+
+        let res = insert_new(&mut tx, op, filter, Some(name)).await?;
+
+        update_schedule(&mut tx, name, trigger, next_run_at, false).await?;
+
+        tx.commit().await?;
+
+        Ok(res)
+    }
+
+    #[instrument(skip_all, fields(%name))]
+    async fn skip_scheduled<'a>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        next_run_at: u64,
+    ) -> Result<(), Self::Error> {
+        let mut tx = self.client.begin().await?;
+
+        update_schedule(&mut tx, name, trigger, next_run_at, true).await?;
+
+        tx.commit().await
+    }
+
+    async fn schedules(&self) -> Result<Vec<ScheduleState>, Self::Error> {
+        self.query_schedules().await
+    }
+}
+
+/// Take the oldest ready item out of the queue, only considering items in `lane` if provided.
+async fn claim_next(
+    tx: &mut Transaction<'static, Postgres>,
+    lane: Option<&str>,
+) -> Result<Option<QueueRecord>, sqlx::Error> {
+    let query = match lane {
+        Some(lane) => sqlx::query(
+            r#"
+            DELETE FROM
+              queue
+            WHERE
+              id = (
+                SELECT
+                  id
+                FROM
+                  queue
+                WHERE
+                  lane = $1
+                  AND handle_at < now()
+                ORDER BY
+                  handle_at ASC
+                FOR UPDATE
+                  SKIP LOCKED
+                LIMIT 1)
+            RETURNING
+              id,
+              parents,
+              item::text,
+              attempt,
+              created_at,
+              schedule,
+              lane
+            "#,
+        )
+        .bind(lane),
+        None => sqlx::query(
+            r#"
+            DELETE FROM
+              queue
+            WHERE
+              id = (
+                SELECT
+                  id
+                FROM
+                  queue
+                WHERE
+                  handle_at < now()
+                ORDER BY
+                  handle_at ASC
+                FOR UPDATE
+                  SKIP LOCKED
+                LIMIT 1)
+            RETURNING
+              id,
+              parents,
+              item::text,
+              attempt,
+              created_at,
+              schedule,
+              lane
+            "#,
+        ),
+    };
+
+    query
+        .try_map(|x| QueueRecord::from_row(&x))
+        .fetch_optional(tx.as_mut())
+        .await
+}
+
+/// Insert a new (i.e. parentless) op into the queue, attributing it to `schedule` if provided.
+async fn insert_new<T: QueueMessage, Filter: InterestFilter<T>>(
+    tx: &mut Transaction<'static, Postgres>,
+    op: Op<T>,
+    filter: &Filter,
+    schedule: Option<&str>,
+) -> Result<EnqueueResult, sqlx::Error> {
+    let (optimize, ready): (Vec<_>, Vec<_>) =
+        op.normalize()
+            .into_iter()
+            .partition_map(|op| match filter.check_interest(&op) {
+                FilterResult::Interest(interest) => Either::Left((op, interest)),
+                FilterResult::NoInterest => Either::Right(op),
+            });
+
+    let ready_ids = sqlx::query(
+        "
+        INSERT INTO queue (item, lane, schedule)
+        SELECT item, lane, $3::TEXT FROM UNNEST($1::JSONB[], $2::TEXT[]) AS t(item, lane)
+        RETURNING id
+        ",
+    )
+    .bind(ready.iter().map(Json).collect::<Vec<_>>())
+    .bind(ready.iter().map(|op| filter.lane(op)).collect::<Vec<_>>())
+    .bind(schedule)
+    .try_map(|x| Id::from_row(&x))
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    for ready in &ready_ids {
+        debug!(id = ready.id, "enqueued ready item");
+    }
+
+    let optimize_further_ids = sqlx::query(
+        "
+        INSERT INTO optimize (item, tag, schedule)
+        SELECT item, tag, $3::TEXT FROM UNNEST($1::JSONB[], $2::TEXT[]) AS t(item, tag)
+        RETURNING id
+        ",
+    )
+    .bind(
+        optimize
+            .iter()
+            .map(|x| Json(x.0.clone()))
+            .collect::<Vec<_>>(),
+    )
+    .bind(
+        optimize
+            .iter()
+            .flat_map(|x| x.1.tags.clone())
+            .collect::<Vec<_>>(),
+    )
+    .bind(schedule)
+    .try_map(|x| Id::from_row(&x))
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    for ready in &optimize_further_ids {
+        debug!(id = ready.id, "enqueued optimize item");
+    }
+
+    Ok(EnqueueResult {
+        queue: ready_ids
+            .into_iter()
+            .map(|id| ItemId::new(id.id).expect("invalid id returned from database"))
+            .collect(),
+        optimize: optimize_further_ids
+            .into_iter()
+            .map(|id| ItemId::new(id.id).expect("invalid id returned from database"))
+            .collect(),
+    })
 }
 
 #[instrument(
@@ -810,8 +1327,8 @@ where
             sqlx::query(
                 "
                 INSERT INTO
-                queue  (id, item,      parents, attempt, handle_at, created_at)
-                VALUES ($1, $2::JSONB, $3,      $4,      $5,        $6        )
+                queue  (id, item,      parents, attempt, handle_at, created_at, schedule, lane)
+                VALUES ($1, $2::JSONB, $3,      $4,      $5,        $6,         $7,       $8  )
                 ",
             )
             .bind(record.id)
@@ -831,6 +1348,8 @@ where
                 ),
             )
             .bind(record.created_at)
+            .bind(record.schedule)
+            .bind(record.lane)
             .execute(tx.as_mut())
             .await?;
 
@@ -868,19 +1387,21 @@ where
 
                 sqlx::query(
                     "
-                    INSERT INTO queue (item, parents)
-                    SELECT *, $1 as parents FROM UNNEST($2::JSONB[])
+                    INSERT INTO queue (item, lane, parents, schedule)
+                    SELECT *, $1 as parents, $3::TEXT as schedule FROM UNNEST($2::JSONB[], $4::TEXT[])
                     ",
                 )
                 .bind(vec![record.id])
-                .bind(ready.into_iter().map(Json).collect::<Vec<_>>())
+                .bind(ready.iter().map(Json).collect::<Vec<_>>())
+                .bind(&record.schedule)
+                .bind(ready.iter().map(|op| filter.lane(op)).collect::<Vec<_>>())
                 .execute(tx.as_mut())
                 .await?;
 
                 sqlx::query(
                     "
-                    INSERT INTO optimize (item, tag, parents)
-                    SELECT *, $1 as parents FROM UNNEST($2::JSONB[], $3::TEXT[])
+                    INSERT INTO optimize (item, tag, parents, schedule)
+                    SELECT *, $1 as parents, $4::TEXT as schedule FROM UNNEST($2::JSONB[], $3::TEXT[])
                     ",
                 )
                 .bind(vec![record.id])
@@ -899,10 +1420,13 @@ where
                         .copied()
                         .collect::<Vec<_>>(),
                 )
+                .bind(&record.schedule)
                 .execute(tx.as_mut())
                 .await?;
 
-                metrics.processed_item_count.add(1, &[]);
+                metrics
+                    .processed_item_count
+                    .add(1, &[KeyValue::new("lane", record.lane)]);
             }
         }
     }
@@ -910,6 +1434,49 @@ where
     Ok(Some(r))
 }
 
+/// Record a run (or a skipped run) of the schedule `name`.
+async fn update_schedule(
+    tx: &mut Transaction<'static, Postgres>,
+    name: &str,
+    trigger: &Trigger,
+    next_run_at: u64,
+    skipped: bool,
+) -> Result<(), sqlx::Error> {
+    let next_run_at = i64::try_from(next_run_at).unwrap_or(i64::MAX);
+
+    let query = if skipped {
+        r#"
+        INSERT INTO
+        schedule (name, trigger, next_run_at, skipped)
+        VALUES   ($1,   $2,      $3,          1      )
+        ON CONFLICT (name) DO UPDATE SET
+          trigger = excluded.trigger,
+          next_run_at = excluded.next_run_at,
+          skipped = schedule.skipped + 1
+        "#
+    } else {
+        r#"
+        INSERT INTO
+        schedule (name, trigger, next_run_at, last_run_at,                    runs)
+        VALUES   ($1,   $2,      $3,          extract(epoch FROM now())::INT8, 1   )
+        ON CONFLICT (name) DO UPDATE SET
+          trigger = excluded.trigger,
+          next_run_at = excluded.next_run_at,
+          last_run_at = excluded.last_run_at,
+          runs = schedule.runs + 1
+        "#
+    };
+
+    sqlx::query(query)
+        .bind(name)
+        .bind(Json(trigger))
+        .bind(next_run_at)
+        .execute(tx.as_mut())
+        .await?;
+
+    Ok(())
+}
+
 async fn insert_error(
     record: QueueRecord,
     error: String,
@@ -920,8 +1487,8 @@ async fn insert_error(
     sqlx::query(
         r#"
         INSERT INTO
-        failed (id, parents, item,      created_at, message)
-        VALUES ($1, $2,      $3::JSONB, $4,         $5     )
+        failed (id, parents, item,      created_at, message, lane)
+        VALUES ($1, $2,      $3::JSONB, $4,         $5,      $6  )
         "#,
     )
     .bind(record.id)
@@ -929,6 +1496,7 @@ async fn insert_error(
     .bind(record.item)
     .bind(record.created_at)
     .bind(error)
+    .bind(record.lane)
     .execute(tx.as_mut())
     .await?;
 
@@ -948,6 +1516,19 @@ fn full_error_string(error: BoxDynError) -> String {
     s
 }
 
+// default to an all-inclusive filter if none are provided
+fn default_filters(mut filters: Vec<String>) -> Vec<String> {
+    if filters.is_empty() {
+        filters.push("%".to_owned());
+    }
+
+    filters
+}
+
+fn item_id(id: i64) -> Result<ItemId, sqlx::Error> {
+    ItemId::new(id).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+}
+
 fn de<T: DeserializeOwned>(s: &str) -> Result<T, serde_json::Error> {
     let mut deserializer = serde_json::Deserializer::from_str(s);
     deserializer.disable_recursion_limit();
@@ -1,4 +1,4 @@
-use opentelemetry::metrics::{Counter, Histogram};
+use opentelemetry::metrics::{Counter, Gauge, Histogram};
 
 #[derive(Debug, Clone)]
 pub struct Metrics {
@@ -9,6 +9,7 @@ pub struct Metrics {
     pub fatal_errors_count: Counter<u64>,
     pub retryable_errors_count: Counter<u64>,
     pub unprocessable_count: Counter<u64>,
+    pub lane_depth: Gauge<u64>,
 }
 
 impl Metrics {
@@ -55,6 +56,10 @@ impl Metrics {
                 .u64_counter("pg_queue_unprocessable_count")
                 .with_description("Total count of unprocessable messages encountered.")
                 .build(),
+            lane_depth: opentelemetry::global::meter("pg_queue")
+                .u64_gauge("pg_queue_lane_depth")
+                .with_description("The amount of items ready to be processed in each lane.")
+                .build(),
         }
     }
 }
@@ -0,0 +1,89 @@
+use sha3::{Digest, Keccak256};
+use unionlabs::primitives::{ByteArrayExt, H256};
+
+/// @dev Below is the encoding for `BlockContext`, total 60 bytes.
+/// ```text
+///   * Field                   Bytes      Type         Index  Comments
+///   * blockNumber             8          uint64       0      The height of this block.
+///   * timestamp               8          uint64       8      The timestamp of this block.
+///   * baseFee                 32         uint256      16     The base fee of this block.
+///   * gasLimit                8          uint64       48     The gas limit of this block.
+///   * numTransactions         2          uint16       56     The number of transactions in this block, both L1 & L2 txs.
+///   * numL1Messages           2          uint16       58     The number of l1 messages in this block.
+/// ```
+pub const BLOCK_CONTEXT_LENGTH: usize = 60;
+
+/// Only the first 58 bytes of a block context (everything but `numL1Messages`) are part of the
+/// chunk data hash.
+const BLOCK_CONTEXT_HASHED_LENGTH: usize = 58;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ChunkError {
+    #[error(
+        "block contexts must be a non-empty sequence of {BLOCK_CONTEXT_LENGTH} byte contexts, found {0} bytes"
+    )]
+    InvalidBlockContextsLength(usize),
+}
+
+/// Compute the data hash of a chunk (V1+), given the block contexts of the chunk as they are
+/// encoded in the `commitBatch` calldata (without the leading `numBlocks` byte) and the hashes of
+/// the L1 messages included in the chunk that were not skipped.
+///
+/// # Errors
+///
+/// Fails if `block_contexts` is not a non-empty sequence of block contexts.
+pub fn chunk_data_hash(
+    block_contexts: &[u8],
+    l1_message_hashes: &[H256],
+) -> Result<H256, ChunkError> {
+    let mut hasher = Keccak256::new();
+
+    for block_context in block_contexts_chunks(block_contexts)? {
+        hasher.update(&block_context[..BLOCK_CONTEXT_HASHED_LENGTH]);
+    }
+    for l1_message_hash in l1_message_hashes {
+        hasher.update(l1_message_hash);
+    }
+
+    Ok(hasher.finalize().into())
+}
+
+/// Compute the data hash of a batch (V1+) from the data hashes of its chunks.
+#[must_use]
+pub fn batch_data_hash<'a>(chunk_data_hashes: impl IntoIterator<Item = &'a H256>) -> H256 {
+    let mut hasher = Keccak256::new();
+
+    for chunk_data_hash in chunk_data_hashes {
+        hasher.update(chunk_data_hash);
+    }
+
+    hasher.finalize().into()
+}
+
+/// The number of the last block in `block_contexts`.
+///
+/// # Errors
+///
+/// Fails if `block_contexts` is not a non-empty sequence of block contexts.
+pub fn last_block_number(block_contexts: &[u8]) -> Result<u64, ChunkError> {
+    block_contexts_chunks(block_contexts)?
+        .last()
+        .map(|last| u64::from_be_bytes(last.array_slice::<0, 8>()))
+        .ok_or(ChunkError::InvalidBlockContextsLength(block_contexts.len()))
+}
+
+fn block_contexts_chunks(
+    block_contexts: &[u8],
+) -> Result<impl Iterator<Item = &[u8; BLOCK_CONTEXT_LENGTH]>, ChunkError> {
+    if block_contexts.is_empty() || block_contexts.len() % BLOCK_CONTEXT_LENGTH != 0 {
+        return Err(ChunkError::InvalidBlockContextsLength(block_contexts.len()));
+    }
+
+    Ok(block_contexts
+        .chunks_exact(BLOCK_CONTEXT_LENGTH)
+        .map(|block_context| {
+            block_context
+                .try_into()
+                .expect("chunks are exactly BLOCK_CONTEXT_LENGTH bytes; qed;")
+        }))
+}
@@ -1,15 +1,33 @@
 use ethereum_light_client_types::{AccountProof, StorageProof};
-use unionlabs::ibc::core::client::height::Height;
+use unionlabs::primitives::{Bytes, H256};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 pub struct Header {
-    pub l1_height: Height,
+    pub l1_height: u64,
     pub l1_account_proof: AccountProof,
     /// This is the finalized state root proof, i.e. the l2 state on the l1
     pub l2_state_root_proof: StorageProof,
     pub last_batch_index_proof: StorageProof,
     pub batch_hash_proof: StorageProof,
     pub l2_ibc_account_proof: AccountProof,
-    pub batch_header: Vec<u8>,
+    pub batch_header: Bytes,
+    /// Proof of the number of the last L2 block of the finalized batch.
+    pub last_block_proof: LastBlockProof,
+}
+
+/// The rollup contract does not store L2 block numbers, but the data hash of a batch commits to
+/// the block contexts of its chunks. This is the data needed to recompute the data hash of the
+/// finalized batch from the last chunk, whose last block context holds the last L2 block number.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct LastBlockProof {
+    /// The data hashes of the chunks of the batch preceding the last one.
+    pub chunk_data_hashes: Vec<H256>,
+    /// The block contexts of the last chunk, as encoded in the `commitBatch` calldata.
+    pub block_contexts: Bytes,
+    /// The hashes of the L1 messages included in the last chunk.
+    pub l1_message_hashes: Vec<H256>,
 }
@@ -1,8 +1,12 @@
 use core::fmt::Debug;
 
 use evm_storage_verifier::{verify_account_storage_root, verify_storage_proof};
-use scroll_codec::{hash_batch, HashBatchError};
-use scroll_light_client_types::{ClientState, Header};
+use scroll_codec::{
+    batch_header::BatchHeaderV3,
+    chunk::{batch_data_hash, chunk_data_hash, last_block_number, ChunkError},
+    hash_batch, HashBatchError,
+};
+use scroll_light_client_types::{ClientStateV1, Header, LastBlockProof};
 use scroll_types::account::Account;
 use unionlabs::{
     ethereum::slot::{MappingKey, Slot},
@@ -26,6 +30,10 @@ pub enum Error {
     ValueMismatch,
     #[error(transparent)]
     HashBatch(#[from] HashBatchError),
+    #[error(transparent)]
+    Chunk(#[from] ChunkError),
+    #[error("batch data hash mismatch, committed {committed} but the last block proof hashes to {found}")]
+    DataHashMismatch { committed: H256, found: H256 },
 }
 
 // 1. rollupContractOnL1 ∈ L1Stateroot
@@ -34,14 +42,14 @@ pub enum Error {
 // 4. batchHash ≡ rollupContractOnL1.batchHashes[lastBatchIndex]
 // 5. ibcContractOnL2 ∈ L2StateRoot
 pub fn verify_header(
-    client_state: &ClientState,
+    client_state: &ClientStateV1,
     header: &Header,
     l1_state_root: H256,
 ) -> Result<(), Error> {
     // Verify that the rollup account root is part of the L1 root
     verify_account_storage_root(
         l1_state_root,
-        &client_state.l2_contract_address,
+        &client_state.rollup_contract_address,
         &header.l1_account_proof.proof,
         &header.l1_account_proof.storage_root,
     )
@@ -93,6 +101,33 @@ pub fn verify_header(
     Ok(())
 }
 
+/// Verify that `proof` hashes to the data hash committed to in `batch_header`, and return the
+/// number of the last L2 block of the batch.
+///
+/// `batch_header` must have been verified against the rollup contract with [`verify_header`].
+pub fn verify_last_block_number(
+    batch_header: &BatchHeaderV3,
+    proof: &LastBlockProof,
+) -> Result<u64, Error> {
+    let last_chunk_data_hash = chunk_data_hash(&proof.block_contexts, &proof.l1_message_hashes)?;
+
+    let data_hash = batch_data_hash(
+        proof
+            .chunk_data_hashes
+            .iter()
+            .chain([&last_chunk_data_hash]),
+    );
+
+    if data_hash != batch_header.data_hash {
+        return Err(Error::DataHashMismatch {
+            committed: batch_header.data_hash,
+            found: data_hash,
+        });
+    }
+
+    Ok(last_block_number(&proof.block_contexts)?)
+}
+
 /// Storage slot of a `mapping(uint256 => bytes32)` mapping, where the mapping is at slot `slot` and the `uint256` is the `batch_index`.
 pub fn mapping_index_to_slot_key(slot: U256, batch_index: U256) -> U256 {
     Slot::Mapping(&Slot::Offset(slot), MappingKey::Uint256(batch_index)).slot()
@@ -156,78 +191,212 @@ pub fn scroll_verify_zktrie_account_storage_root(
     }
 }
 
-// #[cfg(test)]
-// mod tests {
-//     use hex_literal::hex;
-//     use scroll_light_client_types::{ClientState, Header};
-//     use unionlabs::{
-//         primitives::{H160, H256},
-//         ibc::{core::client::height::Height, lightclients::ethereum::storage_proof::StorageProof},
-//     };
-
-//     use crate::{verify_header, verify_zktrie_storage_absence, verify_zktrie_storage_proof};
-
-//     #[test]
-//     fn test_update_header() {
-//         let scroll_client_state = ClientState {
-//             l1_client_id: "cometbls-1".to_string(),
-//             chain_id: 534351.into(),
-//             latest_slot: 65327,
-//             latest_batch_index_slot: 156.into(),
-//             frozen_height: Height {
-//                 revision_number: 0,
-//                 revision_height: 0,
-//             },
-//             l2_contract_address: H160::new(hex!("2d567ece699eabe5afcd141edb7a4f2d0d6ce8a0")),
-//             l2_finalized_state_roots_slot: 158.into(),
-//             l2_committed_batches_slot: 157.into(),
-//             // Dummy contract address for the sake of testing
-//             ibc_contract_address: H160::new(hex!("0000000000000000000000000000000000000000")),
-//         };
-//         let scroll_header: Header =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_header.json").unwrap())
-//                 .unwrap();
-//         let l1_state_root = H256::new(hex!(
-//             "40ab3b90af84c30c31eb0fe9fc8cc5260b59f619d770706750ea3e474ca47c59"
-//         ));
-//         assert_eq!(
-//             verify_header(scroll_client_state, scroll_header, l1_state_root),
-//             Ok(())
-//         );
-//     }
-
-//     #[test]
-//     fn test_l2_contract_slot_exist() {
-//         let proof: StorageProof =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_proof.json").unwrap())
-//                 .unwrap();
-//         assert_eq!(
-//             verify_zktrie_storage_proof(
-//                 H256::new(hex!(
-//                     "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
-//                 )),
-//                 proof.key.to_be_bytes().into(),
-//                 &proof.value.to_be_bytes(),
-//                 &proof.proof
-//             ),
-//             Ok(())
-//         )
-//     }
-
-//     #[test]
-//     fn test_l2_contract_slot_absent() {
-//         let proof: StorageProof =
-//             serde_json::from_str(&std::fs::read_to_string("tests/scroll_absent.json").unwrap())
-//                 .unwrap();
-//         assert_eq!(
-//             verify_zktrie_storage_absence(
-//                 H256::new(hex!(
-//                     "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
-//                 )),
-//                 proof.key.to_be_bytes().into(),
-//                 &proof.proof
-//             ),
-//             Ok(())
-//         )
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use ethereum_light_client_types::StorageProof;
+    use hex_literal::hex;
+    use ibc_union_spec::ClientId;
+
+    use super::*;
+
+    fn read_fixture<T: serde::de::DeserializeOwned>(name: &str) -> T {
+        serde_json::from_str(
+            &std::fs::read_to_string(format!("{}/tests/{name}", env!("CARGO_MANIFEST_DIR")))
+                .unwrap(),
+        )
+        .unwrap()
+    }
+
+    // scroll sepolia, finalized batch as seen from sepolia block 4386369
+    fn client_state() -> ClientStateV1 {
+        ClientStateV1 {
+            chain_id: 534351u32.into(),
+            latest_height: 0,
+            l1_client_id: ClientId!(1),
+            rollup_contract_address: hex!("2d567ece699eabe5afcd141edb7a4f2d0d6ce8a0").into(),
+            latest_batch_index_slot: 156u32.into(),
+            l2_finalized_state_roots_slot: 158u32.into(),
+            l2_committed_batches_slot: 157u32.into(),
+            frozen_height: 0,
+            // Dummy contract address for the sake of testing
+            ibc_contract_address: hex!("0000000000000000000000000000000000000000").into(),
+        }
+    }
+
+    const L1_STATE_ROOT: H256 = H256::new(hex!(
+        "40ab3b90af84c30c31eb0fe9fc8cc5260b59f619d770706750ea3e474ca47c59"
+    ));
+
+    const L2_STORAGE_ROOT: H256 = H256::new(hex!(
+        "1b52888cae05bdba27f8470293a7d2bc3b9a9c822d96affe05ef243e0dfd44a0"
+    ));
+
+    #[test]
+    fn update_header() {
+        let header: Header = read_fixture("scroll_header.json");
+
+        assert_eq!(
+            verify_header(&client_state(), &header, L1_STATE_ROOT),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn update_header_wrong_l1_state_root() {
+        let header: Header = read_fixture("scroll_header.json");
+
+        assert!(matches!(
+            verify_header(&client_state(), &header, H256::default()),
+            Err(Error::InvalidL1AccountProof(_))
+        ));
+    }
+
+    #[test]
+    fn update_header_wrong_batch_header() {
+        let mut header: Header = read_fixture("scroll_header.json");
+
+        let mut batch_header = header.batch_header.to_vec();
+        // last byte of the last block timestamp
+        batch_header[128] ^= 1;
+        header.batch_header = batch_header.into();
+
+        assert!(matches!(
+            verify_header(&client_state(), &header, L1_STATE_ROOT),
+            Err(Error::InvalidBatchHashProof(_))
+        ));
+    }
+
+    fn block_context(number: u64) -> [u8; 60] {
+        let mut block_context = [0; 60];
+        block_context[..8].copy_from_slice(&number.to_be_bytes());
+        block_context
+    }
+
+    fn last_block_proof(block_numbers: &[u64]) -> LastBlockProof {
+        LastBlockProof {
+            chunk_data_hashes: vec![H256::new([1; 32]), H256::new([2; 32])],
+            block_contexts: block_numbers
+                .iter()
+                .flat_map(|number| block_context(*number))
+                .collect::<Vec<_>>()
+                .into(),
+            l1_message_hashes: vec![H256::new([3; 32])],
+        }
+    }
+
+    fn batch_header_committing_to(proof: &LastBlockProof) -> BatchHeaderV3 {
+        let header: Header = read_fixture("scroll_header.json");
+
+        let mut batch_header = BatchHeaderV3::decode(&header.batch_header).unwrap();
+        batch_header.data_hash =
+            batch_data_hash(proof.chunk_data_hashes.iter().chain([
+                &chunk_data_hash(&proof.block_contexts, &proof.l1_message_hashes).unwrap(),
+            ]));
+        batch_header
+    }
+
+    #[test]
+    fn last_block_number_verified() {
+        let proof = last_block_proof(&[9_000_000, 9_000_001]);
+
+        assert_eq!(
+            verify_last_block_number(&batch_header_committing_to(&proof), &proof),
+            Ok(9_000_001)
+        );
+    }
+
+    #[test]
+    fn last_block_number_tampered() {
+        let proof = last_block_proof(&[9_000_000, 9_000_001]);
+        let batch_header = batch_header_committing_to(&proof);
+
+        let tampered = last_block_proof(&[9_000_000, 9_000_002]);
+
+        assert!(matches!(
+            verify_last_block_number(&batch_header, &tampered),
+            Err(Error::DataHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn last_block_number_not_committed_by_finalized_batch() {
+        let header: Header = read_fixture("scroll_header.json");
+        let batch_header = BatchHeaderV3::decode(&header.batch_header).unwrap();
+
+        assert!(matches!(
+            verify_last_block_number(&batch_header, &last_block_proof(&[9_000_000])),
+            Err(Error::DataHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn last_block_number_invalid_block_contexts() {
+        let proof = LastBlockProof {
+            block_contexts: vec![0; 59].into(),
+            ..last_block_proof(&[9_000_000])
+        };
+
+        assert_eq!(
+            verify_last_block_number(&batch_header_committing_to(&last_block_proof(&[1])), &proof),
+            Err(Error::Chunk(ChunkError::InvalidBlockContextsLength(59)))
+        );
+    }
+
+    #[test]
+    fn l2_contract_slot_exist() {
+        let proof: StorageProof = read_fixture("scroll_proof.json");
+
+        assert_eq!(
+            verify_zktrie_storage_proof(
+                L2_STORAGE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &proof.value.to_be_bytes(),
+                &proof.proof
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn l2_contract_slot_wrong_value() {
+        let proof: StorageProof = read_fixture("scroll_proof.json");
+
+        assert_eq!(
+            verify_zktrie_storage_proof(
+                L2_STORAGE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &(proof.value + U256::ONE).to_be_bytes(),
+                &proof.proof
+            ),
+            Err(Error::ValueMismatch)
+        );
+    }
+
+    #[test]
+    fn l2_contract_slot_absent() {
+        let proof: StorageProof = read_fixture("scroll_absent.json");
+
+        assert_eq!(
+            verify_zktrie_storage_absence(
+                L2_STORAGE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &proof.proof
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn l2_contract_slot_not_absent() {
+        let proof: StorageProof = read_fixture("scroll_proof.json");
+
+        assert_eq!(
+            verify_zktrie_storage_absence(
+                L2_STORAGE_ROOT,
+                proof.key.to_be_bytes().into(),
+                &proof.proof
+            ),
+            Err(Error::ValueMismatch)
+        );
+    }
+}
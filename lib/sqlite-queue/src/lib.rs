@@ -0,0 +1,1486 @@
+use core::f64;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    future::Future,
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::TryStreamExt;
+use opentelemetry::KeyValue;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sqlx::{
+    prelude::FromRow,
+    sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous},
+    types::Json,
+    Either, Executor, QueryBuilder, Sqlite, SqliteConnection, SqlitePool, Transaction,
+};
+use tracing::{debug, debug_span, error, info, info_span, instrument, trace, warn, Instrument};
+use voyager_vm::{
+    filter::{FilterResult, Interest, InterestFilter},
+    history::{History, HistoryItem, ItemStatus},
+    pass::{Pass, PassResult},
+    schedule::{ScheduleState, Trigger},
+    BoxDynError, Captures, EnqueueResult, ItemId, Op, QueueError, QueueMessage,
+};
+
+use crate::metrics::Metrics;
+
+pub mod metrics;
+
+#[cfg(test)]
+mod tests;
+
+/// A fifo queue backed by an embedded SQLite database. This provides the same semantics as
+/// `pg-queue`, and is intended for small, single-relayer deployments where running a postgres
+/// server is not worth the operational overhead.
+///
+/// Items are claimed by marking them as `locked` before they are processed, and are only removed
+/// from the queue once processing has completed. Any items that are still locked on startup (i.e.
+/// the previous process exited while processing them) are released back into the queue, so only
+/// one process may use a database file at a time.
+#[derive(Debug, Clone)]
+pub struct SqliteQueue<T> {
+    client: SqlitePool,
+    optimize_batch_limit: Option<i64>,
+    retryable_error_expo_backoff_max: f64,
+    retryable_error_expo_backoff_multiplier: f64,
+
+    metrics: Metrics,
+    lane_depth_reported_at: Arc<Mutex<Option<Instant>>>,
+
+    __marker: PhantomData<fn() -> T>,
+}
+
+const LANE_DEPTH_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SqliteQueueConfig {
+    /// Path to the database file. It will be created if it does not exist.
+    pub path: PathBuf,
+    #[serde(default = "default_max_connections")]
+    pub max_connections: u32,
+    #[serde(default = "default_busy_timeout")]
+    pub busy_timeout: Duration,
+    #[serde(default)]
+    pub optimize_batch_limit: Option<i64>,
+    #[serde(default = "default_retryable_error_expo_backoff_max")]
+    pub retryable_error_expo_backoff_max: f64,
+    #[serde(default = "default_retryable_error_expo_backoff_multiplier")]
+    pub retryable_error_expo_backoff_multiplier: f64,
+    #[serde(default)]
+    pub vacuum_on_boot: bool,
+}
+
+pub const fn default_max_connections() -> u32 {
+    4
+}
+
+pub const fn default_busy_timeout() -> Duration {
+    Duration::from_secs(30)
+}
+
+pub const fn default_retryable_error_expo_backoff_max() -> f64 {
+    60.0 * 5.0
+}
+
+pub const fn default_retryable_error_expo_backoff_multiplier() -> f64 {
+    2.0
+}
+
+impl SqliteQueueConfig {
+    pub async fn into_sqlite_pool(self) -> sqlx::Result<SqlitePool> {
+        SqlitePoolOptions::new()
+            .max_connections(self.max_connections)
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(self.path)
+                    .create_if_missing(true)
+                    .journal_mode(SqliteJournalMode::Wal)
+                    .synchronous(SqliteSynchronous::Normal)
+                    .busy_timeout(self.busy_timeout)
+                    // match the semantics of LIKE in postgres
+                    .pragma("case_sensitive_like", "ON"),
+            )
+            .await
+    }
+}
+
+#[derive(FromRow)]
+struct Id {
+    id: i64,
+}
+
+#[derive(Debug, FromRow)]
+struct QueueRecord {
+    id: i64,
+    parents: Json<Vec<i64>>,
+    item: String,
+    created_at: i64,
+    attempt: i64,
+    schedule: Option<String>,
+    lane: String,
+}
+
+#[derive(Debug, FromRow)]
+struct OptimizeRecord {
+    id: i64,
+    item: String,
+    schedule: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct ScheduleRecord {
+    name: String,
+    trigger: Json<Trigger>,
+    next_run_at: i64,
+    last_run_at: Option<i64>,
+    runs: i64,
+    skipped: i64,
+    in_flight: i64,
+}
+
+#[derive(Debug, FromRow)]
+struct HistoryRecord {
+    id: i64,
+    parents: Json<Vec<i64>>,
+    item: String,
+    status: String,
+    tag: Option<String>,
+    message: Option<String>,
+}
+
+#[derive(Debug, FromRow, Serialize)]
+#[serde(bound(serialize = ""))]
+pub struct FailedRecord<T: QueueMessage> {
+    pub id: i64,
+    pub parents: Json<Vec<i64>>,
+    pub item: Json<Op<T>>,
+    pub message: String,
+}
+
+impl<T: QueueMessage> SqliteQueue<T> {
+    /// Query all failed items, optionally filtered by `item_filters` and `message_filters`.
+    ///
+    /// The filters are `LIKE` patterns, run on the compact JSON of the item and the failure
+    /// message respectively. Item filters written against the postgres text form of the JSON
+    /// (`{"a": {"b": "c"}}`) are compacted first, so the same filter works for both queues.
+    pub async fn query_failed(
+        &self,
+        page: i64,
+        per_page: i64,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<Vec<FailedRecord<T>>, sqlx::Error> {
+        let mut query =
+            QueryBuilder::<Sqlite>::new("SELECT id, parents, item, message FROM failed WHERE ");
+
+        push_failed_filters(&mut query, item_filters, message_filters);
+
+        query
+            .push(" ORDER BY id DESC LIMIT ")
+            .push_bind(per_page)
+            .push(" OFFSET ")
+            .push_bind((page - 1) * per_page);
+
+        query
+            .build()
+            .map(|row| FailedRecord::<T>::from_row(&row))
+            .fetch_all(&self.client)
+            .await?
+            .into_iter()
+            .collect()
+    }
+
+    /// Count all failed items matching `item_filters` and `message_filters`, using the same
+    /// filter semantics as [`Self::query_failed`].
+    pub async fn count_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<i64, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT count(*) FROM failed WHERE ");
+
+        push_failed_filters(&mut query, item_filters, message_filters);
+
+        query
+            .build_query_scalar::<i64>()
+            .fetch_one(&self.client)
+            .await
+    }
+
+    /// Move all failed items matching `item_filters` and `message_filters` back into the queue.
+    ///
+    /// The items keep their original id and parents, and are ready to be processed immediately.
+    /// Returns the amount of items that were requeued.
+    pub async fn requeue_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut tx = self.client.begin().await?;
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "INSERT INTO queue (id, item, parents, created_at, lane, handle_at) \
+            SELECT id, item, parents, created_at, lane, ",
+        );
+        query.push_bind(now_millis()).push(" FROM failed WHERE ");
+        push_failed_filters(&mut query, item_filters, message_filters);
+
+        let requeued = query.build().execute(tx.as_mut()).await?.rows_affected();
+
+        // ids are unique across all tables, so any failed item that is now also in the queue is
+        // one that was just requeued
+        sqlx::query("DELETE FROM failed WHERE id IN (SELECT id FROM queue)")
+            .execute(tx.as_mut())
+            .await?;
+
+        tx.commit().await?;
+
+        info!(count = requeued, "requeued failed items");
+
+        Ok(requeued)
+    }
+
+    /// Permanently delete all failed items matching `item_filters` and `message_filters`. Returns
+    /// the amount of items that were discarded.
+    ///
+    /// Note that if both `item_filters` and `message_filters` are empty, this will discard *all*
+    /// failed items.
+    pub async fn discard_failed(
+        &self,
+        item_filters: Vec<String>,
+        message_filters: Vec<String>,
+    ) -> Result<u64, sqlx::Error> {
+        let mut query = QueryBuilder::<Sqlite>::new("DELETE FROM failed WHERE ");
+
+        push_failed_filters(&mut query, item_filters, message_filters);
+
+        let discarded = query.build().execute(&self.client).await?.rows_affected();
+
+        info!(count = discarded, "discarded failed items");
+
+        Ok(discarded)
+    }
+
+    pub async fn query_failed_by_id(
+        &self,
+        id: i64,
+    ) -> Result<Option<FailedRecord<T>>, sqlx::Error> {
+        sqlx::query(
+            r#"
+            SELECT
+               id,
+               parents,
+               item,
+               message
+            FROM
+               failed
+            WHERE
+               id = $1
+            "#,
+        )
+        .bind(id)
+        .map(|row| FailedRecord::<T>::from_row(&row))
+        .fetch_optional(&self.client)
+        .await?
+        .transpose()
+    }
+
+    pub async fn stats(&self) -> Result<Stats, sqlx::Error> {
+        let (total, ready) = sqlx::query_as::<_, (i64, i64)>(
+            r#"
+            SELECT
+              count(*),
+              count(*) FILTER (WHERE handle_at < $1)
+            FROM
+              queue
+            "#,
+        )
+        .bind(now_millis())
+        .fetch_one(&self.client)
+        .await?;
+
+        let optimize = sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT
+              tag,
+              count(*)
+            FROM
+              optimize
+            GROUP BY
+              tag
+            "#,
+        )
+        .fetch_all(&self.client)
+        .await?
+        .into_iter()
+        .map(|(tag, count)| (tag, count.unsigned_abs()))
+        .collect();
+
+        Ok(Stats {
+            total,
+            ready,
+            optimize,
+            lanes: self.query_lane_depths().await?,
+            schedules: self.query_schedules().await?,
+        })
+    }
+
+    /// The amount of ready items in each lane.
+    async fn query_lane_depths(&self) -> Result<BTreeMap<String, u64>, sqlx::Error> {
+        Ok(sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT
+              lane,
+              count(*)
+            FROM
+              queue
+            WHERE
+              handle_at < $1
+            GROUP BY
+              lane
+            "#,
+        )
+        .bind(now_millis())
+        .fetch_all(&self.client)
+        .await?
+        .into_iter()
+        .map(|(lane, count)| (lane, count.unsigned_abs()))
+        .collect())
+    }
+
+    /// Record the amount of ready items in each lane in [`Metrics::lane_depth`]. This is called on
+    /// every [`process`](voyager_vm::Queue::process), but only queries the database at most once
+    /// every [`LANE_DEPTH_REPORT_INTERVAL`].
+    async fn report_lane_depths(&self) {
+        let now = Instant::now();
+
+        {
+            let mut last_reported = self.lane_depth_reported_at.lock().expect("poisoned");
+
+            if last_reported
+                .is_some_and(|last| now.duration_since(last) < LANE_DEPTH_REPORT_INTERVAL)
+            {
+                return;
+            }
+
+            *last_reported = Some(now);
+        }
+
+        match self.query_lane_depths().await {
+            Ok(depths) => {
+                for (lane, depth) in depths {
+                    self.metrics
+                        .lane_depth
+                        .record(depth, &[KeyValue::new("lane", lane)]);
+                }
+            }
+            Err(error) => {
+                warn!(%error, "error querying lane depths");
+            }
+        }
+    }
+
+    async fn query_schedules(&self) -> Result<Vec<ScheduleState>, sqlx::Error> {
+        let records = sqlx::query(
+            r#"
+            SELECT
+              s.name,
+              s.trigger,
+              s.next_run_at,
+              s.last_run_at,
+              s.runs,
+              s.skipped,
+              (
+                (SELECT count(*) FROM queue q WHERE q.schedule = s.name)
+                + (SELECT count(*) FROM optimize o WHERE o.schedule = s.name)
+              ) AS in_flight
+            FROM
+              schedule s
+            ORDER BY
+              s.name ASC
+            "#,
+        )
+        .try_map(|row| ScheduleRecord::from_row(&row))
+        .fetch_all(&self.client)
+        .await?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| ScheduleState {
+                name: record.name,
+                trigger: record.trigger.0,
+                next_run_at: record.next_run_at.unsigned_abs(),
+                last_run_at: record.last_run_at.map(i64::unsigned_abs),
+                runs: record.runs.unsigned_abs(),
+                skipped: record.skipped.unsigned_abs(),
+                in_flight: record.in_flight.unsigned_abs(),
+            })
+            .collect())
+    }
+
+    pub async fn truncate(&self, tables: Tables) -> Result<(), sqlx::Error> {
+        // sqlite has no TRUNCATE, an unqualified DELETE is optimized to the same thing
+        if tables.queue {
+            sqlx::query(r"DELETE FROM queue")
+                .execute(&self.client)
+                .await?;
+        }
+
+        if tables.optimize {
+            sqlx::query(r"DELETE FROM optimize")
+                .execute(&self.client)
+                .await?;
+        }
+
+        if tables.done {
+            sqlx::query(r"DELETE FROM done")
+                .execute(&self.client)
+                .await?;
+        }
+
+        if tables.failed {
+            sqlx::query(r"DELETE FROM failed")
+                .execute(&self.client)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Rebuild the database file, reclaiming unused space.
+    ///
+    /// Unlike postgres, sqlite can only vacuum the entire database, so the database will be
+    /// vacuumed if *any* of the tables are selected.
+    pub async fn vacuum(&self, tables: Tables) -> Result<(), sqlx::Error> {
+        if tables.queue || tables.optimize || tables.done || tables.failed {
+            sqlx::query(r"VACUUM").execute(&self.client).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Stats {
+    pub total: i64,
+    pub ready: i64,
+    pub optimize: BTreeMap<String, u64>,
+    /// The amount of ready items in each lane.
+    pub lanes: BTreeMap<String, u64>,
+    pub schedules: Vec<ScheduleState>,
+}
+
+pub struct Tables {
+    pub queue: bool,
+    pub optimize: bool,
+    pub done: bool,
+    pub failed: bool,
+}
+
+impl<T: QueueMessage> voyager_vm::Queue<T> for SqliteQueue<T> {
+    type Config = SqliteQueueConfig;
+    type Error = sqlx::Error;
+
+    async fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        let optimize_batch_limit = config.optimize_batch_limit;
+        let retryable_error_expo_backoff_multiplier =
+            config.retryable_error_expo_backoff_multiplier;
+        let retryable_error_expo_backoff_max = config.retryable_error_expo_backoff_max;
+        let vacuum_on_boot = config.vacuum_on_boot;
+
+        let pool = config.into_sqlite_pool().await?;
+
+        pool.execute_many(
+            r#"
+            -- ids are shared across all tables, so that an item keeps it's id as it moves between them
+            CREATE TABLE IF NOT EXISTS
+              id_seq (
+                id INTEGER NOT NULL
+              );
+
+            INSERT INTO id_seq (id) SELECT 0 WHERE NOT EXISTS (SELECT 1 FROM id_seq);
+
+            CREATE TABLE IF NOT EXISTS
+              queue (
+                id INTEGER PRIMARY KEY,
+                item TEXT NOT NULL,
+                parents TEXT NOT NULL DEFAULT '[]',
+                created_at INTEGER NOT NULL,
+                handle_at INTEGER NOT NULL,
+                attempt INTEGER NOT NULL DEFAULT 0,
+                locked INTEGER NOT NULL DEFAULT 0,
+                schedule TEXT,
+                lane TEXT NOT NULL DEFAULT 'default'
+              );
+
+            CREATE TABLE IF NOT EXISTS
+              optimize (
+                id INTEGER PRIMARY KEY,
+                item TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                parents TEXT NOT NULL DEFAULT '[]',
+                created_at INTEGER NOT NULL,
+                schedule TEXT
+              );
+
+            CREATE TABLE IF NOT EXISTS
+              done (
+                id INTEGER PRIMARY KEY,
+                item TEXT NOT NULL,
+                parents TEXT NOT NULL DEFAULT '[]',
+                created_at INTEGER NOT NULL
+              );
+
+            CREATE TABLE IF NOT EXISTS
+              failed (
+                id INTEGER PRIMARY KEY,
+                item TEXT NOT NULL,
+                parents TEXT NOT NULL DEFAULT '[]',
+                message TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                lane TEXT NOT NULL DEFAULT 'default'
+              );
+
+            CREATE INDEX IF NOT EXISTS index_queue_handle_at ON queue (locked, handle_at);
+
+            CREATE INDEX IF NOT EXISTS index_queue_lane_handle_at ON queue (locked, lane, handle_at);
+
+            CREATE INDEX IF NOT EXISTS optimize_tag_id_idx ON optimize (tag, id);
+
+            CREATE INDEX IF NOT EXISTS index_queue_schedule ON queue (schedule) WHERE schedule IS NOT NULL;
+
+            CREATE INDEX IF NOT EXISTS index_optimize_schedule ON optimize (schedule) WHERE schedule IS NOT NULL;
+
+            CREATE TABLE IF NOT EXISTS
+              schedule (
+                name TEXT PRIMARY KEY,
+                -- the trigger that next_run_at was computed with
+                trigger TEXT NOT NULL,
+                -- unix timestamps, in seconds
+                next_run_at INTEGER NOT NULL,
+                last_run_at INTEGER,
+                runs INTEGER NOT NULL DEFAULT 0,
+                skipped INTEGER NOT NULL DEFAULT 0
+              );
+
+            -- release any items that were being processed when the previous process exited
+            UPDATE queue SET locked = 0 WHERE locked = 1;
+            "#,
+        )
+        .try_for_each(|result| async move {
+            trace!("rows affected: {}", result.rows_affected());
+            Ok(())
+        })
+        .instrument(info_span!("init"))
+        .await?;
+
+        let this = Self {
+            client: pool,
+            optimize_batch_limit,
+            retryable_error_expo_backoff_max,
+            retryable_error_expo_backoff_multiplier,
+            metrics: Metrics::new(),
+            lane_depth_reported_at: Arc::new(Mutex::new(None)),
+            __marker: PhantomData,
+        };
+
+        if vacuum_on_boot {
+            this.vacuum(Tables {
+                queue: true,
+                optimize: true,
+                done: true,
+                failed: true,
+            })
+            .await?;
+        }
+
+        Ok(this)
+    }
+
+    async fn enqueue<'a, Filter: InterestFilter<T>>(
+        &'a self,
+        op: Op<T>,
+        filter: &'a Filter,
+    ) -> Result<EnqueueResult, Self::Error> {
+        trace!("enqueue");
+
+        let mut tx = self.client.begin().await?;
+
+        let (queue, optimize) =
+            insert_normalized(tx.as_mut(), filter, op.normalize(), &[], None).await?;
+
+        for id in &queue {
+            debug!(id, "enqueued ready item");
+        }
+
+        for id in &optimize {
+            debug!(id, "enqueued optimize item");
+        }
+
+        tx.commit().await?;
+
+        Ok(EnqueueResult {
+            queue: queue
+                .into_iter()
+                .map(|id| ItemId::new(id).expect("invalid id returned from database"))
+                .collect(),
+            optimize: optimize
+                .into_iter()
+                .map(|id| ItemId::new(id).expect("invalid id returned from database"))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all)]
+    async fn process<'a, F, Fut, R, Filter>(
+        &'a self,
+        filter: &'a Filter,
+        f: F,
+    ) -> Result<Option<R>, Self::Error>
+    where
+        F: (FnOnce(Op<T>, ItemId) -> Fut) + Send + Captures<'a>,
+        Fut: Future<Output = (R, Result<Vec<Op<T>>, QueueError>)> + Send + Captures<'a>,
+        R: Send + Sync + 'static,
+        Filter: InterestFilter<T>,
+    {
+        trace!("process");
+
+        self.report_lane_depths().await;
+
+        let mut row = None;
+
+        // check each lane in order, falling back to any ready item (i.e. one in a lane that is not
+        // configured)
+        for lane in filter.lane_order().into_iter().map(Some).chain([None]) {
+            row = claim_next(&self.client, lane).await?;
+
+            if row.is_some() {
+                if let Some(lane) = lane {
+                    filter.lane_served(lane);
+                }
+
+                break;
+            }
+        }
+
+        match row {
+            Some(record) => {
+                let id = record.id;
+
+                let res = process_item(
+                    &self.client,
+                    &self.metrics,
+                    record,
+                    f,
+                    filter,
+                    self.retryable_error_expo_backoff_max,
+                    self.retryable_error_expo_backoff_multiplier,
+                )
+                .await;
+
+                if res.is_err() {
+                    // best effort, if this fails the item will be released on the next startup
+                    let _ = sqlx::query("UPDATE queue SET locked = 0 WHERE id = $1")
+                        .bind(id)
+                        .execute(&self.client)
+                        .await;
+                }
+
+                res
+            }
+            None => Ok(None),
+        }
+    }
+
+    #[instrument(skip_all, fields(%tag))]
+    async fn optimize<'a, O, Filter>(
+        &'a self,
+        tag: &'a str,
+        filter: &'a Filter,
+        optimizer: &'a O,
+    ) -> Result<(), Either<Self::Error, O::Error>>
+    where
+        O: Pass<T>,
+        Filter: InterestFilter<T>,
+    {
+        trace!(%tag, "optimize");
+
+        // there is only ever one optimizer running per tag, so the items can be read outside of
+        // the transaction and removed once the pass has completed
+        let msgs = sqlx::query(
+            r#"
+            SELECT
+              id,
+              item,
+              schedule
+            FROM
+              optimize
+            WHERE
+              tag = $1
+            ORDER BY
+              id ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(tag)
+        // a negative limit is no limit in sqlite
+        .bind(self.optimize_batch_limit.unwrap_or(-1))
+        .try_map(|x| OptimizeRecord::from_row(&x))
+        .fetch_all(&self.client)
+        .await
+        .map_err(Either::Left)?;
+
+        if msgs.is_empty() {
+            trace!("optimizer queue is empty");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            return Ok(());
+        }
+
+        let schedules = msgs.iter().map(|r| r.schedule.clone()).collect::<Vec<_>>();
+
+        let (ids, msgs) = msgs
+            .into_iter()
+            .map(|r| {
+                Ok((
+                    r.id,
+                    de(&r.item).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                ))
+            })
+            .collect::<Result<(Vec<_>, Vec<_>), sqlx::Error>>()
+            .map_err(Either::Left)?;
+
+        self.metrics.optimize_item_count.record(
+            msgs.len() as u64,
+            &[KeyValue::new("tag".to_owned(), tag.to_owned())],
+        );
+
+        let now = std::time::Instant::now();
+
+        let PassResult {
+            optimize_further,
+            ready,
+        } = optimizer
+            .run_pass(msgs.clone())
+            .instrument(debug_span!(
+                "optimizing items",
+                ids = ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ))
+            .await
+            .map_err(Either::Right)?;
+        self.metrics.optimize_processing_duration.record(
+            Instant::now().duration_since(now).as_secs_f64(),
+            &[KeyValue::new("tag".to_owned(), tag.to_owned())],
+        );
+
+        trace!(
+            ready = ready.len(),
+            optimize_further = optimize_further.len(),
+            "optimized items"
+        );
+
+        let get_parent_ids = |parent_idxs: &[usize]| {
+            ids.iter()
+                .enumerate()
+                .filter_map(|(idx, id)| parent_idxs.contains(&idx).then_some(*id))
+                .collect::<Vec<_>>()
+        };
+
+        // new items are attributed to the schedule of their first parent
+        let get_schedule = |parent_idxs: &[usize]| {
+            parent_idxs
+                .iter()
+                .min()
+                .and_then(|&idx| schedules[idx].as_deref())
+        };
+
+        let mut tx = self.client.begin().await.map_err(Either::Left)?;
+
+        // keep the optimized items around so that the lineage of the new items can be traced
+        sqlx::query(
+            r#"
+            INSERT INTO
+              done (id, item, parents, created_at)
+            SELECT
+              id, item, parents, created_at
+            FROM
+              optimize
+            WHERE
+              id IN (SELECT value FROM json_each($1))
+            "#,
+        )
+        .bind(Json(&ids))
+        .execute(tx.as_mut())
+        .await
+        .map_err(Either::Left)?;
+
+        sqlx::query(r#"DELETE FROM optimize WHERE id IN (SELECT value FROM json_each($1))"#)
+            .bind(Json(&ids))
+            .execute(tx.as_mut())
+            .await
+            .map_err(Either::Left)?;
+
+        for (parent_idxs, new_msg, tag) in optimize_further {
+            let parents = get_parent_ids(&parent_idxs);
+            trace!(parent_idxs = ?&parent_idxs, parents = ?&parents);
+
+            let id = insert_optimize(
+                tx.as_mut(),
+                &new_msg,
+                &tag,
+                &parents,
+                get_schedule(&parent_idxs),
+            )
+            .await
+            .map_err(Either::Left)?;
+
+            debug!(id, "inserted new optimizer message");
+        }
+
+        for (parent_idxs, op) in ready {
+            let parents = get_parent_ids(&parent_idxs);
+            trace!(parent_idxs = ?&parent_idxs, parents = ?&parents);
+
+            insert_normalized(
+                tx.as_mut(),
+                filter,
+                op.normalize(),
+                &parents,
+                get_schedule(&parent_idxs),
+            )
+            .await
+            .map_err(Either::Left)?;
+        }
+
+        tx.commit().await.map_err(Either::Left)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip_all, fields(%name))]
+    async fn enqueue_scheduled<'a, Filter: InterestFilter<T>>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        op: Op<T>,
+        filter: &'a Filter,
+        next_run_at: u64,
+    ) -> Result<EnqueueResult, Self::Error> {
+        trace!("enqueue scheduled");
+
+        let mut tx = self.client.begin().await?;
+
+        let (queue, optimize) =
+            insert_normalized(tx.as_mut(), filter, op.normalize(), &[], Some(name)).await?;
+
+        for id in &queue {
+            debug!(id, "enqueued ready item");
+        }
+
+        for id in &optimize {
+            debug!(id, "enqueued optimize item");
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO
+            schedule (name, trigger, next_run_at, last_run_at, runs)
+            VALUES   ($1,   $2,      $3,          $4,          1   )
+            ON CONFLICT (name) DO UPDATE SET
+              trigger = excluded.trigger,
+              next_run_at = excluded.next_run_at,
+              last_run_at = excluded.last_run_at,
+              runs = runs + 1
+            "#,
+        )
+        .bind(name)
+        .bind(Json(trigger))
+        .bind(i64::try_from(next_run_at).unwrap_or(i64::MAX))
+        .bind(now_millis() / 1000)
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(EnqueueResult {
+            queue: queue
+                .into_iter()
+                .map(|id| ItemId::new(id).expect("invalid id returned from database"))
+                .collect(),
+            optimize: optimize
+                .into_iter()
+                .map(|id| ItemId::new(id).expect("invalid id returned from database"))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(%name))]
+    async fn skip_scheduled<'a>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        next_run_at: u64,
+    ) -> Result<(), Self::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO
+            schedule (name, trigger, next_run_at, skipped)
+            VALUES   ($1,   $2,      $3,          1      )
+            ON CONFLICT (name) DO UPDATE SET
+              trigger = excluded.trigger,
+              next_run_at = excluded.next_run_at,
+              skipped = skipped + 1
+            "#,
+        )
+        .bind(name)
+        .bind(Json(trigger))
+        .bind(i64::try_from(next_run_at).unwrap_or(i64::MAX))
+        .execute(&self.client)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn schedules(&self) -> Result<Vec<ScheduleState>, Self::Error> {
+        self.query_schedules().await
+    }
+
+    async fn history(&self, id: ItemId, max_depth: u32) -> Result<Option<History<T>>, Self::Error> {
+        let records = sqlx::query(
+            r#"
+            WITH RECURSIVE
+              items AS (
+                SELECT id, parents, item, 'queued' AS status, NULL AS tag, NULL AS message FROM queue
+                UNION ALL
+                SELECT id, parents, item, 'optimize', tag, NULL FROM optimize
+                UNION ALL
+                SELECT id, parents, item, 'done', NULL, NULL FROM done
+                UNION ALL
+                SELECT id, parents, item, 'failed', NULL, message FROM failed
+              ),
+              ancestors (id, depth) AS (
+                SELECT $1, 0
+                UNION
+                SELECT
+                  parent.value, a.depth + 1
+                FROM
+                  ancestors a
+                  JOIN items i ON i.id = a.id
+                  JOIN json_each(i.parents) parent
+                WHERE
+                  a.depth < $2
+              ),
+              descendants (id, depth) AS (
+                SELECT $1, 0
+                UNION
+                SELECT
+                  i.id, d.depth + 1
+                FROM
+                  descendants d
+                  JOIN items i ON EXISTS (
+                    SELECT 1 FROM json_each(i.parents) parent WHERE parent.value = d.id
+                  )
+                WHERE
+                  d.depth < $2
+              )
+            SELECT
+              id,
+              parents,
+              item,
+              status,
+              tag,
+              message
+            FROM
+              items
+            WHERE
+              id IN (
+                SELECT id FROM ancestors
+                UNION
+                SELECT id FROM descendants
+              )
+            ORDER BY
+              id ASC
+            "#,
+        )
+        .bind(id.raw())
+        .bind(i64::from(max_depth))
+        .try_map(|row| HistoryRecord::from_row(&row))
+        .fetch_all(&self.client)
+        .await?;
+
+        if !records.iter().any(|record| record.id == id.raw()) {
+            return Ok(None);
+        }
+
+        let items = records
+            .into_iter()
+            .map(|record| {
+                let status = match &*record.status {
+                    "queued" => ItemStatus::Queued,
+                    "optimize" => ItemStatus::Optimize {
+                        tag: record.tag.unwrap_or_default(),
+                    },
+                    "done" => ItemStatus::Done,
+                    "failed" => ItemStatus::Failed {
+                        message: record.message.unwrap_or_default(),
+                    },
+                    status => unreachable!("unknown status `{status}`"),
+                };
+
+                Ok(HistoryItem {
+                    id: item_id(record.id)?,
+                    parents: record
+                        .parents
+                        .0
+                        .into_iter()
+                        .map(item_id)
+                        .collect::<Result<_, _>>()?,
+                    status,
+                    item: de(&record.item).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                })
+            })
+            .collect::<Result<_, sqlx::Error>>()?;
+
+        Ok(Some(History { id, items }))
+    }
+}
+
+#[instrument(
+    skip_all,
+    fields(
+        item_id = record.id,
+        attempt = record.attempt
+    )
+)]
+async fn process_item<'a, T, F, Fut, R, Filter>(
+    client: &SqlitePool,
+    metrics: &Metrics,
+    record: QueueRecord,
+    f: F,
+    filter: &'a Filter,
+    retryable_error_expo_backoff_max: f64,
+    retryable_error_expo_backoff_multiplier: f64,
+) -> Result<Option<R>, sqlx::Error>
+where
+    T: QueueMessage,
+    F: (FnOnce(Op<T>, ItemId) -> Fut) + Send + Captures<'a>,
+    Fut: Future<Output = (R, Result<Vec<Op<T>>, QueueError>)> + Send + Captures<'a>,
+    R: Send + Sync + 'static,
+    Filter: InterestFilter<T>,
+{
+    trace!(%record.item);
+
+    let op = de::<Op<T>>(&record.item).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+    let now = std::time::Instant::now();
+    let (r, res) = f(op, ItemId::new(record.id).unwrap()).await;
+    metrics
+        .item_processing_duration
+        .record(Instant::now().duration_since(now).as_secs_f64(), &[]);
+
+    let mut tx = client.begin().await?;
+
+    match res {
+        Err(QueueError::Fatal(error)) => {
+            let error = full_error_string(error);
+            error!(%error, "fatal error");
+            insert_error(record, error, &mut tx).await?;
+            metrics.fatal_errors_count.add(1, &[]);
+        }
+        Err(QueueError::Unprocessable(error)) => {
+            let error = full_error_string(error);
+            info!(%error, "unprocessable message");
+            insert_error(record, error, &mut tx).await?;
+            metrics.unprocessable_count.add(1, &[]);
+        }
+        Err(QueueError::Retry(error)) => {
+            warn!(error = %full_error_string(error), "retryable error");
+
+            let backoff = Duration::try_from_secs_f64(
+                (record.attempt as f64)
+                    .powf(retryable_error_expo_backoff_multiplier)
+                    .clamp(f64::MIN, retryable_error_expo_backoff_max),
+            )
+            .unwrap_or(Duration::MAX);
+
+            sqlx::query(
+                "
+                UPDATE
+                  queue
+                SET
+                  locked = 0,
+                  attempt = $2,
+                  handle_at = $3
+                WHERE
+                  id = $1
+                ",
+            )
+            .bind(record.id)
+            .bind(record.attempt.saturating_add(1))
+            .bind(
+                now_millis().saturating_add(i64::try_from(backoff.as_millis()).unwrap_or(i64::MAX)),
+            )
+            .execute(tx.as_mut())
+            .await?;
+
+            // release the write lock before backing off, otherwise all other workers stall
+            tx.commit().await?;
+
+            metrics.retryable_errors_count.add(1, &[]);
+            tokio::time::sleep(Duration::from_millis(500)).await;
+
+            return Ok(Some(r));
+        }
+        Ok(ops) => {
+            sqlx::query("DELETE FROM queue WHERE id = $1")
+                .bind(record.id)
+                .execute(tx.as_mut())
+                .await?;
+
+            // insert the op we just processed into done
+            sqlx::query(
+                "
+                INSERT INTO
+                done   (id, parents, item, created_at)
+                VALUES ($1, $2,      $3,   $4        )
+                ",
+            )
+            .bind(record.id)
+            .bind(record.parents)
+            .bind(record.item)
+            .bind(record.created_at)
+            .execute(tx.as_mut())
+            .await?;
+
+            insert_normalized(
+                tx.as_mut(),
+                filter,
+                ops.into_iter().flat_map(Op::normalize),
+                &[record.id],
+                record.schedule.as_deref(),
+            )
+            .await?;
+
+            metrics
+                .processed_item_count
+                .add(1, &[KeyValue::new("lane", record.lane)]);
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(Some(r))
+}
+
+/// Claim the oldest ready item in the queue, only considering items in `lane` if provided.
+///
+/// The item is claimed in a single statement; the transaction is only opened once the item has been
+/// processed so as to not block the other workers for the duration of the processing.
+async fn claim_next(
+    client: &SqlitePool,
+    lane: Option<&str>,
+) -> Result<Option<QueueRecord>, sqlx::Error> {
+    let query = match lane {
+        Some(lane) => sqlx::query(
+            r#"
+            UPDATE
+              queue
+            SET
+              locked = 1
+            WHERE
+              id = (
+                SELECT
+                  id
+                FROM
+                  queue
+                WHERE
+                  locked = 0
+                  AND lane = $2
+                  AND handle_at < $1
+                ORDER BY
+                  handle_at ASC
+                LIMIT 1)
+            RETURNING
+              id,
+              parents,
+              item,
+              attempt,
+              created_at,
+              schedule,
+              lane
+            "#,
+        )
+        .bind(now_millis())
+        .bind(lane),
+        None => sqlx::query(
+            r#"
+            UPDATE
+              queue
+            SET
+              locked = 1
+            WHERE
+              id = (
+                SELECT
+                  id
+                FROM
+                  queue
+                WHERE
+                  locked = 0
+                  AND handle_at < $1
+                ORDER BY
+                  handle_at ASC
+                LIMIT 1)
+            RETURNING
+              id,
+              parents,
+              item,
+              attempt,
+              created_at,
+              schedule,
+              lane
+            "#,
+        )
+        .bind(now_millis()),
+    };
+
+    query
+        .try_map(|x| QueueRecord::from_row(&x))
+        .fetch_optional(client)
+        .await
+}
+
+/// Insert already normalized ops into the queue, running `filter` on each op to determine whether
+/// it should be inserted into the optimize queue instead. Returns the ids of the inserted queue and
+/// optimize items respectively.
+///
+/// The new items are attributed to `schedule`, if provided.
+async fn insert_normalized<T: QueueMessage, Filter: InterestFilter<T>>(
+    tx: &mut SqliteConnection,
+    filter: &Filter,
+    ops: impl IntoIterator<Item = Op<T>>,
+    parents: &[i64],
+    schedule: Option<&str>,
+) -> Result<(Vec<i64>, Vec<i64>), sqlx::Error> {
+    let mut queue_ids = vec![];
+    let mut optimize_ids = vec![];
+
+    for op in ops {
+        match filter.check_interest(&op) {
+            FilterResult::Interest(Interest { tags, remove }) => {
+                for tag in tags {
+                    optimize_ids.push(insert_optimize(tx, &op, tag, parents, schedule).await?);
+                }
+
+                if !remove {
+                    queue_ids
+                        .push(insert_queue(tx, &op, parents, schedule, filter.lane(&op)).await?);
+                }
+            }
+            FilterResult::NoInterest => {
+                queue_ids.push(insert_queue(tx, &op, parents, schedule, filter.lane(&op)).await?);
+            }
+        }
+    }
+
+    Ok((queue_ids, optimize_ids))
+}
+
+async fn insert_queue<T: QueueMessage>(
+    tx: &mut SqliteConnection,
+    op: &Op<T>,
+    parents: &[i64],
+    schedule: Option<&str>,
+    lane: &str,
+) -> Result<i64, sqlx::Error> {
+    let now = now_millis();
+
+    sqlx::query(
+        "
+        INSERT INTO
+        queue  (id, item, parents, created_at, handle_at, schedule, lane)
+        VALUES ($1, $2,   $3,      $4,         $4,        $5,       $6  )
+        RETURNING id
+        ",
+    )
+    .bind(next_id(tx).await?)
+    .bind(Json(op))
+    .bind(Json(parents))
+    .bind(now)
+    .bind(schedule)
+    .bind(lane)
+    .try_map(|x| Id::from_row(&x))
+    .fetch_one(&mut *tx)
+    .await
+    .map(|id| id.id)
+}
+
+async fn insert_optimize<T: QueueMessage>(
+    tx: &mut SqliteConnection,
+    op: &Op<T>,
+    tag: &str,
+    parents: &[i64],
+    schedule: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query(
+        "
+        INSERT INTO
+        optimize (id, item, tag, parents, created_at, schedule)
+        VALUES   ($1, $2,   $3,  $4,      $5,         $6      )
+        RETURNING id
+        ",
+    )
+    .bind(next_id(tx).await?)
+    .bind(Json(op))
+    .bind(tag)
+    .bind(Json(parents))
+    .bind(now_millis())
+    .bind(schedule)
+    .try_map(|x| Id::from_row(&x))
+    .fetch_one(&mut *tx)
+    .await
+    .map(|id| id.id)
+}
+
+async fn next_id(tx: &mut SqliteConnection) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("UPDATE id_seq SET id = id + 1 RETURNING id")
+        .fetch_one(&mut *tx)
+        .await
+}
+
+async fn insert_error(
+    record: QueueRecord,
+    error: String,
+    tx: &mut Transaction<'static, Sqlite>,
+) -> Result<(), sqlx::Error> {
+    // move the op from the queue into failed, along with the error message
+
+    sqlx::query("DELETE FROM queue WHERE id = $1")
+        .bind(record.id)
+        .execute(tx.as_mut())
+        .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO
+        failed (id, parents, item, created_at, message, lane)
+        VALUES ($1, $2,      $3,   $4,         $5,      $6  )
+        "#,
+    )
+    .bind(record.id)
+    .bind(record.parents)
+    .bind(record.item)
+    .bind(record.created_at)
+    .bind(error)
+    .bind(record.lane)
+    .execute(tx.as_mut())
+    .await?;
+
+    Ok(())
+}
+
+/// Push `(column LIKE $a OR column LIKE $b ...)`, defaulting to an all-inclusive filter if none
+/// are provided.
+fn push_failed_filters(
+    query: &mut QueryBuilder<'_, Sqlite>,
+    item_filters: Vec<String>,
+    message_filters: Vec<String>,
+) {
+    push_like_any(
+        query,
+        "item",
+        item_filters
+            .iter()
+            .map(|f| compact_json_filter(f))
+            .collect(),
+    );
+    query.push(" AND ");
+    push_like_any(query, "message", message_filters);
+}
+
+/// Strip the space that postgres' `jsonb::text` puts after the `:` and `,` separators, so that a
+/// filter written against `pg-queue` matches the compact JSON stored in this queue.
+///
+/// Only separators following a JSON token (a closing quote, bracket, number or literal) are
+/// compacted. Note that postgres also reorders object keys, which can not be accounted for here.
+fn compact_json_filter(filter: &str) -> String {
+    let mut out = String::with_capacity(filter.len());
+    let mut chars = filter.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let is_separator = (c == ':' || c == ',')
+            && chars.peek() == Some(&' ')
+            && (out.ends_with(['}', ']'])
+                || out.ends_with(|c: char| c.is_ascii_digit())
+                || ["true", "false", "null"].iter().any(|l| out.ends_with(l))
+                || (out.ends_with('"') && !out.ends_with("\\\"")));
+
+        out.push(c);
+
+        if is_separator {
+            chars.next();
+        }
+    }
+
+    out
+}
+
+fn push_like_any(query: &mut QueryBuilder<'_, Sqlite>, column: &str, mut filters: Vec<String>) {
+    if filters.is_empty() {
+        filters.push("%".to_owned());
+    }
+
+    query.push("(");
+
+    let mut separated = query.separated(" OR ");
+    for filter in filters {
+        separated
+            .push(format_args!("{column} LIKE "))
+            .push_bind_unseparated(filter);
+    }
+
+    query.push(")");
+}
+
+/// Returns the current unix timestamp in milliseconds.
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("the current timestamp must be greater than the unix epoch")
+        .as_millis()
+        .try_into()
+        .expect("millisecond timestamps fit in an i64 for the foreseeable future")
+}
+
+// copied from unionlabs::ErrorReporter
+fn full_error_string(error: BoxDynError) -> String {
+    let mut s = String::new();
+
+    write!(s, "{}", error).unwrap();
+
+    for e in core::iter::successors(error.source(), |e| (*e).source()) {
+        write!(s, ": {e}").unwrap();
+    }
+
+    s
+}
+
+fn item_id(id: i64) -> Result<ItemId, sqlx::Error> {
+    ItemId::new(id).map_err(|e| sqlx::Error::Decode(Box::new(e)))
+}
+
+fn de<T: DeserializeOwned>(s: &str) -> Result<T, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(s);
+    deserializer.disable_recursion_limit();
+    let json = T::deserialize(&mut deserializer)?;
+    Ok(json)
+}
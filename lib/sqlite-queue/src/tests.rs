@@ -0,0 +1,300 @@
+use std::time::Duration;
+
+use voyager_vm::{
+    conc, defer,
+    filter::{FilterResult, InterestFilter},
+    history::ItemStatus,
+    lane::{WeightedLanes, DEFAULT_LANE},
+    schedule::Trigger,
+    ItemId, Op, Queue, QueueError, QueueMessage,
+};
+
+use crate::{compact_json_filter, SqliteQueue, SqliteQueueConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+enum UnitMessage {}
+
+impl QueueMessage for UnitMessage {
+    type Data = ();
+    type Call = ();
+    type Callback = ();
+}
+
+async fn new_queue(name: &str) -> SqliteQueue<UnitMessage> {
+    let path =
+        std::env::temp_dir().join(format!("sqlite-queue-{name}-{}.sqlite", std::process::id()));
+
+    let _ = std::fs::remove_file(&path);
+
+    SqliteQueue::new(SqliteQueueConfig {
+        path,
+        max_connections: 1,
+        busy_timeout: Duration::from_secs(1),
+        optimize_batch_limit: None,
+        retryable_error_expo_backoff_max: 0.0,
+        retryable_error_expo_backoff_multiplier: 2.0,
+        vacuum_on_boot: false,
+    })
+    .await
+    .unwrap()
+}
+
+async fn process_next(
+    queue: &SqliteQueue<UnitMessage>,
+    res: impl FnOnce() -> Result<(), QueueError> + Send,
+) -> Option<ItemId> {
+    // items are only ready once handle_at is strictly in the past
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    queue
+        .process(&(), async move |_, id| (id, res().map(|()| vec![])))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn process_moves_items_between_tables() {
+    let queue = new_queue("process").await;
+
+    let enqueued = queue
+        .enqueue(conc([defer(1), defer(2)]), &())
+        .await
+        .unwrap();
+
+    assert_eq!(enqueued.queue.len(), 2);
+    assert!(enqueued.optimize.is_empty());
+    assert_eq!(queue.stats().await.unwrap().total, 2);
+
+    let done = process_next(&queue, || Ok(())).await;
+    assert_eq!(done, Some(enqueued.queue[0]));
+    assert_eq!(queue.stats().await.unwrap().total, 1);
+
+    let failed = process_next(&queue, || Err(QueueError::Fatal("boom".into()))).await;
+    assert_eq!(failed, Some(enqueued.queue[1]));
+    assert_eq!(queue.stats().await.unwrap().total, 0);
+
+    let records = queue.query_failed(1, 10, vec![], vec![]).await.unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].id, enqueued.queue[1].raw());
+    assert!(records[0].message.contains("boom"));
+
+    assert!(queue
+        .query_failed(1, 10, vec![], vec!["%no match%".to_owned()])
+        .await
+        .unwrap()
+        .is_empty());
+
+    assert!(queue
+        .query_failed_by_id(enqueued.queue[1].raw())
+        .await
+        .unwrap()
+        .is_some());
+
+    assert_eq!(process_next(&queue, || Ok(())).await, None);
+}
+
+#[tokio::test]
+async fn retry_requeues_item() {
+    let queue = new_queue("retry").await;
+
+    let enqueued = queue.enqueue(defer(1), &()).await.unwrap();
+
+    let retried = process_next(&queue, || Err(QueueError::Retry("try again".into()))).await;
+    assert_eq!(retried, Some(enqueued.queue[0]));
+    assert_eq!(queue.stats().await.unwrap().total, 1);
+
+    // the item keeps it's id when it is retried
+    let done = process_next(&queue, || Ok(())).await;
+    assert_eq!(done, Some(enqueued.queue[0]));
+    assert_eq!(queue.stats().await.unwrap().total, 0);
+}
+
+#[tokio::test]
+async fn history_walks_lineage() {
+    let queue = new_queue("history").await;
+
+    let root = queue.enqueue(defer(1), &()).await.unwrap().queue[0];
+
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    queue
+        .process(&(), async |_, id| {
+            (id, Ok(vec![conc([defer(2), defer(3)])]))
+        })
+        .await
+        .unwrap();
+
+    let failed = process_next(&queue, || Err(QueueError::Fatal("boom".into())))
+        .await
+        .unwrap();
+
+    let history = queue.history(root, 10).await.unwrap().unwrap();
+    assert_eq!(history.items.len(), 3);
+    assert_eq!(history.items[0].status, ItemStatus::Done);
+    assert!(history.items[1..].iter().all(|item| item.parents == [root]));
+
+    let history = queue.history(failed, 10).await.unwrap().unwrap();
+    assert_eq!(
+        history.items.iter().map(|item| item.id).collect::<Vec<_>>(),
+        [root, failed]
+    );
+    assert!(matches!(
+        &history.items[1].status,
+        ItemStatus::Failed { message } if message.contains("boom")
+    ));
+
+    assert_eq!(
+        queue.history(ItemId::new(1000).unwrap(), 10).await.unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn bulk_requeue_and_discard_failed() {
+    let queue = new_queue("bulk").await;
+
+    let enqueued = queue
+        .enqueue(conc([defer(1), defer(2), defer(3)]), &())
+        .await
+        .unwrap();
+
+    for message in ["rpc unavailable", "rpc unavailable", "invalid proof"] {
+        process_next(&queue, || Err(QueueError::Fatal(message.into()))).await;
+    }
+
+    assert_eq!(queue.count_failed(vec![], vec![]).await.unwrap(), 3);
+    assert_eq!(
+        queue
+            .count_failed(vec![], vec!["%rpc%".to_owned()])
+            .await
+            .unwrap(),
+        2
+    );
+
+    assert_eq!(
+        queue
+            .requeue_failed(vec![], vec!["%rpc%".to_owned()])
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(queue.stats().await.unwrap().total, 2);
+    assert_eq!(queue.count_failed(vec![], vec![]).await.unwrap(), 1);
+
+    // requeued items keep their id
+    let done = process_next(&queue, || Ok(())).await.unwrap();
+    assert!(enqueued.queue.contains(&done));
+
+    assert_eq!(queue.discard_failed(vec![], vec![]).await.unwrap(), 1);
+    assert_eq!(queue.count_failed(vec![], vec![]).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn scheduled_items_are_tracked_until_done() {
+    let queue = new_queue("schedule").await;
+
+    queue
+        .enqueue_scheduled("tick", &Trigger::Every(100), defer(1), &(), 100)
+        .await
+        .unwrap();
+
+    let [state] = &*queue.schedules().await.unwrap() else {
+        panic!("expected exactly one schedule");
+    };
+    assert_eq!(state.name, "tick");
+    assert_eq!(state.trigger, Trigger::Every(100));
+    assert_eq!(state.next_run_at, 100);
+    assert!(state.last_run_at.is_some());
+    assert_eq!((state.runs, state.skipped, state.in_flight), (1, 0, 1));
+
+    // children of scheduled items are attributed to the same schedule
+    tokio::time::sleep(Duration::from_millis(5)).await;
+    queue
+        .process(&(), async |_, id| (id, Ok(vec![defer(2)])))
+        .await
+        .unwrap();
+    assert_eq!(queue.schedules().await.unwrap()[0].in_flight, 1);
+
+    process_next(&queue, || Ok(())).await.unwrap();
+    assert_eq!(queue.schedules().await.unwrap()[0].in_flight, 0);
+
+    queue
+        .skip_scheduled("tick", &Trigger::Every(100), 200)
+        .await
+        .unwrap();
+
+    let stats = queue.stats().await.unwrap();
+    assert_eq!(stats.schedules.len(), 1);
+    assert_eq!(stats.schedules[0].next_run_at, 200);
+    assert_eq!(
+        (stats.schedules[0].runs, stats.schedules[0].skipped),
+        (1, 1)
+    );
+}
+
+#[tokio::test]
+async fn lanes_are_served_by_weight() {
+    struct LaneFilter(WeightedLanes);
+
+    impl InterestFilter<UnitMessage> for LaneFilter {
+        fn check_interest<'a>(&'a self, _: &Op<UnitMessage>) -> FilterResult<'a> {
+            FilterResult::NoInterest
+        }
+
+        fn lane<'a>(&'a self, op: &Op<UnitMessage>) -> &'a str {
+            match op {
+                Op::Defer { until } if *until >= 100 => "fast",
+                _ => DEFAULT_LANE,
+            }
+        }
+
+        fn lane_order(&self) -> Vec<&str> {
+            self.0.next_order()
+        }
+
+        fn lane_served(&self, lane: &str) {
+            self.0.served(lane);
+        }
+    }
+
+    let queue = new_queue("lanes").await;
+    let filter = LaneFilter(WeightedLanes::new([("fast".to_owned(), 2)]));
+
+    for until in [1, 2, 100, 101] {
+        queue.enqueue(defer(until), &filter).await.unwrap();
+    }
+
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let stats = queue.stats().await.unwrap();
+    assert_eq!(stats.lanes.get("fast"), Some(&2));
+    assert_eq!(stats.lanes.get(DEFAULT_LANE), Some(&2));
+
+    let mut processed = vec![];
+    while let Some(op) = queue
+        .process(&filter, async |op, _| (op, Ok(vec![])))
+        .await
+        .unwrap()
+    {
+        processed.push(op);
+    }
+
+    assert_eq!(processed, [defer(100), defer(1), defer(101), defer(2)]);
+}
+
+#[test]
+fn postgres_item_filters_are_compacted() {
+    assert_eq!(
+        compact_json_filter(r#"%{"a": {"b": "c"}, "d": [1, true, null]}%"#),
+        r#"%{"a":{"b":"c"},"d":[1,true,null]}%"#
+    );
+
+    // separators inside strings are kept
+    assert_eq!(
+        compact_json_filter(r#"%"error: rpc, unavailable"%"#),
+        r#"%"error: rpc, unavailable"%"#
+    );
+    assert_eq!(compact_json_filter(r#"%\": x%"#), r#"%\": x%"#);
+
+    // already compact filters are unchanged
+    assert_eq!(compact_json_filter(r#"%"until":100%"#), r#"%"until":100%"#);
+}
@@ -2,7 +2,9 @@ pub mod client_state;
 pub mod consensus_state;
 pub mod fraction;
 pub mod header;
+pub mod misbehaviour;
 
 pub use crate::{
     client_state::ClientState, consensus_state::ConsensusState, fraction::Fraction, header::Header,
+    misbehaviour::Misbehaviour,
 };
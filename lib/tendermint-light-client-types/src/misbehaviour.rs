@@ -0,0 +1,94 @@
+use crate::header::Header;
+
+/// Evidence of a tendermint chain misbehaving: two headers that are each valid on their own, but
+/// either commit to different blocks at the same height, or break the monotonicity of block time.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Misbehaviour {
+    pub header_a: Header,
+    pub header_b: Header,
+}
+
+#[cfg(feature = "proto")]
+pub mod proto {
+    use unionlabs::{errors::MissingField, impl_proto_via_try_from_into, required};
+
+    use crate::{header, Misbehaviour};
+
+    impl_proto_via_try_from_into!(Misbehaviour => protos::ibc::lightclients::tendermint::v1::Misbehaviour);
+
+    impl From<Misbehaviour> for protos::ibc::lightclients::tendermint::v1::Misbehaviour {
+        #[allow(deprecated)]
+        fn from(value: Misbehaviour) -> Self {
+            Self {
+                client_id: String::new(),
+                header_1: Some(value.header_a.into()),
+                header_2: Some(value.header_b.into()),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Clone, thiserror::Error)]
+    pub enum Error {
+        #[error(transparent)]
+        MissingField(#[from] MissingField),
+        #[error("invalid header 1")]
+        Header1(#[source] header::proto::Error),
+        #[error("invalid header 2")]
+        Header2(#[source] header::proto::Error),
+    }
+
+    impl TryFrom<protos::ibc::lightclients::tendermint::v1::Misbehaviour> for Misbehaviour {
+        type Error = Error;
+
+        fn try_from(
+            value: protos::ibc::lightclients::tendermint::v1::Misbehaviour,
+        ) -> Result<Self, Self::Error> {
+            Ok(Self {
+                header_a: required!(value.header_1)?
+                    .try_into()
+                    .map_err(Error::Header1)?,
+                header_b: required!(value.header_2)?
+                    .try_into()
+                    .map_err(Error::Header2)?,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unionlabs::{
+        encoding::{Bincode, Json},
+        primitives::H256,
+        test_utils::assert_codec_iso,
+    };
+
+    use super::*;
+    use crate::header::tests::mk_header;
+
+    fn mk_misbehaviour() -> Misbehaviour {
+        let mut header_b = mk_header();
+        header_b.signed_header.header.app_hash = H256::new([0xBB; 32]);
+
+        Misbehaviour {
+            header_a: mk_header(),
+            header_b,
+        }
+    }
+
+    #[test]
+    fn bincode_iso() {
+        assert_codec_iso::<_, Bincode>(&mk_misbehaviour());
+    }
+
+    #[test]
+    fn json_iso() {
+        assert_codec_iso::<_, Json>(&mk_misbehaviour());
+    }
+}
@@ -132,12 +132,13 @@ impl<'a> BytesBitIterator<'a> {
         }
     }
 
-    /// Returns the `index`-th bit in the bytes.
+    /// Returns the `index`-th bit in the bytes, counting from the most significant bit of the
+    /// first byte.
     fn get_bit(&self, index: usize) -> bool {
         // debug_assert_eq!(self.hash_bytes.len(), Hash::LENGTH); // invariant
         // debug_assert_lt!(index, Hash::LENGTH_IN_BITS); // assumed precondition
         let pos = index / 8;
-        let bit = index % 8;
+        let bit = 7 - index % 8;
         (self.bz[pos] >> bit) & 1 != 0
     }
 }
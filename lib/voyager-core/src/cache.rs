@@ -12,6 +12,8 @@ use unionlabs::ibc::core::client::height::Height;
 use voyager_primitives::{ChainId, ClientInfo, IbcSpec, IbcSpecId, IbcStorePathKey, Timestamp};
 use voyager_types::RawClientId;
 
+use crate::throttle::Throttle;
+
 #[derive(Debug, Clone)]
 pub struct Cache {
     state_cache: moka::future::Cache<StateRequest, Value>,
@@ -30,11 +32,13 @@ pub struct Cache {
     latest_timestamp_cache: moka::future::Cache<(ChainId, bool), Timestamp>,
     latest_timestamp_metric: opentelemetry::metrics::Gauge<u64>,
     // proof_cache: moka::future::Cache,
+    /// Applied to all requests that miss the cache.
+    throttle: Throttle,
 }
 
 impl Cache {
     #[allow(clippy::new_without_default)]
-    pub fn new(config: Config) -> Self {
+    pub fn new(config: Config, throttle: Throttle) -> Self {
         Self {
             state_cache: moka::future::CacheBuilder::new(config.state.capacity)
                 // .expire_after()
@@ -86,9 +90,14 @@ impl Cache {
             latest_timestamp_metric: opentelemetry::global::meter("voyager")
                 .u64_gauge("chain.latest_timestamp")
                 .build(),
+            throttle,
         }
     }
 
+    pub fn throttle(&self) -> &Throttle {
+        &self.throttle
+    }
+
     pub async fn state<T: Serialize + DeserializeOwned>(
         &self,
         state_request: StateRequest,
@@ -112,8 +121,14 @@ impl Cache {
 
         self.state_cache_miss_counter_metric.add(1, attributes);
 
-        let init = fut
-            .map_ok(|state| serde_json::to_value(state).expect("serialization is infallible; qed;"))
+        let init = self
+            .throttle
+            .run(
+                &state_request.chain_id,
+                fut.map_ok(|state| {
+                    serde_json::to_value(state).expect("serialization is infallible; qed;")
+                }),
+            )
             .await?;
 
         if init.is_null() {
@@ -152,7 +167,11 @@ impl Cache {
         self.client_info_cache_miss_counter_metric
             .add(1, attributes);
 
-        match fut.await? {
+        match self
+            .throttle
+            .run(&client_info_request.chain_id, fut)
+            .await?
+        {
             Some(init) => {
                 let entry = self
                     .client_info_cache
@@ -187,7 +206,7 @@ impl Cache {
             KeyValue::new("finalized", finalized.to_string()),
         ];
 
-        let height_response = match fut.await {
+        let height_response = match self.throttle.run(&chain_id, fut).await {
             Err(err) => {
                 let latest_known_height = self
                     .latest_height_cache
@@ -255,7 +274,7 @@ impl Cache {
             KeyValue::new("finalized", finalized.to_string()),
         ];
 
-        let timestamp_response = match fut.await {
+        let timestamp_response = match self.throttle.run(&chain_id, fut).await {
             Err(why) => {
                 let latest_known_timestamp = self
                     .latest_timestamp_cache
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+};
 
 use jsonrpsee::types::{ErrorObject, ErrorObjectOwned};
 use schemars::JsonSchema;
@@ -15,7 +19,11 @@ use voyager_rpc::{
 };
 use voyager_vm::QueueError;
 
-use crate::{equivalent_chain_ids::EquivalentChainIds, ibc_spec_handlers::IbcSpecHandlers};
+use crate::{
+    equivalent_chain_ids::EquivalentChainIds,
+    health::{HealthConfig, WorkerHealth},
+    ibc_spec_handlers::IbcSpecHandlers,
+};
 
 pub struct Context {
     pub(crate) state_modules: HashMap<(ChainId, IbcSpecId), WorkerClient>,
@@ -34,12 +42,37 @@ pub struct Context {
 
     pub(crate) plugins: HashMap<String, WorkerClient>,
 
+    /// map of plugin name or module id to the health of the worker.
+    pub(crate) health: HashMap<String, WorkerHealth>,
+
+    pub(crate) health_config: HealthConfig,
+
     pub(crate) equivalent_chain_ids: EquivalentChainIds,
 
     // ibc version id => handler
     pub(crate) ibc_spec_handlers: IbcSpecHandlers,
 }
 
+/// Shared handle to the current [`Context`].
+///
+/// The context is replaced wholesale whenever the plugin and module configuration is reloaded;
+/// anything that needs a consistent view of the loaded plugins and modules (such as the handling of
+/// a single queue item) should hold on to the [`Arc`] returned by [`ContextHandle::get`] instead of
+/// calling it repeatedly.
+#[derive(Clone, Default)]
+pub struct ContextHandle(Arc<RwLock<Option<Arc<Context>>>>);
+
+impl ContextHandle {
+    /// Returns the current context, if it has been loaded.
+    pub fn get(&self) -> Option<Arc<Context>> {
+        self.0.read().expect("lock is poisoned").clone()
+    }
+
+    pub(crate) fn set(&self, context: Context) {
+        *self.0.write().expect("lock is poisoned") = Some(Arc::new(context));
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PluginConfig {
@@ -132,15 +165,36 @@ impl Context {
             })
             .collect();
 
+        let mut health = self
+            .health
+            .values()
+            .map(WorkerHealth::info)
+            .collect::<Vec<_>>();
+
+        health.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
         InfoResponse {
             state,
             proof,
             consensus,
             client,
             client_bootstrap,
+            health,
         }
     }
 
+    /// The health of the plugin `name`, if it is unhealthy. Calls to unhealthy plugins should be
+    /// deferred by [`HealthConfig::defer_seconds`] instead of being made.
+    pub(crate) fn unhealthy_plugin(&self, name: &str) -> Option<&WorkerHealth> {
+        self.health
+            .get(name)
+            .filter(|health| self.plugins.contains_key(name) && health.is_unhealthy())
+    }
+
+    pub fn health_config(&self) -> &HealthConfig {
+        &self.health_config
+    }
+
     pub fn plugin<'a>(&'a self, name: &str) -> Result<&'a WorkerClient, PluginNotFound> {
         self.plugins.get(name).ok_or_else(|| PluginNotFound {
             name: name.to_owned(),
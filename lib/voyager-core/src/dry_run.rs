@@ -0,0 +1,171 @@
+//! Execution of an op against live plugins and modules, stopping short of submitting any
+//! transactions.
+
+use std::{
+    collections::VecDeque,
+    ops::ControlFlow,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::anyhow;
+use tracing::{debug, info};
+use voyager_message::{
+    call::{Call, SubmitTx},
+    callback::Callback,
+    data::Data,
+    VoyagerMessage,
+};
+use voyager_vm::{
+    filter::{FilterResult, InterestFilter},
+    in_memory::InMemoryQueue,
+    noop, HandlerFactory, ItemId, Op, QueueError,
+};
+
+use crate::{
+    filter::InterestFilters,
+    replay::{ReplayOutcome, ReplayStep},
+    server::Server,
+    Engine, Handler,
+};
+
+impl Engine<InMemoryQueue<VoyagerMessage>> {
+    /// Run `op` to completion against the plugins and modules of this engine, returning all
+    /// transactions that would have been submitted.
+    ///
+    /// [`SubmitTx`] calls are never passed to any plugins (and as such, no transactions are
+    /// submitted); instead, they are collected and returned once there is nothing left to process.
+    /// All other ops are processed as they would be by a running voyager.
+    ///
+    /// Processing stops at the first item that fails, including items that fail with a retryable
+    /// error.
+    pub async fn dry_run(&self, op: Op<VoyagerMessage>) -> anyhow::Result<Vec<SubmitTx>> {
+        let handlers = DryRunHandlers {
+            server: self.server(),
+            submitted: Arc::new(Mutex::new(vec![])),
+        };
+
+        let mut failed = None;
+
+        self.run_to_completion(
+            &DryRunFilter(self.interest_filters()),
+            &handlers,
+            |op| op,
+            op,
+            None,
+            |step| {
+                debug!(
+                    step = step.step,
+                    op = %serde_json::to_string(&step.op).unwrap(),
+                    "processed item"
+                );
+
+                if matches!(step.outcome, ReplayOutcome::Ok(_)) {
+                    ControlFlow::Continue(())
+                } else {
+                    failed = Some(step);
+                    ControlFlow::Break(())
+                }
+            },
+        )
+        .await?;
+
+        if let Some(ReplayStep {
+            item_id,
+            op,
+            outcome,
+            ..
+        }) = failed
+        {
+            let error = match outcome {
+                ReplayOutcome::Ok(_) => unreachable!(),
+                ReplayOutcome::Fatal(error) => format!("fatal error: {error}"),
+                ReplayOutcome::Unprocessable(error) => format!("unprocessable: {error}"),
+                ReplayOutcome::Retry(error) => format!("retryable error: {error}"),
+            };
+
+            return Err(anyhow!(
+                "item {} failed: {error}; op: {}",
+                item_id.raw(),
+                serde_json::to_string(&op).unwrap()
+            ));
+        }
+
+        let submitted = std::mem::take(&mut *handlers.submitted.lock().expect("mutex is poisoned"));
+
+        info!("dry run produced {} transaction(s)", submitted.len());
+
+        Ok(submitted)
+    }
+}
+
+/// Keeps [`SubmitTx`] calls out of the optimization queues of the transaction plugins, such that
+/// they are handled by [`DryRunHandler`] instead.
+struct DryRunFilter(InterestFilters);
+
+impl InterestFilter<VoyagerMessage> for DryRunFilter {
+    fn check_interest<'a>(&'a self, op: &Op<VoyagerMessage>) -> FilterResult<'a> {
+        match op {
+            Op::Call(Call::SubmitTx(_)) => FilterResult::NoInterest,
+            op => self.0.check_interest(op),
+        }
+    }
+
+    fn lane<'a>(&'a self, op: &Op<VoyagerMessage>) -> &'a str {
+        self.0.lane(op)
+    }
+
+    fn lane_order(&self) -> Vec<&str> {
+        self.0.lane_order()
+    }
+
+    fn lane_served(&self, lane: &str) {
+        self.0.lane_served(lane);
+    }
+}
+
+struct DryRunHandlers {
+    server: Server,
+    submitted: Arc<Mutex<Vec<SubmitTx>>>,
+}
+
+impl HandlerFactory<VoyagerMessage> for DryRunHandlers {
+    type Handler = DryRunHandler;
+
+    fn make_handler(&self, item_id: ItemId) -> Self::Handler {
+        DryRunHandler {
+            handler: self.server.make_handler(item_id),
+            submitted: self.submitted.clone(),
+        }
+    }
+}
+
+struct DryRunHandler {
+    handler: Handler,
+    submitted: Arc<Mutex<Vec<SubmitTx>>>,
+}
+
+impl voyager_vm::Handler<VoyagerMessage> for DryRunHandler {
+    async fn call(&self, call: Call) -> Result<Op<VoyagerMessage>, QueueError> {
+        match call {
+            Call::SubmitTx(submit_tx) => {
+                info!(chain_id = %submit_tx.chain_id, "not submitting transaction in dry run");
+
+                self.submitted
+                    .lock()
+                    .expect("mutex is poisoned")
+                    .push(submit_tx);
+
+                Ok(noop())
+            }
+            call => voyager_vm::Handler::call(&self.handler, call).await,
+        }
+    }
+
+    async fn callback(
+        &self,
+        callback: Callback,
+        data: VecDeque<Data>,
+    ) -> Result<Op<VoyagerMessage>, QueueError> {
+        voyager_vm::Handler::callback(&self.handler, callback, data).await
+    }
+}
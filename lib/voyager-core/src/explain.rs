@@ -0,0 +1,160 @@
+//! Human readable rendering of an op tree, annotated with where each message will be handled.
+
+use std::fmt::Write;
+
+use voyager_message::{call::Call, callback::Callback, PluginMessage, VoyagerMessage};
+use voyager_vm::{
+    filter::{FilterResult, Interest, InterestFilter},
+    Op, Promise,
+};
+
+use crate::filter::InterestFilters;
+
+/// Render `op` as an indented plan, one op per line.
+///
+/// Every call and promise is annotated with where it will be handled: by the plugins whose
+/// interest filters match it (as determined by `filters`), by the plugin it is addressed to, or by
+/// voyager itself. Note that interest filters are only run on ops once they are queued at the top
+/// level, so ops nested within a `seq` or `promise` are checked here as if they were queued on their
+/// own.
+#[must_use]
+pub fn explain(op: &Op<VoyagerMessage>, filters: &InterestFilters) -> String {
+    let mut out = String::new();
+
+    explain_op(&mut out, op, filters, "", "", "");
+
+    out
+}
+
+fn explain_op(
+    out: &mut String,
+    op: &Op<VoyagerMessage>,
+    filters: &InterestFilters,
+    prefix: &str,
+    branch: &str,
+    indent: &str,
+) {
+    match op {
+        Op::Data(data) => {
+            writeln!(
+                out,
+                "{prefix}{branch}data{}",
+                type_of(&serde_json::to_value(data).unwrap())
+            )
+            .unwrap();
+        }
+        Op::Call(call) => {
+            let route = match (call, routing(op, filters)) {
+                (_, Some(route)) => route,
+                (Call::Plugin(PluginMessage { plugin, .. }), None) => format!("plugin `{plugin}`"),
+                (
+                    Call::Index(_)
+                    | Call::IndexRange(_)
+                    | Call::FetchUpdateHeaders(_)
+                    | Call::SubmitTx(_),
+                    None,
+                ) => "no plugin is interested in this call, it will fail".to_owned(),
+                (_, None) => "voyager".to_owned(),
+            };
+
+            writeln!(
+                out,
+                "{prefix}{branch}call{} -> {route}",
+                type_of(&serde_json::to_value(call).unwrap())
+            )
+            .unwrap();
+        }
+        Op::Defer { until } => writeln!(out, "{prefix}{branch}defer until {until}").unwrap(),
+        Op::DeferRelative { secs } => writeln!(out, "{prefix}{branch}defer for {secs}s").unwrap(),
+        Op::Seq(ops) => {
+            writeln!(out, "{prefix}{branch}seq (in order)").unwrap();
+            explain_children(out, ops.iter(), filters, &format!("{prefix}{indent}"));
+        }
+        Op::Conc(ops) => {
+            writeln!(out, "{prefix}{branch}conc (concurrently)").unwrap();
+            explain_children(out, ops.iter(), filters, &format!("{prefix}{indent}"));
+        }
+        Op::Promise(Promise {
+            queue,
+            data,
+            receiver,
+        }) => {
+            let callback = match receiver {
+                Callback::Plugin(PluginMessage { plugin, message }) => {
+                    format!("{} -> plugin `{plugin}`", type_of(message))
+                }
+                receiver => format!(
+                    "{} -> voyager",
+                    type_of(&serde_json::to_value(receiver).unwrap())
+                ),
+            };
+
+            writeln!(
+                out,
+                "{prefix}{branch}promise, aggregating the data of {} op(s){} into callback{callback}",
+                queue.len(),
+                if data.is_empty() {
+                    String::new()
+                } else {
+                    format!(" (with {} already resolved)", data.len())
+                },
+            )
+            .unwrap();
+
+            explain_children(out, queue.iter(), filters, &format!("{prefix}{indent}"));
+        }
+        Op::Void(op) => {
+            writeln!(out, "{prefix}{branch}void (discarding any data)").unwrap();
+            explain_children(
+                out,
+                [&**op].into_iter(),
+                filters,
+                &format!("{prefix}{indent}"),
+            );
+        }
+        Op::Noop => writeln!(out, "{prefix}{branch}noop").unwrap(),
+    }
+}
+
+fn explain_children<'a>(
+    out: &mut String,
+    ops: impl ExactSizeIterator<Item = &'a Op<VoyagerMessage>>,
+    filters: &InterestFilters,
+    prefix: &str,
+) {
+    let len = ops.len();
+
+    for (idx, op) in ops.enumerate() {
+        let (branch, indent) = if idx + 1 == len {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+
+        explain_op(out, op, filters, prefix, branch, indent);
+    }
+}
+
+/// The plugins whose interest filters match `op`, if any.
+fn routing(op: &Op<VoyagerMessage>, filters: &InterestFilters) -> Option<String> {
+    match filters.check_interest(op) {
+        FilterResult::Interest(Interest { tags, remove }) => Some(format!(
+            "{} ({})",
+            tags.iter()
+                .map(|tag| format!("plugin `{tag}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            if remove { "take" } else { "copy" }
+        )),
+        FilterResult::NoInterest => None,
+    }
+}
+
+/// The `@type` of a serialized message, formatted as `(type)`.
+fn type_of(value: &serde_json::Value) -> String {
+    value
+        .get("@type")
+        .and_then(|ty| ty.as_str())
+        .map(|ty| format!("({ty})"))
+        .unwrap_or_default()
+}
@@ -1,15 +1,18 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
 use jaq_core::{
     load::{Arena, File, Loader},
     Ctx, Filter, Native, RcIter,
 };
 use jaq_json::Val;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use tracing::{error, instrument, trace};
 use voyager_rpc::types::PluginInfo;
 use voyager_vm::{
     filter::{FilterResult, Interest, InterestFilter},
+    lane::{WeightedLanes, DEFAULT_LANE},
     Op,
 };
 
@@ -18,47 +21,75 @@ use crate::VoyagerMessage;
 #[derive(Clone)]
 pub struct InterestFilters {
     pub filters: Vec<(Filter<Native<Val>>, String)>,
+    /// The lane filters, in priority order. An op is queued in the first lane whose filter returns
+    /// `true`, or in the [`DEFAULT_LANE`] if none match.
+    pub lanes: Vec<(Filter<Native<Val>>, String)>,
+    /// `None` if no lanes are configured.
+    pub weighted_lanes: Option<Arc<WeightedLanes>>,
 }
 
 impl InterestFilters {
-    pub fn new(filters: Vec<PluginInfo>) -> anyhow::Result<Self> {
+    pub fn new(filters: Vec<PluginInfo>, lanes: Vec<LaneConfig>) -> anyhow::Result<Self> {
+        for (idx, lane) in lanes.iter().enumerate() {
+            if lanes[..idx].iter().any(|prev| prev.name == lane.name) {
+                return Err(anyhow!(
+                    "multiple lanes configured with name `{}`",
+                    lane.name
+                ));
+            }
+        }
+
+        let weighted_lanes = (!lanes.is_empty()).then(|| {
+            Arc::new(WeightedLanes::new(
+                lanes.iter().map(|lane| (lane.name.clone(), lane.weight)),
+            ))
+        });
+
         Ok(Self {
             filters: filters
                 .into_iter()
                 .map(make_filter)
                 .collect::<anyhow::Result<_>>()?,
+            lanes: lanes
+                .into_iter()
+                .map(|LaneConfig { name, filter, .. }| {
+                    compile_filter(&filter, &name)
+                        .with_context(|| format!("invalid filter for lane `{name}`"))
+                        .map(|filter| (filter, name))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            weighted_lanes,
         })
     }
 }
 
+/// A lane in the queue. Lanes are checked in the order they are configured in, weighted by
+/// [`Self::weight`]; see [`WeightedLanes`] for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LaneConfig {
+    pub name: String,
+    /// A jq filter that is run on each op as it is queued. The op is queued in this lane if the
+    /// filter returns `true`.
+    pub filter: String,
+    /// How often this lane is served relative to the other lanes while they all have items ready.
+    /// A lane with a weight of `0` is only served once all other lanes are empty.
+    #[serde(default = "default_lane_weight")]
+    pub weight: u64,
+}
+
+#[must_use]
+pub const fn default_lane_weight() -> u64 {
+    1
+}
+
 pub fn make_filter(
     PluginInfo {
         name,
         interest_filter,
     }: PluginInfo,
 ) -> anyhow::Result<(Filter<Native<Val>>, String)> {
-    fn map_jq_errs(es: Vec<(File<&str, &str>, impl Debug)>) -> anyhow::Error {
-        anyhow!(es
-            .iter()
-            .map(|(file, error)| format!("{}: {:?}", file.path, error))
-            .collect::<Vec<_>>()
-            .join(","))
-    }
-
-    let program = File {
-        code: &*interest_filter,
-        path: &*name,
-    };
-
-    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
-    let arena = Arena::default();
-
-    let modules = loader.load(&arena, program).map_err(map_jq_errs)?;
-
-    let filter = jaq_core::Compiler::default()
-        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
-        .compile(modules)
-        .map_err(map_jq_errs)?;
+    let filter = compile_filter(&interest_filter, &name)?;
 
     // let mut ctx = ParseCtx::new(["PLUGIN_NAME".to_owned()].into());
     // ctx.insert_natives(jaq_core::core());
@@ -103,6 +134,29 @@ pub fn make_filter(
     Ok((filter, name))
 }
 
+/// Compile a jq filter. `path` is only used in error messages.
+pub fn compile_filter(code: &str, path: &str) -> anyhow::Result<Filter<Native<Val>>> {
+    fn map_jq_errs(es: Vec<(File<&str, &str>, impl Debug)>) -> anyhow::Error {
+        anyhow!(es
+            .iter()
+            .map(|(file, error)| format!("{}: {:?}", file.path, error))
+            .collect::<Vec<_>>()
+            .join(","))
+    }
+
+    let program = File { code, path };
+
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+    let arena = Arena::default();
+
+    let modules = loader.load(&arena, program).map_err(map_jq_errs)?;
+
+    jaq_core::Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(map_jq_errs)
+}
+
 impl InterestFilter<VoyagerMessage> for InterestFilters {
     fn check_interest<'a>(&'a self, op: &Op<VoyagerMessage>) -> FilterResult<'a> {
         let msg_json = Val::from(serde_json::to_value(op.clone()).unwrap());
@@ -130,6 +184,37 @@ impl InterestFilter<VoyagerMessage> for InterestFilters {
             })
         }
     }
+
+    fn lane<'a>(&'a self, op: &Op<VoyagerMessage>) -> &'a str {
+        if self.lanes.is_empty() {
+            return DEFAULT_LANE;
+        }
+
+        let msg_json = Val::from(serde_json::to_value(op.clone()).unwrap());
+
+        self.lanes
+            .iter()
+            .find(|(filter, lane)| {
+                matches!(
+                    run_filter(filter, lane, msg_json.clone()),
+                    Ok(JaqFilterResult::Take(_))
+                )
+            })
+            .map_or(DEFAULT_LANE, |(_, lane)| lane)
+    }
+
+    fn lane_order(&self) -> Vec<&str> {
+        self.weighted_lanes
+            .as_deref()
+            .map(WeightedLanes::next_order)
+            .unwrap_or_default()
+    }
+
+    fn lane_served(&self, lane: &str) {
+        if let Some(weighted_lanes) = self.weighted_lanes.as_deref() {
+            weighted_lanes.served(lane);
+        }
+    }
 }
 
 #[instrument(
@@ -0,0 +1,258 @@
+//! Health checking of the plugin and module processes.
+
+use std::{
+    num::NonZeroU32,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use opentelemetry::{
+    metrics::{Counter, Gauge},
+    KeyValue,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use unionlabs::ErrorReporter;
+use voyager_plugin_protocol::WorkerClient;
+use voyager_rpc::types::{HealthStatus, WorkerHealthInfo};
+use voyager_vm::now;
+
+/// How the plugins and modules are checked for liveness, and what happens to them when they stop
+/// responding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct HealthConfig {
+    /// How often each plugin and module is pinged.
+    #[serde(default = "default_ping_interval_seconds")]
+    pub ping_interval_seconds: u64,
+    /// How long a ping can take before it is considered failed.
+    #[serde(default = "default_ping_timeout_seconds")]
+    pub ping_timeout_seconds: u64,
+    /// The amount of consecutive failed pings after which a plugin or module is considered
+    /// unhealthy and is restarted.
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: NonZeroU32,
+    /// Restarts of a worker that stays unhealthy are backed off exponentially, starting at 1
+    /// second and capped at this value.
+    #[serde(default = "default_max_restart_backoff_seconds")]
+    pub max_restart_backoff_seconds: u64,
+    /// How long calls and callbacks to an unhealthy plugin are deferred for before being tried
+    /// again.
+    #[serde(default = "default_defer_seconds")]
+    pub defer_seconds: u64,
+}
+
+impl Default for HealthConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval_seconds: default_ping_interval_seconds(),
+            ping_timeout_seconds: default_ping_timeout_seconds(),
+            failure_threshold: default_failure_threshold(),
+            max_restart_backoff_seconds: default_max_restart_backoff_seconds(),
+            defer_seconds: default_defer_seconds(),
+        }
+    }
+}
+
+#[must_use]
+pub const fn default_ping_interval_seconds() -> u64 {
+    10
+}
+
+#[must_use]
+pub const fn default_ping_timeout_seconds() -> u64 {
+    5
+}
+
+#[must_use]
+pub const fn default_failure_threshold() -> NonZeroU32 {
+    NonZeroU32::new(3).unwrap()
+}
+
+#[must_use]
+pub const fn default_max_restart_backoff_seconds() -> u64 {
+    60
+}
+
+#[must_use]
+pub const fn default_defer_seconds() -> u64 {
+    5
+}
+
+/// The health of a single plugin or module, shared between its supervisor and the handlers that
+/// make calls to it.
+///
+/// A worker starts out as [`HealthStatus::Starting`], and is not restarted until it has responded
+/// to a ping at least once; plugins commonly do slow work (such as connecting to a chain) before
+/// they start serving requests, and a worker that crashes during startup is already respawned by
+/// the process supervisor. Once a worker has been healthy, failing
+/// [`HealthConfig::failure_threshold`] pings in a row marks it as unhealthy, after which it stays
+/// unhealthy (and is restarted again if it keeps failing) until a ping succeeds.
+#[derive(Clone)]
+pub(crate) struct WorkerHealth {
+    name: String,
+    state: Arc<Mutex<HealthState>>,
+
+    healthy_metric: Gauge<u64>,
+    failed_pings_metric: Counter<u64>,
+    restarts_metric: Counter<u64>,
+    deferred_calls_metric: Counter<u64>,
+}
+
+#[derive(Debug)]
+struct HealthState {
+    status: HealthStatus,
+    consecutive_failures: u32,
+    /// Restarts since the worker was last healthy, used to back off restarts.
+    consecutive_restarts: u32,
+    restarts: u64,
+    last_healthy_at: Option<u64>,
+}
+
+impl WorkerHealth {
+    pub(crate) fn new(name: String) -> Self {
+        let meter = opentelemetry::global::meter("voyager");
+
+        Self {
+            name,
+            state: Arc::new(Mutex::new(HealthState {
+                status: HealthStatus::Starting,
+                consecutive_failures: 0,
+                consecutive_restarts: 0,
+                restarts: 0,
+                last_healthy_at: None,
+            })),
+            healthy_metric: meter
+                .u64_gauge("worker.healthy")
+                .with_description("Whether a plugin or module is responding to health checks")
+                .build(),
+            failed_pings_metric: meter
+                .u64_counter("worker.failed_pings")
+                .with_description("Health check pings to a plugin or module that failed")
+                .build(),
+            restarts_metric: meter
+                .u64_counter("worker.health_restarts")
+                .with_description("Restarts of a plugin or module due to failing health checks")
+                .build(),
+            deferred_calls_metric: meter
+                .u64_counter("worker.deferred_calls")
+                .with_description("Plugin calls and callbacks deferred due to an unhealthy plugin")
+                .build(),
+        }
+    }
+
+    /// Returns `true` if calls to this worker should be held back.
+    pub(crate) fn is_unhealthy(&self) -> bool {
+        self.state().status == HealthStatus::Unhealthy
+    }
+
+    pub(crate) fn info(&self) -> WorkerHealthInfo {
+        let state = self.state();
+
+        WorkerHealthInfo {
+            name: self.name.clone(),
+            status: state.status,
+            consecutive_failures: state.consecutive_failures,
+            restarts: state.restarts,
+            last_healthy_at: state.last_healthy_at,
+        }
+    }
+
+    /// Record that a call or callback to this worker was deferred due to it being unhealthy.
+    pub(crate) fn record_deferred(&self) {
+        self.deferred_calls_metric.add(1, &self.attributes());
+    }
+
+    /// Ping the worker through `client` every [`HealthConfig::ping_interval_seconds`], returning
+    /// once it is considered unhealthy.
+    pub(crate) async fn monitor(&self, client: &WorkerClient, config: &HealthConfig) {
+        loop {
+            tokio::time::sleep(Duration::from_secs(config.ping_interval_seconds)).await;
+
+            let res = tokio::time::timeout(
+                Duration::from_secs(config.ping_timeout_seconds),
+                client.ping(),
+            )
+            .await;
+
+            let error = match res {
+                Ok(Ok(())) => {
+                    self.record_healthy();
+                    continue;
+                }
+                Ok(Err(error)) => ErrorReporter(error).to_string(),
+                Err(_) => "timed out".to_owned(),
+            };
+
+            if self.record_failure(config.failure_threshold) {
+                return;
+            }
+
+            warn!(name = %self.name, %error, "health check failed");
+        }
+    }
+
+    /// Record that the worker is being restarted, returning how long to wait before starting it
+    /// again.
+    pub(crate) fn restart(&self, config: &HealthConfig) -> Duration {
+        let mut state = self.state();
+
+        state.consecutive_failures = 0;
+        state.consecutive_restarts = state.consecutive_restarts.saturating_add(1);
+        state.restarts += 1;
+
+        self.restarts_metric.add(1, &self.attributes());
+
+        Duration::from_secs(
+            1_u64
+                .checked_shl(state.consecutive_restarts - 1)
+                .unwrap_or(u64::MAX)
+                .min(config.max_restart_backoff_seconds),
+        )
+    }
+
+    fn record_healthy(&self) {
+        let mut state = self.state();
+
+        if state.status != HealthStatus::Healthy {
+            info!(name = %self.name, "{} is healthy", self.name);
+        }
+
+        state.status = HealthStatus::Healthy;
+        state.consecutive_failures = 0;
+        state.consecutive_restarts = 0;
+        state.last_healthy_at = Some(now());
+
+        self.healthy_metric.record(1, &self.attributes());
+    }
+
+    /// Returns `true` if this failure makes the worker unhealthy.
+    fn record_failure(&self, failure_threshold: NonZeroU32) -> bool {
+        let mut state = self.state();
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+
+        self.failed_pings_metric.add(1, &self.attributes());
+
+        if state.status == HealthStatus::Starting
+            || state.consecutive_failures < failure_threshold.get()
+        {
+            return false;
+        }
+
+        state.status = HealthStatus::Unhealthy;
+
+        self.healthy_metric.record(0, &self.attributes());
+
+        true
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, HealthState> {
+        self.state.lock().expect("mutex is poisoned")
+    }
+
+    fn attributes(&self) -> [KeyValue; 1] {
+        [KeyValue::new("name", self.name.clone())]
+    }
+}
@@ -7,6 +7,7 @@ use voyager_primitives::{IbcSpec, IbcSpecId};
 use voyager_rpc::FATAL_JSONRPC_ERROR_CODE;
 use voyager_types::RawClientId;
 
+#[derive(Clone)]
 pub struct IbcSpecHandlers {
     pub(crate) handlers: HashMap<IbcSpecId, IbcSpecHandler>,
 }
@@ -35,6 +36,7 @@ impl IbcSpecHandlers {
 }
 
 /// A type-erased version of the methods on [`IbcSpec`] (essentially a vtable).
+#[derive(Clone)]
 pub struct IbcSpecHandler {
     pub client_state_path: fn(RawClientId) -> anyhow::Result<Value>,
     pub consensus_state_path: fn(RawClientId, String) -> anyhow::Result<Value>,
@@ -1,28 +1,27 @@
 #![feature(trait_alias, slice_partition_dedup)]
 
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::VecDeque,
     net::{IpAddr, Ipv4Addr, SocketAddr},
     panic::AssertUnwindSafe,
-    sync::{Arc, OnceLock},
+    pin::pin,
+    sync::Arc,
     time::Duration,
 };
 
 use anyhow::{anyhow, Context as _};
 use futures::{
-    future::{self, BoxFuture},
+    future::BoxFuture,
     stream::{self, FuturesUnordered},
     Future, FutureExt, StreamExt, TryFutureExt, TryStreamExt,
 };
 use itertools::Itertools;
 use jsonrpsee::core::middleware::{RpcServiceBuilder, RpcServiceT};
 use opentelemetry::{metrics::Counter, KeyValue};
-use serde::Serialize;
 use serde_json::Value;
+use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
-use tracing::{
-    debug, debug_span, error, info, info_span, instrument, trace, trace_span, warn, Instrument,
-};
+use tracing::{debug, error, info, info_span, instrument, trace, trace_span, Instrument};
 use unionlabs::{traits::Member, ErrorReporter};
 use voyager_message::{
     call::{
@@ -34,43 +33,54 @@ use voyager_message::{
     PluginMessage, VoyagerMessage,
 };
 use voyager_plugin_protocol::{
-    coordinator_server, worker_child_process, WithId, WorkerClient, INVALID_CONFIG_EXIT_CODE,
+    record::{Recorder, Recording},
+    WithId, INVALID_CONFIG_EXIT_CODE,
 };
 use voyager_primitives::{ClientInfo, IbcSpec, QueryHeight};
 use voyager_rpc::{
     error_object_to_queue_error, json_rpc_error_to_queue_error, missing_state,
-    types::{
-        ClientBootstrapModuleInfo, ClientModuleInfo, FinalityModuleInfo, PluginInfo,
-        ProofModuleInfo, StateModuleInfo,
-    },
-    ClientModuleClient, PluginClient, VoyagerRpcServer,
+    types::{PluginInfo, ReloadResponse},
+    ClientModuleClient, ConfigRpcServer, PluginClient, QueueRpcServer, VoyagerRpcServer,
 };
 use voyager_vm::{
     defer,
     in_memory::InMemoryQueue,
     noop, now,
     pass::{Pass, PassResult},
+    promise,
+    schedule::{Schedule, Scheduler},
     seq, BoxDynError, HandlerFactory, ItemId, Op, Queue, QueueError,
 };
 
 use crate::{
-    context::{Context, ModuleConfig, ModulesConfig, PluginConfig},
+    context::{ContextHandle, ModulesConfig, PluginConfig},
     equivalent_chain_ids::EquivalentChainIds,
-    filter::InterestFilters,
+    filter::{InterestFilters, LaneConfig},
+    health::HealthConfig,
     ibc_spec_handlers::IbcSpecHandlers,
-    server::Server,
+    reload::{ConfigLoader, ReloadableConfig, Reloader},
+    server::{ConfigServer, QueueServer, Server},
+    throttle::{ChainLimitsConfig, Throttle},
+    workers::Workers,
 };
 
 pub mod cache;
 pub mod context;
+pub mod dry_run;
 pub mod equivalent_chain_ids;
+pub mod explain;
 pub mod filter;
+pub mod health;
 pub mod ibc_spec_handlers;
+pub mod reload;
+pub mod replay;
 pub mod server;
+pub mod throttle;
+mod workers;
 
 pub struct Engine<Q: Queue<VoyagerMessage>> {
-    context: Arc<OnceLock<Context>>,
-    interest_filters: InterestFilters,
+    context: ContextHandle,
+    reloader: Reloader,
     cache: cache::Cache,
     queue: Q,
     cancellation_token: CancellationToken,
@@ -79,6 +89,7 @@ pub struct Engine<Q: Queue<VoyagerMessage>> {
     rest_laddr: SocketAddr,
     rpc_laddr: SocketAddr,
     optimizer_delay_milliseconds: u64,
+    schedules: Vec<Schedule<VoyagerMessage>>,
     // TODO: Make this generic
     rpc_middleware: LoggerMiddlewareLayer,
 }
@@ -98,6 +109,13 @@ impl Engine<InMemoryQueue<VoyagerMessage>> {
             rest_laddr: default_rest_laddr(),
             rpc_laddr: default_rpc_laddr(),
             optimizer_delay_milliseconds: default_optimizer_delay_milliseconds(),
+            schedules: vec![],
+            lanes: vec![],
+            chain_limits: vec![],
+            health_config: HealthConfig::default(),
+            recorder: None,
+            replay: None,
+            config_loader: None,
             queue_config: (),
         }
     }
@@ -122,9 +140,30 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
         Server::new(self.cache.clone(), self.context.clone())
     }
 
+    /// The interest filters of the currently loaded plugins.
+    pub fn interest_filters(&self) -> InterestFilters {
+        self.reloader.interest_filters.borrow().clone()
+    }
+
+    /// Reload the plugins and modules of this engine with `config`. See [`Engine::reload_config`].
+    pub async fn reload(&self, config: ReloadableConfig) -> anyhow::Result<ReloadResponse> {
+        self.reloader.reload(config).await
+    }
+
+    /// Reload the plugins and modules of this engine with the config returned by the
+    /// [`ConfigLoader`] this engine was built with.
+    ///
+    /// Only the plugins and modules whose config changed are restarted. Items that are being
+    /// processed while the config is reloaded finish with the plugins and modules they started
+    /// with (or are retried, if a plugin or module they depend on is restarted underneath them);
+    /// the queue is never stopped.
+    pub async fn reload_config(&self) -> anyhow::Result<ReloadResponse> {
+        self.reloader.reload_config().await
+    }
+
     #[allow(clippy::too_many_lines)]
     pub fn run(&self) -> impl Future<Output = ()> + use<'_, Q> {
-        let queue_rx = api::run(&self.rest_laddr);
+        let queue_rx = api::run(&self.rest_laddr, self.queue.clone());
 
         let mut tasks = FuturesUnordered::<BoxFuture<Result<Result<(), BoxDynError>, _>>>::new();
 
@@ -144,7 +183,11 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
 
                     let addr = server.local_addr()?;
 
-                    let handle = server.start(self.server().into_rpc());
+                    let mut rpc_module = self.server().into_rpc();
+                    rpc_module.merge(QueueServer::new(self.queue.clone()).into_rpc())?;
+                    rpc_module.merge(ConfigServer::new(self.reloader.clone()).into_rpc())?;
+
+                    let handle = server.start(rpc_module);
 
                     info!("rpc listening on {addr}");
 
@@ -170,7 +213,7 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
                             serde_json::to_value(&op).unwrap()
                         );
 
-                        self.queue.enqueue(op, &self.interest_filters).await?;
+                        self.queue.enqueue(op, &self.interest_filters()).await?;
                     }
 
                     Ok(())
@@ -178,90 +221,33 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
                 .catch_unwind(),
             ));
 
-            info!("spawning {} workers", self.num_workers);
+            tasks.push(Box::pin(
+                AssertUnwindSafe(async {
+                    let mut interest_filters = self.reloader.interest_filters.subscribe();
 
-            for id in 0..self.num_workers {
-                debug!("spawning worker {id}");
+                    loop {
+                        let current = interest_filters.borrow_and_update().clone();
 
-                tasks.push(Box::pin(
-                    AssertUnwindSafe(
-                        voyager_vm::engine::Engine::new(
-                            self.server(),
-                            &self.queue,
-                            &self.interest_filters,
-                        )
-                        .run()
-                        .for_each(async |res| match res {
-                            Ok(data) => {
-                                debug!(
-                                    data = %serde_json::to_value(&data).unwrap(),
-                                    "received data outside of an aggregation",
-                                );
-                            }
-                            Err(error) => {
-                                error!(
-                                    error = %ErrorReporter(&*error),
-                                    "error processing message"
-                                );
-                            }
-                        })
-                        .map(Ok)
-                        .instrument(trace_span!("engine task", %id)),
-                    )
-                    .catch_unwind(),
-                ));
-            }
+                        let drain = CancellationToken::new();
 
-            for (_filter, plugin_name) in &self.interest_filters.filters {
-                info!(%plugin_name, "spawning optimizer");
-
-                tasks.push(Box::pin(
-                    AssertUnwindSafe(
-                        async {
-                            let plugin_name = plugin_name.clone();
-
-                            let pass = PluginOptPass::new(
-                                self.context
-                                    .get()
-                                    .unwrap()
-                                    .plugin(&plugin_name)
-                                    .expect("plugin exists")
-                                    .client(),
-                            );
+                        let mut processing = pin!(self.process(&current, &drain));
 
-                            loop {
-                                trace!("optimizing");
-
-                                let res = self
-                                    .queue
-                                    .optimize(&plugin_name, &self.interest_filters, &pass)
-                                    .await
-                                    .map_err(|e| {
-                                        e.map_either::<_, _, BoxDynError, BoxDynError>(
-                                            |x| Box::new(x),
-                                            |x| Box::new(x),
-                                        )
-                                        .into_inner()
-                                    });
+                        tokio::select! {
+                            res = &mut processing => break res,
+                            Ok(()) = interest_filters.changed() => {
+                                info!("config reloaded, restarting workers");
 
-                                if let Err(error) = res {
-                                    error!(
-                                        error = %ErrorReporter(&*error),
-                                        "optimization pass returned with error"
-                                    );
-                                }
+                                drain.cancel();
 
-                                tokio::time::sleep(std::time::Duration::from_millis(
-                                    self.optimizer_delay_milliseconds,
-                                ))
-                                .await;
+                                if let Err(error) = processing.await {
+                                    break Err(error);
+                                }
                             }
                         }
-                        .instrument(info_span!("optimize", %plugin_name)), // .instrument(trace_span!("optimize_verbose", filter = %filter.to_string())),
-                    )
-                    .catch_unwind(),
-                ));
-            }
+                    }
+                })
+                .catch_unwind(),
+            ));
         }
 
         self.cancellation_token
@@ -288,6 +274,157 @@ impl<Q: Queue<VoyagerMessage>> Engine<Q> {
             })
             .map(|_| ())
     }
+
+    /// Run the workers, optimizers, and scheduler with `interest_filters`, returning once any of
+    /// them exit with an error or panic.
+    ///
+    /// Once `drain` is cancelled, the workers finish the items they are currently processing and
+    /// all tasks exit, after which this returns `Ok(())`.
+    fn process<'a>(
+        &'a self,
+        interest_filters: &'a InterestFilters,
+        drain: &'a CancellationToken,
+    ) -> impl Future<Output = Result<(), BoxDynError>> + use<'a, Q> {
+        let mut tasks = FuturesUnordered::<BoxFuture<Result<Result<(), BoxDynError>, _>>>::new();
+
+        info!("spawning {} workers", self.num_workers);
+
+        for id in 0..self.num_workers {
+            debug!("spawning worker {id}");
+
+            let engine =
+                voyager_vm::engine::Engine::new(self.server(), &self.queue, interest_filters);
+
+            tasks.push(Box::pin(
+                AssertUnwindSafe(
+                    async move {
+                        while !drain.is_cancelled() {
+                            match engine.step().await {
+                                Ok(Some(data)) => {
+                                    debug!(
+                                        data = %serde_json::to_value(&data).unwrap(),
+                                        "received data outside of an aggregation",
+                                    );
+                                }
+                                Ok(None) => {}
+                                Err(error) => {
+                                    error!(
+                                        error = %ErrorReporter(&*error),
+                                        "error processing message"
+                                    );
+                                    break;
+                                }
+                            }
+                        }
+
+                        Ok(())
+                    }
+                    .instrument(trace_span!("engine task", %id)),
+                )
+                .catch_unwind(),
+            ));
+        }
+
+        let context = self.context.get().expect("engine is built");
+
+        for (_filter, plugin_name) in &interest_filters.filters {
+            info!(%plugin_name, "spawning optimizer");
+
+            let context = context.clone();
+
+            tasks.push(Box::pin(
+                AssertUnwindSafe(
+                    async move {
+                        let pass =
+                            PluginOptPass::new(context.plugin(plugin_name).expect("plugin exists"));
+
+                        while !drain.is_cancelled() {
+                            // the pass would only fail while the plugin is down, leaving the ops
+                            // in the optimization queue until the next pass anyways
+                            if context.unhealthy_plugin(plugin_name).is_some() {
+                                trace!("plugin is unhealthy, skipping optimization pass");
+
+                                drain
+                                    .run_until_cancelled(tokio::time::sleep(
+                                        std::time::Duration::from_secs(
+                                            context.health_config().defer_seconds,
+                                        ),
+                                    ))
+                                    .await;
+
+                                continue;
+                            }
+
+                            trace!("optimizing");
+
+                            let res = self
+                                .queue
+                                .optimize(plugin_name, interest_filters, &pass)
+                                .await
+                                .map_err(|e| {
+                                    e.map_either::<_, _, BoxDynError, BoxDynError>(
+                                        |x| Box::new(x),
+                                        |x| Box::new(x),
+                                    )
+                                    .into_inner()
+                                });
+
+                            if let Err(error) = res {
+                                error!(
+                                    error = %ErrorReporter(&*error),
+                                    "optimization pass returned with error"
+                                );
+                            }
+
+                            drain
+                                .run_until_cancelled(tokio::time::sleep(
+                                    std::time::Duration::from_millis(
+                                        self.optimizer_delay_milliseconds,
+                                    ),
+                                ))
+                                .await;
+                        }
+
+                        Ok(())
+                    }
+                    .instrument(info_span!("optimize", %plugin_name)), // .instrument(trace_span!("optimize_verbose", filter = %filter.to_string())),
+                )
+                .catch_unwind(),
+            ));
+        }
+
+        if !self.schedules.is_empty() {
+            info!("spawning scheduler for {} schedules", self.schedules.len());
+
+            tasks.push(Box::pin(
+                AssertUnwindSafe(
+                    drain
+                        .run_until_cancelled(
+                            Scheduler::new(self.schedules.clone(), &self.queue, interest_filters)
+                                .run(),
+                        )
+                        .map(|res| res.unwrap_or(Ok(())))
+                        .instrument(info_span!("scheduler")),
+                )
+                .catch_unwind(),
+            ));
+        }
+
+        async move {
+            while let Some(res) = tasks.next().await {
+                match res {
+                    Ok(Ok(())) => {
+                        info!("task exited gracefully");
+                    }
+                    Ok(Err(error)) => return Err(error),
+                    // can't do anything with dyn Any
+                    Err(_err) => return Err("task panicked".into()),
+                }
+            }
+
+            Ok(())
+        }
+    }
 }
 
 pub struct EngineBuilder<Q: Queue<VoyagerMessage> = InMemoryQueue<VoyagerMessage>> {
@@ -303,6 +440,13 @@ pub struct EngineBuilder<Q: Queue<VoyagerMessage> = InMemoryQueue<VoyagerMessage
     rest_laddr: SocketAddr,
     rpc_laddr: SocketAddr,
     optimizer_delay_milliseconds: u64,
+    schedules: Vec<Schedule<VoyagerMessage>>,
+    lanes: Vec<LaneConfig>,
+    chain_limits: Vec<ChainLimitsConfig>,
+    health_config: HealthConfig,
+    recorder: Option<Recorder>,
+    replay: Option<Recording>,
+    config_loader: Option<ConfigLoader>,
 }
 
 impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
@@ -370,6 +514,57 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
         }
     }
 
+    pub fn with_schedules(self, schedules: Vec<Schedule<VoyagerMessage>>) -> Self {
+        Self { schedules, ..self }
+    }
+
+    pub fn with_lanes(self, lanes: Vec<LaneConfig>) -> Self {
+        Self { lanes, ..self }
+    }
+
+    pub fn with_chain_limits(self, chain_limits: Vec<ChainLimitsConfig>) -> Self {
+        Self {
+            chain_limits,
+            ..self
+        }
+    }
+
+    pub fn with_health_config(self, health_config: HealthConfig) -> Self {
+        Self {
+            health_config,
+            ..self
+        }
+    }
+
+    /// Record all requests made to plugins and modules, along with their responses.
+    pub fn with_recorder(self, recorder: Recorder) -> Self {
+        Self {
+            recorder: Some(recorder),
+            ..self
+        }
+    }
+
+    /// Serve all requests to plugins and modules from `recording`, instead of spawning them. See
+    /// [`Engine::replay`].
+    pub fn with_replay(self, recording: Recording) -> Self {
+        Self {
+            replay: Some(recording),
+            ..self
+        }
+    }
+
+    /// Allow reloading the plugin and module configuration with [`Engine::reload_config`] and the
+    /// `config_reload` rpc method, reading the new config with `config_loader`.
+    pub fn with_config_loader(
+        self,
+        config_loader: impl Fn() -> anyhow::Result<ReloadableConfig> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            config_loader: Some(Arc::new(config_loader)),
+            ..self
+        }
+    }
+
     pub fn register_ibc_spec_handler<S: IbcSpec>(mut self) -> Self {
         self.ibc_spec_handlers.register::<S>();
         self
@@ -392,6 +587,13 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             rest_laddr: self.rest_laddr,
             rpc_laddr: self.rpc_laddr,
             optimizer_delay_milliseconds: self.optimizer_delay_milliseconds,
+            schedules: self.schedules,
+            lanes: self.lanes,
+            chain_limits: self.chain_limits,
+            health_config: self.health_config,
+            recorder: self.recorder,
+            replay: self.replay,
+            config_loader: self.config_loader,
         }
     }
 }
@@ -400,379 +602,66 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
     pub async fn build(self) -> anyhow::Result<Engine<Q>> {
         let cancellation_token = CancellationToken::new();
 
-        let queue = Q::new(self.queue_config).await?;
-
-        let mut context_inner = Context {
-            state_modules: Default::default(),
-            proof_modules: Default::default(),
-            client_modules: Default::default(),
-            client_bootstrap_modules: Default::default(),
-            finality_modules: Default::default(),
-            chain_consensus_types: Default::default(),
-            client_consensus_types: Default::default(),
-            plugins: Default::default(),
-            equivalent_chain_ids: self.equivalent_chain_ids,
-            ibc_spec_handlers: self.ibc_spec_handlers,
-        };
-
-        let logger_middleware_layer = LoggerMiddlewareLayer::new();
-
-        let context = Arc::new(OnceLock::new());
-
-        let mut interest_filters = HashMap::new();
-
-        let cache = cache::Cache::new(self.cache_config);
+        for (idx, schedule) in self.schedules.iter().enumerate() {
+            schedule
+                .trigger
+                .validate()
+                .with_context(|| format!("invalid trigger for schedule `{}`", schedule.name))?;
 
-        info!("spawning {} plugins", self.plugin_configs.len());
-
-        stream::iter(self.plugin_configs.into_iter().enumerate())
-            .filter(|(_, plugin_config)| {
-                future::ready(if !plugin_config.enabled {
-                    info!(
-                        plugin_path = %plugin_config.path.to_string_lossy(),
-                        "plugin is not enabled, skipping"
-                    );
-                    false
-                } else {
-                    true
-                })
-            })
-            .zip(stream::repeat(Server::new(cache.clone(), context.clone())))
-            .then(async |((idx, plugin_config), server)| {
-                let plugin_info = info_span!("get_plugin_info", %idx)
-                    .in_scope(|| get_plugin_info(&plugin_config))?;
-
-                debug!("starting rpc server for plugin {}", plugin_info.name);
-
-                tokio::spawn(
-                    coordinator_server(&plugin_info.name, server, logger_middleware_layer.clone())
-                        .await?,
-                );
-
-                debug!("started rpc server for plugin {}", plugin_info.name);
-
-                Ok((idx, plugin_config, plugin_info))
-            })
-            .try_for_each_concurrent(
-                None,
-                |(
-                    idx,
-                    plugin_config,
-                    PluginInfo {
-                        name,
-                        interest_filter,
-                    },
-                )| {
-                    debug!("registering plugin {}", name);
-
-                    tokio::spawn(worker_child_process(
-                        name.clone(),
-                        plugin_config.path,
-                        cancellation_token.clone(),
-                        [plugin_config.config.to_string()]
-                            .into_iter()
-                            .chain(self.metrics_endpoint.clone()),
-                    ));
-
-                    let rpc_client = WorkerClient::new(&name, self.ipc_client_request_timeout);
-
-                    let prev = context_inner
-                        .plugins
-                        .insert(name.clone(), rpc_client.clone());
-
-                    if prev.is_some() {
-                        return future::ready(Err(anyhow!(
-                            "multiple plugins configured with name `{name}`"
-                        )));
-                    }
-
-                    info!("registered plugin {name}");
-
-                    interest_filters.insert((idx, name), interest_filter);
+            if self.schedules[..idx]
+                .iter()
+                .any(|prev| prev.name == schedule.name)
+            {
+                return Err(anyhow!(
+                    "multiple schedules configured with name `{}`",
+                    schedule.name
+                ));
+            }
+        }
 
-                    future::ready(Ok(()))
-                },
-            )
-            .await?;
+        let throttle = Throttle::new(self.chain_limits, &self.equivalent_chain_ids)?;
 
-        modules_startup(
-            self.module_configs.state,
-            logger_middleware_layer.clone(),
-            cancellation_token.clone(),
-            Server::new(cache.clone(), context.clone()),
-            self.ipc_client_request_timeout,
-            |info| info.id(),
-            |StateModuleInfo {
-                 chain_id,
-                 ibc_spec_id,
-             },
-             rpc_client| {
-                for equivalent_chain_id in context_inner
-                    .equivalent_chain_ids
-                    .equivalents(chain_id)
-                    .chain([chain_id])
-                {
-                    let prev = context_inner.state_modules.insert(
-                        (equivalent_chain_id.clone(), ibc_spec_id.clone()),
-                        rpc_client.clone(),
-                    );
-
-                    if prev.is_some() {
-                        return Err(anyhow!(
-                            "multiple state modules configured for chain id \
-                            `{equivalent_chain_id}` and IBC version `{ibc_spec_id}`",
-                        ));
-                    }
-                }
+        let queue = Q::new(self.queue_config).await?;
 
-                Ok(())
-            },
-            self.metrics_endpoint.clone(),
-        )
-        .await?;
+        let logger_middleware_layer = LoggerMiddlewareLayer::new();
 
-        modules_startup(
-            self.module_configs.proof,
-            logger_middleware_layer.clone(),
-            cancellation_token.clone(),
-            Server::new(cache.clone(), context.clone()),
-            self.ipc_client_request_timeout,
-            |info| info.id(),
-            |ProofModuleInfo {
-                 chain_id,
-                 ibc_spec_id,
-             },
-             rpc_client| {
-                for equivalent_chain_id in context_inner
-                    .equivalent_chain_ids
-                    .equivalents(chain_id)
-                    .chain([chain_id])
-                {
-                    let prev = context_inner.proof_modules.insert(
-                        (equivalent_chain_id.clone(), ibc_spec_id.clone()),
-                        rpc_client.clone(),
-                    );
-
-                    if prev.is_some() {
-                        return Err(anyhow!(
-                            "multiple proof modules configured for chain id \
-                            `{equivalent_chain_id}` and IBC version `{ibc_spec_id}`",
-                        ));
-                    }
-                }
+        let context = ContextHandle::default();
 
-                Ok(())
-            },
-            self.metrics_endpoint.clone(),
-        )
-        .await?;
+        let cache = cache::Cache::new(self.cache_config, throttle);
 
-        modules_startup(
-            self.module_configs.consensus,
-            logger_middleware_layer.clone(),
-            cancellation_token.clone(),
-            Server::new(cache.clone(), context.clone()),
+        let mut workers = Workers::new(
             self.ipc_client_request_timeout,
-            |info| info.id(),
-            |FinalityModuleInfo {
-                 chain_id,
-                 consensus_type,
-             },
-             rpc_client| {
-                for equivalent_chain_id in context_inner
-                    .equivalent_chain_ids
-                    .equivalents(chain_id)
-                    .chain([chain_id])
-                {
-                    let prev = context_inner
-                        .finality_modules
-                        .insert(equivalent_chain_id.clone(), rpc_client.clone());
-
-                    if prev.is_some() {
-                        return Err(anyhow!(
-                            "multiple consensus modules configured for chain id `{}`",
-                            equivalent_chain_id
-                        ));
-                    }
-
-                    let None = context_inner
-                        .chain_consensus_types
-                        .insert(equivalent_chain_id.clone(), consensus_type.clone())
-                    else {
-                        unreachable!()
-                    };
-                }
-
-                Ok(())
-            },
-            self.metrics_endpoint.clone(),
-        )
-        .await?;
-
-        modules_startup(
-            self.module_configs.client,
+            self.recorder,
+            self.replay,
+            self.metrics_endpoint,
             logger_middleware_layer.clone(),
-            cancellation_token.clone(),
+            self.health_config,
             Server::new(cache.clone(), context.clone()),
-            self.ipc_client_request_timeout,
-            |info| info.id(),
-            |ClientModuleInfo {
-                 client_type,
-                 consensus_type,
-                 ibc_interface,
-                 ibc_spec_id,
-             },
-             rpc_client| {
-                if !context_inner
-                    .ibc_spec_handlers
-                    .handlers
-                    .contains_key(ibc_spec_id)
-                {
-                    return Err(anyhow!(
-                        "IBC version `{ibc_spec_id}` is not supported in this build of voyager"
-                    ));
-                }
-
-                let prev = context_inner.client_modules.insert(
-                    (
-                        client_type.clone(),
-                        ibc_interface.clone(),
-                        ibc_spec_id.clone(),
-                    ),
-                    rpc_client.clone(),
-                );
-
-                if prev.is_some() {
-                    return Err(anyhow!(
-                        "multiple client modules configured for client \
-                        type `{client_type}`, IBC interface `{ibc_interface}`, \
-                        and IBC version `{ibc_spec_id}`",
-                    ));
-                }
-
-                if let Some(previous_consensus_type) = context_inner
-                    .client_consensus_types
-                    .insert(client_type.clone(), consensus_type.clone())
-                {
-                    if previous_consensus_type != consensus_type {
-                        return Err(anyhow!(
-                            "inconsistency in client consensus types: \
-                            client type `{client_type}` is registered \
-                            as tracking both `{previous_consensus_type}` \
-                            and `{consensus_type}`"
-                        ));
-                    }
-                }
-
-                Ok(())
-            },
-            self.metrics_endpoint.clone(),
-        )
-        .await?;
-
-        modules_startup(
-            self.module_configs.client_bootstrap,
-            logger_middleware_layer.clone(),
             cancellation_token.clone(),
-            Server::new(cache.clone(), context.clone()),
-            self.ipc_client_request_timeout,
-            |info| info.id(),
-            |ClientBootstrapModuleInfo {
-                 client_type,
-                 chain_id,
-             },
-             rpc_client| {
-                for equivalent_chain_id in context_inner
-                    .equivalent_chain_ids
-                    .equivalents(chain_id)
-                    .chain([chain_id])
-                {
-                    let prev = context_inner.client_bootstrap_modules.insert(
-                        (equivalent_chain_id.clone(), client_type.clone()),
-                        rpc_client.clone(),
-                    );
-
-                    if prev.is_some() {
-                        return Err(anyhow!(
-                            "multiple client bootstrap modules configured for client \
-                            type `{client_type}` and chain id `{equivalent_chain_id}`",
-                        ));
-                    }
-
-                    // TODO: Check consistency with client_consensus_types and chain_id?
-
-                    // if let Some(previous_consensus_type) = modules
-                    //     .client_consensus_types
-                    //     .insert(client_type.clone(), consensus_type.clone())
-                    // {
-                    //     if previous_consensus_type != consensus_type {
-                    //         return Err(anyhow!(
-                    //             "inconsistency in client consensus types: \
-                    //             client type `{client_type}` is registered \
-                    //             as tracking both `{previous_consensus_type}` \
-                    //             and `{consensus_type}`"
-                    //         ));
-                    //     }
-                    // }
-                }
-
-                Ok(())
-            },
-            self.metrics_endpoint.clone(),
-        )
-        .await?;
-
-        info!("checking for plugin health...");
-
-        let futures = context_inner
-            .plugins
-            .iter()
-            .map(|(name, client)| async move {
-                match client
-                    .inner()
-                    .wait_until_connected(Duration::from_secs(10))
-                    .instrument(debug_span!("health check", %name))
-                    .await
-                {
-                    Ok(()) => {
-                        info!("plugin {name} connected")
-                    }
-                    Err(_) => {
-                        warn!("plugin {name} failed to connect after 10 seconds")
-                    }
-                }
-            })
-            .collect::<FuturesUnordered<_>>();
+        );
 
-        match cancellation_token
-            .run_until_cancelled(futures.collect::<Vec<_>>())
-            .await
-        {
-            Some(_) => {}
-            None => return Err(anyhow!("startup error")),
-        }
+        let (context_inner, interest_filters, _) = workers
+            .load(
+                self.plugin_configs,
+                self.module_configs,
+                self.equivalent_chain_ids,
+                self.ibc_spec_handlers,
+                self.lanes.clone(),
+            )
+            .await?;
 
-        context
-            .set(context_inner)
-            .ok()
-            .expect("context is only set once");
+        context.set(context_inner);
 
         info!("started");
 
-        let interest_filters = InterestFilters::new(
-            interest_filters
-                .into_iter()
-                .sorted_unstable_by(|((a, _), _), ((b, _), _)| a.cmp(b))
-                .map(|((_, k), v)| (k, v))
-                .map(|(name, interest_filter)| PluginInfo {
-                    name,
-                    interest_filter,
-                })
-                .collect(),
-        )?;
-
         Ok(Engine {
-            interest_filters,
+            reloader: Reloader {
+                workers: Arc::new(tokio::sync::Mutex::new(workers)),
+                context: context.clone(),
+                interest_filters: Arc::new(watch::Sender::new(interest_filters)),
+                lanes: self.lanes,
+                loader: self.config_loader,
+            },
             cancellation_token,
             context,
             cache,
@@ -781,6 +670,7 @@ impl<Q: Queue<VoyagerMessage>> EngineBuilder<Q> {
             rest_laddr: self.rest_laddr,
             rpc_laddr: self.rpc_laddr,
             optimizer_delay_milliseconds: self.optimizer_delay_milliseconds,
+            schedules: self.schedules,
             rpc_middleware: logger_middleware_layer,
         })
     }
@@ -1110,13 +1000,39 @@ impl voyager_vm::Handler<VoyagerMessage> for Handler {
             }
 
             Call::Plugin(PluginMessage { plugin, message }) => {
+                let throttle = self.server.throttle();
+
+                // don't tie up a worker waiting on a saturated chain, other chains may have work
+                // ready to be processed
+                if let Some(chain_id) = throttle.plugin_chain(&plugin) {
+                    if throttle.is_saturated(chain_id) {
+                        debug!(%plugin, %chain_id, "chain is saturated, deferring plugin call");
+
+                        throttle.record_deferred(chain_id);
+
+                        return Ok(seq([
+                            defer(now() + 1),
+                            voyager_vm::call(PluginMessage { plugin, message }),
+                        ]));
+                    }
+                }
+
+                let context = self.server.context().map_err(error_object_to_queue_error)?;
+
+                // the plugin is being restarted, requeue the call instead of failing it
+                if let Some(health) = context.unhealthy_plugin(&plugin) {
+                    debug!(%plugin, "plugin is unhealthy, deferring plugin call");
+
+                    health.record_deferred();
+
+                    return Ok(seq([
+                        defer(now() + context.health_config().defer_seconds),
+                        voyager_vm::call(PluginMessage { plugin, message }),
+                    ]));
+                }
+
                 Ok(PluginClient::<Value, Value>::call(
-                    &self
-                        .server
-                        .context()
-                        .map_err(error_object_to_queue_error)?
-                        .plugin(&plugin)?
-                        .with_id(self.server.id()),
+                    &context.plugin(&plugin)?.with_id(self.server.id()),
                     message,
                 )
                 .await
@@ -1219,13 +1135,25 @@ impl voyager_vm::Handler<VoyagerMessage> for Handler {
                 }))
             }
             Callback::Plugin(PluginMessage { plugin, message }) => {
+                let context = self.server.context().map_err(error_object_to_queue_error)?;
+
+                if let Some(health) = context.unhealthy_plugin(&plugin) {
+                    debug!(%plugin, "plugin is unhealthy, deferring plugin callback");
+
+                    health.record_deferred();
+
+                    return Ok(seq([
+                        defer(now() + context.health_config().defer_seconds),
+                        promise(
+                            [],
+                            data,
+                            Callback::Plugin(PluginMessage { plugin, message }),
+                        ),
+                    ]));
+                }
+
                 Ok(PluginClient::<Value, Value>::callback(
-                    &self
-                        .server
-                        .context()
-                        .map_err(error_object_to_queue_error)?
-                        .plugin(&plugin)?
-                        .with_id(self.server.id()),
+                    &context.plugin(&plugin)?.with_id(self.server.id()),
                     message,
                     data,
                 )
@@ -1276,95 +1204,11 @@ pub fn get_plugin_info(plugin_config: &PluginConfig) -> anyhow::Result<PluginInf
     Ok(serde_json::from_slice(&output.stdout).unwrap())
 }
 
-#[allow(clippy::too_many_arguments)] // coward
-async fn modules_startup<Info: Serialize + Clone + Unpin + Send + 'static>(
-    configs: Vec<ModuleConfig<Info>>,
-    logger_middleware_layer: LoggerMiddlewareLayer,
-    cancellation_token: CancellationToken,
-    server: Server,
-    ipc_client_request_timeout: Duration,
-    id_f: fn(&Info) -> String,
-    mut push_f: impl FnMut(&Info, WorkerClient) -> anyhow::Result<()>,
-    metrics_endpoint: Option<String>,
-) -> anyhow::Result<()> {
-    stream::iter(configs)
-        .filter(|module_config| {
-            future::ready(if !module_config.enabled {
-                info!(
-                    module_path = %module_config.path.to_string_lossy(),
-                    "module is not enabled, skipping"
-                );
-                false
-            } else {
-                true
-            })
-        })
-        .zip(stream::repeat((
-            server.clone(),
-            logger_middleware_layer.clone(),
-        )))
-        .map::<anyhow::Result<_>, _>(anyhow::Result::Ok)
-        .try_filter_map(
-            |(module_config, (server, logger_middleware_layer))| async move {
-                if !module_config.enabled {
-                    info!(
-                        module_path = %module_config.path.to_string_lossy(),
-                        "module is not enabled, skipping"
-                    );
-                    anyhow::Result::Ok(None)
-                } else {
-                    debug!(
-                        "starting rpc server for module {}",
-                        id_f(&module_config.info)
-                    );
-                    tokio::spawn(
-                        coordinator_server(
-                            &id_f(&module_config.info),
-                            server,
-                            logger_middleware_layer,
-                        )
-                        .await?,
-                    );
-
-                    anyhow::Result::Ok(Some(module_config))
-                }
-            },
-        )
-        .try_collect::<FuturesUnordered<_>>()
-        .await?
-        .into_iter()
-        .try_for_each(|module_config| {
-            let id = id_f(&module_config.info);
-
-            debug!("registering module {}", id);
-
-            tokio::spawn(worker_child_process(
-                id.clone(),
-                module_config.path,
-                cancellation_token.clone(),
-                [
-                    module_config.config.to_string(),
-                    serde_json::to_string(&module_config.info).unwrap(),
-                ]
-                .into_iter()
-                .chain(metrics_endpoint.clone()),
-            ));
-
-            let rpc_client = WorkerClient::new(&id, ipc_client_request_timeout);
-
-            push_f(&module_config.info, rpc_client)?;
-
-            info!("registered module {id}");
-
-            Ok(())
-        })
-}
-
 pub mod api {
     use std::net::SocketAddr;
 
     use axum::{
-        extract::State,
+        extract::{Path, Query, State},
         http::StatusCode,
         routing::{get, post},
         Json,
@@ -1373,16 +1217,34 @@ pub mod api {
         channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
         SinkExt,
     };
+    use serde::Deserialize;
+    use unionlabs::ErrorReporter;
     use voyager_message::VoyagerMessage;
-    use voyager_vm::Op;
+    use voyager_vm::{
+        history::{History, DEFAULT_MAX_DEPTH},
+        ItemId, Op, Queue,
+    };
+
+    #[derive(Clone)]
+    struct ApiState<Q> {
+        sender: UnboundedSender<Op<VoyagerMessage>>,
+        queue: Q,
+    }
 
-    pub fn run(laddr: &SocketAddr) -> UnboundedReceiver<Op<VoyagerMessage>> {
+    pub fn run<Q: Queue<VoyagerMessage>>(
+        laddr: &SocketAddr,
+        queue: Q,
+    ) -> UnboundedReceiver<Op<VoyagerMessage>> {
         let (queue_tx, queue_rx) = unbounded::<Op<VoyagerMessage>>();
 
         let app = axum::Router::new()
-            .route("/enqueue", post(enqueue))
+            .route("/enqueue", post(enqueue::<Q>))
+            .route("/history/:id", get(history::<Q>))
             .route("/health", get(async || StatusCode::OK))
-            .with_state(queue_tx.clone());
+            .with_state(ApiState {
+                sender: queue_tx.clone(),
+                queue,
+            });
 
         tokio::spawn(axum::Server::bind(laddr).serve(app.into_make_service()));
 
@@ -1390,14 +1252,45 @@ pub mod api {
     }
 
     // #[axum::debug_handler]
-    async fn enqueue(
-        State(mut sender): State<UnboundedSender<Op<VoyagerMessage>>>,
+    async fn enqueue<Q: Queue<VoyagerMessage>>(
+        State(mut state): State<ApiState<Q>>,
         Json(op): Json<Op<VoyagerMessage>>,
     ) -> StatusCode {
-        sender.send(op).await.expect("receiver should not close");
+        state
+            .sender
+            .send(op)
+            .await
+            .expect("receiver should not close");
 
         StatusCode::OK
     }
+
+    #[derive(Deserialize)]
+    struct HistoryParams {
+        max_depth: Option<u32>,
+    }
+
+    async fn history<Q: Queue<VoyagerMessage>>(
+        State(state): State<ApiState<Q>>,
+        Path(id): Path<ItemId>,
+        Query(params): Query<HistoryParams>,
+    ) -> Result<Json<History<VoyagerMessage>>, (StatusCode, String)> {
+        match state
+            .queue
+            .history(id, params.max_depth.unwrap_or(DEFAULT_MAX_DEPTH))
+            .await
+        {
+            Ok(Some(history)) => Ok(Json(history)),
+            Ok(None) => Err((
+                StatusCode::NOT_FOUND,
+                format!("item {} not found", id.raw()),
+            )),
+            Err(error) => Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorReporter(error).to_string(),
+            )),
+        }
+    }
 }
 
 pub struct PluginOptPass<T> {
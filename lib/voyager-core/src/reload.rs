@@ -0,0 +1,77 @@
+//! Reloading of the plugin and module configuration of a running engine.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use tokio::sync::{watch, Mutex};
+use tracing::info;
+use voyager_rpc::types::ReloadResponse;
+
+use crate::{
+    context::{ContextHandle, ModulesConfig, PluginConfig},
+    filter::{InterestFilters, LaneConfig},
+    workers::Workers,
+};
+
+/// The parts of the voyager config that can be changed without restarting voyager.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReloadableConfig {
+    pub plugins: Vec<PluginConfig>,
+    pub modules: ModulesConfig,
+}
+
+/// Reads the current [`ReloadableConfig`], for reloads that are triggered without providing a
+/// config (i.e. through the `config_reload` rpc method).
+pub type ConfigLoader = Arc<dyn Fn() -> anyhow::Result<ReloadableConfig> + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct Reloader {
+    pub(crate) workers: Arc<Mutex<Workers>>,
+    pub(crate) context: ContextHandle,
+    /// The interest filters of the currently loaded plugins. The engine restarts its workers,
+    /// optimizers, and scheduler whenever this changes.
+    pub(crate) interest_filters: Arc<watch::Sender<InterestFilters>>,
+    pub(crate) lanes: Vec<LaneConfig>,
+    pub(crate) loader: Option<ConfigLoader>,
+}
+
+impl Reloader {
+    pub(crate) async fn reload(&self, config: ReloadableConfig) -> anyhow::Result<ReloadResponse> {
+        // held for the entire reload, such that concurrent reloads are applied one at a time
+        let mut workers = self.workers.lock().await;
+
+        let current = self.context.get().expect("engine is built");
+
+        let (context, interest_filters, response) = workers
+            .load(
+                config.plugins,
+                config.modules,
+                current.equivalent_chain_ids.clone(),
+                current.ibc_spec_handlers.clone(),
+                self.lanes.clone(),
+            )
+            .await?;
+
+        self.context.set(context);
+        self.interest_filters.send_replace(interest_filters);
+
+        info!(
+            started = response.started.len(),
+            restarted = response.restarted.len(),
+            stopped = response.stopped.len(),
+            unchanged = response.unchanged.len(),
+            "reloaded config"
+        );
+
+        Ok(response)
+    }
+
+    pub(crate) async fn reload_config(&self) -> anyhow::Result<ReloadResponse> {
+        let loader = self
+            .loader
+            .as_ref()
+            .ok_or_else(|| anyhow!("no config loader is configured, unable to reload"))?;
+
+        self.reload(loader()?).await
+    }
+}
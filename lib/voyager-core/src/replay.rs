@@ -0,0 +1,203 @@
+//! Deterministic re-execution of an op against a [`Recording`] of the requests made to the plugins
+//! and modules of a previous run.
+//!
+//! [`Recording`]: voyager_plugin_protocol::record::Recording
+
+use std::{collections::VecDeque, ops::ControlFlow, sync::Mutex};
+
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use unionlabs::ErrorReporter;
+use voyager_message::VoyagerMessage;
+use voyager_vm::{
+    filter::InterestFilter, in_memory::InMemoryQueue, noop, process, BoxDynError, HandlerFactory,
+    ItemId, Op, Promise, Queue, QueueError,
+};
+
+use crate::{Engine, PluginOptPass};
+
+/// A single item processed while replaying an op.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayStep {
+    pub step: usize,
+    pub item_id: ItemId,
+    pub op: Op<VoyagerMessage>,
+    pub outcome: ReplayOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum ReplayOutcome {
+    /// The item was processed successfully, producing these ops (which are queued again).
+    Ok(Vec<Op<VoyagerMessage>>),
+    /// The item failed fatally and was removed from the queue.
+    Fatal(String),
+    /// The item was unprocessable and was removed from the queue.
+    Unprocessable(String),
+    /// The item failed with a retryable error, and was put back into the queue.
+    Retry(String),
+}
+
+impl Engine<InMemoryQueue<VoyagerMessage>> {
+    /// Run `op` to completion (or until `max_steps` items have been processed), calling `f` with
+    /// every item that is processed.
+    ///
+    /// This engine is expected to have been built with
+    /// [`EngineBuilder::with_replay`](crate::EngineBuilder::with_replay), such that all requests to
+    /// plugins and modules are served from the recording.
+    ///
+    /// Since the outcome of a replay must not depend on when it is run, all defers are treated as
+    /// immediately elapsed. Items queued for optimization are only optimized once there are no
+    /// other items ready to be processed, and as such the batches passed to the plugins may differ
+    /// from the recorded run; any optimizations that were not recorded are dropped with a warning.
+    pub async fn replay(
+        &self,
+        op: Op<VoyagerMessage>,
+        max_steps: usize,
+        mut f: impl FnMut(ReplayStep),
+    ) -> anyhow::Result<()> {
+        let finished = self
+            .run_to_completion(
+                &self.interest_filters(),
+                &self.server(),
+                strip_defers,
+                op,
+                Some(max_steps),
+                |step| {
+                    f(step);
+                    ControlFlow::Continue(())
+                },
+            )
+            .await?;
+
+        if !finished {
+            warn!(%max_steps, "replay did not finish within the maximum amount of steps");
+        }
+
+        Ok(())
+    }
+
+    /// Process `op` and all ops it produces in this engine's queue until there is nothing left to
+    /// process, `max_steps` items have been processed, or `f` breaks. `map` is applied to `op` and
+    /// all ops produced by processing it before they are queued.
+    ///
+    /// Returns `true` if there was nothing left to process.
+    pub(crate) async fn run_to_completion<F, H>(
+        &self,
+        filter: &F,
+        handlers: &H,
+        map: fn(Op<VoyagerMessage>) -> Op<VoyagerMessage>,
+        op: Op<VoyagerMessage>,
+        max_steps: Option<usize>,
+        mut f: impl FnMut(ReplayStep) -> ControlFlow<()>,
+    ) -> anyhow::Result<bool>
+    where
+        F: InterestFilter<VoyagerMessage>,
+        H: HandlerFactory<VoyagerMessage>,
+    {
+        self.queue.enqueue(map(op), filter).await?;
+
+        let mut step = 0;
+        let mut optimized = false;
+
+        while max_steps.is_none_or(|max_steps| step < max_steps) {
+            let processed = Mutex::new(None);
+
+            self.queue
+                .process::<_, _, (), _>(filter, async |op, item_id| {
+                    let res = process(map(op.clone()), &handlers.make_handler(item_id), 0)
+                        .map(|res| res.map(|op| op.into_iter().map(map).collect()))
+                        .await;
+
+                    let outcome = match &res {
+                        Ok(ops) => ReplayOutcome::Ok(Vec::clone(ops)),
+                        Err(QueueError::Fatal(error)) => {
+                            ReplayOutcome::Fatal(ErrorReporter(&**error).to_string())
+                        }
+                        Err(QueueError::Unprocessable(error)) => {
+                            ReplayOutcome::Unprocessable(ErrorReporter(&**error).to_string())
+                        }
+                        Err(QueueError::Retry(error)) => {
+                            ReplayOutcome::Retry(ErrorReporter(&**error).to_string())
+                        }
+                    };
+
+                    *processed.lock().expect("mutex is poisoned") = Some(ReplayStep {
+                        step,
+                        item_id,
+                        op,
+                        outcome,
+                    });
+
+                    ((), res)
+                })
+                .await?;
+
+            match processed.into_inner().expect("mutex is poisoned") {
+                Some(replay_step) => {
+                    if f(replay_step).is_break() {
+                        return Ok(false);
+                    }
+
+                    step += 1;
+                    optimized = false;
+                }
+                // nothing is ready, and optimizing didn't make anything ready either
+                None if optimized => {
+                    info!(%step, "nothing left to process");
+
+                    return Ok(true);
+                }
+                None => {
+                    let context = self.context.get().expect("engine is built");
+
+                    for (_filter, plugin_name) in &self.interest_filters().filters {
+                        let pass =
+                            PluginOptPass::new(context.plugin(plugin_name).expect("plugin exists"));
+
+                        if let Err(error) = self.queue.optimize(plugin_name, filter, &pass).await {
+                            let error = error
+                                .map_either::<_, _, BoxDynError, BoxDynError>(
+                                    |x| Box::new(x),
+                                    |x| Box::new(x),
+                                )
+                                .into_inner();
+
+                            warn!(
+                                %plugin_name,
+                                error = %ErrorReporter(&*error),
+                                "optimization pass returned with error"
+                            );
+                        }
+                    }
+
+                    optimized = true;
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+/// Replace all defers in `op` with noops.
+fn strip_defers(op: Op<VoyagerMessage>) -> Op<VoyagerMessage> {
+    match op {
+        Op::Defer { .. } | Op::DeferRelative { .. } => noop(),
+        Op::Seq(ops) => Op::Seq(ops.into_iter().map(strip_defers).collect()),
+        Op::Conc(ops) => Op::Conc(ops.into_iter().map(strip_defers).collect()),
+        Op::Promise(Promise {
+            queue,
+            data,
+            receiver,
+        }) => Op::Promise(Promise {
+            queue: queue.into_iter().map(strip_defers).collect::<VecDeque<_>>(),
+            data,
+            receiver,
+        }),
+        Op::Void(op) => Op::Void(Box::new(strip_defers(*op))),
+        op @ (Op::Data(_) | Op::Call(_) | Op::Noop) => op,
+    }
+}
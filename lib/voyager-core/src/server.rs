@@ -1,6 +1,6 @@
 // #![warn(clippy::unwrap_used)]
 
-use std::sync::{Arc, OnceLock};
+use std::sync::Arc;
 
 use anyhow::{anyhow, Context as _};
 use futures::TryFutureExt;
@@ -12,31 +12,41 @@ use jsonrpsee::{
 use serde_json::Value;
 use tracing::{debug, info_span, instrument, trace};
 use unionlabs::{ibc::core::client::height::Height, primitives::Bytes, ErrorReporter};
+use voyager_message::VoyagerMessage;
 use voyager_plugin_protocol::WithId;
 use voyager_primitives::{
     ChainId, ClientInfo, ClientStateMeta, ClientType, ConsensusStateMeta, IbcInterface, IbcSpec,
     IbcSpecId, IbcStorePathKey, QueryHeight, Timestamp,
 };
 use voyager_rpc::{
-    json_rpc_error_to_error_object,
+    json_rpc_error_to_error_object, rpc_error,
     types::{
-        IbcProofResponse, IbcStateResponse, InfoResponse, SelfClientStateResponse,
+        IbcProofResponse, IbcStateResponse, InfoResponse, ReloadResponse, SelfClientStateResponse,
         SelfConsensusStateResponse,
     },
-    ClientBootstrapModuleClient, ClientModuleClient, FinalityModuleClient, PluginClient,
-    RawProofModuleClient, RawStateModuleClient, VoyagerRpcServer, FATAL_JSONRPC_ERROR_CODE,
+    ClientBootstrapModuleClient, ClientModuleClient, ConfigRpcServer, FinalityModuleClient,
+    PluginClient, QueueRpcServer, RawProofModuleClient, RawStateModuleClient, VoyagerRpcServer,
+    FATAL_JSONRPC_ERROR_CODE,
 };
 use voyager_types::{IbcProof, RawClientId};
-use voyager_vm::ItemId;
+use voyager_vm::{
+    history::{History, DEFAULT_MAX_DEPTH},
+    ItemId, Queue,
+};
 
 use crate::{
     cache::{ClientInfoRequest, StateRequest},
-    context::Context,
+    context::{Context, ContextHandle},
+    reload::Reloader,
+    throttle::Throttle,
 };
 
 #[derive(Clone)]
 pub struct Server {
-    context: Arc<OnceLock<Context>>,
+    contexts: ContextHandle,
+    /// The context as of when this server was created, such that all requests made through it see
+    /// the same plugins and modules even if the config is reloaded in the meantime.
+    context: Option<Arc<Context>>,
     cache: crate::cache::Cache,
     item_id: Option<ItemId>,
     server_metrics: ServerMetrics,
@@ -62,9 +72,10 @@ impl ServerMetrics {
 }
 
 impl Server {
-    pub fn new(cache: crate::cache::Cache, context: Arc<OnceLock<Context>>) -> Self {
+    pub fn new(cache: crate::cache::Cache, contexts: ContextHandle) -> Self {
         Server {
-            context,
+            context: contexts.get(),
+            contexts,
             cache,
             item_id: None,
             server_metrics: ServerMetrics::new(),
@@ -78,7 +89,8 @@ impl Server {
     // REVIEW: Don't clone here?
     pub fn with_id(&self, item_id: Option<ItemId>) -> Server {
         Server {
-            context: self.context.clone(),
+            contexts: self.contexts.clone(),
+            context: self.contexts.get(),
             cache: self.cache.clone(),
             item_id,
             server_metrics: self.server_metrics.clone(),
@@ -92,10 +104,14 @@ impl Server {
         }
     }
 
+    pub fn throttle(&self) -> &Throttle {
+        self.cache.throttle()
+    }
+
     /// Returns the contained context, if it has been loaded.
     pub fn context(&self) -> RpcResult<&Context> {
         self.context
-            .get()
+            .as_deref()
             .ok_or_else(|| ErrorObject::owned(-2, "server has not started", None::<()>))
     }
 
@@ -458,8 +474,12 @@ impl Server {
                     .state_module(&chain_id, &ibc_spec_id)?
                     .with_id(self.item_id);
 
-                let value = state_module
-                    .query_raw(serde_json::to_value(query.clone()).unwrap())
+                let value = self
+                    .throttle()
+                    .run(
+                        &chain_id,
+                        state_module.query_raw(serde_json::to_value(query.clone()).unwrap()),
+                    )
                     .await
                     .map_err(json_rpc_error_to_error_object)?;
 
@@ -542,8 +562,9 @@ impl Server {
                     .proof_module(&chain_id, &ibc_spec_id)?
                     .with_id(self.item_id);
 
-                let res = proof_module
-                    .query_ibc_proof_raw(height, path)
+                let res = self
+                    .throttle()
+                    .run(&chain_id, proof_module.query_ibc_proof_raw(height, path))
                     .await
                     .map_err(json_rpc_error_to_error_object)?;
 
@@ -627,8 +648,15 @@ impl Server {
                     .proof_module(chain_id, &P::Spec::ID)?
                     .with_id(self.item_id);
 
-                let res = proof_module
-                    .query_ibc_proof_raw(height, serde_json::to_value(path.clone()).unwrap())
+                let res = self
+                    .throttle()
+                    .run(
+                        chain_id,
+                        proof_module.query_ibc_proof_raw(
+                            height,
+                            serde_json::to_value(path.clone()).unwrap(),
+                        ),
+                    )
                     .await
                     .map_err(json_rpc_error_to_error_object)?;
 
@@ -665,8 +693,12 @@ impl Server {
                     .client_bootstrap_module(&chain_id, &client_type)?
                     .with_id(self.item_id);
 
-                let state = client_bootstrap_module
-                    .self_client_state(height, config)
+                let state = self
+                    .throttle()
+                    .run(
+                        &chain_id,
+                        client_bootstrap_module.self_client_state(height, config),
+                    )
                     .await
                     .map_err(json_rpc_error_to_error_object)?;
 
@@ -697,8 +729,12 @@ impl Server {
 
                 let height = self.query_height(&chain_id, height).await?;
 
-                let state = client_bootstrap_module
-                    .self_consensus_state(height, config)
+                let state = self
+                    .throttle()
+                    .run(
+                        &chain_id,
+                        client_bootstrap_module.self_consensus_state(height, config),
+                    )
                     .await
                     .map_err(json_rpc_error_to_error_object)?;
 
@@ -889,16 +925,17 @@ impl Server {
 /// rpc impl
 #[async_trait]
 impl VoyagerRpcServer for Server {
-    async fn info(&self, _: &Extensions) -> RpcResult<InfoResponse> {
-        Ok(self.context()?.info())
+    async fn info(&self, e: &Extensions) -> RpcResult<InfoResponse> {
+        Ok(self.with_id(e.try_get().ok().cloned()).context()?.info())
     }
 
     async fn equivalent_chain_ids(
         &self,
-        _: &Extensions,
+        e: &Extensions,
         chain_id: ChainId,
     ) -> RpcResult<Vec<ChainId>> {
         Ok(self
+            .with_id(e.try_get().ok().cloned())
             .context()?
             .equivalent_chain_ids()
             .equivalents(&chain_id)
@@ -1209,3 +1246,50 @@ impl ExtensionsExt for Extensions {
         }
     }
 }
+
+/// Serves the `queue` rpc namespace, backed by the queue of the running engine.
+#[derive(Debug, Clone)]
+pub struct QueueServer<Q> {
+    queue: Q,
+}
+
+impl<Q> QueueServer<Q> {
+    pub fn new(queue: Q) -> Self {
+        Self { queue }
+    }
+}
+
+#[async_trait]
+impl<Q: Queue<VoyagerMessage>> QueueRpcServer for QueueServer<Q> {
+    async fn history(
+        &self,
+        id: ItemId,
+        max_depth: Option<u32>,
+    ) -> RpcResult<Option<History<VoyagerMessage>>> {
+        self.queue
+            .history(id, max_depth.unwrap_or(DEFAULT_MAX_DEPTH))
+            .await
+            .map_err(rpc_error("error querying item history", None))
+    }
+}
+
+/// Serves the `config` rpc namespace, reloading the plugins and modules of the running engine.
+#[derive(Clone)]
+pub struct ConfigServer {
+    reloader: Reloader,
+}
+
+impl ConfigServer {
+    pub(crate) fn new(reloader: Reloader) -> Self {
+        Self { reloader }
+    }
+}
+
+#[async_trait]
+impl ConfigRpcServer for ConfigServer {
+    async fn reload(&self) -> RpcResult<ReloadResponse> {
+        self.reloader.reload_config().await.map_err(|e| {
+            ErrorObject::owned(-1, format!("error reloading config: {e:#}"), None::<()>)
+        })
+    }
+}
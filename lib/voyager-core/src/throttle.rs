@@ -0,0 +1,265 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    num::{NonZeroU32, NonZeroUsize},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+use opentelemetry::{metrics::Counter, KeyValue};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tracing::trace;
+use voyager_primitives::ChainId;
+
+use crate::equivalent_chain_ids::EquivalentChainIds;
+
+/// Limits on the requests that voyager makes to the modules and plugins of a single chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ChainLimitsConfig {
+    /// The chain to limit requests to. These limits are shared with all chains that are configured
+    /// as equivalent to this chain.
+    pub chain_id: ChainId,
+    /// The maximum amount of requests to the modules of this chain that can be in flight at once.
+    ///
+    /// Note that this must be large enough to account for any modules that make requests to their
+    /// own chain through voyager while handling a request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<NonZeroUsize>,
+    /// The maximum sustained rate of requests to this chain, per second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_second: Option<NonZeroU32>,
+    /// The amount of requests that can be made at once after this chain has been idle. Defaults to
+    /// `max_requests_per_second`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub burst: Option<NonZeroU32>,
+    /// Plugins that make requests to this chain. Calls to these plugins are put back into the
+    /// queue while this chain is saturated, instead of taking up a worker while waiting on the
+    /// requests they make.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plugins: Vec<String>,
+}
+
+/// Per-chain concurrency and rate limits, shared between all requests to the modules and plugins
+/// of each chain.
+///
+/// Requests to chains without any configured limits are not throttled.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    chains: Arc<HashMap<ChainId, Arc<ChainThrottle>>>,
+    plugins: Arc<HashMap<String, ChainId>>,
+
+    throttled_time_metric: Counter<f64>,
+    throttled_requests_metric: Counter<u64>,
+    deferred_calls_metric: Counter<u64>,
+}
+
+#[derive(Debug)]
+struct ChainThrottle {
+    /// The chain id the limits were configured for, used to report metrics under a single chain
+    /// id for all equivalent chains.
+    chain_id: ChainId,
+    concurrency: Option<Semaphore>,
+    rate: Option<RateLimiter>,
+}
+
+impl Throttle {
+    pub fn new(
+        limits: Vec<ChainLimitsConfig>,
+        equivalent_chain_ids: &EquivalentChainIds,
+    ) -> anyhow::Result<Self> {
+        let mut chains = HashMap::new();
+        let mut plugins = HashMap::new();
+
+        for config in limits {
+            if config.burst.is_some() && config.max_requests_per_second.is_none() {
+                return Err(anyhow!(
+                    "burst is configured for chain `{}` without max_requests_per_second",
+                    config.chain_id
+                ));
+            }
+
+            let chain = Arc::new(ChainThrottle {
+                chain_id: config.chain_id.clone(),
+                concurrency: config
+                    .max_concurrent_requests
+                    .map(|max| Semaphore::new(max.get())),
+                rate: config
+                    .max_requests_per_second
+                    .map(|rate| RateLimiter::new(rate.get(), config.burst.unwrap_or(rate).get())),
+            });
+
+            for chain_id in equivalent_chain_ids
+                .equivalents(&config.chain_id)
+                .chain([&config.chain_id])
+            {
+                if chains.insert(chain_id.clone(), chain.clone()).is_some() {
+                    return Err(anyhow!(
+                        "multiple limits configured for chain `{chain_id}` (including \
+                        equivalent chain ids)"
+                    ));
+                }
+            }
+
+            for plugin in config.plugins {
+                if let Some(prev) = plugins.insert(plugin.clone(), config.chain_id.clone()) {
+                    return Err(anyhow!(
+                        "plugin `{plugin}` is configured under the limits of both \
+                        `{prev}` and `{}`",
+                        config.chain_id
+                    ));
+                }
+            }
+        }
+
+        let meter = opentelemetry::global::meter("voyager");
+
+        Ok(Self {
+            chains: Arc::new(chains),
+            plugins: Arc::new(plugins),
+            throttled_time_metric: meter
+                .f64_counter("throttle.throttled_time")
+                .with_unit("s")
+                .with_description("Time spent waiting on the limits of a chain")
+                .build(),
+            throttled_requests_metric: meter
+                .u64_counter("throttle.throttled_requests")
+                .with_description("Requests that had to wait on the limits of a chain")
+                .build(),
+            deferred_calls_metric: meter
+                .u64_counter("throttle.deferred_calls")
+                .with_description("Plugin calls that were deferred due to a saturated chain")
+                .build(),
+        })
+    }
+
+    /// The chain that the requests of `plugin` count towards, if any.
+    pub fn plugin_chain(&self, plugin: &str) -> Option<&ChainId> {
+        self.plugins.get(plugin)
+    }
+
+    /// Returns `true` if a request to `chain_id` made now would have to wait on the limits of the
+    /// chain.
+    pub fn is_saturated(&self, chain_id: &ChainId) -> bool {
+        self.chains.get(chain_id).is_some_and(|chain| {
+            chain
+                .concurrency
+                .as_ref()
+                .is_some_and(|semaphore| semaphore.available_permits() == 0)
+                || chain.rate.as_ref().is_some_and(RateLimiter::is_exhausted)
+        })
+    }
+
+    /// Record that a call to a plugin of `chain_id` was deferred due to the chain being
+    /// saturated.
+    pub fn record_deferred(&self, chain_id: &ChainId) {
+        let chain_id = self
+            .chains
+            .get(chain_id)
+            .map_or(chain_id, |chain| &chain.chain_id);
+
+        self.deferred_calls_metric
+            .add(1, &[KeyValue::new("chain_id", chain_id.to_string())]);
+    }
+
+    /// Run `fut` within the limits of `chain_id`, waiting until both a concurrency slot and the rate
+    /// budget are available.
+    pub async fn run<F: Future>(&self, chain_id: &ChainId, fut: F) -> F::Output {
+        let Some(chain) = self.chains.get(chain_id) else {
+            return fut.await;
+        };
+
+        let start = Instant::now();
+        let mut throttled = false;
+
+        let _permit = match &chain.concurrency {
+            Some(semaphore) => Some(match semaphore.try_acquire() {
+                Ok(permit) => permit,
+                Err(_) => {
+                    throttled = true;
+                    semaphore
+                        .acquire()
+                        .await
+                        .expect("semaphore is never closed; qed;")
+                }
+            }),
+            None => None,
+        };
+
+        if let Some(wait) = chain.rate.as_ref().and_then(RateLimiter::reserve) {
+            throttled = true;
+            tokio::time::sleep(wait).await;
+        }
+
+        if throttled {
+            let throttled_time = start.elapsed();
+
+            trace!(
+                chain_id = %chain.chain_id,
+                throttled_time_ms = throttled_time.as_millis(),
+                "request was throttled"
+            );
+
+            let attributes = &[KeyValue::new("chain_id", chain.chain_id.to_string())];
+
+            self.throttled_time_metric
+                .add(throttled_time.as_secs_f64(), attributes);
+            self.throttled_requests_metric.add(1, attributes);
+        }
+
+        fut.await
+    }
+}
+
+/// A token bucket that allows requests to go into debt, such that each request reserves the next
+/// available slot and waiting requests are served in the order they arrived in.
+#[derive(Debug)]
+struct RateLimiter {
+    /// Tokens added per second.
+    rate: f64,
+    /// The maximum amount of tokens that can be accumulated.
+    burst: f64,
+    /// (available tokens, last refill)
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    fn new(rate: u32, burst: u32) -> Self {
+        Self {
+            rate: rate.into(),
+            burst: burst.into(),
+            state: Mutex::new((burst.into(), Instant::now())),
+        }
+    }
+
+    fn refill(&self, (tokens, last_refill): &mut (f64, Instant)) {
+        let now = Instant::now();
+
+        *tokens =
+            (*tokens + now.duration_since(*last_refill).as_secs_f64() * self.rate).min(self.burst);
+        *last_refill = now;
+    }
+
+    /// Take a token, returning how long to wait before the request can be made if the bucket is
+    /// empty.
+    fn reserve(&self) -> Option<Duration> {
+        let mut state = self.state.lock().expect("mutex is poisoned");
+
+        self.refill(&mut state);
+
+        state.0 -= 1.0;
+
+        (state.0 < 0.0).then(|| Duration::from_secs_f64(-state.0 / self.rate))
+    }
+
+    fn is_exhausted(&self) -> bool {
+        let mut state = self.state.lock().expect("mutex is poisoned");
+
+        self.refill(&mut state);
+
+        state.0 < 1.0
+    }
+}
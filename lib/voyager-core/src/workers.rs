@@ -0,0 +1,657 @@
+//! Management of the plugin and module subprocesses.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    pin::pin,
+    time::Duration,
+};
+
+use anyhow::anyhow;
+use futures::{stream::FuturesUnordered, StreamExt};
+use serde::Serialize;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, info, info_span, warn, Instrument};
+use unionlabs::ErrorReporter;
+use voyager_plugin_protocol::{
+    coordinator_server,
+    record::{Recorder, Recording},
+    worker_child_process, WorkerClient,
+};
+use voyager_rpc::types::{
+    ClientBootstrapModuleInfo, ClientModuleInfo, FinalityModuleInfo, PluginInfo, ProofModuleInfo,
+    ReloadResponse, StateModuleInfo,
+};
+
+use crate::{
+    context::{Context, ModuleConfig, ModulesConfig, PluginConfig},
+    equivalent_chain_ids::EquivalentChainIds,
+    filter::{InterestFilters, LaneConfig},
+    get_plugin_info,
+    health::{HealthConfig, WorkerHealth},
+    ibc_spec_handlers::IbcSpecHandlers,
+    server::Server,
+    LoggerMiddlewareLayer,
+};
+
+/// The plugin and module processes of an engine, along with the clients used to talk to them.
+pub(crate) struct Workers {
+    ipc_client_request_timeout: Duration,
+    recorder: Option<Recorder>,
+    replay: Option<Recording>,
+    metrics_endpoint: Option<String>,
+    logger_middleware_layer: LoggerMiddlewareLayer,
+    health_config: HealthConfig,
+    /// The server exposed to the plugins and modules over their coordinator sockets.
+    server: Server,
+    cancellation_token: CancellationToken,
+    running: HashMap<String, RunningWorker>,
+    /// The names of the workers that a coordinator server has been started for. These are never
+    /// stopped, since a worker may be restarted under the same name (and therefore the same
+    /// socket).
+    coordinator_servers: HashSet<String>,
+}
+
+/// How a worker process is spawned. If this changes for a worker on reload, the worker is
+/// restarted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WorkerSpec {
+    path: PathBuf,
+    args: Vec<String>,
+}
+
+struct RunningWorker {
+    spec: WorkerSpec,
+    client: WorkerClient,
+    health: WorkerHealth,
+    stop: CancellationToken,
+    handle: JoinHandle<()>,
+}
+
+impl Workers {
+    pub(crate) fn new(
+        ipc_client_request_timeout: Duration,
+        recorder: Option<Recorder>,
+        replay: Option<Recording>,
+        metrics_endpoint: Option<String>,
+        logger_middleware_layer: LoggerMiddlewareLayer,
+        health_config: HealthConfig,
+        server: Server,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        Self {
+            ipc_client_request_timeout,
+            recorder,
+            replay,
+            metrics_endpoint,
+            logger_middleware_layer,
+            health_config,
+            server,
+            cancellation_token,
+            running: HashMap::new(),
+            coordinator_servers: HashSet::new(),
+        }
+    }
+
+    /// Whether the plugin and module processes should be spawned. This is `false` when replaying
+    /// a recording.
+    fn spawn(&self) -> bool {
+        self.replay.is_none()
+    }
+
+    fn client(&self, name: &str) -> WorkerClient {
+        match &self.replay {
+            Some(recording) => WorkerClient::replay(name, recording.clone()),
+            None => WorkerClient::new(name, self.ipc_client_request_timeout)
+                .with_recorder(self.recorder.clone()),
+        }
+    }
+
+    /// The client and health for the worker `name`, reusing those of the running worker if it
+    /// will be kept running.
+    fn client_for(&self, name: &str, spec: &WorkerSpec) -> (WorkerClient, WorkerHealth) {
+        match self.running.get(name) {
+            Some(running) if running.spec == *spec => {
+                (running.client.clone(), running.health.clone())
+            }
+            _ => (self.client(name), WorkerHealth::new(name.to_owned())),
+        }
+    }
+
+    /// Load the given plugin and module configuration, returning the new context and interest
+    /// filters.
+    ///
+    /// The configuration is fully validated before any processes are touched, such that an error
+    /// leaves the currently running workers as they are. Once validated, workers that are no
+    /// longer configured are stopped, workers whose configuration changed are restarted, and new
+    /// workers are started; all other workers are left running.
+    pub(crate) async fn load(
+        &mut self,
+        plugins: Vec<PluginConfig>,
+        modules: ModulesConfig,
+        equivalent_chain_ids: EquivalentChainIds,
+        ibc_spec_handlers: IbcSpecHandlers,
+        lanes: Vec<LaneConfig>,
+    ) -> anyhow::Result<(Context, InterestFilters, ReloadResponse)> {
+        let mut context = Context {
+            state_modules: Default::default(),
+            proof_modules: Default::default(),
+            client_modules: Default::default(),
+            client_bootstrap_modules: Default::default(),
+            finality_modules: Default::default(),
+            chain_consensus_types: Default::default(),
+            client_consensus_types: Default::default(),
+            plugins: Default::default(),
+            health: Default::default(),
+            health_config: self.health_config.clone(),
+            equivalent_chain_ids,
+            ibc_spec_handlers,
+        };
+
+        let mut workers = HashMap::new();
+        let mut plugin_infos = vec![];
+
+        info!("loading {} plugins", plugins.len());
+
+        for (idx, plugin_config) in plugins.into_iter().enumerate() {
+            if !plugin_config.enabled {
+                info!(
+                    plugin_path = %plugin_config.path.to_string_lossy(),
+                    "plugin is not enabled, skipping"
+                );
+                continue;
+            }
+
+            let PluginInfo {
+                name,
+                interest_filter,
+            } = info_span!("get_plugin_info", %idx).in_scope(|| get_plugin_info(&plugin_config))?;
+
+            debug!("registering plugin {}", name);
+
+            let spec = WorkerSpec {
+                path: plugin_config.path,
+                args: [plugin_config.config.to_string()]
+                    .into_iter()
+                    .chain(self.metrics_endpoint.clone())
+                    .collect(),
+            };
+
+            let (rpc_client, health) = self.client_for(&name, &spec);
+
+            let prev = context.plugins.insert(name.clone(), rpc_client.clone());
+
+            if prev.is_some() {
+                return Err(anyhow!("multiple plugins configured with name `{name}`"));
+            }
+
+            info!("registered plugin {name}");
+
+            workers.insert(name.clone(), (spec, rpc_client, health));
+
+            plugin_infos.push(PluginInfo {
+                name,
+                interest_filter,
+            });
+        }
+
+        self.register_modules(
+            modules.state,
+            &mut workers,
+            |info| info.id(),
+            |StateModuleInfo {
+                 chain_id,
+                 ibc_spec_id,
+             },
+             rpc_client| {
+                for equivalent_chain_id in context
+                    .equivalent_chain_ids
+                    .equivalents(chain_id)
+                    .chain([chain_id])
+                {
+                    let prev = context.state_modules.insert(
+                        (equivalent_chain_id.clone(), ibc_spec_id.clone()),
+                        rpc_client.clone(),
+                    );
+
+                    if prev.is_some() {
+                        return Err(anyhow!(
+                            "multiple state modules configured for chain id \
+                            `{equivalent_chain_id}` and IBC version `{ibc_spec_id}`",
+                        ));
+                    }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        self.register_modules(
+            modules.proof,
+            &mut workers,
+            |info| info.id(),
+            |ProofModuleInfo {
+                 chain_id,
+                 ibc_spec_id,
+             },
+             rpc_client| {
+                for equivalent_chain_id in context
+                    .equivalent_chain_ids
+                    .equivalents(chain_id)
+                    .chain([chain_id])
+                {
+                    let prev = context.proof_modules.insert(
+                        (equivalent_chain_id.clone(), ibc_spec_id.clone()),
+                        rpc_client.clone(),
+                    );
+
+                    if prev.is_some() {
+                        return Err(anyhow!(
+                            "multiple proof modules configured for chain id \
+                            `{equivalent_chain_id}` and IBC version `{ibc_spec_id}`",
+                        ));
+                    }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        self.register_modules(
+            modules.consensus,
+            &mut workers,
+            |info| info.id(),
+            |FinalityModuleInfo {
+                 chain_id,
+                 consensus_type,
+             },
+             rpc_client| {
+                for equivalent_chain_id in context
+                    .equivalent_chain_ids
+                    .equivalents(chain_id)
+                    .chain([chain_id])
+                {
+                    let prev = context
+                        .finality_modules
+                        .insert(equivalent_chain_id.clone(), rpc_client.clone());
+
+                    if prev.is_some() {
+                        return Err(anyhow!(
+                            "multiple consensus modules configured for chain id `{}`",
+                            equivalent_chain_id
+                        ));
+                    }
+
+                    let None = context
+                        .chain_consensus_types
+                        .insert(equivalent_chain_id.clone(), consensus_type.clone())
+                    else {
+                        unreachable!()
+                    };
+                }
+
+                Ok(())
+            },
+        )?;
+
+        self.register_modules(
+            modules.client,
+            &mut workers,
+            |info| info.id(),
+            |ClientModuleInfo {
+                 client_type,
+                 consensus_type,
+                 ibc_interface,
+                 ibc_spec_id,
+             },
+             rpc_client| {
+                if !context.ibc_spec_handlers.handlers.contains_key(ibc_spec_id) {
+                    return Err(anyhow!(
+                        "IBC version `{ibc_spec_id}` is not supported in this build of voyager"
+                    ));
+                }
+
+                let prev = context.client_modules.insert(
+                    (
+                        client_type.clone(),
+                        ibc_interface.clone(),
+                        ibc_spec_id.clone(),
+                    ),
+                    rpc_client.clone(),
+                );
+
+                if prev.is_some() {
+                    return Err(anyhow!(
+                        "multiple client modules configured for client \
+                        type `{client_type}`, IBC interface `{ibc_interface}`, \
+                        and IBC version `{ibc_spec_id}`",
+                    ));
+                }
+
+                if let Some(previous_consensus_type) = context
+                    .client_consensus_types
+                    .insert(client_type.clone(), consensus_type.clone())
+                {
+                    if previous_consensus_type != consensus_type {
+                        return Err(anyhow!(
+                            "inconsistency in client consensus types: \
+                            client type `{client_type}` is registered \
+                            as tracking both `{previous_consensus_type}` \
+                            and `{consensus_type}`"
+                        ));
+                    }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        self.register_modules(
+            modules.client_bootstrap,
+            &mut workers,
+            |info| info.id(),
+            |ClientBootstrapModuleInfo {
+                 client_type,
+                 chain_id,
+             },
+             rpc_client| {
+                for equivalent_chain_id in context
+                    .equivalent_chain_ids
+                    .equivalents(chain_id)
+                    .chain([chain_id])
+                {
+                    let prev = context.client_bootstrap_modules.insert(
+                        (equivalent_chain_id.clone(), client_type.clone()),
+                        rpc_client.clone(),
+                    );
+
+                    if prev.is_some() {
+                        return Err(anyhow!(
+                            "multiple client bootstrap modules configured for client \
+                            type `{client_type}` and chain id `{equivalent_chain_id}`",
+                        ));
+                    }
+
+                    // TODO: Check consistency with client_consensus_types and chain_id?
+
+                    // if let Some(previous_consensus_type) = modules
+                    //     .client_consensus_types
+                    //     .insert(client_type.clone(), consensus_type.clone())
+                    // {
+                    //     if previous_consensus_type != consensus_type {
+                    //         return Err(anyhow!(
+                    //             "inconsistency in client consensus types: \
+                    //             client type `{client_type}` is registered \
+                    //             as tracking both `{previous_consensus_type}` \
+                    //             and `{consensus_type}`"
+                    //         ));
+                    //     }
+                    // }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        let interest_filters = InterestFilters::new(plugin_infos, lanes)?;
+
+        context.health = workers
+            .iter()
+            .map(|(name, (_, _, health))| (name.clone(), health.clone()))
+            .collect();
+
+        if !self.spawn() {
+            return Ok((context, interest_filters, ReloadResponse::default()));
+        }
+
+        let response = self.apply(workers).await?;
+
+        self.health_check(&context, response.started.iter().chain(&response.restarted))
+            .await?;
+
+        Ok((context, interest_filters, response))
+    }
+
+    fn register_modules<Info: Serialize>(
+        &self,
+        configs: Vec<ModuleConfig<Info>>,
+        workers: &mut HashMap<String, (WorkerSpec, WorkerClient, WorkerHealth)>,
+        id_f: fn(&Info) -> String,
+        mut push_f: impl FnMut(&Info, WorkerClient) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        for module_config in configs {
+            if !module_config.enabled {
+                info!(
+                    module_path = %module_config.path.to_string_lossy(),
+                    "module is not enabled, skipping"
+                );
+                continue;
+            }
+
+            let id = id_f(&module_config.info);
+
+            debug!("registering module {}", id);
+
+            let spec = WorkerSpec {
+                path: module_config.path,
+                args: [
+                    module_config.config.to_string(),
+                    serde_json::to_string(&module_config.info).unwrap(),
+                ]
+                .into_iter()
+                .chain(self.metrics_endpoint.clone())
+                .collect(),
+            };
+
+            let (rpc_client, health) = self.client_for(&id, &spec);
+
+            push_f(&module_config.info, rpc_client.clone())?;
+
+            info!("registered module {id}");
+
+            workers.insert(id, (spec, rpc_client, health));
+        }
+
+        Ok(())
+    }
+
+    /// Bring the running workers in line with `workers`.
+    async fn apply(
+        &mut self,
+        mut workers: HashMap<String, (WorkerSpec, WorkerClient, WorkerHealth)>,
+    ) -> anyhow::Result<ReloadResponse> {
+        let mut response = ReloadResponse::default();
+
+        for name in self.running.keys().cloned().collect::<Vec<_>>() {
+            match workers.get(&name) {
+                Some((spec, _, _)) if *spec == self.running[&name].spec => {
+                    workers.remove(&name);
+                    response.unchanged.push(name);
+                }
+                Some(_) => {
+                    info!("config for {name} changed, restarting");
+
+                    self.stop(&name).await;
+                    response.restarted.push(name);
+                }
+                None => {
+                    info!("{name} is no longer configured, stopping");
+
+                    self.stop(&name).await;
+                    response.stopped.push(name);
+                }
+            }
+        }
+
+        for (name, (spec, client, health)) in workers {
+            if !response.restarted.contains(&name) {
+                response.started.push(name.clone());
+            }
+
+            self.start(name, spec, client, health).await?;
+        }
+
+        response.started.sort_unstable();
+        response.restarted.sort_unstable();
+        response.stopped.sort_unstable();
+        response.unchanged.sort_unstable();
+
+        Ok(response)
+    }
+
+    async fn start(
+        &mut self,
+        name: String,
+        spec: WorkerSpec,
+        client: WorkerClient,
+        health: WorkerHealth,
+    ) -> anyhow::Result<()> {
+        if !self.coordinator_servers.contains(&name) {
+            debug!("starting rpc server for {name}");
+
+            tokio::spawn(
+                coordinator_server(
+                    &name,
+                    self.server.clone(),
+                    self.logger_middleware_layer.clone(),
+                )
+                .await?,
+            );
+
+            debug!("started rpc server for {name}");
+
+            self.coordinator_servers.insert(name.clone());
+        }
+
+        let stop = CancellationToken::new();
+
+        let handle = tokio::spawn(run_worker(
+            name.clone(),
+            spec.clone(),
+            client.clone(),
+            health.clone(),
+            self.health_config.clone(),
+            self.cancellation_token.clone(),
+            stop.clone(),
+        ));
+
+        self.running.insert(
+            name,
+            RunningWorker {
+                spec,
+                client,
+                health,
+                stop,
+                handle,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Stop the worker `name`, waiting for its process to exit.
+    async fn stop(&mut self, name: &str) {
+        if let Some(running) = self.running.remove(name) {
+            running.stop.cancel();
+
+            if let Err(error) = running.handle.await {
+                error!(
+                    error = %ErrorReporter(error),
+                    "error stopping {name}"
+                );
+            }
+        }
+    }
+
+    async fn health_check(
+        &self,
+        context: &Context,
+        names: impl Iterator<Item = &String>,
+    ) -> anyhow::Result<()> {
+        info!("checking for plugin health...");
+
+        let futures = names
+            .filter_map(|name| Some((name, context.plugins.get(name)?.inner()?)))
+            .map(|(name, client)| async move {
+                match client
+                    .wait_until_connected(Duration::from_secs(10))
+                    .instrument(debug_span!("health check", %name))
+                    .await
+                {
+                    Ok(()) => {
+                        info!("plugin {name} connected")
+                    }
+                    Err(_) => {
+                        warn!("plugin {name} failed to connect after 10 seconds")
+                    }
+                }
+            })
+            .collect::<FuturesUnordered<_>>();
+
+        match self
+            .cancellation_token
+            .run_until_cancelled(futures.collect::<Vec<_>>())
+            .await
+        {
+            Some(_) => Ok(()),
+            None => Err(anyhow!("startup error")),
+        }
+    }
+}
+
+/// Run the worker process until either `cancellation_token` or `stop` is cancelled, restarting it
+/// with an exponential backoff whenever it becomes unhealthy.
+///
+/// A worker that exits with an invalid config cancels `cancellation_token`, shutting down the
+/// engine; this is the same whether the worker was configured at startup or on a reload.
+async fn run_worker(
+    name: String,
+    spec: WorkerSpec,
+    client: WorkerClient,
+    health: WorkerHealth,
+    health_config: HealthConfig,
+    cancellation_token: CancellationToken,
+    stop: CancellationToken,
+) {
+    loop {
+        let token = cancellation_token.child_token();
+
+        let mut worker = pin!(worker_child_process(
+            name.clone(),
+            spec.path.clone(),
+            token.clone(),
+            spec.args.clone()
+        ));
+
+        tokio::select! {
+            () = &mut worker => {
+                cancellation_token.cancel();
+                return;
+            }
+            () = stop.cancelled() => {
+                token.cancel();
+                worker.await;
+                return;
+            }
+            () = health.monitor(&client, &health_config) => {
+                token.cancel();
+                worker.await;
+            }
+        }
+
+        let backoff = health.restart(&health_config);
+
+        warn!(
+            "{name} failed {} health checks in a row, restarting in {}s",
+            health_config.failure_threshold,
+            backoff.as_secs()
+        );
+
+        tokio::select! {
+            () = tokio::time::sleep(backoff) => {}
+            () = stop.cancelled() => return,
+            () = cancellation_token.cancelled() => return,
+        }
+    }
+}
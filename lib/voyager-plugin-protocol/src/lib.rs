@@ -56,9 +56,17 @@ use voyager_client::VoyagerClient;
 use voyager_rpc::VoyagerRpcServer;
 use voyager_vm::ItemId;
 
+use crate::record::{RecordedRequest, RecordedResponse, Recorder, Recording};
+
+pub mod record;
+
 pub const INVALID_CONFIG_EXIT_CODE: u8 = 13;
 pub const STARTUP_ERROR_EXIT_CODE: u8 = 14;
 
+/// Method served by every worker in addition to its own rpc methods, used by the coordinator to
+/// check that the worker is still responsive. See [`WorkerClient::ping`].
+pub const PING_METHOD: &str = "worker_ping";
+
 /// Run the coordinator server.
 ///
 /// This will listen to messages on [`coordinator_socket_path`]`(name)`.
@@ -150,7 +158,10 @@ pub async fn worker_server<T>(
         )
         .build(worker_socket);
 
-    let rpcs = into_rpc(worker_server);
+    let mut rpcs = into_rpc(worker_server);
+
+    rpcs.register_method(PING_METHOD, |_, _, _| ())
+        .expect("ping method is reserved for voyager");
 
     trace!(methods = ?*rpcs, "registered methods");
     let addr = ipc_server.endpoint();
@@ -166,21 +177,122 @@ pub async fn worker_server<T>(
 /// The RPC client to communicate with a worker from the coordinator.
 ///
 /// This is a thin wrapper around a [`reconnecting_jsonrpc_ws_client::Client`]. If the worker crashes or restarts, it will automatically attempt to reconnect.
+///
+/// All requests made through this client can optionally be written to a [`Recorder`], and a client can be created that serves responses from a [`Recording`] instead of connecting to a running worker (see the [`record`] module).
 #[derive(Clone)]
 pub struct WorkerClient {
-    client: reconnecting_jsonrpc_ws_client::Client,
-    #[allow(dead_code)]
+    transport: WorkerTransport,
     name: String,
+    recorder: Option<Recorder>,
+}
+
+#[derive(Clone)]
+enum WorkerTransport {
+    Ipc(reconnecting_jsonrpc_ws_client::Client),
+    Replay(Recording),
 }
 
 impl WorkerClient {
-    pub fn inner(&self) -> &reconnecting_jsonrpc_ws_client::Client {
-        &self.client
+    /// The underlying IPC client, or `None` if this client is replaying a recording.
+    pub fn inner(&self) -> Option<&reconnecting_jsonrpc_ws_client::Client> {
+        match &self.transport {
+            WorkerTransport::Ipc(client) => Some(client),
+            WorkerTransport::Replay(_) => None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Check that the worker is up and responding to requests.
+    ///
+    /// Pings are never recorded, and always succeed when replaying a recording.
+    pub async fn ping(&self) -> Result<(), jsonrpsee::core::client::Error> {
+        match &self.transport {
+            WorkerTransport::Ipc(client) => client
+                .request::<serde_json::Value, _>(PING_METHOD, RawParams(None))
+                .await
+                .map(|_| ()),
+            WorkerTransport::Replay(_) => Ok(()),
+        }
+    }
+}
+
+impl ClientT for WorkerClient {
+    async fn notification<Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<(), jsonrpsee::core::client::Error>
+    where
+        Params: ToRpcParams + Send,
+    {
+        match &self.transport {
+            WorkerTransport::Ipc(client) => client.notification(method, params).await,
+            WorkerTransport::Replay(_) => Err(jsonrpsee::core::client::Error::Custom(
+                "notifications cannot be replayed".to_owned(),
+            )),
+        }
+    }
+
+    async fn request<R, Params>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<R, jsonrpsee::core::client::Error>
+    where
+        R: DeserializeOwned,
+        Params: ToRpcParams + Send,
+    {
+        if let (WorkerTransport::Ipc(client), None) = (&self.transport, &self.recorder) {
+            return client.request(method, params).await;
+        }
+
+        // the raw response is needed here, either to record it or to replay it
+        let params = params.to_rpc_params()?;
+        let recorded_params = record::recorded_params(params.as_deref());
+
+        let response = match &self.transport {
+            WorkerTransport::Ipc(client) => {
+                client
+                    .request::<serde_json::Value, _>(method, RawParams(params))
+                    .await
+            }
+            WorkerTransport::Replay(recording) => {
+                recording.next_response(&self.name, method, recorded_params.as_ref())
+            }
+        };
+
+        if let Some(recorder) = &self.recorder {
+            recorder.record(&RecordedRequest {
+                worker: self.name.clone(),
+                method: method.to_owned(),
+                params: recorded_params,
+                response: RecordedResponse::from_result(&response),
+            });
+        }
+
+        Ok(serde_json::from_value(response?)?)
+    }
+
+    async fn batch_request<'a, R>(
+        &self,
+        batch: BatchRequestBuilder<'a>,
+    ) -> Result<BatchResponse<'a, R>, jsonrpsee::core::client::Error>
+    where
+        R: DeserializeOwned + Debug + 'a,
+    {
+        match &self.transport {
+            WorkerTransport::Ipc(client) => client.batch_request(batch).await,
+            WorkerTransport::Replay(_) => Err(jsonrpsee::core::client::Error::Custom(
+                "batch requests cannot be replayed".to_owned(),
+            )),
+        }
     }
 }
 
-delegate_client_impl!(WorkerClient: |this| this.client);
-delegate_client_impl!(&WorkerClient: |this| this.client);
+delegate_client_impl!(&WorkerClient: |this| **this);
 
 impl WorkerClient {
     pub fn new(name: &str, request_timeout: Duration) -> Self {
@@ -206,13 +318,33 @@ impl WorkerClient {
         });
 
         Self {
-            client,
+            transport: WorkerTransport::Ipc(client),
+            name: name.to_owned(),
+            recorder: None,
+        }
+    }
+
+    /// Create a client that serves all requests from `recording`, without connecting to a worker.
+    pub fn replay(name: &str, recording: Recording) -> Self {
+        Self {
+            transport: WorkerTransport::Replay(recording),
             name: name.to_owned(),
+            recorder: None,
         }
     }
 
-    pub fn client(&self) -> &reconnecting_jsonrpc_ws_client::Client {
-        &self.client
+    /// Record all requests made through this client, and their responses, to `recorder`.
+    pub fn with_recorder(self, recorder: Option<Recorder>) -> Self {
+        Self { recorder, ..self }
+    }
+}
+
+/// Already serialized request params.
+struct RawParams(Option<Box<RawValue>>);
+
+impl ToRpcParams for RawParams {
+    fn to_rpc_params(self) -> Result<Option<Box<RawValue>>, serde_json::Error> {
+        Ok(self.0)
     }
 }
 
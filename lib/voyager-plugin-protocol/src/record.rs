@@ -0,0 +1,202 @@
+//! Recording and replaying of the requests made by the coordinator to its workers.
+//!
+//! A [`Recorder`] appends every request/response pair to a file as newline-delimited JSON, which
+//! can later be loaded as a [`Recording`] to serve the same responses without running any workers.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use jsonrpsee::types::ErrorObject;
+use serde::{Deserialize, Serialize};
+use serde_json::{value::RawValue, Value};
+use tracing::{error, trace};
+use voyager_rpc::FATAL_JSONRPC_ERROR_CODE;
+
+use crate::ParamsWithItemId;
+
+/// A single request made to a worker, along with the response it returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RecordedRequest {
+    /// The name of the worker (plugin or module) the request was made to.
+    pub worker: String,
+    pub method: String,
+    /// The params of the request. Any threaded item id is not included, as item ids are not
+    /// stable between runs.
+    pub params: Option<Value>,
+    pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum RecordedResponse {
+    Ok(Value),
+    /// The worker returned an error.
+    Error {
+        code: i32,
+        message: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        data: Option<Value>,
+    },
+    /// The request failed without reaching the worker (i.e. a timeout or a closed connection).
+    Transport(String),
+}
+
+impl RecordedResponse {
+    pub fn from_result(result: &Result<Value, jsonrpsee::core::client::Error>) -> Self {
+        match result {
+            Ok(value) => RecordedResponse::Ok(value.clone()),
+            Err(jsonrpsee::core::client::Error::Call(error)) => RecordedResponse::Error {
+                code: error.code(),
+                message: error.message().to_owned(),
+                data: error.data().map(|data| {
+                    serde_json::from_str(data.get()).expect("data is valid json; qed;")
+                }),
+            },
+            Err(error) => RecordedResponse::Transport(error.to_string()),
+        }
+    }
+
+    pub fn into_result(self) -> Result<Value, jsonrpsee::core::client::Error> {
+        match self {
+            RecordedResponse::Ok(value) => Ok(value),
+            RecordedResponse::Error {
+                code,
+                message,
+                data,
+            } => Err(jsonrpsee::core::client::Error::Call(ErrorObject::owned(
+                code, message, data,
+            ))),
+            RecordedResponse::Transport(error) => {
+                Err(jsonrpsee::core::client::Error::Custom(error))
+            }
+        }
+    }
+}
+
+/// Strip the threaded item id (if any) from the raw params of a request.
+pub(crate) fn recorded_params(params: Option<&RawValue>) -> Option<Value> {
+    let params = params?;
+
+    match serde_json::from_str::<ParamsWithItemId>(params.get()) {
+        Ok(ParamsWithItemId { params, .. }) => params
+            .map(|params| serde_json::from_str(params.get()).expect("params are valid json; qed;")),
+        Err(_) => Some(serde_json::from_str(params.get()).expect("params are valid json; qed;")),
+    }
+}
+
+/// Appends all requests made to workers to a file.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    file: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl Recorder {
+    /// Create a new recorder writing to `path`. If the file already exists, new requests are
+    /// appended to it.
+    pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = File::options().create(true).append(true).open(path)?;
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(BufWriter::new(file))),
+        })
+    }
+
+    pub fn record(&self, request: &RecordedRequest) {
+        let line = serde_json::to_string(request).expect("serialization is infallible; qed;");
+
+        let mut file = self.file.lock().expect("mutex is poisoned");
+
+        if let Err(error) = writeln!(file, "{line}").and_then(|()| file.flush()) {
+            error!(%error, worker = %request.worker, method = %request.method, "unable to record request");
+        }
+    }
+}
+
+/// The responses of a previous run, as written by a [`Recorder`].
+///
+/// Responses are matched by worker, method and params. If the same request was made multiple
+/// times, the recorded responses are returned in the order they were recorded in.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    #[allow(clippy::type_complexity)]
+    responses: Arc<Mutex<HashMap<(String, String, Option<String>), VecDeque<RecordedResponse>>>>,
+}
+
+impl Recording {
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let mut responses = HashMap::<_, VecDeque<_>>::new();
+
+        for (idx, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let request = serde_json::from_str::<RecordedRequest>(&line).map_err(|e| {
+                anyhow::anyhow!("invalid recorded request on line {}: {e}", idx + 1)
+            })?;
+
+            responses
+                .entry(key(
+                    &request.worker,
+                    &request.method,
+                    request.params.as_ref(),
+                ))
+                .or_default()
+                .push_back(request.response);
+        }
+
+        Ok(Self {
+            responses: Arc::new(Mutex::new(responses)),
+        })
+    }
+
+    /// Take the next recorded response to this request.
+    ///
+    /// Requests that were not recorded (or that have been replayed more times than they were
+    /// recorded) fail with a fatal error, since retrying them would never succeed.
+    pub fn next_response(
+        &self,
+        worker: &str,
+        method: &str,
+        params: Option<&Value>,
+    ) -> Result<Value, jsonrpsee::core::client::Error> {
+        let response = self
+            .responses
+            .lock()
+            .expect("mutex is poisoned")
+            .get_mut(&key(worker, method, params))
+            .and_then(VecDeque::pop_front);
+
+        match response {
+            Some(response) => {
+                trace!(%worker, %method, "replaying recorded response");
+
+                response.into_result()
+            }
+            None => Err(jsonrpsee::core::client::Error::Call(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "no recorded response for {method} on {worker} with params {}",
+                    params.map_or_else(|| "null".to_owned(), ToString::to_string)
+                ),
+                None::<()>,
+            ))),
+        }
+    }
+}
+
+fn key(worker: &str, method: &str, params: Option<&Value>) -> (String, String, Option<String>) {
+    (
+        worker.to_owned(),
+        method.to_owned(),
+        params.map(ToString::to_string),
+    )
+}
@@ -99,6 +99,12 @@ impl ClientType {
     /// [Scroll]: https://github.com/scroll-tech/scroll
     pub const SCROLL: &'static str = "scroll";
 
+    /// A client tracking the state of the [Linea] zkEVM L2, settling on
+    /// Ethereum, verified by verifying the L2 settlement on the L1.
+    ///
+    /// [Linea]: https://github.com/Consensys/linea-monorepo
+    pub const LINEA: &'static str = "linea";
+
     /// A client tracking the state of the [Arbitrum] optimistic L2, settling on
     /// Ethereum, verified by verifying the L2 settlement on the L1.
     ///
@@ -197,6 +203,11 @@ impl ConsensusType {
     /// [Scroll]: https://github.com/scroll-tech/scroll
     pub const SCROLL: &'static str = "scroll";
 
+    /// [Linea] zkEVM L2, settling on Ethereum.
+    ///
+    /// [Linea]: https://github.com/Consensys/linea-monorepo
+    pub const LINEA: &'static str = "linea";
+
     /// [Arbitrum] optimistic L2, settling on Ethereum.
     ///
     /// [Arbitrum]: https://github.com/OffchainLabs/nitro-contracts
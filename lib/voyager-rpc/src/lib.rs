@@ -24,10 +24,10 @@ use voyager_primitives::{
     IbcSpecId, QueryHeight, Timestamp,
 };
 use voyager_types::{ProofType, RawClientId};
-use voyager_vm::{pass::PassResult, Op, QueueError};
+use voyager_vm::{history::History, pass::PassResult, ItemId, Op, QueueError};
 
 use crate::types::{
-    IbcProofResponse, IbcStateResponse, InfoResponse, SelfClientStateResponse,
+    IbcProofResponse, IbcStateResponse, InfoResponse, ReloadResponse, SelfClientStateResponse,
     SelfConsensusStateResponse,
 };
 
@@ -321,6 +321,32 @@ pub trait VoyagerRpc {
     ) -> RpcResult<Value>;
 }
 
+/// Inspect the queue of a running voyager instance.
+#[rpc(client, server, namespace = "queue")]
+pub trait QueueRpc {
+    /// Query the ancestors and descendants of the item with the given id. `max_depth` defaults to
+    /// [`voyager_vm::history::DEFAULT_MAX_DEPTH`].
+    #[method(name = "history")]
+    async fn history(
+        &self,
+        id: ItemId,
+        max_depth: Option<u32>,
+    ) -> RpcResult<Option<History<VoyagerMessage>>>;
+}
+
+/// Manage the configuration of a running voyager instance.
+#[rpc(client, server, namespace = "config")]
+pub trait ConfigRpc {
+    /// Re-read the plugin and module configuration from the config file that voyager was started
+    /// with, restarting only the plugins and modules whose config has changed. The queue keeps
+    /// running throughout.
+    ///
+    /// If the new configuration is invalid, an error is returned and the running configuration is
+    /// left untouched.
+    #[method(name = "reload")]
+    async fn reload(&self) -> RpcResult<ReloadResponse>;
+}
+
 #[rpc(client, server, namespace = "plugin")]
 pub trait Plugin<C: Member, Cb: Member> {
     #[method(name = "runPass", with_extensions)]
@@ -16,6 +16,35 @@ pub struct InfoResponse {
     pub consensus: Vec<FinalityModuleInfo>,
     pub client: Vec<ClientModuleInfo>,
     pub client_bootstrap: Vec<ClientBootstrapModuleInfo>,
+    /// The health of every running plugin and module, sorted by name.
+    #[serde(default)]
+    pub health: Vec<WorkerHealthInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WorkerHealthInfo {
+    /// The name of the plugin, or the id of the module.
+    pub name: String,
+    pub status: HealthStatus,
+    /// The amount of health checks that have failed since the last successful one.
+    pub consecutive_failures: u32,
+    /// The amount of times this worker has been restarted after failing its health checks.
+    pub restarts: u64,
+    /// Unix timestamp (in seconds) of the last successful health check, if any.
+    pub last_healthy_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    /// The worker has been (re)started, but has not yet responded to a health check.
+    Starting,
+    /// The worker is responding to health checks.
+    Healthy,
+    /// The worker stopped responding to health checks and is being restarted. Calls to an
+    /// unhealthy plugin are deferred until it is healthy again.
+    Unhealthy,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -298,6 +327,22 @@ pub struct PluginInfo {
     pub interest_filter: String,
 }
 
+/// The plugins and modules affected by reloading the config of a running voyager instance, by
+/// name.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReloadResponse {
+    /// Plugins and modules that were not previously configured, and have been started.
+    pub started: Vec<String>,
+    /// Plugins and modules whose config changed, and have been restarted with the new config.
+    pub restarted: Vec<String>,
+    /// Plugins and modules that are no longer configured (or are no longer enabled), and have
+    /// been stopped.
+    pub stopped: Vec<String>,
+    /// Plugins and modules whose config did not change. These are left running untouched.
+    pub unchanged: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct IbcStateResponse<State> {
     /// The height that the state was read at.
@@ -37,7 +37,11 @@ where
         .try_filter_map(async |e| Ok(e))
     }
 
-    pub(crate) fn step<'b>(
+    /// Process a single item from the queue, if one is ready.
+    ///
+    /// Unlike [`Engine::run`], this allows for stopping between items without dropping an item
+    /// that is in the middle of being processed.
+    pub fn step<'b>(
         &'b self,
     ) -> impl Future<Output = Result<Option<T::Data>, BoxDynError>> + use<'a, 'b, T, Q, H, F> + Send
     {
@@ -1,8 +1,32 @@
-use crate::{Op, QueueMessage};
+use crate::{lane::DEFAULT_LANE, Op, QueueMessage};
 
 /// A filter to run on [`Op`]s before they're pushed into the queue.
 pub trait InterestFilter<T: QueueMessage>: Send + Sync + Sized + 'static {
     fn check_interest<'a>(&'a self, op: &Op<T>) -> FilterResult<'a>;
+
+    /// The lane to queue `op` in once it's ready to be processed. Defaults to [`DEFAULT_LANE`].
+    fn lane<'a>(&'a self, op: &Op<T>) -> &'a str {
+        let _ = op;
+
+        DEFAULT_LANE
+    }
+
+    /// The order in which lanes should be checked when pulling the next item to process, see
+    /// [`WeightedLanes::next_order`](crate::lane::WeightedLanes::next_order). Items in lanes that
+    /// are not in this list are processed after all of these lanes are empty.
+    ///
+    /// If this is empty (the default), lanes are ignored and items are processed in the order
+    /// they're ready in.
+    fn lane_order(&self) -> Vec<&str> {
+        vec![]
+    }
+
+    /// Called once an item has been claimed from `lane`, see
+    /// [`WeightedLanes::served`](crate::lane::WeightedLanes::served). Only called for lanes
+    /// returned by [`Self::lane_order`].
+    fn lane_served(&self, lane: &str) {
+        let _ = lane;
+    }
 }
 
 /// The result of running an [`InterestFilter`] on an [`Op`].
@@ -0,0 +1,256 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt::Write,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ItemId, Op, QueueMessage};
+
+/// The default maximum depth to walk in either direction when querying the history of an item.
+pub const DEFAULT_MAX_DEPTH: u32 = 10;
+
+/// The lineage of an item in the queue: all of it's ancestors (the items that were processed to
+/// produce it) and descendants (the items produced by processing it), up to a maximum depth.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    bound(serialize = "", deserialize = ""),
+    deny_unknown_fields,
+    rename_all = "snake_case"
+)]
+pub struct History<T: QueueMessage> {
+    /// The item that the history was queried for.
+    pub id: ItemId,
+    /// All items related to [`Self::id`], including the item itself, ordered by id.
+    pub items: Vec<HistoryItem<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    bound(serialize = "", deserialize = ""),
+    deny_unknown_fields,
+    rename_all = "snake_case"
+)]
+pub struct HistoryItem<T: QueueMessage> {
+    pub id: ItemId,
+    pub parents: Vec<ItemId>,
+    pub status: ItemStatus,
+    pub item: Op<T>,
+}
+
+/// Where an item currently lives in the queue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "@type",
+    content = "@value",
+    rename_all = "snake_case",
+    deny_unknown_fields
+)]
+pub enum ItemStatus {
+    /// The item is waiting to be processed.
+    Queued,
+    /// The item is waiting to be picked up by the optimizer for `tag`.
+    Optimize { tag: String },
+    /// The item was processed (or consumed by an optimization pass) successfully.
+    Done,
+    /// Processing the item failed with a fatal or unprocessable error.
+    Failed { message: String },
+}
+
+impl<T: QueueMessage> History<T> {
+    /// Build the history of `id` out of a set of items, walking at most `max_depth` levels of
+    /// ancestors and descendants.
+    ///
+    /// This is intended for queues that can't express the graph traversal natively, and as such
+    /// `items` is expected to contain every item known to the queue. Returns `None` if `id` is not
+    /// in `items`.
+    pub fn from_items(
+        id: ItemId,
+        max_depth: u32,
+        items: impl IntoIterator<Item = HistoryItem<T>>,
+    ) -> Option<Self> {
+        let items = items
+            .into_iter()
+            .map(|item| (item.id, item))
+            .collect::<BTreeMap<_, _>>();
+
+        if !items.contains_key(&id) {
+            return None;
+        }
+
+        let mut children = BTreeMap::<ItemId, Vec<ItemId>>::new();
+        for item in items.values() {
+            for parent in &item.parents {
+                children.entry(*parent).or_default().push(item.id);
+            }
+        }
+
+        let mut related = BTreeSet::from([id]);
+
+        let mut walk = |next: &dyn Fn(ItemId) -> Vec<ItemId>| {
+            let mut frontier = VecDeque::from([(id, 0)]);
+            while let Some((id, depth)) = frontier.pop_front() {
+                if depth >= max_depth {
+                    continue;
+                }
+
+                for next in next(id) {
+                    if related.insert(next) {
+                        frontier.push_back((next, depth + 1));
+                    }
+                }
+            }
+        };
+
+        walk(&|id| {
+            items
+                .get(&id)
+                .map(|item| item.parents.clone())
+                .unwrap_or_default()
+        });
+        walk(&|id| children.get(&id).cloned().unwrap_or_default());
+
+        Some(Self {
+            id,
+            items: items
+                .into_values()
+                .filter(|item| related.contains(&item.id))
+                .collect(),
+        })
+    }
+
+    /// Render the history as a tree, one item per line. The item that the history was queried for
+    /// is marked with a `*`.
+    ///
+    /// Items produced by an optimization pass can have multiple parents; such items are printed
+    /// in full under their first parent only, and referenced by id under the others.
+    #[must_use]
+    pub fn render_tree(&self) -> String {
+        let ids = self
+            .items
+            .iter()
+            .map(|item| item.id)
+            .collect::<BTreeSet<_>>();
+
+        let mut children = BTreeMap::<ItemId, Vec<&HistoryItem<T>>>::new();
+        let mut roots = vec![];
+        for item in &self.items {
+            let mut parents = item.parents.iter().filter(|p| ids.contains(*p)).peekable();
+
+            if parents.peek().is_none() {
+                roots.push(item);
+            }
+
+            for parent in parents {
+                children.entry(*parent).or_default().push(item);
+            }
+        }
+
+        let mut out = String::new();
+        let mut seen = BTreeSet::new();
+
+        for (idx, root) in roots.iter().enumerate() {
+            self.render_item(
+                &mut out,
+                root,
+                &children,
+                &mut seen,
+                "",
+                idx + 1 == roots.len(),
+                true,
+            );
+        }
+
+        out
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn render_item(
+        &self,
+        out: &mut String,
+        item: &HistoryItem<T>,
+        children: &BTreeMap<ItemId, Vec<&HistoryItem<T>>>,
+        seen: &mut BTreeSet<ItemId>,
+        prefix: &str,
+        last: bool,
+        root: bool,
+    ) {
+        let (branch, indent) = match (root, last) {
+            (true, _) => ("", ""),
+            (false, true) => ("└── ", "    "),
+            (false, false) => ("├── ", "│   "),
+        };
+
+        let marker = if item.id == self.id { "* " } else { "" };
+
+        if !seen.insert(item.id) {
+            writeln!(out, "{prefix}{branch}{marker}{} (see above)", item.id.raw()).unwrap();
+            return;
+        }
+
+        writeln!(
+            out,
+            "{prefix}{branch}{marker}{} [{}] {}",
+            item.id.raw(),
+            item.status.summary(),
+            op_summary(&item.item),
+        )
+        .unwrap();
+
+        let prefix = format!("{prefix}{indent}");
+        let item_children = children
+            .get(&item.id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        for (idx, child) in item_children.iter().enumerate() {
+            self.render_item(
+                out,
+                child,
+                children,
+                seen,
+                &prefix,
+                idx + 1 == item_children.len(),
+                false,
+            );
+        }
+    }
+}
+
+impl ItemStatus {
+    fn summary(&self) -> String {
+        match self {
+            ItemStatus::Queued => "queued".to_owned(),
+            ItemStatus::Optimize { tag } => format!("optimize: {tag}"),
+            ItemStatus::Done => "done".to_owned(),
+            ItemStatus::Failed { message } => format!("failed: {message}"),
+        }
+    }
+}
+
+/// A short, single line description of an op. For data and calls, this includes the `@type` of
+/// the inner value if it has one.
+fn op_summary<T: QueueMessage>(op: &Op<T>) -> String {
+    let inner_type = |value: serde_json::Value| {
+        value
+            .get("@type")
+            .and_then(|ty| ty.as_str())
+            .map(|ty| format!("({ty})"))
+            .unwrap_or_default()
+    };
+
+    match op {
+        Op::Data(data) => format!("data{}", inner_type(serde_json::to_value(data).unwrap())),
+        Op::Call(call) => format!("call{}", inner_type(serde_json::to_value(call).unwrap())),
+        Op::Defer { until } => format!("defer(until: {until})"),
+        Op::DeferRelative { secs } => format!("defer_relative(secs: {secs})"),
+        Op::Seq(ops) => format!("seq(len: {})", ops.len()),
+        Op::Conc(ops) => format!("conc(len: {})", ops.len()),
+        Op::Promise(promise) => format!(
+            "promise{}",
+            inner_type(serde_json::to_value(&promise.receiver).unwrap())
+        ),
+        Op::Void(_) => "void".to_owned(),
+        Op::Noop => "noop".to_owned(),
+    }
+}
@@ -13,7 +13,10 @@ use unionlabs::ErrorReporter;
 
 use crate::{
     filter::{FilterResult, Interest, InterestFilter},
+    history::{History, HistoryItem, ItemStatus},
+    now,
     pass::Pass,
+    schedule::{ScheduleState, Trigger},
     Captures, EnqueueResult, ItemId, Op, Queue, QueueError, QueueMessage,
 };
 
@@ -22,86 +25,121 @@ pub struct InMemoryQueue<T: QueueMessage> {
     idx: Arc<AtomicU32>,
     ready: Arc<Mutex<BTreeMap<u32, Item<T>>>>,
     done: Arc<Mutex<BTreeMap<u32, Item<T>>>>,
+    failed: Arc<Mutex<BTreeMap<u32, (Item<T>, String)>>>,
     #[allow(clippy::type_complexity)]
     optimizer_queue: Arc<Mutex<BTreeMap<String, BTreeMap<u32, Item<T>>>>>,
+    schedules: Arc<Mutex<BTreeMap<String, ScheduleState>>>,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct Item<T: QueueMessage> {
-    #[allow(dead_code)] // used in debug
     parents: Vec<u32>,
+    /// The schedule that (transitively) created this item, if any.
+    schedule: Option<String>,
+    lane: String,
     op: Op<T>,
 }
 
-impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
-    type Error = std::convert::Infallible;
-    type Config = ();
-
-    fn new(_cfg: Self::Config) -> impl Future<Output = Result<Self, Self::Error>> {
-        futures::future::ok(Self {
-            idx: Arc::new(AtomicU32::default()),
-            done: Arc::new(Mutex::new(BTreeMap::default())),
-            ready: Arc::new(Mutex::new(BTreeMap::default())),
-            optimizer_queue: Arc::new(Mutex::new(BTreeMap::default())),
-        })
-    }
-
-    fn enqueue<'a, Filter>(
-        &'a self,
+impl<T: QueueMessage> InMemoryQueue<T> {
+    fn enqueue_inner<Filter: InterestFilter<T>>(
+        &self,
         op: Op<T>,
-        filter: &'a Filter,
-    ) -> impl Future<Output = Result<EnqueueResult, Self::Error>> + Send + 'a
-    where
-        Filter: InterestFilter<T>,
-    {
+        filter: &Filter,
+        schedule: Option<&str>,
+    ) -> EnqueueResult {
         debug!(?op, "enqueueing new item");
 
         let mut optimizer_queue = self.optimizer_queue.lock().expect("mutex is poisoned");
         let mut ready = self.ready.lock().expect("mutex is poisoned");
 
+        let item = |op: Op<T>| Item {
+            parents: vec![],
+            schedule: schedule.map(ToOwned::to_owned),
+            lane: filter.lane(&op).to_owned(),
+            op,
+        };
+
         for op in op.normalize() {
             match filter.check_interest(&op) {
                 FilterResult::Interest(Interest { tags, remove }) => {
                     for tag in tags {
-                        optimizer_queue.entry(tag.to_owned()).or_default().insert(
-                            self.idx.fetch_add(1, Ordering::SeqCst),
-                            Item {
-                                parents: vec![],
-                                op: op.clone(),
-                            },
-                        );
+                        optimizer_queue
+                            .entry(tag.to_owned())
+                            .or_default()
+                            .insert(self.idx.fetch_add(1, Ordering::SeqCst), item(op.clone()));
                     }
 
                     if !remove {
-                        ready.insert(
-                            self.idx.fetch_add(1, Ordering::SeqCst),
-                            Item {
-                                parents: vec![],
-                                op,
-                            },
-                        );
+                        ready.insert(self.idx.fetch_add(1, Ordering::SeqCst), item(op));
                     }
                 }
                 FilterResult::NoInterest => {
-                    ready.insert(
-                        self.idx.fetch_add(1, Ordering::SeqCst),
-                        Item {
-                            parents: vec![],
-                            op,
-                        },
-                    );
+                    ready.insert(self.idx.fetch_add(1, Ordering::SeqCst), item(op));
                 }
             }
         }
 
         debug!("enqueued new item");
 
-        futures::future::ok(EnqueueResult {
+        EnqueueResult {
             queue: vec![],
             optimize: vec![],
+        }
+    }
+
+    fn update_schedule(&self, name: &str, trigger: &Trigger, next_run_at: u64, skipped: bool) {
+        let mut schedules = self.schedules.lock().expect("mutex is poisoned");
+
+        let state = schedules
+            .entry(name.to_owned())
+            .or_insert_with(|| ScheduleState {
+                name: name.to_owned(),
+                trigger: trigger.clone(),
+                next_run_at,
+                last_run_at: None,
+                runs: 0,
+                skipped: 0,
+                in_flight: 0,
+            });
+
+        state.trigger = trigger.clone();
+        state.next_run_at = next_run_at;
+
+        if skipped {
+            state.skipped += 1;
+        } else {
+            state.runs += 1;
+            state.last_run_at = Some(now());
+        }
+    }
+}
+
+impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
+    type Error = std::convert::Infallible;
+    type Config = ();
+
+    fn new(_cfg: Self::Config) -> impl Future<Output = Result<Self, Self::Error>> {
+        futures::future::ok(Self {
+            idx: Arc::new(AtomicU32::default()),
+            done: Arc::new(Mutex::new(BTreeMap::default())),
+            failed: Arc::new(Mutex::new(BTreeMap::default())),
+            ready: Arc::new(Mutex::new(BTreeMap::default())),
+            optimizer_queue: Arc::new(Mutex::new(BTreeMap::default())),
+            schedules: Arc::new(Mutex::new(BTreeMap::default())),
         })
     }
 
+    fn enqueue<'a, Filter>(
+        &'a self,
+        op: Op<T>,
+        filter: &'a Filter,
+    ) -> impl Future<Output = Result<EnqueueResult, Self::Error>> + Send + 'a
+    where
+        Filter: InterestFilter<T>,
+    {
+        futures::future::ok(self.enqueue_inner(op, filter, None))
+    }
+
     async fn process<'a, F, Fut, R, Filter>(
         &'a self,
         filter: &'a Filter,
@@ -115,7 +153,24 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
     {
         let op = {
             let mut queue = self.ready.lock().expect("mutex is poisoned");
-            let op = queue.pop_first();
+
+            // take the oldest item in the first non-empty lane, falling back to the oldest item in
+            // any lane
+            let item_id = filter
+                .lane_order()
+                .into_iter()
+                .find_map(|lane| {
+                    let item_id = queue
+                        .iter()
+                        .find_map(|(id, item)| (item.lane == lane).then_some(*id))?;
+
+                    filter.lane_served(lane);
+
+                    Some(item_id)
+                })
+                .or_else(|| queue.keys().next().copied());
+
+            let op = item_id.and_then(|item_id| queue.remove_entry(&item_id));
 
             drop(queue);
 
@@ -126,11 +181,6 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
             Some((item_id, item)) => {
                 let span = info_span!("processing item", %item_id);
 
-                self.done
-                    .lock()
-                    .expect("mutex is poisoned")
-                    .insert(item_id, item.clone());
-
                 let (r, res) = f(
                     item.op.clone(),
                     ItemId::new(i64::from(item_id)).expect("infallible"),
@@ -143,6 +193,11 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
 
                 match res {
                     Ok(ops) => {
+                        self.done
+                            .lock()
+                            .expect("mutex is poisoned")
+                            .insert(item_id, item.clone());
+
                         for op in ops.into_iter().flat_map(Op::normalize) {
                             match filter.check_interest(&op) {
                                 FilterResult::Interest(Interest { tags, remove }) => {
@@ -150,7 +205,9 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                                         optimizer_queue.entry(tag.to_owned()).or_default().insert(
                                             self.idx.fetch_add(1, Ordering::SeqCst),
                                             Item {
-                                                parents: vec![],
+                                                parents: vec![item_id],
+                                                schedule: item.schedule.clone(),
+                                                lane: filter.lane(&op).to_owned(),
                                                 op: op.clone(),
                                             },
                                         );
@@ -160,7 +217,9 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                                         ready.insert(
                                             self.idx.fetch_add(1, Ordering::SeqCst),
                                             Item {
-                                                parents: vec![],
+                                                parents: vec![item_id],
+                                                schedule: item.schedule.clone(),
+                                                lane: filter.lane(&op).to_owned(),
                                                 op,
                                             },
                                         );
@@ -170,7 +229,9 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                                     ready.insert(
                                         self.idx.fetch_add(1, Ordering::SeqCst),
                                         Item {
-                                            parents: vec![],
+                                            parents: vec![item_id],
+                                            schedule: item.schedule.clone(),
+                                            lane: filter.lane(&op).to_owned(),
                                             op,
                                         },
                                     );
@@ -183,10 +244,18 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                     Err(why) => match why {
                         QueueError::Fatal(error) => {
                             error!(error = %ErrorReporter(&*error), "fatal error");
+                            self.failed
+                                .lock()
+                                .expect("mutex is poisoned")
+                                .insert(item_id, (item, ErrorReporter(&*error).to_string()));
                             Ok(None)
                         }
                         QueueError::Unprocessable(error) => {
                             info!(error = %ErrorReporter(&*error), "unprocessable message");
+                            self.failed
+                                .lock()
+                                .expect("mutex is poisoned")
+                                .insert(item_id, (item, ErrorReporter(&*error).to_string()));
                             Ok(None)
                         }
                         QueueError::Retry(error) => {
@@ -233,6 +302,19 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
 
             let (ids, ops): (Vec<_>, Vec<_>) = tagged_optimizer_queue.clone().into_iter().unzip();
 
+            // items produced by the optimizer are attributed to the schedule of their first (i.e.
+            // oldest) parent
+            let schedules = ops
+                .iter()
+                .map(|item| item.schedule.clone())
+                .collect::<Vec<_>>();
+            let schedule_of = |parents_idxs: &[usize]| {
+                parents_idxs
+                    .iter()
+                    .min()
+                    .and_then(|&i| schedules[i].clone())
+            };
+
             let res = optimizer
                 .run_pass(ops.into_iter().map(|item| item.op).collect())
                 .await
@@ -259,6 +341,8 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                                             .map(|&i| &ids[i])
                                             .copied()
                                             .collect(),
+                                        schedule: schedule_of(&parents_idxs),
+                                        lane: filter.lane(&op).to_owned(),
                                         op: op.clone(),
                                     },
                                 );
@@ -275,6 +359,8 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                         self.idx.fetch_add(1, Ordering::SeqCst),
                         Item {
                             parents: parents_idxs.iter().map(|&i| &ids[i]).copied().collect(),
+                            schedule: schedule_of(&parents_idxs),
+                            lane: filter.lane(&op).to_owned(),
                             op,
                         },
                     );
@@ -286,6 +372,8 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
                     self.idx.fetch_add(1, Ordering::SeqCst),
                     Item {
                         parents: parents_idxs.iter().map(|&i| &ids[i]).copied().collect(),
+                        schedule: schedule_of(&parents_idxs),
+                        lane: filter.lane(&op).to_owned(),
                         op,
                     },
                 );
@@ -294,4 +382,119 @@ impl<T: QueueMessage> Queue<T> for InMemoryQueue<T> {
             Ok(())
         }
     }
+
+    async fn history(&self, id: ItemId, max_depth: u32) -> Result<Option<History<T>>, Self::Error> {
+        let history_item = |id: u32, item: Item<T>, status: ItemStatus| HistoryItem {
+            id: ItemId::new(i64::from(id)).expect("infallible"),
+            parents: item
+                .parents
+                .into_iter()
+                .map(|parent| ItemId::new(i64::from(parent)).expect("infallible"))
+                .collect(),
+            status,
+            item: item.op,
+        };
+
+        let mut items = vec![];
+
+        for (tag, tagged_optimizer_queue) in self
+            .optimizer_queue
+            .lock()
+            .expect("mutex is poisoned")
+            .iter()
+        {
+            items.extend(tagged_optimizer_queue.iter().map(|(id, item)| {
+                history_item(*id, item.clone(), ItemStatus::Optimize { tag: tag.clone() })
+            }));
+        }
+
+        items.extend(
+            self.ready
+                .lock()
+                .expect("mutex is poisoned")
+                .iter()
+                .map(|(id, item)| history_item(*id, item.clone(), ItemStatus::Queued)),
+        );
+
+        items.extend(
+            self.done
+                .lock()
+                .expect("mutex is poisoned")
+                .iter()
+                .map(|(id, item)| history_item(*id, item.clone(), ItemStatus::Done)),
+        );
+
+        items.extend(self.failed.lock().expect("mutex is poisoned").iter().map(
+            |(id, (item, message))| {
+                history_item(
+                    *id,
+                    item.clone(),
+                    ItemStatus::Failed {
+                        message: message.clone(),
+                    },
+                )
+            },
+        ));
+
+        Ok(History::from_items(id, max_depth, items))
+    }
+
+    fn enqueue_scheduled<'a, Filter>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        op: Op<T>,
+        filter: &'a Filter,
+        next_run_at: u64,
+    ) -> impl Future<Output = Result<EnqueueResult, Self::Error>> + Send + 'a
+    where
+        Filter: InterestFilter<T>,
+    {
+        let res = self.enqueue_inner(op, filter, Some(name));
+
+        self.update_schedule(name, trigger, next_run_at, false);
+
+        futures::future::ok(res)
+    }
+
+    async fn skip_scheduled(
+        &self,
+        name: &str,
+        trigger: &Trigger,
+        next_run_at: u64,
+    ) -> Result<(), Self::Error> {
+        self.update_schedule(name, trigger, next_run_at, true);
+
+        Ok(())
+    }
+
+    async fn schedules(&self) -> Result<Vec<ScheduleState>, Self::Error> {
+        let mut in_flight = BTreeMap::<String, u64>::new();
+
+        let optimizer_queue = self.optimizer_queue.lock().expect("mutex is poisoned");
+        let ready = self.ready.lock().expect("mutex is poisoned");
+
+        for item in ready
+            .values()
+            .chain(optimizer_queue.values().flat_map(BTreeMap::values))
+        {
+            if let Some(schedule) = &item.schedule {
+                *in_flight.entry(schedule.clone()).or_default() += 1;
+            }
+        }
+
+        drop(optimizer_queue);
+        drop(ready);
+
+        Ok(self
+            .schedules
+            .lock()
+            .expect("mutex is poisoned")
+            .values()
+            .map(|state| ScheduleState {
+                in_flight: in_flight.get(&state.name).copied().unwrap_or_default(),
+                ..state.clone()
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,98 @@
+use std::sync::Mutex;
+
+/// The lane that items are queued in if no other lane is assigned to them.
+pub const DEFAULT_LANE: &str = "default";
+
+/// A set of lanes that are served by smooth weighted round-robin.
+///
+/// Lanes are specified in priority order. Whenever a queue pulls the next item to process, it first
+/// checks the lane selected by the round-robin, and then all other lanes in priority order. As
+/// such, a lane with a weight of `n` is served `n` times as often as a lane with a weight of `1`
+/// while both have items ready, and a lane with a weight of `0` is only served once all other
+/// lanes are empty.
+#[derive(Debug)]
+pub struct WeightedLanes {
+    lanes: Vec<(String, u64)>,
+    current: Mutex<Vec<i128>>,
+}
+
+impl WeightedLanes {
+    /// Create a new set of lanes from `(name, weight)` pairs, in priority order. If the
+    /// [`DEFAULT_LANE`] is not specified, it is added as the lowest priority lane with a weight of
+    /// `1`.
+    pub fn new(lanes: impl IntoIterator<Item = (String, u64)>) -> Self {
+        let mut lanes = lanes.into_iter().collect::<Vec<_>>();
+
+        if !lanes.iter().any(|(name, _)| name == DEFAULT_LANE) {
+            lanes.push((DEFAULT_LANE.to_owned(), 1));
+        }
+
+        Self {
+            current: Mutex::new(vec![0; lanes.len()]),
+            lanes,
+        }
+    }
+
+    /// The names of all lanes, in priority order.
+    pub fn lanes(&self) -> impl Iterator<Item = &str> {
+        self.lanes.iter().map(|(name, _)| &**name)
+    }
+
+    /// The order in which the lanes should be checked for the next item to process: the lane
+    /// selected by the round-robin, followed by all other lanes in priority order.
+    ///
+    /// This does not advance the round-robin; call [`Self::served`] once an item has been claimed
+    /// from one of the lanes.
+    #[must_use]
+    pub fn next_order(&self) -> Vec<&str> {
+        let current = self.current.lock().expect("mutex is poisoned");
+
+        let mut selected = 0;
+        for (idx, (_, weight)) in self.lanes.iter().enumerate() {
+            // ties go to the higher priority lane
+            if current[idx] + i128::from(*weight)
+                > current[selected] + i128::from(self.lanes[selected].1)
+            {
+                selected = idx;
+            }
+        }
+
+        drop(current);
+
+        [selected]
+            .into_iter()
+            .chain((0..self.lanes.len()).filter(|idx| *idx != selected))
+            .map(|idx| &*self.lanes[idx].0)
+            .collect()
+    }
+
+    /// Advance the round-robin, charging `lane` for the item that was just claimed from it. Lanes
+    /// that are not part of this set are ignored.
+    ///
+    /// Only charging the lane that was actually served ensures that the weights are honored while
+    /// some of the lanes are empty. The accumulated credit of a lane is capped, so that a lane that
+    /// was empty for a while does not starve the other lanes once it has items again.
+    pub fn served(&self, lane: &str) {
+        let Some(served) = self.lanes.iter().position(|(name, _)| name == lane) else {
+            return;
+        };
+
+        let total = self
+            .lanes
+            .iter()
+            .map(|(_, weight)| i128::from(*weight))
+            .sum::<i128>();
+
+        let mut current = self.current.lock().expect("mutex is poisoned");
+
+        for (idx, (_, weight)) in self.lanes.iter().enumerate() {
+            current[idx] += i128::from(*weight);
+        }
+
+        current[served] -= total;
+
+        for current in current.iter_mut() {
+            *current = (*current).clamp(-total, total);
+        }
+    }
+}
@@ -20,12 +20,20 @@ use tokio::time::sleep;
 use tracing::{debug, error, info, trace, warn};
 use unionlabs::bounded::{BoundedI64, BoundedIntError};
 
-use crate::{filter::InterestFilter, pass::Pass};
+use crate::{
+    filter::InterestFilter,
+    history::History,
+    pass::Pass,
+    schedule::{ScheduleState, Trigger},
+};
 
 pub mod engine;
 pub mod filter;
+pub mod history;
 pub mod in_memory;
+pub mod lane;
 pub mod pass;
+pub mod schedule;
 
 #[cfg(test)]
 mod tests;
@@ -73,6 +81,47 @@ pub trait Queue<T: QueueMessage>: Debug + Clone + Send + Sync + Sized + 'static
     where
         O: Pass<T>,
         Filter: InterestFilter<T>;
+
+    /// Query the lineage of the item with the given id, walking at most `max_depth` levels of
+    /// ancestors and descendants. This will find items in any state (queued, waiting to be
+    /// optimized, done, or failed), as far as the queue still retains them.
+    ///
+    /// Returns `None` if no item with the given id exists.
+    fn history(
+        &self,
+        id: ItemId,
+        max_depth: u32,
+    ) -> impl Future<Output = Result<Option<History<T>>, Self::Error>> + Send + '_;
+
+    /// Enqueue an item on behalf of the schedule `name`, recording the run, the trigger of the
+    /// schedule, and the time of the next run in the queue.
+    ///
+    /// All items produced by processing this item (transitively) are attributed to the schedule
+    /// while they are in the queue, see [`ScheduleState::in_flight`].
+    fn enqueue_scheduled<'a, Filter>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        item: Op<T>,
+        filter: &'a Filter,
+        next_run_at: u64,
+    ) -> impl Future<Output = Result<EnqueueResult, Self::Error>> + Send + 'a
+    where
+        Filter: InterestFilter<T>;
+
+    /// Record that a run of the schedule `name` was skipped, along with the trigger of the schedule
+    /// and the time of the next run.
+    fn skip_scheduled<'a>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        next_run_at: u64,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send + 'a;
+
+    /// The persisted state of all schedules known to this queue.
+    fn schedules(
+        &self,
+    ) -> impl Future<Output = Result<Vec<ScheduleState>, Self::Error>> + Send + '_;
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
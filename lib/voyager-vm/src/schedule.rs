@@ -0,0 +1,254 @@
+use std::{collections::BTreeMap, str::FromStr, time::Duration};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use tokio::time::sleep;
+use tracing::{debug, error, info, info_span, Instrument};
+use unionlabs::ErrorReporter;
+
+use crate::{filter::InterestFilter, now, BoxDynError, Op, Queue, QueueMessage};
+
+/// An op that is enqueued on a recurring basis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = ""), deny_unknown_fields)]
+pub struct Schedule<T: QueueMessage> {
+    /// The name of the schedule. The state of the schedule is persisted in the queue under this
+    /// name, so renaming a schedule will reset it.
+    pub name: String,
+    pub trigger: Trigger,
+    /// Delay each run by a random amount of up to this many seconds.
+    #[serde(default)]
+    pub jitter: u64,
+    /// Skip a run if any items created by the previous run are still in the queue.
+    #[serde(default)]
+    pub skip_if_running: bool,
+    pub op: Op<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Trigger {
+    /// Run every `n` seconds.
+    Every(u64),
+    /// Run according to a cron expression, evaluated in UTC. Note that the expression includes
+    /// seconds:
+    ///
+    /// ```txt
+    /// sec  min  hour  day-of-month  month  day-of-week  [year]
+    /// ```
+    Cron(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TriggerError {
+    #[error("interval must be non-zero")]
+    ZeroInterval,
+    #[error("invalid cron expression `{0}`")]
+    InvalidCron(String, #[source] cron::error::Error),
+    #[error("cron expression `{0}` has no upcoming runs")]
+    NoUpcomingRuns(String),
+}
+
+impl Trigger {
+    /// Check that the trigger is valid and will fire at least once more.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::next_after`].
+    pub fn validate(&self) -> Result<(), TriggerError> {
+        self.next_after(now()).map(|_| ())
+    }
+
+    /// The next time this trigger fires strictly after `after`, as a unix timestamp in seconds.
+    ///
+    /// # Errors
+    ///
+    /// This will error if the trigger is invalid, or if it will never fire after `after`.
+    pub fn next_after(&self, after: u64) -> Result<u64, TriggerError> {
+        match self {
+            Trigger::Every(0) => Err(TriggerError::ZeroInterval),
+            Trigger::Every(secs) => Ok(after.saturating_add(*secs)),
+            Trigger::Cron(expr) => {
+                let schedule = cron::Schedule::from_str(expr)
+                    .map_err(|e| TriggerError::InvalidCron(expr.clone(), e))?;
+
+                let after =
+                    chrono::DateTime::from_timestamp(i64::try_from(after).unwrap_or(i64::MAX), 0)
+                        .ok_or_else(|| TriggerError::NoUpcomingRuns(expr.clone()))?;
+
+                schedule
+                    .after(&after)
+                    .next()
+                    .map(|next| next.timestamp().unsigned_abs())
+                    .ok_or_else(|| TriggerError::NoUpcomingRuns(expr.clone()))
+            }
+        }
+    }
+}
+
+/// The persisted state of a [`Schedule`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleState {
+    pub name: String,
+    /// The trigger that [`Self::next_run_at`] was computed with. If the configured trigger of the
+    /// schedule differs from this, the persisted next run is discarded and recomputed.
+    pub trigger: Trigger,
+    /// The time of the next run, as a unix timestamp in seconds.
+    pub next_run_at: u64,
+    /// The time of the last run, as a unix timestamp in seconds. This is `None` if all runs so far
+    /// have been skipped.
+    pub last_run_at: Option<u64>,
+    pub runs: u64,
+    pub skipped: u64,
+    /// The amount of items in the queue (either ready or waiting to be optimized) that were
+    /// created by this schedule.
+    pub in_flight: u64,
+}
+
+/// Drives a set of [`Schedule`]s, enqueueing their ops into a [`Queue`] whenever they are due.
+pub struct Scheduler<'a, T: QueueMessage, Q, F> {
+    schedules: Vec<Schedule<T>>,
+    queue: &'a Q,
+    filter: &'a F,
+}
+
+impl<'a, T, Q, F> Scheduler<'a, T, Q, F>
+where
+    T: QueueMessage,
+    Q: Queue<T>,
+    F: InterestFilter<T>,
+{
+    #[must_use]
+    pub fn new(schedules: Vec<Schedule<T>>, queue: &'a Q, filter: &'a F) -> Self {
+        Self {
+            schedules,
+            queue,
+            filter,
+        }
+    }
+
+    /// Run the scheduler. This only returns if the initial state of the schedules can't be loaded
+    /// from the queue; errors after that point are logged and the affected run is retried.
+    ///
+    /// # Errors
+    ///
+    /// This will error if the persisted state can't be read, or if any of the triggers are invalid.
+    pub async fn run(self) -> Result<(), BoxDynError> {
+        let persisted = self
+            .queue
+            .schedules()
+            .await?
+            .into_iter()
+            .map(|state| (state.name.clone(), state))
+            .collect::<BTreeMap<_, _>>();
+
+        // (time the trigger fired, time the run is due including jitter)
+        let mut next_runs = self
+            .schedules
+            .iter()
+            .map(|schedule| match persisted.get(&schedule.name) {
+                Some(state) if state.trigger == schedule.trigger => {
+                    Ok((state.next_run_at, state.next_run_at))
+                }
+                state => {
+                    if let Some(state) = state {
+                        info!(
+                            name = %schedule.name,
+                            previous_trigger = ?state.trigger,
+                            trigger = ?schedule.trigger,
+                            "trigger changed, recomputing next run"
+                        );
+                    }
+
+                    let next = schedule.trigger.next_after(now())?;
+                    Ok((next, next.saturating_add(jitter(schedule.jitter))))
+                }
+            })
+            .collect::<Result<Vec<_>, TriggerError>>()?;
+
+        for (schedule, (_, due)) in self.schedules.iter().zip(&next_runs) {
+            info!(name = %schedule.name, next_run_at = due, "loaded schedule");
+        }
+
+        loop {
+            let now = now();
+
+            for (schedule, (fired, due)) in self.schedules.iter().zip(&mut next_runs) {
+                if now < *due {
+                    continue;
+                }
+
+                let span = info_span!("schedule", name = %schedule.name);
+
+                match self.tick(schedule, *fired, now).instrument(span).await {
+                    Ok(next) => {
+                        *fired = next;
+                        *due = next.saturating_add(jitter(schedule.jitter));
+                    }
+                    Err(error) => {
+                        error!(
+                            name = %schedule.name,
+                            error = %ErrorReporter(&*error),
+                            "error running schedule"
+                        );
+                    }
+                }
+            }
+
+            sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Run `schedule` if possible, returning the time that the trigger next fires.
+    async fn tick(&self, schedule: &Schedule<T>, fired: u64, now: u64) -> Result<u64, BoxDynError> {
+        // if any runs were missed (i.e. voyager was not running), only run once to catch up
+        let mut next = schedule.trigger.next_after(fired)?;
+        if next <= now {
+            next = schedule.trigger.next_after(now)?;
+        }
+
+        if schedule.skip_if_running {
+            let in_flight = self
+                .queue
+                .schedules()
+                .await?
+                .into_iter()
+                .find(|state| state.name == schedule.name)
+                .map_or(0, |state| state.in_flight);
+
+            if in_flight > 0 {
+                info!(in_flight, "previous run is still in flight, skipping");
+
+                self.queue
+                    .skip_scheduled(&schedule.name, &schedule.trigger, next)
+                    .await?;
+
+                return Ok(next);
+            }
+        }
+
+        let res = self
+            .queue
+            .enqueue_scheduled(
+                &schedule.name,
+                &schedule.trigger,
+                schedule.op.clone(),
+                self.filter,
+                next,
+            )
+            .await?;
+
+        debug!(?res, next_run_at = next, "enqueued scheduled op");
+
+        Ok(next)
+    }
+}
+
+fn jitter(max: u64) -> u64 {
+    if max == 0 {
+        0
+    } else {
+        rand::thread_rng().gen_range(0..=max)
+    }
+}
@@ -1,9 +1,14 @@
 use macros::model;
 
 use crate::{
-    call, conc, data, defer, noop, now, promise, seq,
+    call, conc, data, defer,
+    history::ItemStatus,
+    in_memory::InMemoryQueue,
+    noop, now, promise,
+    schedule::Trigger,
+    seq,
     tests::utils::{BuildPrintAbc, DataA, DataB, DataC, FetchA, FetchB, PrintAbc, SimpleMessage},
-    QueueMessage,
+    ItemId, Queue, QueueError, QueueMessage,
 };
 
 pub mod utils;
@@ -268,3 +273,253 @@ fn seq_conc_conc() {
 
     assert_eq!(op.normalize(), expected_output);
 }
+
+#[tokio::test]
+async fn in_memory_history() {
+    let queue = InMemoryQueue::<UnitMessage>::new(()).await.unwrap();
+
+    queue.enqueue(defer(1), &()).await.unwrap();
+
+    // 0 -> [1, 2]
+    queue
+        .process(&(), async |_, id| {
+            (id, Ok(vec![conc([defer(2), defer(3)])]))
+        })
+        .await
+        .unwrap();
+
+    // 1 fails
+    queue
+        .process(&(), async |_, id| {
+            (id, Err(QueueError::Fatal("boom".into())))
+        })
+        .await
+        .unwrap();
+
+    let id = |id| ItemId::new(id).unwrap();
+
+    let history = queue.history(id(0), 10).await.unwrap().unwrap();
+    assert_eq!(
+        history.items.iter().map(|i| i.id).collect::<Vec<_>>(),
+        [id(0), id(1), id(2)]
+    );
+    assert_eq!(history.items[0].status, ItemStatus::Done);
+    assert_eq!(history.items[1].parents, [id(0)]);
+    assert_eq!(history.items[2].status, ItemStatus::Queued);
+
+    // siblings are not part of the lineage
+    let history = queue.history(id(1), 10).await.unwrap().unwrap();
+    assert_eq!(
+        history.items.iter().map(|i| i.id).collect::<Vec<_>>(),
+        [id(0), id(1)]
+    );
+    assert!(matches!(
+        &history.items[1].status,
+        ItemStatus::Failed { message } if message.contains("boom")
+    ));
+
+    let history = queue.history(id(1), 0).await.unwrap().unwrap();
+    assert_eq!(history.items.len(), 1);
+
+    assert_eq!(queue.history(id(3), 10).await.unwrap(), None);
+}
+
+#[test]
+fn trigger_next_after() {
+    use crate::schedule::{Trigger, TriggerError};
+
+    assert_eq!(
+        Trigger::Every(600).next_after(1_700_000_000).unwrap(),
+        1_700_000_600
+    );
+    assert!(matches!(
+        Trigger::Every(0).next_after(1_700_000_000),
+        Err(TriggerError::ZeroInterval)
+    ));
+
+    // 2023-11-14T22:13:20Z -> 2023-11-14T22:20:00Z
+    let every_ten_minutes = Trigger::Cron("0 */10 * * * *".to_owned());
+    assert_eq!(
+        every_ten_minutes.next_after(1_700_000_000).unwrap(),
+        1_700_000_400
+    );
+    // the next run is strictly after the given time
+    assert_eq!(
+        every_ten_minutes.next_after(1_700_000_400).unwrap(),
+        1_700_001_000
+    );
+
+    assert!(matches!(
+        Trigger::Cron("not a cron expression".to_owned()).next_after(1_700_000_000),
+        Err(TriggerError::InvalidCron(..))
+    ));
+}
+
+#[tokio::test]
+async fn in_memory_schedules() {
+    let q = InMemoryQueue::<UnitMessage>::new(()).await.unwrap();
+
+    q.enqueue_scheduled("tick", &Trigger::Every(100), defer(1), &(), 100)
+        .await
+        .unwrap();
+    q.enqueue(defer(2), &()).await.unwrap();
+
+    let states = q.schedules().await.unwrap();
+    assert_eq!(states.len(), 1);
+    assert_eq!(states[0].name, "tick");
+    assert_eq!(states[0].trigger, Trigger::Every(100));
+    assert_eq!(states[0].next_run_at, 100);
+    assert_eq!((states[0].runs, states[0].in_flight), (1, 1));
+
+    // the child of the scheduled item is still attributed to the schedule
+    q.process(&(), async |_, _| ((), Ok(vec![defer(3)])))
+        .await
+        .unwrap();
+    assert_eq!(q.schedules().await.unwrap()[0].in_flight, 1);
+
+    // unscheduled items don't count towards the schedule
+    q.process(&(), async |_, _| ((), Ok(vec![]))).await.unwrap();
+    assert_eq!(q.schedules().await.unwrap()[0].in_flight, 1);
+
+    q.process(&(), async |_, _| ((), Ok(vec![]))).await.unwrap();
+    assert_eq!(q.schedules().await.unwrap()[0].in_flight, 0);
+
+    q.skip_scheduled("tick", &Trigger::Every(100), 200)
+        .await
+        .unwrap();
+
+    let states = q.schedules().await.unwrap();
+    assert_eq!(states[0].next_run_at, 200);
+    assert_eq!((states[0].runs, states[0].skipped), (1, 1));
+}
+
+#[test]
+fn weighted_lanes_round_robin() {
+    use crate::lane::{WeightedLanes, DEFAULT_LANE};
+
+    let lanes = WeightedLanes::new([("fast".to_owned(), 3), ("slow".to_owned(), 0)]);
+
+    assert_eq!(
+        lanes.lanes().collect::<Vec<_>>(),
+        ["fast", "slow", DEFAULT_LANE]
+    );
+
+    let selected = (0..8)
+        .map(|_| {
+            let lane = lanes.next_order()[0];
+            lanes.served(lane);
+            lane
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        selected,
+        [
+            "fast",
+            "fast",
+            DEFAULT_LANE,
+            "fast",
+            "fast",
+            "fast",
+            DEFAULT_LANE,
+            "fast"
+        ]
+    );
+
+    // the remaining lanes are in priority order
+    assert_eq!(lanes.next_order(), ["fast", "slow", DEFAULT_LANE]);
+
+    // checking the order does not advance the round-robin
+    assert_eq!(lanes.next_order(), ["fast", "slow", DEFAULT_LANE]);
+}
+
+#[test]
+fn weighted_lanes_only_charge_served_lanes() {
+    use crate::lane::{WeightedLanes, DEFAULT_LANE};
+
+    let lanes = WeightedLanes::new([("fast".to_owned(), 2)]);
+
+    // the fast lane is selected, but empty, so the default lane is served instead
+    assert_eq!(lanes.next_order(), ["fast", DEFAULT_LANE]);
+    lanes.served(DEFAULT_LANE);
+
+    // the fast lane was not charged, so it is still selected
+    assert_eq!(lanes.next_order(), ["fast", DEFAULT_LANE]);
+
+    // once both lanes have items, the fast lane catches up on the round it missed and is then
+    // served twice as often as the default lane
+    let selected = (0..9)
+        .map(|_| {
+            let lane = lanes.next_order()[0];
+            lanes.served(lane);
+            lane
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        selected,
+        [
+            "fast",
+            "fast",
+            "fast",
+            DEFAULT_LANE,
+            "fast",
+            "fast",
+            DEFAULT_LANE,
+            "fast",
+            "fast"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn in_memory_lanes() {
+    use crate::{
+        filter::{FilterResult, InterestFilter},
+        lane::{WeightedLanes, DEFAULT_LANE},
+        Op,
+    };
+
+    struct LaneFilter(WeightedLanes);
+
+    impl InterestFilter<UnitMessage> for LaneFilter {
+        fn check_interest<'a>(&'a self, _: &Op<UnitMessage>) -> FilterResult<'a> {
+            FilterResult::NoInterest
+        }
+
+        fn lane<'a>(&'a self, op: &Op<UnitMessage>) -> &'a str {
+            match op {
+                Op::Defer { until } if *until >= 100 => "fast",
+                _ => DEFAULT_LANE,
+            }
+        }
+
+        fn lane_order(&self) -> Vec<&str> {
+            self.0.next_order()
+        }
+
+        fn lane_served(&self, lane: &str) {
+            self.0.served(lane);
+        }
+    }
+
+    let filter = LaneFilter(WeightedLanes::new([("fast".to_owned(), 2)]));
+
+    let q = InMemoryQueue::<UnitMessage>::new(()).await.unwrap();
+
+    for until in [1, 2, 100, 101] {
+        q.enqueue(defer(until), &filter).await.unwrap();
+    }
+
+    let mut processed = vec![];
+    while let Some(op) = q
+        .process(&filter, async |op, _| (op, Ok(vec![])))
+        .await
+        .unwrap()
+    {
+        processed.push(op);
+    }
+
+    // the fast lane is served twice as often as the default lane while both have items ready
+    assert_eq!(processed, [defer(100), defer(1), defer(101), defer(2)]);
+}
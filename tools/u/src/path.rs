@@ -2,8 +2,8 @@ use anyhow::Result;
 use clap::{Args, Subcommand};
 use ibc_union_spec::{
     path::{
-        BatchPacketsPath, BatchReceiptsPath, ChannelPath, ClientStatePath, ConnectionPath,
-        ConsensusStatePath,
+        BatchPacketsPath, BatchReceiptsPath, ChannelPath, ChannelUpgradeErrorPath,
+        ChannelUpgradePath, ClientStatePath, ConnectionPath, ConsensusStatePath,
     },
     ChannelId, ClientId, ConnectionId,
 };
@@ -30,6 +30,10 @@ pub enum StorePath {
     Connection { connection_id: ConnectionId },
     #[command(visible_alias = "chan")]
     Channel { channel_id: ChannelId },
+    #[command(visible_alias = "chanup")]
+    ChannelUpgrade { channel_id: ChannelId },
+    #[command(visible_alias = "chanuperr")]
+    ChannelUpgradeError { channel_id: ChannelId },
     #[command(visible_alias = "br")]
     BatchReceipts { batch_hash: H256 },
     #[command(visible_alias = "bp")]
@@ -47,6 +51,10 @@ impl Cmd {
             .key(),
             StorePath::Connection { connection_id } => ConnectionPath { connection_id }.key(),
             StorePath::Channel { channel_id } => ChannelPath { channel_id }.key(),
+            StorePath::ChannelUpgrade { channel_id } => ChannelUpgradePath { channel_id }.key(),
+            StorePath::ChannelUpgradeError { channel_id } => {
+                ChannelUpgradeErrorPath { channel_id }.key()
+            }
             StorePath::BatchReceipts { batch_hash } => BatchReceiptsPath { batch_hash }.key(),
             StorePath::BatchPackets { batch_hash } => BatchPacketsPath { batch_hash }.key(),
         };
@@ -1,6 +1,9 @@
 use std::process::Command;
 
-use ibc_union_spec::datagram::{Datagram, MsgChannelOpenInit, MsgConnectionOpenInit};
+use ibc_union_spec::{
+    datagram::{Datagram, MsgChannelOpenInit, MsgConnectionOpenInit},
+    ChannelOrder,
+};
 use unionlabs::primitives::Bytes;
 use voyager_sdk::{
     anyhow,
@@ -110,6 +113,7 @@ pub fn channel_open(
                         counterparty_port_id,
                         connection_id: connection_id.try_into().unwrap(),
                         version,
+                        ordering: ChannelOrder::Unordered,
                     }))
                     .unwrap(),
                 }],
@@ -2,7 +2,7 @@ use alloy::{
     network::AnyNetwork,
     providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
 };
-use arbitrum_light_client_types::{ClientState, ClientStateV1, ConsensusState};
+use arbitrum_light_client_types::{ClientState, ClientStateV2, ConsensusState, FinalityPolicy};
 use ibc_union_spec::{ClientId, IbcUnion, Timestamp};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -66,6 +66,10 @@ pub struct ClientStateConfig {
     /// The chain that the new client will be created on.
     // TODO: Consider threading this through the self_*_state endpoints
     pub host_chain_id: ChainId,
+
+    /// The policy used by the new client to decide whether the L2 state in a header is final.
+    #[serde(default)]
+    pub finality_policy: FinalityPolicy,
 }
 
 impl Module {
@@ -153,7 +157,7 @@ impl ClientBootstrapModuleServer for Module {
         )
         .await?;
 
-        Ok(into_value(ClientState::V1(ClientStateV1 {
+        Ok(into_value(ClientState::V2(ClientStateV2 {
             l1_client_id: config.l1_client_id,
             latest_height: height.height(),
             chain_id: self
@@ -164,6 +168,7 @@ impl ClientBootstrapModuleServer for Module {
             l1_contract_address: self.l1_contract_address,
             frozen_height: Height::new(0),
             ibc_contract_address: self.ibc_handler_address,
+            finality_policy: config.finality_policy,
         })))
     }
 
@@ -2,7 +2,7 @@ use alloy::{
     network::AnyNetwork,
     providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
 };
-use base_light_client_types::{ClientState, ClientStateV1, ConsensusState};
+use base_light_client_types::{ClientState, ClientStateV2, ConsensusState, FinalityPolicy};
 // use base_types::L2_OUTPUTS_SLOT;
 use ibc_union_spec::{ClientId, Timestamp};
 use jsonrpsee::{
@@ -66,6 +66,10 @@ pub struct Config {
 #[serde(deny_unknown_fields)]
 pub struct ClientStateConfig {
     pub l1_client_id: ClientId,
+
+    /// The policy used by the new client to decide whether the L2 state in a header is final.
+    #[serde(default)]
+    pub finality_policy: FinalityPolicy,
 }
 
 impl Module {}
@@ -120,7 +124,7 @@ impl ClientBootstrapModuleServer for Module {
             )
         })?;
 
-        Ok(into_value(ClientState::V1(ClientStateV1 {
+        Ok(into_value(ClientState::V2(ClientStateV2 {
             l1_client_id: config.l1_client_id,
             latest_height: height.height(),
             chain_id: self
@@ -134,6 +138,7 @@ impl ClientBootstrapModuleServer for Module {
             dispute_game_factory_dispute_game_list_slot: self
                 .dispute_game_factory_dispute_game_list_slot,
             fault_dispute_game_code_root_claim_index: self.fault_dispute_game_code_root_claim_index,
+            finality_policy: config.finality_policy,
         })))
     }
 
@@ -2,7 +2,7 @@ use alloy::{
     network::AnyNetwork,
     providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
 };
-use bob_light_client_types::{ClientState, ClientStateV2, ConsensusState};
+use bob_light_client_types::{ClientState, ClientStateV3, ConsensusState, FinalityPolicy};
 use ibc_union_spec::{ClientId, Timestamp};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -65,6 +65,10 @@ pub struct Config {
 #[serde(deny_unknown_fields)]
 pub struct ClientStateConfig {
     pub l1_client_id: ClientId,
+
+    /// The policy used by the new client to decide whether the L2 state in a header is final.
+    #[serde(default)]
+    pub finality_policy: FinalityPolicy,
 }
 
 impl Module {}
@@ -119,7 +123,7 @@ impl ClientBootstrapModuleServer for Module {
             )
         })?;
 
-        Ok(into_value(ClientState::V2(ClientStateV2 {
+        Ok(into_value(ClientState::V3(ClientStateV3 {
             l1_client_id: config.l1_client_id,
             latest_height: height.height(),
             chain_id: self
@@ -133,6 +137,7 @@ impl ClientBootstrapModuleServer for Module {
             fault_dispute_game_code_root_claim_index: self.fault_dispute_game_code_root_claim_index,
             frozen_height: 0,
             ibc_contract_address: self.ibc_handler_address,
+            finality_policy: config.finality_policy,
         })))
     }
 
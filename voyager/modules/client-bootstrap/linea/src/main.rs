@@ -0,0 +1,244 @@
+use alloy::{
+    network::AnyNetwork,
+    providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
+};
+use gnark_mimc::new_mimc_constants_bls12_377;
+use ibc_union_spec::{ClientId, Timestamp};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use linea_light_client_types::{ClientState, ClientStateV1, ConsensusState};
+use linea_types::{
+    account::ZkAccount,
+    proof::{GetProof, MerklePath, MerkleProof},
+};
+use linea_zktrie::verify::{verify_inclusion_and_key, VerifiablePath};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::instrument;
+use unionlabs::{
+    ibc::core::client::height::Height,
+    primitives::{H160, H256, U256},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow, into_value,
+    plugin::ClientBootstrapModule,
+    primitives::{ChainId, ClientType},
+    rpc::{
+        types::ClientBootstrapModuleInfo, ClientBootstrapModuleServer, FATAL_JSONRPC_ERROR_CODE,
+    },
+};
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+
+    pub l1_rollup_contract_address: H160,
+    pub l1_rollup_current_l2_block_number_slot: U256,
+    pub l1_rollup_current_l2_timestamp_slot: U256,
+    pub l1_rollup_l2_state_root_hashes_slot: U256,
+
+    pub provider: DynProvider<AnyNetwork>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The address of the linea rollup contract on the L1.
+    pub l1_rollup_contract_address: H160,
+    pub l1_rollup_current_l2_block_number_slot: U256,
+    pub l1_rollup_current_l2_timestamp_slot: U256,
+    pub l1_rollup_l2_state_root_hashes_slot: U256,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+
+    /// The RPC endpoint for the linea (L2) execution chain.
+    ///
+    /// This must expose `linea_getProof`.
+    pub rpc_url: String,
+
+    #[serde(default)]
+    pub max_cache_size: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientStateConfig {
+    pub l1_client_id: ClientId,
+}
+
+impl ClientBootstrapModule for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: ClientBootstrapModuleInfo) -> anyhow::Result<Self> {
+        let provider = DynProvider::new(
+            ProviderBuilder::new()
+                .network::<AnyNetwork>()
+                .layer(CacheLayer::new(config.max_cache_size))
+                .connect(&config.rpc_url)
+                .await?,
+        );
+
+        let l2_chain_id = ChainId::new(provider.get_chain_id().await?.to_string());
+
+        info.ensure_chain_id(l2_chain_id.to_string())?;
+        info.ensure_client_type(ClientType::LINEA)?;
+
+        Ok(Self {
+            chain_id: l2_chain_id,
+            ibc_handler_address: config.ibc_handler_address,
+            l1_rollup_contract_address: config.l1_rollup_contract_address,
+            l1_rollup_current_l2_block_number_slot: config.l1_rollup_current_l2_block_number_slot,
+            l1_rollup_current_l2_timestamp_slot: config.l1_rollup_current_l2_timestamp_slot,
+            l1_rollup_l2_state_root_hashes_slot: config.l1_rollup_l2_state_root_hashes_slot,
+            provider,
+        })
+    }
+}
+
+#[async_trait]
+impl ClientBootstrapModuleServer for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %height))]
+    async fn self_client_state(
+        &self,
+        _: &Extensions,
+        height: Height,
+        config: Value,
+    ) -> RpcResult<Value> {
+        let config = serde_json::from_value::<ClientStateConfig>(config).map_err(|err| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "unable to deserialize client state config: {}",
+                    ErrorReporter(err)
+                ),
+                None::<()>,
+            )
+        })?;
+
+        Ok(into_value(ClientState::V1(ClientStateV1 {
+            chain_id: self
+                .chain_id
+                .as_str()
+                .parse()
+                .expect("self.chain_id is a valid u256; qed;"),
+            latest_height: height.height(),
+            l1_client_id: config.l1_client_id,
+            l1_rollup_contract_address: self.l1_rollup_contract_address,
+            l1_rollup_current_l2_block_number_slot: self.l1_rollup_current_l2_block_number_slot,
+            l1_rollup_current_l2_timestamp_slot: self.l1_rollup_current_l2_timestamp_slot,
+            l1_rollup_l2_state_root_hashes_slot: self.l1_rollup_l2_state_root_hashes_slot,
+            l2_ibc_contract_address: self.ibc_handler_address,
+            frozen_height: 0,
+        })))
+    }
+
+    /// The consensus state on this chain at the specified `Height`.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, %height))]
+    async fn self_consensus_state(
+        &self,
+        _: &Extensions,
+        height: Height,
+        _: Value,
+    ) -> RpcResult<Value> {
+        let l2_block = self
+            .provider
+            .get_block(height.height().into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching l2 block"),
+                    None::<()>,
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorObject::owned(-1, format!("l2 block {height} does not exist"), None::<()>)
+            })?;
+
+        let proof = self
+            .provider
+            .raw_request::<_, GetProof>(
+                "linea_getProof".into(),
+                (
+                    self.ibc_handler_address,
+                    Vec::<H256>::new(),
+                    format!("0x{:x}", l2_block.header.number),
+                ),
+            )
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching ibc contract proof"),
+                    None::<()>,
+                )
+            })?;
+
+        let MerkleProof::Inclusion(inclusion_proof) = proof.account_proof else {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "ibc contract {} does not exist at height {height}",
+                    self.ibc_handler_address
+                ),
+                None::<()>,
+            ));
+        };
+
+        // the header of a linea block commits to the besu state root, whereas the rollup contract
+        // finalizes the root of the sparse merkle trie maintained by shomei
+        let (state_root, ibc_contract_account) = zk_state_root_and_account(
+            &inclusion_proof.proof,
+            inclusion_proof.leaf_index,
+            self.ibc_handler_address,
+        )
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("invalid ibc contract proof"),
+                None::<()>,
+            )
+        })?;
+
+        Ok(into_value(ConsensusState {
+            state_root,
+            ibc_storage_root: ibc_contract_account.storage_root,
+            timestamp: Timestamp::from_secs(l2_block.header.timestamp),
+        }))
+    }
+}
+
+fn zk_state_root_and_account(
+    merkle_path: &MerklePath,
+    leaf_index: u64,
+    address: H160,
+) -> Result<(H256, ZkAccount), linea_zktrie::verify::Error> {
+    let constants = new_mimc_constants_bls12_377();
+
+    let state_root = VerifiablePath::try_from(merkle_path)?
+        .root
+        .hash(&constants)?;
+
+    let (_, account) = verify_inclusion_and_key::<ZkAccount>(
+        &constants,
+        leaf_index,
+        merkle_path,
+        state_root,
+        address,
+    )?;
+
+    Ok((state_root, account))
+}
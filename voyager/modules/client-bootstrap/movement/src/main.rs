@@ -1,7 +1,9 @@
 use aptos_move_ibc::ibc::ClientExt;
 use aptos_rest_client::error::RestError;
+use ibc_union_spec::ClientId;
 use jsonrpsee::{
     core::{async_trait, RpcResult},
+    types::ErrorObject,
     Extensions,
 };
 use movement_light_client_types::{ClientState, ConsensusState};
@@ -14,7 +16,8 @@ use unionlabs::{
         transaction_proof::TransactionInfoWithProof,
     },
     ibc::core::client::height::Height,
-    primitives::{FixedBytes, H160, U256},
+    primitives::{FixedBytes, H160, H256, U256},
+    ErrorReporter,
 };
 use voyager_sdk::{
     anyhow, ensure_null,
@@ -45,7 +48,7 @@ pub struct Module {
     /// The address of the settlement contract on Eth.
     pub l1_settlement_address: H160,
 
-    pub l1_client_id: u32,
+    pub l1_client_id: ClientId,
 
     pub aptos_client: aptos_rest_client::Client,
 
@@ -97,7 +100,7 @@ pub struct Config {
     pub l1_settlement_address: H160,
 
     /// Id of the light client that this client depends on
-    pub l1_client_id: u32,
+    pub l1_client_id: ClientId,
 
     /// The RPC endpoint for aptos.
     pub aptos_rest_api: String,
@@ -196,15 +199,45 @@ impl ClientBootstrapModuleServer for Module {
     async fn self_consensus_state(
         &self,
         _: &Extensions,
-        _height: Height,
+        height: Height,
         config: Value,
     ) -> RpcResult<Value> {
         ensure_null(config)?;
 
+        let block = self
+            .aptos_client
+            .get_block_by_height(height.height(), false)
+            .await
+            .map_err(|e| ErrorObject::owned(-1, ErrorReporter(e).to_string(), None::<()>))?
+            .into_inner();
+
+        // the last transaction of a block is the state checkpoint, which commits to the state
+        // root that the light client verifies storage proofs against
+        let state_checkpoint = self
+            .aptos_client
+            .get_transaction_by_version(block.last_version.0)
+            .await
+            .map_err(|e| ErrorObject::owned(-1, ErrorReporter(e).to_string(), None::<()>))?
+            .into_inner();
+
+        let state_root = state_checkpoint
+            .transaction_info()
+            .ok()
+            .and_then(|info| info.state_checkpoint_hash)
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!(
+                        "transaction {} at height {height} is not a state checkpoint",
+                        block.last_version.0
+                    ),
+                    None::<()>,
+                )
+            })?;
+
         Ok(serde_json::to_value(ConsensusState {
-            state_root: Default::default(),
-            // TODO: Fix this - this is currently effectively broken.
-            timestamp: Timestamp::from_secs(1000),
+            state_root: H256::new(*state_root.0),
+            timestamp: Timestamp::from_nanos(block.block_timestamp.0 * 1_000),
             state_proof_hash: Default::default(),
         })
         .expect("infallible"))
@@ -91,6 +91,10 @@ impl ClientModuleServer for Module {
                 counterparty_chain_id: ChainId::new(v2.chain_id.to_string()),
                 counterparty_height: Module::make_height(v2.latest_height),
             }),
+            ClientState::V3(v3) => Ok(ClientStateMeta {
+                counterparty_chain_id: ChainId::new(v3.chain_id.to_string()),
+                counterparty_height: Module::make_height(v3.latest_height),
+            }),
         }
     }
 
@@ -2,7 +2,7 @@
 
 use alloy::{
     eips::BlockId,
-    network::AnyNetwork,
+    network::{AnyNetwork, AnyRpcBlock},
     providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
 };
 use jsonrpsee::{
@@ -10,6 +10,7 @@ use jsonrpsee::{
     types::ErrorObject,
     Extensions,
 };
+use l2_finality_policy::FinalityPolicy;
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use unionlabs::{ibc::core::client::height::Height, primitives::H160, ErrorReporter};
@@ -34,6 +35,8 @@ pub struct Module {
 
     pub l1_contract_address: H160,
 
+    pub finality_policy: FinalityPolicy,
+
     pub l1_provider: DynProvider,
     pub l2_provider: DynProvider<AnyNetwork>,
 }
@@ -53,6 +56,11 @@ pub struct Config {
     /// The RPC endpoint for the main (L2) execution chain.
     pub l2_rpc_url: String,
 
+    /// The policy used to decide which L2 blocks are finalized. This must match the policy of the
+    /// light clients tracking this chain.
+    #[serde(default)]
+    pub finality_policy: FinalityPolicy,
+
     #[serde(default)]
     pub max_cache_size: u32,
 }
@@ -86,39 +94,59 @@ impl FinalityModule for Module {
             chain_id: l2_chain_id,
             l1_chain_id,
             l1_contract_address: config.l1_contract_address,
+            finality_policy: config.finality_policy,
             l1_provider,
             l2_provider,
         })
     }
 }
 
+impl Module {
+    /// Query the L2 block of the latest node that is finalized according to the configured
+    /// [`FinalityPolicy`], as of the latest finalized L1 height.
+    async fn finalized_l2_block(&self, e: &Extensions) -> RpcResult<AnyRpcBlock> {
+        let voyager_client = e.voyager_client()?;
+
+        let l1_latest_height = voyager_client
+            .query_latest_height(self.l1_chain_id.clone(), true)
+            .await?;
+
+        arbitrum_client::finalized_l2_block_of_l1_height(
+            &self.l1_provider,
+            &self.l2_provider,
+            self.l1_contract_address,
+            l1_latest_height.height(),
+            self.finality_policy,
+        )
+        .await
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(&*e)
+                    .with_message("error fetching finalized execution block of l1 height"),
+                None::<()>,
+            )
+        })?
+        .ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!(
+                    "no node satisfies the finality policy {:?} at l1 height {l1_latest_height}",
+                    self.finality_policy
+                ),
+                None::<()>,
+            )
+        })
+    }
+}
+
 #[async_trait]
 impl FinalityModuleServer for Module {
     /// Query the latest finalized height of this chain.
     #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
     async fn query_latest_height(&self, e: &Extensions, finalized: bool) -> RpcResult<Height> {
-        if finalized {
-            let voyager_client = e.voyager_client()?;
-
-            let l1_latest_height = voyager_client
-                .query_latest_height(self.l1_chain_id.clone(), true)
-                .await?;
-
-            let block = arbitrum_client::finalized_l2_block_of_l1_height(
-                &self.l1_provider,
-                &self.l2_provider,
-                self.l1_contract_address,
-                l1_latest_height.height(),
-            )
-            .await
-            .map_err(|e| {
-                ErrorObject::owned(
-                    -1,
-                    ErrorReporter(&*e)
-                        .with_message("error fetching finalized execution block of l1 height"),
-                    None::<()>,
-                )
-            })?;
+        if finalized && self.finality_policy != FinalityPolicy::SequencerSoftConfirmation {
+            let block = self.finalized_l2_block(e).await?;
 
             Ok(Height::new(block.header.number))
         } else {
@@ -137,27 +165,8 @@ impl FinalityModuleServer for Module {
         e: &Extensions,
         finalized: bool,
     ) -> RpcResult<Timestamp> {
-        if finalized {
-            let voyager_client = e.voyager_client()?;
-
-            let l1_latest_height = voyager_client
-                .query_latest_height(self.l1_chain_id.clone(), true)
-                .await?;
-
-            let block = arbitrum_client::finalized_l2_block_of_l1_height(
-                &self.l1_provider,
-                &self.l2_provider,
-                self.l1_contract_address,
-                l1_latest_height.height(),
-            )
-            .await
-            .map_err(|e| {
-                ErrorObject::owned(
-                    -1,
-                    ErrorReporter(&*e).with_message("error fetching finalized l2 block"),
-                    None::<()>,
-                )
-            })?;
+        if finalized && self.finality_policy != FinalityPolicy::SequencerSoftConfirmation {
+            let block = self.finalized_l2_block(e).await?;
 
             Ok(Timestamp::from_secs(block.header.timestamp))
         } else {
@@ -10,6 +10,7 @@ use jsonrpsee::{
     types::ErrorObject,
     Extensions,
 };
+use l2_finality_policy::FinalityPolicy;
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 use unionlabs::{ibc::core::client::height::Height, primitives::H160, ErrorReporter};
@@ -34,6 +35,8 @@ pub struct Module {
 
     pub l1_dispute_game_factory_proxy: H160,
 
+    pub finality_policy: FinalityPolicy,
+
     pub l1_provider: DynProvider,
     pub l2_provider: DynProvider<AnyNetwork>,
 }
@@ -52,6 +55,11 @@ pub struct Config {
     /// The RPC endpoint for the main (L2) execution chain.
     pub l2_rpc_url: String,
 
+    /// The policy used to decide which L2 blocks are finalized. This must match the policy of the
+    /// light clients tracking this chain.
+    #[serde(default)]
+    pub finality_policy: FinalityPolicy,
+
     #[serde(default)]
     pub max_cache_size: u32,
 }
@@ -85,38 +93,58 @@ impl FinalityModule for Module {
             chain_id: l2_chain_id,
             l1_chain_id,
             l1_dispute_game_factory_proxy: config.l1_dispute_game_factory_proxy,
+            finality_policy: config.finality_policy,
             l1_provider,
             l2_provider,
         })
     }
 }
 
+impl Module {
+    /// Query the latest L2 block number that is finalized according to the configured
+    /// [`FinalityPolicy`], as of the latest finalized L1 height.
+    async fn finalized_l2_block_number(&self, e: &Extensions) -> RpcResult<u64> {
+        let voyager_client = e.voyager_client()?;
+
+        let l1_latest_height = voyager_client
+            .query_latest_height(self.l1_chain_id.clone(), true)
+            .await?;
+
+        bob_client::finalized_l2_block_number_of_l1_block_number(
+            &self.l1_provider,
+            self.l1_dispute_game_factory_proxy,
+            l1_latest_height.height(),
+            self.finality_policy,
+        )
+        .await
+        .map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e)
+                    .with_message("error fetching finalized l2 execution block of l1 height"),
+                None::<()>,
+            )
+        })?
+        .ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!(
+                    "no dispute game satisfies the finality policy {:?} at l1 height {l1_latest_height}",
+                    self.finality_policy
+                ),
+                None::<()>,
+            )
+        })
+    }
+}
+
 #[async_trait]
 impl FinalityModuleServer for Module {
     /// Query the latest finalized height of this chain.
     #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
     async fn query_latest_height(&self, e: &Extensions, finalized: bool) -> RpcResult<Height> {
-        if finalized {
-            let voyager_client = e.voyager_client()?;
-
-            let l1_latest_height = voyager_client
-                .query_latest_height(self.l1_chain_id.clone(), true)
-                .await?;
-
-            let block_number = bob_client::finalized_l2_block_number_of_l1_block_number(
-                &self.l1_provider,
-                self.l1_dispute_game_factory_proxy,
-                l1_latest_height.height(),
-            )
-            .await
-            .map_err(|e| {
-                ErrorObject::owned(
-                    -1,
-                    ErrorReporter(e)
-                        .with_message("error fetching finalized l2 execution block of l1 height"),
-                    None::<()>,
-                )
-            })?;
+        if finalized && self.finality_policy != FinalityPolicy::SequencerSoftConfirmation {
+            let block_number = self.finalized_l2_block_number(e).await?;
 
             Ok(Height::new(block_number))
         } else {
@@ -135,27 +163,8 @@ impl FinalityModuleServer for Module {
         e: &Extensions,
         finalized: bool,
     ) -> RpcResult<Timestamp> {
-        if finalized {
-            let voyager_client = e.voyager_client()?;
-
-            let l1_latest_height = voyager_client
-                .query_latest_height(self.l1_chain_id.clone(), true)
-                .await?;
-
-            let block_number = bob_client::finalized_l2_block_number_of_l1_block_number(
-                &self.l1_provider,
-                self.l1_dispute_game_factory_proxy,
-                l1_latest_height.height(),
-            )
-            .await
-            .map_err(|e| {
-                ErrorObject::owned(
-                    -1,
-                    ErrorReporter(e)
-                        .with_message("error fetching finalized l2 execution block of l1 height"),
-                    None::<()>,
-                )
-            })?;
+        if finalized && self.finality_policy != FinalityPolicy::SequencerSoftConfirmation {
+            let block_number = self.finalized_l2_block_number(e).await?;
 
             let block = self
                 .l2_provider
@@ -0,0 +1,203 @@
+#![warn(clippy::unwrap_used)]
+
+use alloy::{
+    eips::BlockId,
+    network::AnyNetwork,
+    providers::{layers::CacheLayer, DynProvider, Provider, ProviderBuilder},
+};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use scroll_api::ScrollClient;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, instrument};
+use unionlabs::{
+    ibc::core::client::height::Height,
+    primitives::{H160, U256},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow,
+    plugin::FinalityModule,
+    primitives::{ChainId, ConsensusType, Timestamp},
+    rpc::{types::FinalityModuleInfo, FinalityModuleServer},
+    ExtensionsExt,
+};
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() {
+    Module::run().await
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub l1_chain_id: ChainId,
+
+    pub rollup_contract_address: H160,
+    pub rollup_last_finalized_batch_index_slot: U256,
+
+    pub l1_provider: DynProvider,
+    pub l2_provider: DynProvider<AnyNetwork>,
+
+    pub scroll_api_client: ScrollClient,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The chain id of the chain this scroll chain settles on.
+    pub l1_chain_id: ChainId,
+
+    /// The address of the scroll rollup contract on the L1.
+    pub rollup_contract_address: H160,
+    pub rollup_last_finalized_batch_index_slot: U256,
+
+    /// The RPC endpoint for the settlement (L1) execution chain.
+    pub l1_rpc_url: String,
+
+    /// The RPC endpoint for the main (L2) execution chain.
+    pub l2_rpc_url: String,
+
+    /// The endpoint of the scroll rollup explorer API, used to map batches to L2 blocks.
+    pub scroll_api_url: String,
+
+    #[serde(default)]
+    pub max_cache_size: u32,
+}
+
+impl FinalityModule for Module {
+    type Config = Config;
+
+    async fn new(config: Self::Config, info: FinalityModuleInfo) -> anyhow::Result<Self> {
+        let l1_provider = DynProvider::new(
+            ProviderBuilder::new()
+                .layer(CacheLayer::new(config.max_cache_size))
+                .connect(&config.l1_rpc_url)
+                .await?,
+        );
+
+        let l2_provider = DynProvider::new(
+            ProviderBuilder::new()
+                .layer(CacheLayer::new(config.max_cache_size))
+                .network::<AnyNetwork>()
+                .connect(&config.l2_rpc_url)
+                .await?,
+        );
+
+        let l1_chain_id = ChainId::new(l1_provider.get_chain_id().await?.to_string());
+        let l2_chain_id = ChainId::new(l2_provider.get_chain_id().await?.to_string());
+
+        info.ensure_chain_id(l2_chain_id.to_string())?;
+        info.ensure_consensus_type(ConsensusType::SCROLL)?;
+
+        Ok(Self {
+            chain_id: l2_chain_id,
+            l1_chain_id,
+            rollup_contract_address: config.rollup_contract_address,
+            rollup_last_finalized_batch_index_slot: config.rollup_last_finalized_batch_index_slot,
+            l1_provider,
+            l2_provider,
+            scroll_api_client: ScrollClient::new(config.scroll_api_url),
+        })
+    }
+}
+
+impl Module {
+    /// The last L2 block of the latest batch finalized on the L1 as of the latest finalized L1
+    /// block.
+    async fn finalized_l2_block_number(&self, e: &Extensions) -> RpcResult<u64> {
+        let voyager_client = e.voyager_client()?;
+
+        let l1_latest_height = voyager_client
+            .query_latest_height(self.l1_chain_id.clone(), true)
+            .await?;
+
+        let batch_index = self
+            .l1_provider
+            .get_storage_at(
+                self.rollup_contract_address.into(),
+                self.rollup_last_finalized_batch_index_slot.into(),
+            )
+            .block_id(l1_latest_height.height().into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching last finalized batch index"),
+                    None::<()>,
+                )
+            })?;
+
+        let batch_index = u64::try_from(batch_index).map_err(|_| {
+            ErrorObject::owned(
+                -1,
+                format!("last finalized batch index {batch_index} does not fit in a u64"),
+                None::<()>,
+            )
+        })?;
+
+        let batch = self.scroll_api_client.batch(batch_index).await.batch;
+
+        debug!(
+            %batch_index,
+            end_block_number = batch.end_block_number,
+            "last finalized batch"
+        );
+
+        Ok(batch.end_block_number)
+    }
+}
+
+#[async_trait]
+impl FinalityModuleServer for Module {
+    /// Query the latest finalized height of this chain.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
+    async fn query_latest_height(&self, e: &Extensions, finalized: bool) -> RpcResult<Height> {
+        if finalized {
+            self.finalized_l2_block_number(e).await.map(Height::new)
+        } else {
+            self.l2_provider
+                .get_block_number()
+                .await
+                .map(Height::new)
+                .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))
+        }
+    }
+
+    /// Query the latest finalized timestamp of this chain.
+    #[instrument(skip_all, fields(chain_id = %self.chain_id, finalized))]
+    async fn query_latest_timestamp(
+        &self,
+        e: &Extensions,
+        finalized: bool,
+    ) -> RpcResult<Timestamp> {
+        let block_id = if finalized {
+            self.finalized_l2_block_number(e).await?.into()
+        } else {
+            BlockId::latest()
+        };
+
+        self.l2_provider
+            .get_block(block_id)
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching l2 block"),
+                    None::<()>,
+                )
+            })?
+            .map(|block| Timestamp::from_secs(block.header.timestamp))
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("l2 block {block_id} does not exist"),
+                    None::<()>,
+                )
+            })
+    }
+}
@@ -18,7 +18,7 @@ use unionlabs::{
         storage_proof::{StateValue, StateValueMetadata, StorageProof},
     },
     ibc::core::client::height::Height,
-    primitives::{H256, U256},
+    primitives::{encoding::HexUnprefixed, Bytes, H256, U256},
     ErrorReporter,
 };
 use voyager_sdk::{
@@ -50,6 +50,8 @@ pub struct Module {
     pub movement_rpc_url: String,
 
     pub ibc_handler_address: Address,
+
+    pub custom_proof_api: bool,
 }
 
 impl ProofModule<IbcUnion> for Module {
@@ -67,6 +69,7 @@ impl ProofModule<IbcUnion> for Module {
             aptos_client,
             movement_rpc_url: config.movement_rpc_url,
             ibc_handler_address: config.ibc_handler_address,
+            custom_proof_api: config.custom_proof_api,
         })
     }
 }
@@ -77,6 +80,12 @@ pub struct Config {
     pub rpc_url: String,
     pub movement_rpc_url: String,
     pub ibc_handler_address: Address,
+    /// Whether `movement_rpc_url` serves the table item proofs of Union's custom Movement node.
+    ///
+    /// Without it, empty proofs are returned, which are only accepted by light clients built
+    /// without the `union-movement` feature.
+    #[serde(default)]
+    pub custom_proof_api: bool,
 }
 
 impl aptos_move_ibc::ibc::ClientExt for Module {
@@ -115,8 +124,21 @@ impl ProofModuleServer<IbcUnion> for Module {
         &self,
         _: &Extensions,
         at: Height,
-        _path: StorePath,
+        path: StorePath,
     ) -> RpcResult<Option<(Value, ProofType)>> {
+        if !self.custom_proof_api {
+            return Ok(Some((
+                into_value(StorageProof {
+                    state_value: None,
+                    proof: SparseMerkleProof {
+                        leaf: None,
+                        siblings: Vec::new(),
+                    },
+                }),
+                ProofType::Membership,
+            )));
+        }
+
         let ledger_version = self.ledger_version_of_height(at.height()).await;
 
         let vault_addr = self
@@ -124,7 +146,7 @@ impl ProofModuleServer<IbcUnion> for Module {
             .await
             .unwrap();
 
-        let _address_str = self
+        let address_str = self
             .aptos_client
             .get_account_resource(
                 vault_addr.into(),
@@ -139,28 +161,29 @@ impl ProofModuleServer<IbcUnion> for Module {
             .as_str()
             .unwrap()
             .to_owned();
-        let _address = <H256>::new(U256::from_be_hex(_address_str).unwrap().to_be_bytes());
-
-        // NOTE(aeryz): This only works with Union's custom Movement node. When the following PR is merged,
-        // we will uncomment this: https://github.com/movementlabsxyz/movement/pull/645
-        // let storage_proof = get_storage_proof(
-        //     &self.ctx.movement_rpc_url,
-        //     address,
-        //     hex::encode(bcs::to_bytes(&path.to_string().as_bytes()).expect("won't fail")),
-        //     at.revision_height,
-        // ).await;
-
-        Ok(Some((
-            into_value(StorageProof {
-                state_value: None,
-                proof: SparseMerkleProof {
-                    leaf: None,
-                    siblings: Vec::new(),
-                },
-            }),
-            // TODO: Implement properly, see above
-            ProofType::Membership,
-        )))
+        let address = <H256>::new(U256::from_be_hex(address_str).unwrap().to_be_bytes());
+
+        // NOTE(aeryz): This only works with Union's custom Movement node, see
+        // https://github.com/movementlabsxyz/movement/pull/645
+        let storage_proof = get_storage_proof(
+            &self.movement_rpc_url,
+            address,
+            // the commitments table is keyed by the bcs encoded commitment key
+            Bytes::<HexUnprefixed>::from(
+                bcs::to_bytes(path.key().get().as_slice()).expect("won't fail"),
+            )
+            .to_string(),
+            at.height(),
+        )
+        .await;
+
+        let proof_type = if storage_proof.state_value.is_some() {
+            ProofType::Membership
+        } else {
+            ProofType::NonMembership
+        };
+
+        Ok(Some((into_value(storage_proof), proof_type)))
     }
 }
 
@@ -11,8 +11,8 @@ use ibc_union_spec::{
         ClientStatus, PacketAckByHash, PacketAckByHashResponse, PacketByHash, PacketByHashResponse,
         PacketsByBatchHash, PacketsByBatchHashResponse, Query,
     },
-    Channel, ChannelId, ClientId, Connection, ConnectionId, IbcUnion, MustBeZero, Packet, Status,
-    Timestamp,
+    Channel, ChannelId, ChannelUpgrade, ClientId, Connection, ConnectionId, IbcUnion, MustBeZero,
+    Packet, Status, Timestamp,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -432,6 +432,52 @@ impl Module {
 
         Ok(commitment.flatten())
     }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %height,
+            %channel_id
+        )
+    )]
+    async fn query_channel_upgrade(
+        &self,
+        height: Height,
+        channel_id: ChannelId,
+    ) -> RpcResult<Option<ChannelUpgrade>> {
+        let upgrade = self
+            .query_smart::<_, Option<ChannelUpgrade>>(
+                &ibc_union_msg::query::QueryMsg::GetChannelUpgrade { channel_id },
+                Some(height),
+            )
+            .await?;
+
+        Ok(upgrade.flatten())
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %height,
+            %channel_id
+        )
+    )]
+    async fn query_channel_upgrade_error(
+        &self,
+        height: Height,
+        channel_id: ChannelId,
+    ) -> RpcResult<Option<ChannelUpgrade>> {
+        let upgrade = self
+            .query_smart::<_, Option<ChannelUpgrade>>(
+                &ibc_union_msg::query::QueryMsg::GetChannelUpgradeError { channel_id },
+                Some(height),
+            )
+            .await?;
+
+        Ok(upgrade.flatten())
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -605,6 +651,14 @@ impl StateModuleServer<IbcUnion> for Module {
                 .query_channel(at, path.channel_id)
                 .await
                 .map(into_value),
+            StorePath::ChannelUpgrade(path) => self
+                .query_channel_upgrade(at, path.channel_id)
+                .await
+                .map(into_value),
+            StorePath::ChannelUpgradeError(path) => self
+                .query_channel_upgrade_error(at, path.channel_id)
+                .await
+                .map(into_value),
             StorePath::BatchPackets(path) => self
                 .query_batch_packets(at, path.batch_hash)
                 .await
@@ -18,8 +18,8 @@ use ibc_solidity::{
 use ibc_union_spec::{
     path::{BatchPacketsPath, BatchReceiptsPath, StorePath},
     query::{PacketAckByHashResponse, PacketByHashResponse, PacketsByBatchHashResponse, Query},
-    Channel, ChannelId, ChannelState, ClientId, Connection, ConnectionId, ConnectionState,
-    IbcUnion, Status,
+    Channel, ChannelId, ChannelState, ChannelUpgrade, ClientId, Connection, ConnectionId,
+    ConnectionState, IbcUnion, Status,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -810,6 +810,10 @@ impl StateModuleServer<IbcUnion> for Module {
                 .query_channel(at, path.channel_id)
                 .await
                 .map(into_value),
+            // channel upgrades are not supported by the solidity implementation
+            StorePath::ChannelUpgrade(_) | StorePath::ChannelUpgradeError(_) => {
+                Ok(into_value(None::<ChannelUpgrade>))
+            }
             StorePath::BatchReceipts(path) => self
                 .query_batch_receipts(at, path.batch_hash)
                 .await
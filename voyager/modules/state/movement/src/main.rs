@@ -6,8 +6,8 @@ use aptos_move_ibc::{
 use aptos_rest_client::{aptos_api_types::Address, error::RestError};
 use aptos_types::state_store::state_value::PersistedStateValueMetadata;
 use ibc_union_spec::{
-    path::StorePath, query::Query, Channel, ChannelState, ClientId, Connection, ConnectionState,
-    IbcUnion,
+    path::StorePath, query::Query, Channel, ChannelState, ChannelUpgrade, ClientId, Connection,
+    ConnectionState, IbcUnion,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -30,7 +30,7 @@ use voyager_sdk::{
     anyhow, into_value,
     plugin::StateModule,
     primitives::{ChainId, ClientInfo, ClientType, IbcInterface, Timestamp},
-    rpc::{types::StateModuleInfo, StateModuleServer},
+    rpc::{types::StateModuleInfo, StateModuleServer, FATAL_JSONRPC_ERROR_CODE},
 };
 
 pub mod events;
@@ -162,10 +162,20 @@ impl Module {
 impl StateModuleServer<IbcUnion> for Module {
     #[instrument(skip_all, fields(chain_id = %self.chain_id))]
     async fn query(&self, _: &Extensions, query: Query) -> RpcResult<Value> {
-        match query {
-            Query::PacketByHash(_packet_by_hash) => todo!(),
-            Query::PacketsByBatchHash(_packets_by_batch_hash) => todo!(),
-        }
+        // the aptos rest api does not allow filtering events by their fields, so none of the
+        // indexed queries can be served without scanning the whole history of the ibc handler
+        let query = match query {
+            Query::PacketByHash(_) => "packet_by_hash",
+            Query::PacketsByBatchHash(_) => "packets_by_batch_hash",
+            Query::PacketAckByHash(_) => "packet_ack_by_hash",
+            Query::ClientStatus(_) => "client_status",
+        };
+
+        Err(ErrorObject::owned(
+            FATAL_JSONRPC_ERROR_CODE,
+            format!("query {query} is not supported on movement"),
+            None::<()>,
+        ))
     }
 
     #[instrument(skip_all, fields(chain_id = %self.chain_id))]
@@ -201,7 +211,7 @@ impl StateModuleServer<IbcUnion> for Module {
                     .map_err(rest_error_to_rpc_error)?
                     .into();
 
-                into_value(client_state_bytes)
+                into_value((!client_state_bytes.is_empty()).then_some(client_state_bytes))
             }
             StorePath::ConsensusState(path) => {
                 let consensus_state_bytes: Bytes = self
@@ -214,7 +224,7 @@ impl StateModuleServer<IbcUnion> for Module {
                     .map_err(rest_error_to_rpc_error)?
                     .into();
 
-                into_value(consensus_state_bytes)
+                into_value((!consensus_state_bytes.is_empty()).then_some(consensus_state_bytes))
             }
             StorePath::Connection(path) => {
                 let connection = self
@@ -224,9 +234,8 @@ impl StateModuleServer<IbcUnion> for Module {
                         (path.connection_id.raw(),),
                     )
                     .await
-                    .map_err(rest_error_to_rpc_error)?
-                    .unwrap();
-                into_value(convert_connection(connection))
+                    .map_err(rest_error_to_rpc_error)?;
+                into_value(connection.map(convert_connection))
             }
             StorePath::Channel(path) => {
                 let channel = self
@@ -236,40 +245,47 @@ impl StateModuleServer<IbcUnion> for Module {
                         (path.channel_id.raw(),),
                     )
                     .await
-                    .map_err(rest_error_to_rpc_error)?
-                    .unwrap();
-                into_value(convert_channel(channel))
+                    .map_err(rest_error_to_rpc_error)?;
+                into_value(channel.map(convert_channel))
+            }
+            // channel upgrades are not supported by the move implementation
+            StorePath::ChannelUpgrade(_) | StorePath::ChannelUpgradeError(_) => {
+                into_value(None::<ChannelUpgrade>)
+            }
+            StorePath::BatchPackets(path) => {
+                into_value(self.query_commitment(ledger_version, path.key()).await?)
             }
-            // TODO(aeryz): check if we have to do `TryInto<H256>` here
-            StorePath::BatchPackets(path) => into_value(
-                self.get_commitment(
-                    self.ibc_handler_address.into(),
-                    Some(ledger_version),
-                    (path.key().into_bytes().into(),),
-                )
-                .await
-                .map_err(rest_error_to_rpc_error)?,
-            ),
             StorePath::BatchReceipts(path) => {
-                let commitment = self
-                    .get_commitment(
-                        self.ibc_handler_address.into(),
-                        Some(ledger_version),
-                        (path.key().into_bytes().into(),),
-                    )
-                    .await
-                    .map_err(rest_error_to_rpc_error)?;
-
-                into_value(match &commitment[..] {
-                    [] => false,
-                    [1] => true,
-                    _ => panic!("not a bool??? {commitment:?}"),
-                })
+                into_value(self.query_commitment(ledger_version, path.key()).await?)
             }
         })
     }
 }
 
+impl Module {
+    async fn query_commitment(&self, ledger_version: u64, key: H256) -> RpcResult<Option<H256>> {
+        let commitment = self
+            .get_commitment(
+                self.ibc_handler_address.into(),
+                Some(ledger_version),
+                (key.into_bytes().into(),),
+            )
+            .await
+            .map_err(rest_error_to_rpc_error)?;
+
+        match &commitment[..] {
+            [] => Ok(None),
+            commitment => H256::try_from(commitment).map(Some).map_err(|err| {
+                ErrorObject::owned(
+                    -1,
+                    format!("invalid commitment: {}", ErrorReporter(err)),
+                    None::<()>,
+                )
+            }),
+        }
+    }
+}
+
 pub fn rest_error_to_rpc_error(e: RestError) -> ErrorObjectOwned {
     ErrorObject::owned(-1, format!("rest error: {}", ErrorReporter(e)), None::<()>)
 }
@@ -5,10 +5,16 @@ use std::collections::VecDeque;
 use alloy::{
     network::AnyNetwork,
     providers::{DynProvider, Provider, ProviderBuilder},
+    rpc::types::EIP1186StorageProof,
+};
+use arbitrum_client::{
+    finalized_l2_block_of_l1_height, l2_block_of_node_num, node_num_of_l1_height,
+};
+use arbitrum_light_client_types::{ClientState, FinalityPolicy, Header, L2Header, NodeProof};
+use arbitrum_types::slots::{
+    read_node_prev_num, rollup_core_nodes_confirm_data_slot, rollup_core_nodes_slot,
+    NODE_CHILDREN_OFFSET, NODE_PREV_NUM_OFFSET, ROLLUP_CORE_LATEST_NODE_CREATED,
 };
-use arbitrum_client::finalized_l2_block_of_l1_height;
-use arbitrum_light_client_types::{ClientState, Header, L2Header};
-use arbitrum_types::slots::{rollup_core_nodes_confirm_data_slot, ROLLUP_CORE_LATEST_NODE_CREATED};
 use ethereum_light_client_types::{AccountProof, StorageProof};
 use ibc_union_spec::{path::ClientStatePath, ClientId, IbcUnion};
 use jsonrpsee::{
@@ -272,34 +278,43 @@ impl Module {
         })
     }
 
-    /// Fetch the settlement state and proofs, stored in the L1 Rollup contract at the specified ***L1*** block number.
+    /// Fetch the settlement state and proofs of the node `node_num`, stored in the L1 Rollup contract at the specified ***L1*** block number.
+    ///
+    /// The proofs of the node's dispute state are only fetched if the finality policy requires them.
     #[instrument(
         skip_all,
         fields(
             %l1_block_number,
+            %node_num,
+            ?finality_policy,
             l1_contract_address = %self.l1_contract_address
         )
     )]
-    async fn fetch_l1_latest_confirmed_proofs(
+    async fn fetch_l1_settlement_proofs(
         &self,
         l1_block_number: u64,
-    ) -> LatestConfirmedProofs {
-        let latest_confirmed = arbitrum_client::next_node_num_at_l1_height(
-            &self.l1_provider,
-            self.l1_contract_address,
-            l1_block_number,
-        )
-        .await
-        .unwrap();
-
-        // fetch two proofs at once!
-        let [latest_confirmed_slot_proof, nodes_slot_proof]: [_; 2] = self
+        node_num: u64,
+        finality_policy: FinalityPolicy,
+    ) -> SettlementProofs {
+        // fetch all of the node's proofs at once!
+        let [
+            latest_confirmed_slot_proof,
+            nodes_slot_proof,
+            node_prev_num_slot_proof,
+            node_created_at_block_slot_proof,
+        ]: [_; 4] = self
             .l1_provider
             .get_proof(
                 self.l1_contract_address.into(),
                 vec![
                     ROLLUP_CORE_LATEST_NODE_CREATED.slot().to_be_bytes().into(),
-                    rollup_core_nodes_confirm_data_slot(latest_confirmed)
+                    rollup_core_nodes_confirm_data_slot(node_num)
+                        .to_be_bytes()
+                        .into(),
+                    rollup_core_nodes_slot(node_num, NODE_PREV_NUM_OFFSET)
+                        .to_be_bytes()
+                        .into(),
+                    rollup_core_nodes_slot(node_num, NODE_CHILDREN_OFFSET)
                         .to_be_bytes()
                         .into(),
                 ],
@@ -311,27 +326,47 @@ impl Module {
             .try_into()
             .unwrap();
 
-        LatestConfirmedProofs {
-            latest_confirmed,
-            // TODO: Extract this logic into a fn, we do it all over the place
-            latest_confirmed_slot_proof: StorageProof {
-                key: U256::from_be_bytes(latest_confirmed_slot_proof.key.as_b256().0),
-                value: latest_confirmed_slot_proof.value.into(),
-                proof: latest_confirmed_slot_proof
-                    .proof
-                    .into_iter()
-                    .map(|bytes| bytes.into())
-                    .collect(),
-            },
-            nodes_slot_proof: StorageProof {
-                key: U256::from_be_bytes(nodes_slot_proof.key.as_b256().0),
-                value: nodes_slot_proof.value.into(),
-                proof: nodes_slot_proof
-                    .proof
-                    .into_iter()
-                    .map(|bytes| bytes.into())
-                    .collect(),
-            },
+        let node_proof = match finality_policy {
+            FinalityPolicy::L1Included {
+                unchallenged_blocks,
+            } if unchallenged_blocks > 0 => {
+                let node_prev_num_slot_proof = into_storage_proof(node_prev_num_slot_proof);
+
+                let prev_num = read_node_prev_num(node_prev_num_slot_proof.value);
+
+                let [parent_node_children_slot_proof]: [_; 1] = self
+                    .l1_provider
+                    .get_proof(
+                        self.l1_contract_address.into(),
+                        vec![rollup_core_nodes_slot(prev_num, NODE_CHILDREN_OFFSET)
+                            .to_be_bytes()
+                            .into()],
+                    )
+                    .block_id(l1_block_number.into())
+                    .await
+                    .unwrap()
+                    .storage_proof
+                    .try_into()
+                    .unwrap();
+
+                Some(NodeProof {
+                    node_num,
+                    node_prev_num_slot_proof,
+                    node_created_at_block_slot_proof: into_storage_proof(
+                        node_created_at_block_slot_proof,
+                    ),
+                    parent_node_children_slot_proof: into_storage_proof(
+                        parent_node_children_slot_proof,
+                    ),
+                })
+            }
+            _ => None,
+        };
+
+        SettlementProofs {
+            latest_confirmed_slot_proof: into_storage_proof(latest_confirmed_slot_proof),
+            nodes_slot_proof: into_storage_proof(nodes_slot_proof),
+            node_proof,
         }
     }
 
@@ -394,13 +429,20 @@ impl Module {
             .client_info::<IbcUnion>(counterparty_chain_id.clone(), client_id)
             .await?;
 
-        let ClientState::V1(arbitrum_client_state) = voyager_client
+        let ClientState::V2(arbitrum_client_state) = voyager_client
             .decode_client_state::<IbcUnion, ClientState>(
                 arbitrum_client_info.client_type,
                 arbitrum_client_info.ibc_interface,
                 arbitrum_client_state_raw,
             )
-            .await?;
+            .await?
+        else {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                "expected arbitrum client state v2".to_string(),
+                None::<()>,
+            ));
+        };
 
         // the client on the counterparty chain tracking the L1 that the L2 being tracked by the client we're updating settles on
         let l1_client_meta = voyager_client
@@ -422,14 +464,22 @@ impl Module {
             .query_latest_height(l1_client_meta.counterparty_chain_id.clone(), true)
             .await?;
 
-        let l2_settlement_block = finalized_l2_block_of_l1_height(
+        let Some(l2_settlement_block) = finalized_l2_block_of_l1_height(
             &self.l1_provider,
             &self.l2_provider,
             self.l1_contract_address,
             l1_latest_height.height(),
+            arbitrum_client_state.finality_policy,
         )
         .await
-        .unwrap();
+        .unwrap() else {
+            info!(
+                finality_policy = ?arbitrum_client_state.finality_policy,
+                "no node satisfies the finality policy, l2 client will not be updated"
+            );
+
+            return Ok(data(OrderedHeaders { headers: vec![] }));
+        };
 
         info!(
             number = %l2_settlement_block.header.number,
@@ -531,13 +581,20 @@ impl Module {
             "arbitrum client info"
         );
 
-        let ClientState::V1(arbitrum_client_state) = voyager_client
+        let ClientState::V2(arbitrum_client_state) = voyager_client
             .decode_client_state::<IbcUnion, ClientState>(
                 arbitrum_client_info.client_type,
                 arbitrum_client_info.ibc_interface,
                 arbitrum_client_state_raw,
             )
-            .await?;
+            .await?
+        else {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                "expected arbitrum client state v2".to_string(),
+                None::<()>,
+            ));
+        };
 
         debug!(?arbitrum_client_state, "arbitrum client state");
 
@@ -556,11 +613,32 @@ impl Module {
             "l1 client meta"
         );
 
-        let l2_settlement_block = finalized_l2_block_of_l1_height(
+        let finality_policy = arbitrum_client_state.finality_policy;
+
+        let node_num = node_num_of_l1_height(
             &self.l1_provider,
-            &self.l2_provider,
             self.l1_contract_address,
             l1_client_meta.counterparty_height.height(),
+            finality_policy,
+        )
+        .await
+        .unwrap()
+        .ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!(
+                    "no node satisfies the finality policy {finality_policy:?} at l1 height {}",
+                    l1_client_meta.counterparty_height
+                ),
+                None::<()>,
+            )
+        })?;
+
+        let l2_settlement_block = l2_block_of_node_num(
+            &self.l1_provider,
+            &self.l2_provider,
+            self.l1_contract_address,
+            node_num,
         )
         .await
         .unwrap();
@@ -589,8 +667,12 @@ impl Module {
                 .await
                 .unwrap();
 
-            let l1_latest_confirmed_proofs = self
-                .fetch_l1_latest_confirmed_proofs(l1_client_meta.counterparty_height.height())
+            let l1_settlement_proofs = self
+                .fetch_l1_settlement_proofs(
+                    l1_client_meta.counterparty_height.height(),
+                    node_num,
+                    finality_policy,
+                )
                 .await;
 
             let l2_ibc_account_proof = self
@@ -606,9 +688,9 @@ impl Module {
                         l1_height: l1_client_meta.counterparty_height,
                         l1_account_proof,
                         l2_ibc_account_proof,
-                        l1_next_node_num_slot_proof: l1_latest_confirmed_proofs
+                        l1_next_node_num_slot_proof: l1_settlement_proofs
                             .latest_confirmed_slot_proof,
-                        l1_nodes_slot_proof: l1_latest_confirmed_proofs.nodes_slot_proof,
+                        l1_nodes_slot_proof: l1_settlement_proofs.nodes_slot_proof,
                         l2_header: L2Header {
                             parent_hash: l2_settlement_block.header.parent_hash.into(),
                             sha3_uncles: l2_settlement_block.header.ommers_hash.into(),
@@ -640,6 +722,7 @@ impl Module {
                                 .unwrap_or_default()
                                 .into(),
                         },
+                        l1_node_proof: l1_settlement_proofs.node_proof,
                     }),
                 )],
             }))
@@ -647,8 +730,16 @@ impl Module {
     }
 }
 
-pub struct LatestConfirmedProofs {
-    pub latest_confirmed: u64,
+pub struct SettlementProofs {
     pub latest_confirmed_slot_proof: StorageProof,
     pub nodes_slot_proof: StorageProof,
+    pub node_proof: Option<NodeProof>,
+}
+
+fn into_storage_proof(proof: EIP1186StorageProof) -> StorageProof {
+    StorageProof {
+        key: U256::from_be_bytes(proof.key.as_b256().0),
+        value: proof.value.into(),
+        proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
+    }
 }
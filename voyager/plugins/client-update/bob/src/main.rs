@@ -8,7 +8,7 @@ use alloy::{
 };
 use bob_light_client_types::{
     header::{L2Header, OutputRootProof},
-    ClientState, Header,
+    ClientState, FinalityPolicy, Header,
 };
 use bob_types::L2_TO_L1_MESSAGE_PASSER;
 use call::FetchL2Update;
@@ -169,6 +169,34 @@ impl Module {
             proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
         })
     }
+
+    pub async fn fetch_game_storage_proof(
+        &self,
+        game_account: H160,
+        slot: U256,
+        height: u64,
+    ) -> RpcResult<StorageProof> {
+        let [proof]: [_; 1] = self
+            .l1_provider
+            .get_proof(game_account.into(), vec![slot.to_be_bytes().into()])
+            .block_id(height.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    format!("error fetching game storage proof: {}", ErrorReporter(e)),
+                    None::<()>,
+                )
+            })?
+            .storage_proof
+            .try_into()
+            .unwrap();
+        Ok(StorageProof {
+            key: U256::from_be_bytes(proof.key.as_b256().0),
+            value: U256::from_be_bytes(proof.value.to_be_bytes()),
+            proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
+        })
+    }
 }
 
 impl Plugin for Module {
@@ -449,7 +477,7 @@ impl Module {
 
         debug!(?bob_client_state_info);
 
-        let ClientState::V2(bob_client_state) = voy_client
+        let ClientState::V3(bob_client_state) = voy_client
             .decode_client_state::<IbcUnion, ClientState>(
                 bob_client_state_info.client_type.clone(),
                 bob_client_state_info.ibc_interface,
@@ -459,7 +487,7 @@ impl Module {
         else {
             return Err(ErrorObject::owned(
                 FATAL_JSONRPC_ERROR_CODE,
-                "expected bob client state v2".to_string(),
+                "expected bob client state v3".to_string(),
                 None::<()>,
             )
             .into());
@@ -571,7 +599,7 @@ impl Module {
 
         debug!(?bob_client_state_info);
 
-        let ClientState::V2(bob_client_state) = voy_client
+        let ClientState::V3(bob_client_state) = voy_client
             .decode_client_state::<IbcUnion, ClientState>(
                 bob_client_state_info.client_type.clone(),
                 bob_client_state_info.ibc_interface,
@@ -581,7 +609,7 @@ impl Module {
         else {
             return Err(ErrorObject::owned(
                 FATAL_JSONRPC_ERROR_CODE,
-                "expected bob client state v2".to_string(),
+                "expected bob client state v3".to_string(),
                 None::<()>,
             )
             .into());
@@ -599,10 +627,31 @@ impl Module {
 
         let l1_height = l1_client_meta.counterparty_height.height();
 
-        let l2_block_number = bob_client::finalized_l2_block_number_of_l1_block_number(
+        let finality_policy = bob_client_state.finality_policy;
+
+        let game_index = bob_client::latest_final_game_of_l1_block_number(
             &self.l1_provider,
+            l1_height,
             self.l1_dispute_game_factory_proxy,
+            finality_policy,
+        )
+        .await
+        .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?
+        .ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!(
+                    "no dispute game satisfies the finality policy {finality_policy:?} at l1 height {l1_height}"
+                ),
+                None::<()>,
+            )
+        })?;
+
+        let l2_block_number = bob_client::finalized_l2_block_of_game_index(
+            &self.l1_provider,
             l1_height,
+            self.l1_dispute_game_factory_proxy,
+            game_index,
         )
         .await
         .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
@@ -622,16 +671,6 @@ impl Module {
 
         let output_root_proof = self.fetch_output_root_proof(l2_block.header.number).await?;
 
-        let game_index = bob_client::latest_game_of_l1_block_number(
-            &self.l1_provider,
-            l1_height,
-            self.l1_dispute_game_factory_proxy,
-        )
-        .await
-        .map_err(|err| ErrorObject::owned(-1, ErrorReporter(err).to_string(), None::<()>))?;
-
-        let game_index = game_index - U256::ONE;
-
         let dispute_game_factory_account_proof = self
             .fetch_dispute_game_factory_account_proof(l1_height)
             .await
@@ -649,6 +688,29 @@ impl Module {
         let game_account_proof = self
             .fetch_game_account_proof(l1_height, game_account_address)
             .await?;
+        let (game_status_proof, claim_data_length_proof) =
+            if finality_policy == FinalityPolicy::SequencerSoftConfirmation {
+                (None, None)
+            } else {
+                (
+                    Some(
+                        self.fetch_game_storage_proof(
+                            game_account_address,
+                            bob_verifier::GAME_STATUS_SLOT,
+                            l1_height,
+                        )
+                        .await?,
+                    ),
+                    Some(
+                        self.fetch_game_storage_proof(
+                            game_account_address,
+                            bob_verifier::CLAIM_DATA_LENGTH_SLOT,
+                            l1_height,
+                        )
+                        .await?,
+                    ),
+                )
+            };
         let game_account_code =
             <Bytes>::new(self.fetch_game_account_code(game_account_address).await?);
 
@@ -703,6 +765,8 @@ impl Module {
                     game_proof,
                     game_account_proof,
                     game_account_code,
+                    game_status_proof,
+                    claim_data_length_proof,
                 }),
             )],
         }))
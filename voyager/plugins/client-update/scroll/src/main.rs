@@ -0,0 +1,777 @@
+// #![warn(clippy::unwrap_used)]
+
+use std::collections::VecDeque;
+
+use alloy::{
+    consensus::{Transaction, Typed2718},
+    network::{AnyNetwork, TransactionResponse},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    sol,
+    sol_types::SolCall,
+};
+use call::FetchL2Update;
+use ethereum_light_client_types::{AccountProof, StorageProof};
+use ibc_union_spec::{path::ClientStatePath, ClientId, IbcUnion};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use scroll_api::ScrollClient;
+use scroll_codec::chunk::{chunk_data_hash, last_block_number, BLOCK_CONTEXT_LENGTH};
+use scroll_light_client_types::{ClientState, ClientStateV1, Header, LastBlockProof};
+use scroll_rpc::BlockId;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, instrument};
+use unionlabs::{
+    ibc::core::client::height::Height,
+    never::Never,
+    primitives::{ByteArrayExt, Bytes, H160, H256, U256},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow,
+    hook::UpdateHook,
+    into_value,
+    message::{
+        call::{Call, FetchUpdateHeaders, WaitForHeightRelative, WaitForTrustedHeight},
+        callback::AggregateSubmitTxFromOrderedHeaders,
+        data::{Data, DecodedHeaderMeta, OrderedHeaders},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::{ChainId, ClientType, IbcSpec, QueryHeight},
+    rpc::{types::PluginInfo, PluginServer},
+    types::RawClientId,
+    vm::{call, conc, data, pass::PassResult, promise, seq, BoxDynError, Op, Visit},
+    DefaultCmd, ExtensionsExt, VoyagerClient,
+};
+
+use crate::call::{FetchUpdate, ModuleCall};
+
+pub mod call;
+
+#[tokio::main]
+async fn main() {
+    Module::run().await
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub l1_provider: DynProvider,
+    pub l2_provider: DynProvider<AnyNetwork>,
+
+    /// Client for scroll's `eth_getProof`, which returns zktrie proofs.
+    pub scroll_rpc: scroll_rpc::JsonRpcClient,
+
+    pub scroll_api_client: ScrollClient,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub l2_chain_id: ChainId,
+
+    /// The RPC endpoint for the settlement (L1) execution chain.
+    pub l1_rpc_url: String,
+
+    /// The websocket RPC endpoint for the main (L2) execution chain.
+    pub l2_rpc_url: String,
+
+    /// The endpoint of the scroll rollup explorer API, used to map batches to L2 blocks.
+    pub scroll_api_url: String,
+
+    /// The address of the `IBCHandler` smart contract.
+    pub ibc_handler_address: H160,
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+
+    /// Fetch the proofs of the rollup contract account and of the finalized batch `batch_index`
+    /// against the L1 state root at `l1_height`.
+    ///
+    /// Returns the account proof along with the last finalized batch index, finalized state root
+    /// and committed batch hash proofs, in that order.
+    #[instrument(
+        skip_all,
+        fields(
+            %l1_height,
+            %batch_index,
+            rollup_contract_address = %client_state.rollup_contract_address
+        )
+    )]
+    pub async fn fetch_rollup_proofs(
+        &self,
+        client_state: &ClientStateV1,
+        l1_height: u64,
+        batch_index: U256,
+    ) -> RpcResult<(AccountProof, [StorageProof; 3])> {
+        let proof = self
+            .l1_provider
+            .get_proof(
+                client_state.rollup_contract_address.into(),
+                vec![
+                    client_state.latest_batch_index_slot.to_be_bytes().into(),
+                    scroll_verifier::mapping_index_to_slot_key(
+                        client_state.l2_finalized_state_roots_slot,
+                        batch_index,
+                    )
+                    .to_be_bytes()
+                    .into(),
+                    scroll_verifier::mapping_index_to_slot_key(
+                        client_state.l2_committed_batches_slot,
+                        batch_index,
+                    )
+                    .to_be_bytes()
+                    .into(),
+                ],
+            )
+            .block_id(l1_height.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching rollup contract proof"),
+                    None::<()>,
+                )
+            })?;
+
+        debug!(storage_hash = %proof.storage_hash, "fetched rollup contract proof");
+
+        let storage_proofs: [_; 3] = proof
+            .storage_proof
+            .into_iter()
+            .map(|proof| StorageProof {
+                key: U256::from_be_bytes(proof.key.as_b256().0),
+                value: U256::from_be_bytes(proof.value.to_be_bytes()),
+                proof: proof.proof.into_iter().map(|bytes| bytes.into()).collect(),
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .expect("three storage proofs were requested; qed;");
+
+        Ok((
+            AccountProof {
+                storage_root: proof.storage_hash.into(),
+                proof: proof.account_proof.into_iter().map(|x| x.into()).collect(),
+            },
+            storage_proofs,
+        ))
+    }
+
+    /// Fetch the header of a finalized batch from the calldata of the transaction that finalized
+    /// it, since the rollup contract only stores the hash of the header.
+    ///
+    /// Returns the header along with the proof of the last L2 block of the batch.
+    #[instrument(skip_all, fields(%batch_index))]
+    pub async fn fetch_batch_header(&self, batch_index: u64) -> RpcResult<(Bytes, LastBlockProof)> {
+        let batch = self.scroll_api_client.batch(batch_index).await.batch;
+
+        let finalize_tx_hash = batch.finalize_tx_hash.ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!("batch {batch_index} has not been finalized"),
+                None::<()>,
+            )
+        })?;
+
+        let tx = self
+            .l1_provider
+            .get_transaction_by_hash(finalize_tx_hash.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching batch finalization tx"),
+                    None::<()>,
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("batch finalization tx {finalize_tx_hash} not found"),
+                    None::<()>,
+                )
+            })?;
+
+        let call =
+            ScrollChain::finalizeBundleWithProofCall::abi_decode(tx.input()).map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("unable to decode batch finalization tx"),
+                    None::<()>,
+                )
+            })?;
+
+        debug!(
+            end_block_number = batch.end_block_number,
+            %finalize_tx_hash,
+            "fetched batch header"
+        );
+
+        let last_block_proof = self.fetch_last_block_proof(batch.commit_tx_hash).await?;
+
+        Ok((call.batchHeader.into(), last_block_proof))
+    }
+
+    /// Fetch the chunks of a batch from the calldata of the transaction that committed it, and
+    /// build the proof of its last L2 block out of them.
+    #[instrument(skip_all, fields(%commit_tx_hash))]
+    pub async fn fetch_last_block_proof(&self, commit_tx_hash: H256) -> RpcResult<LastBlockProof> {
+        let tx = self
+            .l1_provider
+            .get_transaction_by_hash(commit_tx_hash.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching batch commit tx"),
+                    None::<()>,
+                )
+            })?
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("batch commit tx {commit_tx_hash} not found"),
+                    None::<()>,
+                )
+            })?;
+
+        let call =
+            ScrollChain::commitBatchWithBlobProofCall::abi_decode(tx.input()).map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("unable to decode batch commit tx"),
+                    None::<()>,
+                )
+            })?;
+
+        let mut chunk_data_hashes = vec![];
+        let mut last_chunk = None;
+
+        for chunk in &call.chunks {
+            // a chunk is encoded as `numBlocks` (1 byte) followed by the block contexts
+            let block_contexts = chunk.get(1..).unwrap_or_default();
+            let l1_message_hashes = self.fetch_l1_message_hashes(block_contexts).await?;
+
+            if let Some((block_contexts, l1_message_hashes)) =
+                last_chunk.replace((block_contexts, l1_message_hashes))
+            {
+                chunk_data_hashes.push(
+                    chunk_data_hash(block_contexts, &l1_message_hashes).map_err(|e| {
+                        ErrorObject::owned(
+                            -1,
+                            ErrorReporter(e).with_message("invalid chunk in batch commit tx"),
+                            None::<()>,
+                        )
+                    })?,
+                );
+            }
+        }
+
+        let (block_contexts, l1_message_hashes) = last_chunk.ok_or_else(|| {
+            ErrorObject::owned(
+                -1,
+                format!("batch commit tx {commit_tx_hash} has no chunks"),
+                None::<()>,
+            )
+        })?;
+
+        debug!(
+            chunks = call.chunks.len(),
+            l1_messages = l1_message_hashes.len(),
+            "fetched last block proof"
+        );
+
+        Ok(LastBlockProof {
+            chunk_data_hashes,
+            block_contexts: block_contexts.to_vec().into(),
+            l1_message_hashes,
+        })
+    }
+
+    /// Fetch the hashes of the L1 messages included in the blocks of `block_contexts`. Skipped
+    /// messages are not included in the L2 blocks, nor in the chunk data hash.
+    async fn fetch_l1_message_hashes(&self, block_contexts: &[u8]) -> RpcResult<Vec<H256>> {
+        let mut l1_message_hashes = vec![];
+
+        for block_context in block_contexts.chunks_exact(BLOCK_CONTEXT_LENGTH) {
+            let block_context: [u8; BLOCK_CONTEXT_LENGTH] = block_context
+                .try_into()
+                .expect("chunks are exactly BLOCK_CONTEXT_LENGTH bytes; qed;");
+
+            let block_number = u64::from_be_bytes(block_context.array_slice::<0, 8>());
+            let num_l1_messages = u16::from_be_bytes(block_context.array_slice::<58, 2>());
+
+            if num_l1_messages == 0 {
+                continue;
+            }
+
+            let block = self
+                .l2_provider
+                .get_block_by_number(block_number.into())
+                .full()
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        ErrorReporter(e).with_message("error fetching l2 block"),
+                        None::<()>,
+                    )
+                })?
+                .ok_or_else(|| {
+                    ErrorObject::owned(
+                        -1,
+                        format!("l2 block {block_number} does not exist"),
+                        None::<()>,
+                    )
+                })?;
+
+            l1_message_hashes.extend(
+                block
+                    .transactions
+                    .txns()
+                    .filter(|tx| tx.ty() == L1_MESSAGE_TX_TYPE)
+                    .map(|tx| H256::from(tx.tx_hash())),
+            );
+        }
+
+        Ok(l1_message_hashes)
+    }
+
+    async fn fetch_ibc_contract_root_proof(&self, height: u64) -> RpcResult<AccountProof> {
+        let proof = self
+            .scroll_rpc
+            .get_proof(self.ibc_handler_address, [], BlockId::Number(height))
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    format!("error fetching ibc contract proof: {}", ErrorReporter(e)),
+                    None::<()>,
+                )
+            })?;
+        Ok(AccountProof {
+            storage_root: proof.storage_hash,
+            proof: proof.account_proof.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = DefaultCmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let l1_provider =
+            DynProvider::new(ProviderBuilder::new().connect(&config.l1_rpc_url).await?);
+
+        let l2_provider = DynProvider::new(
+            ProviderBuilder::new()
+                .network::<AnyNetwork>()
+                .connect(&config.l2_rpc_url)
+                .await?,
+        );
+
+        let l2_chain_id = ChainId::new(l2_provider.get_chain_id().await?.to_string());
+
+        assert_eq!(l2_chain_id, config.l2_chain_id);
+
+        let scroll_rpc = scroll_rpc::JsonRpcClient::new(&config.l2_rpc_url).await?;
+
+        Ok(Self {
+            chain_id: l2_chain_id,
+            l1_provider,
+            l2_provider,
+            scroll_rpc,
+            scroll_api_client: ScrollClient::new(config.scroll_api_url),
+            ibc_handler_address: config.ibc_handler_address,
+        })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.l2_chain_id),
+            interest_filter: UpdateHook::filter(
+                &config.l2_chain_id,
+                &ClientType::new(ClientType::SCROLL),
+            ),
+        }
+    }
+
+    async fn cmd(_config: Self::Config, cmd: Self::Cmd) {
+        match cmd {}
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        Ok(PassResult {
+            optimize_further: vec![],
+            ready: msgs
+                .into_iter()
+                .map(|mut op| {
+                    UpdateHook::new(
+                        &self.chain_id,
+                        &ClientType::new(ClientType::SCROLL),
+                        |fetch| {
+                            Call::Plugin(PluginMessage::new(
+                                self.plugin_name(),
+                                ModuleCall::from(FetchUpdate {
+                                    from_height: fetch.update_from,
+                                    to_height: fetch.update_to,
+                                    counterparty_chain_id: fetch.counterparty_chain_id.clone(),
+                                    client_id: fetch
+                                        .client_id
+                                        .clone()
+                                        .decode_spec::<IbcUnion>()
+                                        .unwrap(),
+                                }),
+                            ))
+                        },
+                    )
+                    .visit_op(&mut op);
+
+                    op
+                })
+                .enumerate()
+                .map(|(i, op)| (vec![i], op))
+                .collect(),
+        })
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::FetchUpdate(FetchUpdate {
+                from_height,
+                to_height,
+                counterparty_chain_id,
+                client_id,
+            }) => self
+                .fetch_update(
+                    e.voyager_client()?,
+                    from_height,
+                    to_height,
+                    counterparty_chain_id,
+                    client_id,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!("error fetching update: {}", ErrorReporter(&*e)),
+                        None::<()>,
+                    )
+                }),
+            ModuleCall::FetchL2Update(FetchL2Update {
+                update_from,
+                counterparty_chain_id,
+                client_id,
+            }) => self
+                .fetch_l2_update(
+                    e.voyager_client()?,
+                    update_from,
+                    counterparty_chain_id,
+                    client_id,
+                )
+                .await
+                .map_err(|e| {
+                    ErrorObject::owned(
+                        -1,
+                        format!("error fetching l2 update: {}", ErrorReporter(&*e)),
+                        None::<()>,
+                    )
+                }),
+        }
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
+
+impl Module {
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %update_from,
+            %update_to,
+        )
+    )]
+    async fn fetch_update(
+        &self,
+        voy_client: &VoyagerClient,
+        update_from: Height,
+        update_to: Height,
+        counterparty_chain_id: ChainId,
+        client_id: ClientId,
+    ) -> Result<Op<VoyagerMessage>, BoxDynError> {
+        let counterparty_latest_height = voy_client
+            .query_latest_height(counterparty_chain_id.clone(), false)
+            .await?;
+
+        let raw_scroll_client_state = voy_client
+            .query_ibc_state(
+                counterparty_chain_id.clone(),
+                QueryHeight::Specific(counterparty_latest_height),
+                ClientStatePath { client_id },
+            )
+            .await?;
+
+        debug!(?raw_scroll_client_state);
+
+        let scroll_client_state_info = voy_client
+            .client_info::<IbcUnion>(counterparty_chain_id.clone(), client_id)
+            .await?;
+
+        debug!(?scroll_client_state_info);
+
+        let ClientState::V1(scroll_client_state) = voy_client
+            .decode_client_state::<IbcUnion, ClientState>(
+                scroll_client_state_info.client_type.clone(),
+                scroll_client_state_info.ibc_interface,
+                raw_scroll_client_state,
+            )
+            .await?;
+
+        debug!(?scroll_client_state);
+
+        if scroll_client_state.latest_height >= update_to.height() {
+            info!("scroll: irrelevant update");
+            Ok(data(OrderedHeaders { headers: vec![] }))
+        } else {
+            let l1_client_info = voy_client
+                .client_info::<IbcUnion>(
+                    counterparty_chain_id.clone(),
+                    scroll_client_state.l1_client_id,
+                )
+                .await?;
+
+            let l1_client_meta = voy_client
+                .client_state_meta::<IbcUnion>(
+                    counterparty_chain_id.clone(),
+                    QueryHeight::Latest,
+                    scroll_client_state.l1_client_id,
+                )
+                .await?;
+
+            // Latest L1 finalized height
+            let l1_latest_height = voy_client
+                .query_latest_height(l1_client_meta.counterparty_chain_id.clone(), true)
+                .await?;
+
+            Ok(conc([
+                promise(
+                    [call(FetchUpdateHeaders {
+                        client_type: l1_client_info.client_type,
+                        chain_id: l1_client_meta.counterparty_chain_id.clone(),
+                        counterparty_chain_id: counterparty_chain_id.clone(),
+                        client_id: RawClientId::new(scroll_client_state.l1_client_id),
+                        update_from: l1_client_meta.counterparty_height,
+                        update_to: l1_latest_height,
+                    })],
+                    [],
+                    AggregateSubmitTxFromOrderedHeaders {
+                        ibc_spec_id: IbcUnion::ID,
+                        chain_id: counterparty_chain_id.clone(),
+                        client_id: RawClientId::new(scroll_client_state.l1_client_id),
+                    },
+                ),
+                seq([
+                    call(WaitForTrustedHeight {
+                        chain_id: counterparty_chain_id.clone(),
+                        ibc_spec_id: IbcUnion::ID,
+                        client_id: RawClientId::new(scroll_client_state.l1_client_id),
+                        height: l1_latest_height,
+                        finalized: false,
+                    }),
+                    // wait for 1 extra block to ensure that the L1 update is in state, and this update will not end up in the same block (and potentially get reordered)
+                    call(WaitForHeightRelative {
+                        chain_id: counterparty_chain_id.clone(),
+                        height_diff: 1,
+                        finalized: false,
+                    }),
+                    call(PluginMessage::new(
+                        self.plugin_name(),
+                        ModuleCall::from(FetchL2Update {
+                            update_from,
+                            counterparty_chain_id,
+                            client_id,
+                        }),
+                    )),
+                ]),
+            ]))
+        }
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            chain_id = %self.chain_id,
+            %counterparty_chain_id,
+            %update_from,
+            %client_id,
+        )
+    )]
+    async fn fetch_l2_update(
+        &self,
+        voy_client: &VoyagerClient,
+        update_from: Height,
+        counterparty_chain_id: ChainId,
+        client_id: ClientId,
+    ) -> Result<Op<VoyagerMessage>, BoxDynError> {
+        let counterparty_latest_height = voy_client
+            .query_latest_height(counterparty_chain_id.clone(), false)
+            .await?;
+
+        let raw_scroll_client_state = voy_client
+            .query_ibc_state(
+                counterparty_chain_id.clone(),
+                QueryHeight::Specific(counterparty_latest_height),
+                ClientStatePath { client_id },
+            )
+            .await?;
+
+        debug!(?raw_scroll_client_state);
+
+        let scroll_client_state_info = voy_client
+            .client_info::<IbcUnion>(counterparty_chain_id.clone(), client_id)
+            .await?;
+
+        debug!(?scroll_client_state_info);
+
+        let ClientState::V1(scroll_client_state) = voy_client
+            .decode_client_state::<IbcUnion, ClientState>(
+                scroll_client_state_info.client_type.clone(),
+                scroll_client_state_info.ibc_interface,
+                raw_scroll_client_state,
+            )
+            .await?;
+
+        debug!(?scroll_client_state);
+
+        let l1_client_meta = voy_client
+            .client_state_meta::<IbcUnion>(
+                counterparty_chain_id.clone(),
+                QueryHeight::Latest,
+                scroll_client_state.l1_client_id,
+            )
+            .await?;
+
+        let l1_height = l1_client_meta.counterparty_height.height();
+
+        let batch_index = self
+            .l1_provider
+            .get_storage_at(
+                scroll_client_state.rollup_contract_address.into(),
+                scroll_client_state.latest_batch_index_slot.into(),
+            )
+            .block_id(l1_height.into())
+            .await
+            .map_err(|e| {
+                ErrorObject::owned(
+                    -1,
+                    ErrorReporter(e).with_message("error fetching last finalized batch index"),
+                    None::<()>,
+                )
+            })?;
+
+        let batch_index = U256::from(batch_index);
+
+        let (l1_account_proof, [last_batch_index_proof, l2_state_root_proof, batch_hash_proof]) =
+            self.fetch_rollup_proofs(&scroll_client_state, l1_height, batch_index)
+                .await?;
+
+        let (batch_header, last_block_proof) = self
+            .fetch_batch_header(u64::try_from(batch_index).map_err(|_| {
+                ErrorObject::owned(
+                    -1,
+                    format!("batch index {batch_index} does not fit in a u64"),
+                    None::<()>,
+                )
+            })?)
+            .await?;
+
+        let l2_block_number = last_block_number(&last_block_proof.block_contexts).map_err(|e| {
+            ErrorObject::owned(
+                -1,
+                ErrorReporter(e).with_message("invalid last block proof"),
+                None::<()>,
+            )
+        })?;
+
+        let l2_ibc_account_proof = self.fetch_ibc_contract_root_proof(l2_block_number).await?;
+
+        Ok(data(OrderedHeaders {
+            headers: vec![(
+                DecodedHeaderMeta {
+                    height: Height::new(l2_block_number),
+                },
+                into_value(Header {
+                    l1_height,
+                    l1_account_proof,
+                    l2_state_root_proof,
+                    last_batch_index_proof,
+                    batch_hash_proof,
+                    l2_ibc_account_proof,
+                    batch_header,
+                    last_block_proof,
+                }),
+            )],
+        }))
+    }
+}
+
+/// The EIP-2718 type of the L1 message transactions on scroll.
+const L1_MESSAGE_TX_TYPE: u8 = 0x7e;
+
+sol! {
+    interface ScrollChain {
+        function commitBatchWithBlobProof(
+            uint8 version,
+            bytes calldata parentBatchHeader,
+            bytes[] memory chunks,
+            bytes calldata skippedL1MessageBitmap,
+            bytes calldata blobDataProof
+        ) external;
+
+        function finalizeBundleWithProof(
+            bytes calldata batchHeader,
+            bytes32 postStateRoot,
+            bytes32 withdrawRoot,
+            bytes calldata aggrProof
+        ) external;
+    }
+}
@@ -1,6 +1,6 @@
 use std::{io::Write, num::NonZeroU64};
 
-use ibc_union_spec::{ChannelId, ClientId, ConnectionId, Timestamp};
+use ibc_union_spec::{ChannelId, ChannelOrder, ClientId, ConnectionId, Timestamp};
 use serde::{Deserialize, Serialize};
 use sha2::Digest;
 use unionlabs::{
@@ -206,6 +206,13 @@ pub enum IbcEvent {
         counterparty_height: u64,
     },
 
+    #[serde(rename = "wasm-client_misbehaviour")]
+    WasmClientMisbehaviour {
+        #[serde(with = "serde_utils::string")]
+        client_id: ClientId,
+        client_type: String,
+    },
+
     #[serde(rename = "wasm-connection_open_init")]
     WasmConnectionOpenInit {
         #[serde(with = "serde_utils::string")]
@@ -261,6 +268,8 @@ pub enum IbcEvent {
         #[serde(with = "serde_utils::string")]
         connection_id: ConnectionId,
         version: String,
+        #[serde(default)]
+        ordering: ChannelOrder,
     },
 
     #[serde(rename = "wasm-channel_open_try")]
@@ -300,6 +309,96 @@ pub enum IbcEvent {
         connection_id: ConnectionId,
     },
 
+    #[serde(rename = "wasm-channel_upgrade_init")]
+    WasmChannelUpgradeInit {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
+    #[serde(rename = "wasm-channel_upgrade_try")]
+    WasmChannelUpgradeTry {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
+    #[serde(rename = "wasm-channel_upgrade_ack")]
+    WasmChannelUpgradeAck {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
+    #[serde(rename = "wasm-channel_upgrade_confirm")]
+    WasmChannelUpgradeConfirm {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
+    #[serde(rename = "wasm-channel_upgrade_open")]
+    WasmChannelUpgradeOpen {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
+    #[serde(rename = "wasm-channel_upgrade_cancel")]
+    WasmChannelUpgradeCancel {
+        port_id: Bech32<H256>,
+        #[serde(with = "serde_utils::string")]
+        channel_id: ChannelId,
+        counterparty_port_id: Bytes<HexUnprefixed>,
+        #[serde(with = "serde_utils::string")]
+        counterparty_channel_id: ChannelId,
+        #[serde(with = "serde_utils::string")]
+        connection_id: ConnectionId,
+        version: String,
+        #[serde(with = "serde_utils::string")]
+        upgrade_sequence: u64,
+    },
+
     #[serde(rename = "wasm-packet_send")]
     WasmPacketSend {
         #[serde(with = "serde_utils::string")]
@@ -314,6 +413,8 @@ pub enum IbcEvent {
         #[serde(with = "serde_utils::string")]
         channel_id: ChannelId,
         packet_hash: H256,
+        #[serde(default, with = "serde_utils::string_opt")]
+        sequence: Option<u64>,
     },
 
     #[serde(rename = "wasm-batch_send")]
@@ -447,7 +548,7 @@ impl IbcEvent {
 
             IbcEvent::WasmCreateClient { .. } => "create_client",
             IbcEvent::WasmUpdateClient { .. } => "update_client",
-            // IbcEvent::UnionClientMisbehaviour{..} => "client_misbehaviour",
+            IbcEvent::WasmClientMisbehaviour { .. } => "client_misbehaviour",
             // IbcEvent::UnionSubmitEvidence{..} => "submit_evidence",
             IbcEvent::WasmConnectionOpenInit { .. } => "connection_open_init",
             IbcEvent::WasmConnectionOpenTry { .. } => "connection_open_try",
@@ -457,6 +558,12 @@ impl IbcEvent {
             IbcEvent::WasmChannelOpenTry { .. } => "channel_open_try",
             IbcEvent::WasmChannelOpenAck { .. } => "channel_open_ack",
             IbcEvent::WasmChannelOpenConfirm { .. } => "channel_open_confirm",
+            IbcEvent::WasmChannelUpgradeInit { .. } => "channel_upgrade_init",
+            IbcEvent::WasmChannelUpgradeTry { .. } => "channel_upgrade_try",
+            IbcEvent::WasmChannelUpgradeAck { .. } => "channel_upgrade_ack",
+            IbcEvent::WasmChannelUpgradeConfirm { .. } => "channel_upgrade_confirm",
+            IbcEvent::WasmChannelUpgradeOpen { .. } => "channel_upgrade_open",
+            IbcEvent::WasmChannelUpgradeCancel { .. } => "channel_upgrade_cancel",
             IbcEvent::WasmPacketRecv { .. } => "recv_packet",
             IbcEvent::WasmPacketSend { .. } => "send_packet",
             IbcEvent::WasmBatchSend { .. } => "batch_send",
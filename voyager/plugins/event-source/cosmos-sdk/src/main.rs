@@ -1246,6 +1246,35 @@ impl Module {
                     event,
                 )))
             }
+            IbcEvent::WasmClientMisbehaviour {
+                client_id,
+                client_type,
+            } => {
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(self.chain_id.clone(), height.into(), client_id)
+                    .await?;
+
+                let event = ibc_union_spec::event::ClientMisbehaviour {
+                    client_id,
+                    client_type: ClientType::new(client_type),
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
             IbcEvent::WasmConnectionOpenInit {
                 connection_id,
                 client_id,
@@ -1382,6 +1411,7 @@ impl Module {
                 counterparty_port_id,
                 connection_id,
                 version,
+                ordering,
             } => {
                 let connection = voyager_client
                     .query_ibc_state(
@@ -1409,6 +1439,7 @@ impl Module {
                     counterparty_port_id: counterparty_port_id.into_encoding(),
                     connection,
                     version,
+                    ordering,
                 }
                 .into();
 
@@ -1585,6 +1616,312 @@ impl Module {
                     event,
                 )))
             }
+            IbcEvent::WasmChannelUpgradeInit {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeInit {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
+            IbcEvent::WasmChannelUpgradeTry {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeTry {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
+            IbcEvent::WasmChannelUpgradeAck {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeAck {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
+            IbcEvent::WasmChannelUpgradeConfirm {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeConfirm {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
+            IbcEvent::WasmChannelUpgradeOpen {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeOpen {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
+            IbcEvent::WasmChannelUpgradeCancel {
+                port_id,
+                channel_id,
+                counterparty_port_id,
+                counterparty_channel_id,
+                connection_id,
+                version,
+                upgrade_sequence,
+            } => {
+                let connection = voyager_client
+                    .query_ibc_state(
+                        self.chain_id.clone(),
+                        QueryHeight::Specific(height),
+                        ibc_union_spec::path::ConnectionPath { connection_id },
+                    )
+                    .await?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(self.chain_id.clone(), connection.client_id)
+                    .await?;
+
+                let client_state_meta = voyager_client
+                    .client_state_meta::<IbcUnion>(
+                        self.chain_id.clone(),
+                        height.into(),
+                        connection.client_id,
+                    )
+                    .await?;
+
+                let event = ibc_union_spec::event::ChannelUpgradeCancel {
+                    port_id: port_id.to_string().into_bytes().into(),
+                    channel_id,
+                    counterparty_port_id: counterparty_port_id.into_encoding(),
+                    counterparty_channel_id,
+                    connection,
+                    version,
+                    upgrade_sequence,
+                }
+                .into();
+
+                ibc_union_spec::log_event(&event, &self.chain_id);
+
+                Ok(data(ChainEvent::new::<IbcUnion>(
+                    self.chain_id.clone(),
+                    client_info,
+                    client_state_meta.counterparty_chain_id,
+                    tx_hash,
+                    provable_height,
+                    event,
+                )))
+            }
             IbcEvent::WasmPacketSend {
                 packet_source_channel_id,
                 packet_destination_channel_id,
@@ -1593,6 +1930,7 @@ impl Module {
                 packet_timeout_timestamp,
                 channel_id: _,
                 packet_hash: _,
+                sequence,
             } => {
                 let packet = Packet {
                     source_channel_id: packet_source_channel_id,
@@ -1670,6 +2008,7 @@ impl Module {
                         },
                         timeout_timestamp: packet.timeout_timestamp,
                     },
+                    sequence,
                 }
                 .into();
 
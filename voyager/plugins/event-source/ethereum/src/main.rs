@@ -17,7 +17,7 @@ use ibc_union_spec::{
     },
     path::{BatchPacketsPath, BatchReceiptsPath, ChannelPath, ConnectionPath},
     query::PacketByHash,
-    ChannelId, ChannelState, IbcUnion, Packet,
+    ChannelId, ChannelOrder, ChannelState, IbcUnion, Packet,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -825,6 +825,7 @@ impl Module {
                     counterparty_port_id: raw_event.counterparty_port_id.into(),
                     connection,
                     version: channel.version,
+                    ordering: ChannelOrder::Unordered,
                 }
                 .into();
 
@@ -1071,6 +1072,7 @@ impl Module {
                                         destination_channel,
                                         timeout_timestamp: packet.timeout_timestamp,
                                     },
+                                    sequence: None,
                                 }
                                 .into();
 
@@ -7,7 +7,7 @@ use ibc_union_spec::{
         ConnectionOpenTry, CreateClient, FullEvent, PacketMetadata, PacketSend, UpdateClient,
     },
     path::{ChannelPath, ConnectionPath},
-    ChannelId, ClientId, IbcUnion, Timestamp,
+    ChannelId, ChannelOrder, ClientId, IbcUnion, Timestamp,
 };
 use jsonrpsee::{
     core::{async_trait, RpcResult},
@@ -518,6 +518,7 @@ impl PluginServer<ModuleCall, ModuleCallback> for Module {
                                 counterparty_port_id: event.counterparty_port_id.into(),
                                 connection,
                                 version: event.version,
+                                ordering: ChannelOrder::Unordered,
                             }
                             .into(),
                             client_id,
@@ -676,6 +677,7 @@ impl PluginServer<ModuleCall, ModuleCallback> for Module {
                                         packet.timeout_timestamp.0,
                                     ),
                                 },
+                                sequence: None,
                             }
                             .into(),
                             client_id,
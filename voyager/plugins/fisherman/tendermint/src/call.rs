@@ -0,0 +1,23 @@
+use enumorph::Enumorph;
+use ibc_union_spec::ClientId;
+use macros::model;
+use unionlabs::ibc::core::client::height::Height;
+use voyager_sdk::primitives::ChainId;
+
+#[model]
+#[derive(Enumorph)]
+pub enum ModuleCall {
+    CheckClient(CheckClient),
+}
+
+/// Check the latest consensus state of a client tracking this chain against the headers of this
+/// chain, and then keep checking it every [`Config::check_interval`](crate::Config::check_interval)
+/// seconds.
+#[model]
+pub struct CheckClient {
+    /// The chain the client is on.
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    /// The latest height of the client that has already been checked.
+    pub checked_height: Option<Height>,
+}
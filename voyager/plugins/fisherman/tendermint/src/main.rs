@@ -0,0 +1,604 @@
+#![warn(clippy::unwrap_used)]
+
+use std::{
+    collections::{HashMap, VecDeque},
+    num::{NonZeroU32, NonZeroU64, NonZeroU8},
+};
+
+use cometbft_types::types::{
+    signed_header::SignedHeader, validator::Validator, validator_set::ValidatorSet,
+};
+use ibc_union_spec::{datagram::MsgMisbehaviour, path::ConsensusStatePath, ClientId, IbcUnion};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    types::ErrorObject,
+    Extensions,
+};
+use opentelemetry::{metrics::Counter, KeyValue};
+use serde::{Deserialize, Serialize};
+use tendermint_light_client_types::{ConsensusState, Header, Misbehaviour};
+use tracing::{debug, error, info, instrument, warn};
+use unionlabs::{
+    cosmos::tx::{tx_body::TxBody, tx_raw::TxRaw},
+    cosmwasm::wasm::msg_execute_contract::MsgExecuteContract,
+    encoding::{Bincode, DecodeAs, EncodeAs, Proto},
+    google::protobuf::any::RawAny,
+    ibc::core::{client::height::Height, commitment::merkle_root::MerkleRoot},
+    never::Never,
+    option_unwrap,
+    primitives::{encoding::HexUnprefixed, H160},
+    ErrorReporter,
+};
+use voyager_sdk::{
+    anyhow::{self, bail},
+    into_value,
+    message::{
+        call::SubmitTx,
+        data::{Data, IbcDatagram},
+        PluginMessage, VoyagerMessage,
+    },
+    plugin::Plugin,
+    primitives::{ChainId, QueryHeight},
+    rpc::{rpc_error, types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
+    vm::{call, conc, defer, now, pass::PassResult, seq, Op},
+    ExtensionsExt, VoyagerClient,
+};
+
+use crate::call::{CheckClient, ModuleCall};
+
+pub mod call;
+
+/// The amount of client update transactions to search through when looking for the header a client
+/// was updated with. There is usually only one, but multiple relayers can race to update a client to
+/// the same height.
+const UPDATE_TXS_LIMIT: NonZeroU8 = option_unwrap!(NonZeroU8::new(10));
+
+#[tokio::main]
+async fn main() {
+    Module::run().await
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub chain_id: ChainId,
+
+    pub cometbft_client: cometbft_rpc::Client,
+
+    /// Clients for the chains the watched clients are on, keyed by chain id.
+    pub host_clients: HashMap<ChainId, cometbft_rpc::Client>,
+
+    pub alert_only: bool,
+    pub check_interval: u64,
+
+    pub metrics: Metrics,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The tendermint chain that the watched clients are tracking.
+    pub chain_id: ChainId,
+
+    /// RPC endpoint of the tracked chain. The on-chain consensus states of the watched clients are
+    /// compared against the headers fetched from this endpoint, so this should be a node that is
+    /// independent of the relayers updating the clients.
+    pub rpc_url: String,
+
+    /// The clients to watch.
+    pub clients: Vec<WatchedClient>,
+
+    /// Only log and record metrics when misbehaviour is detected, instead of submitting it to freeze
+    /// the client.
+    #[serde(default)]
+    pub alert_only: bool,
+
+    /// How often to check each client, in seconds.
+    #[serde(default = "default_check_interval")]
+    pub check_interval: u64,
+}
+
+fn default_check_interval() -> u64 {
+    60
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WatchedClient {
+    /// The chain the client is on. This must be a cosmwasm chain running ibc-union.
+    pub chain_id: ChainId,
+    pub client_id: ClientId,
+    /// RPC endpoint of `chain_id`, used to look up the transactions that updated the client.
+    pub rpc_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Metrics {
+    checks: Counter<u64>,
+    divergences: Counter<u64>,
+    submitted: Counter<u64>,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let meter = opentelemetry::global::meter("voyager");
+
+        Self {
+            checks: meter
+                .u64_counter("fisherman.checks")
+                .with_description("Consensus states of watched clients that have been checked")
+                .build(),
+            divergences: meter
+                .u64_counter("fisherman.divergences")
+                .with_description(
+                    "Consensus states of watched clients that do not match the tracked chain",
+                )
+                .build(),
+            submitted: meter
+                .u64_counter("fisherman.misbehaviour_submitted")
+                .with_description("Misbehaviour submissions for watched clients")
+                .build(),
+        }
+    }
+}
+
+impl Plugin for Module {
+    type Call = ModuleCall;
+    type Callback = Never;
+
+    type Config = Config;
+    type Cmd = Cmd;
+
+    async fn new(config: Self::Config) -> anyhow::Result<Self> {
+        let cometbft_client = cometbft_rpc::Client::new(config.rpc_url).await?;
+
+        let chain_id = cometbft_client
+            .status()
+            .await?
+            .node_info
+            .network
+            .to_string();
+
+        if chain_id != config.chain_id.as_str() {
+            bail!(
+                "incorrect chain id: expected `{}`, but found `{}`",
+                config.chain_id,
+                chain_id
+            );
+        }
+
+        let mut host_clients = HashMap::new();
+
+        for client in config.clients {
+            if host_clients.contains_key(&client.chain_id) {
+                continue;
+            }
+
+            let host_client = cometbft_rpc::Client::new(client.rpc_url).await?;
+
+            let host_chain_id = host_client.status().await?.node_info.network.to_string();
+
+            if host_chain_id != client.chain_id.as_str() {
+                bail!(
+                    "incorrect chain id for the host of client {}: expected `{}`, but found `{}`",
+                    client.client_id,
+                    client.chain_id,
+                    host_chain_id
+                );
+            }
+
+            host_clients.insert(client.chain_id, host_client);
+        }
+
+        if config.alert_only {
+            info!("alert only mode is enabled, misbehaviour will not be submitted");
+        }
+
+        Ok(Self {
+            chain_id: ChainId::new(chain_id),
+            cometbft_client,
+            host_clients,
+            alert_only: config.alert_only,
+            check_interval: config.check_interval,
+            metrics: Metrics::new(),
+        })
+    }
+
+    fn info(config: Self::Config) -> PluginInfo {
+        PluginInfo {
+            name: plugin_name(&config.chain_id),
+            // never interested in any messages since this plugin does not utilize a queue
+            interest_filter: "null".to_owned(),
+        }
+    }
+
+    async fn cmd(config: Self::Config, cmd: Self::Cmd) {
+        match cmd {
+            Cmd::MakeMessage => {
+                let op: Op<VoyagerMessage> = conc(config.clients.into_iter().map(|client| {
+                    call(PluginMessage::new(
+                        plugin_name(&config.chain_id),
+                        ModuleCall::from(CheckClient {
+                            chain_id: client.chain_id,
+                            client_id: client.client_id,
+                            checked_height: None,
+                        }),
+                    ))
+                }));
+
+                println!("{}", into_value(op));
+            }
+        }
+    }
+}
+
+#[derive(clap::Subcommand)]
+pub enum Cmd {
+    /// Print the message that starts watching all of the configured clients.
+    MakeMessage,
+}
+
+fn plugin_name(chain_id: &ChainId) -> String {
+    pub const PLUGIN_NAME: &str = env!("CARGO_PKG_NAME");
+
+    format!("{PLUGIN_NAME}/{}", chain_id)
+}
+
+impl Module {
+    fn plugin_name(&self) -> String {
+        plugin_name(&self.chain_id)
+    }
+
+    fn check_again(
+        &self,
+        chain_id: ChainId,
+        client_id: ClientId,
+        checked_height: Option<Height>,
+    ) -> Op<VoyagerMessage> {
+        seq([
+            defer(now() + self.check_interval),
+            call(PluginMessage::new(
+                self.plugin_name(),
+                ModuleCall::from(CheckClient {
+                    chain_id,
+                    client_id,
+                    checked_height,
+                }),
+            )),
+        ])
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            %chain_id,
+            %client_id,
+            ?checked_height
+        )
+    )]
+    async fn check_client(
+        &self,
+        voyager_client: &VoyagerClient,
+        chain_id: ChainId,
+        client_id: ClientId,
+        checked_height: Option<Height>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        let client_state_meta = voyager_client
+            .client_state_meta::<IbcUnion>(chain_id.clone(), QueryHeight::Latest, client_id)
+            .await?;
+
+        if client_state_meta.counterparty_chain_id != self.chain_id {
+            return Err(ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!(
+                    "client {client_id} on {chain_id} tracks {}, not {}",
+                    client_state_meta.counterparty_chain_id, self.chain_id
+                ),
+                None::<()>,
+            ));
+        }
+
+        let height = client_state_meta.counterparty_height;
+
+        if checked_height == Some(height) {
+            debug!(%height, "client has not been updated since the last check");
+
+            return Ok(self.check_again(chain_id, client_id, checked_height));
+        }
+
+        let client_info = voyager_client
+            .client_info::<IbcUnion>(chain_id.clone(), client_id)
+            .await?;
+
+        let consensus_state_bytes = voyager_client
+            .query_ibc_state(
+                chain_id.clone(),
+                QueryHeight::Latest,
+                ConsensusStatePath {
+                    client_id,
+                    height: height.height(),
+                },
+            )
+            .await?;
+
+        let consensus_state = voyager_client
+            .decode_consensus_state::<IbcUnion, ConsensusState>(
+                client_info.client_type,
+                client_info.ibc_interface,
+                consensus_state_bytes,
+            )
+            .await?;
+
+        let commit = self
+            .cometbft_client
+            .commit(Some(nonzero_height(height)))
+            .await
+            .map_err(rpc_error(
+                format_args!("error fetching commit at {height}"),
+                None,
+            ))?;
+
+        let expected_consensus_state = ConsensusState {
+            timestamp: commit.signed_header.header.time,
+            root: MerkleRoot {
+                hash: (*commit.signed_header.header.app_hash.get()).into(),
+            },
+            next_validators_hash: commit.signed_header.header.next_validators_hash,
+        };
+
+        let attributes = [
+            KeyValue::new("chain_id", chain_id.to_string()),
+            KeyValue::new("client_id", client_id.to_string()),
+        ];
+
+        self.metrics.checks.add(1, &attributes);
+
+        if consensus_state == expected_consensus_state {
+            info!(%height, "consensus state matches the tracked chain");
+
+            return Ok(self.check_again(chain_id, client_id, Some(height)));
+        }
+
+        self.metrics.divergences.add(1, &attributes);
+
+        error!(
+            %height,
+            ?consensus_state,
+            ?expected_consensus_state,
+            "consensus state does not match the tracked chain, the client has been updated with a \
+            conflicting header"
+        );
+
+        if self.alert_only {
+            warn!("alert only mode is enabled, not submitting misbehaviour");
+
+            return Ok(self.check_again(chain_id, client_id, Some(height)));
+        }
+
+        let misbehaviour = self
+            .build_misbehaviour(&chain_id, client_id, height, commit.signed_header)
+            .await?;
+
+        info!(%height, "submitting misbehaviour");
+
+        self.metrics.submitted.add(1, &attributes);
+
+        // the client is frozen once the misbehaviour is accepted, so there is nothing left to check
+        Ok(call(SubmitTx {
+            chain_id,
+            datagrams: vec![IbcDatagram::new::<IbcUnion>(MsgMisbehaviour {
+                client_id,
+                client_message: misbehaviour.encode_as::<Bincode>().into(),
+            })],
+        }))
+    }
+
+    /// Builds the evidence for a client that was updated to `height` with a header that conflicts
+    /// with `signed_header`, the header of the tracked chain at that height.
+    async fn build_misbehaviour(
+        &self,
+        chain_id: &ChainId,
+        client_id: ClientId,
+        height: Height,
+        signed_header: SignedHeader,
+    ) -> RpcResult<Misbehaviour> {
+        let conflicting_header = self.find_update_header(chain_id, client_id, height).await?;
+
+        // the conflicting header was accepted by the client, so a consensus state exists at its
+        // trusted height, which the header of the tracked chain can be verified against as well
+        let trusted_height = conflicting_header.trusted_height;
+
+        let validators = self
+            .cometbft_client
+            .all_validators(Some(nonzero_height(height)))
+            .await
+            .map_err(rpc_error("validators", None))?;
+
+        let trusted_commit = self
+            .cometbft_client
+            .commit(Some(nonzero_height(trusted_height.increment())))
+            .await
+            .map_err(rpc_error("trusted commit", None))?;
+
+        let trusted_validators = self
+            .cometbft_client
+            .all_validators(Some(nonzero_height(trusted_height.increment())))
+            .await
+            .map_err(rpc_error("trusted validators", None))?;
+
+        let header = Header {
+            validator_set: mk_validator_set(
+                validators.validators,
+                signed_header.header.proposer_address,
+            ),
+            signed_header,
+            trusted_height,
+            trusted_validators: mk_validator_set(
+                trusted_validators.validators,
+                trusted_commit.signed_header.header.proposer_address,
+            ),
+        };
+
+        Ok(Misbehaviour {
+            header_a: conflicting_header,
+            header_b: header,
+        })
+    }
+
+    /// Finds the header that `client_id` was updated to `height` with, by looking up the update
+    /// transaction on the chain the client is on.
+    async fn find_update_header(
+        &self,
+        chain_id: &ChainId,
+        client_id: ClientId,
+        height: Height,
+    ) -> RpcResult<Header> {
+        let host_client = self.host_clients.get(chain_id).ok_or_else(|| {
+            ErrorObject::owned(
+                FATAL_JSONRPC_ERROR_CODE,
+                format!("no rpc endpoint configured for {chain_id}"),
+                None::<()>,
+            )
+        })?;
+
+        let response = host_client
+            .tx_search(
+                format!(
+                    "wasm-update_client.client_id='{client_id}' AND \
+                    wasm-update_client.counterparty_height='{}'",
+                    height.height()
+                ),
+                false,
+                NonZeroU32::MIN,
+                UPDATE_TXS_LIMIT,
+                cometbft_rpc::rpc_types::Order::Asc,
+            )
+            .await
+            .map_err(rpc_error(
+                format_args!("error searching for updates of client {client_id} to {height}"),
+                None,
+            ))?;
+
+        // the first matching update is the one that wrote the consensus state, any later ones
+        // would have been no-ops
+        response
+            .txs
+            .into_iter()
+            .flat_map(|tx| update_client_messages(&tx.tx))
+            .filter(|(id, _)| *id == client_id)
+            .find_map(
+                |(_, client_message)| match Header::decode_as::<Bincode>(&client_message) {
+                    Ok(header) => (header.signed_header.header.height.inner()
+                        == i64::try_from(height.height()).expect("valid height"))
+                    .then_some(header),
+                    Err(err) => {
+                        warn!(err = %ErrorReporter(err), "unable to decode client message");
+                        None
+                    }
+                },
+            )
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    -1,
+                    format!("no update of client {client_id} to {height} found on {chain_id}"),
+                    None::<()>,
+                )
+            })
+    }
+}
+
+/// Returns the client id and client message of all of the ibc-union `UpdateClient` messages in the
+/// transaction.
+fn update_client_messages(tx_bytes: &[u8]) -> Vec<(ClientId, Vec<u8>)> {
+    let tx_raw = match TxRaw::decode_as::<Proto>(tx_bytes) {
+        Ok(tx_raw) => tx_raw,
+        Err(err) => {
+            warn!(err = %ErrorReporter(err), "unable to decode transaction");
+            return vec![];
+        }
+    };
+
+    let tx_body = match <TxBody<RawAny>>::decode_as::<Proto>(&tx_raw.body_bytes) {
+        Ok(tx_body) => tx_body,
+        Err(err) => {
+            warn!(err = %ErrorReporter(err), "unable to decode transaction body");
+            return vec![];
+        }
+    };
+
+    tx_body
+        .messages
+        .iter()
+        .filter_map(|msg| msg.decode::<MsgExecuteContract>().ok())
+        .filter_map(|msg| {
+            match serde_json::from_slice::<ibc_union_msg::msg::ExecuteMsg>(&msg.msg) {
+                Ok(ibc_union_msg::msg::ExecuteMsg::UpdateClient(msg_update_client)) => Some((
+                    msg_update_client.client_id,
+                    msg_update_client.client_message.into_vec(),
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn nonzero_height(height: Height) -> NonZeroU64 {
+    height.height().try_into().expect("valid height")
+}
+
+fn mk_validator_set(
+    validators: Vec<Validator>,
+    proposer_address: H160<HexUnprefixed>,
+) -> ValidatorSet {
+    let proposer = validators
+        .iter()
+        .find(|val| val.address == proposer_address)
+        .expect("proposer must exist in set")
+        .clone();
+
+    let total_voting_power = validators
+        .iter()
+        .map(|v| v.voting_power.inner())
+        .sum::<i64>();
+
+    ValidatorSet {
+        validators,
+        proposer,
+        total_voting_power,
+    }
+}
+
+#[async_trait]
+impl PluginServer<ModuleCall, Never> for Module {
+    async fn run_pass(
+        &self,
+        _: &Extensions,
+        msgs: Vec<Op<VoyagerMessage>>,
+    ) -> RpcResult<PassResult<VoyagerMessage>> {
+        error!(?msgs, "this plugin does not utilize a queue");
+
+        Ok(PassResult::default())
+    }
+
+    #[instrument(skip_all, fields(chain_id = %self.chain_id))]
+    async fn call(&self, e: &Extensions, msg: ModuleCall) -> RpcResult<Op<VoyagerMessage>> {
+        match msg {
+            ModuleCall::CheckClient(CheckClient {
+                chain_id,
+                client_id,
+                checked_height,
+            }) => {
+                self.check_client(e.voyager_client()?, chain_id, client_id, checked_height)
+                    .await
+            }
+        }
+    }
+
+    async fn callback(
+        &self,
+        _: &Extensions,
+        cb: Never,
+        _data: VecDeque<Data>,
+    ) -> RpcResult<Op<VoyagerMessage>> {
+        match cb {}
+    }
+}
@@ -15,10 +15,13 @@ pub struct BatchSend {
     /// unix timestamp (in ms) of when this event was first seen by this plugin.
     pub first_seen_at: u64,
     pub packet: Packet,
+    /// The sequence of the packet, if it was sent on an ordered channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
 }
 
 impl BatchSend {
-    pub fn new(packet: Packet) -> Self {
+    pub fn new(packet: Packet, sequence: Option<u64>) -> Self {
         Self {
             first_seen_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -27,6 +30,7 @@ impl BatchSend {
                 .try_into()
                 .expect("how many milliseconds can there be man"),
             packet,
+            sequence,
         }
     }
 }
@@ -155,7 +155,7 @@ impl PluginServer<Never, Never> for Module {
                             Data::IbcEvent(chain_event) => {
                                 match chain_event.decode_event::<IbcUnion>().unwrap().unwrap() {
                                     FullEvent::PacketSend(e) => Some(ModuleData::BatchSendPacket(
-                                        vec![BatchSend::new(e.packet())],
+                                        vec![BatchSend::new(e.packet(), e.sequence)],
                                     )),
                                     FullEvent::WriteAck(e) => Some(ModuleData::BatchAckPacket(
                                         vec![BatchAck::new(e.packet(), e.acknowledgement)],
@@ -184,6 +184,7 @@ impl PluginServer<Never, Never> for Module {
             now: u64,
             get_first_seen_at: fn(&T) -> u64,
             get_packet: fn(&T) -> &Packet,
+            get_sequence: fn(&T) -> Option<u64>,
             max_batch_count: &usize,
             max_batch_size_bytes: &usize,
             max_wait_time: &Duration,
@@ -191,9 +192,28 @@ impl PluginServer<Never, Never> for Module {
             let (ready, wait) = msgs
                 .into_iter()
                 .flat_map(|(idx, ts)| ts.into_iter().map(move |t| (idx, t)))
-                .sorted_by_key(|(_, t)| get_first_seen_at(t))
-                .fold(vec![(false, vec![], vec![])], |mut acc, (idx, t)| {
-                    let (full, idxs, current_batch) = acc.last_mut().unwrap();
+                // packets on ordered channels must be delivered in sequence order, all other
+                // packets are batched in the order they were seen
+                .sorted_by_key(|(_, t)| (get_sequence(t), get_first_seen_at(t)))
+                .fold(vec![(false, false, vec![], vec![])], |mut acc, (idx, t)| {
+                    let (full, gapped, idxs, current_batch) = acc.last_mut().unwrap();
+                    let gapped = *gapped;
+
+                    // a gap in the sequence means that a packet has not been seen yet, so the
+                    // packets after the gap can't be in the same batch as the ones before it, and
+                    // can't be submitted until the gap has been filled
+                    if let (Some(prev), Some(sequence)) = (
+                        current_batch.last().and_then(get_sequence),
+                        get_sequence(&t),
+                    ) {
+                        if prev + 1 != sequence {
+                            info!(%prev, %sequence, "sequence gap in batch");
+
+                            acc.push((false, true, vec![idx], vec![t]));
+
+                            return acc;
+                        }
+                    }
 
                     let size = current_batch
                         .iter()
@@ -210,13 +230,13 @@ impl PluginServer<Never, Never> for Module {
                         );
                         *full = true;
 
-                        acc.push((false, vec![idx], vec![t]));
+                        acc.push((false, gapped, vec![idx], vec![t]));
                     } else if current_batch.len() + 1 > *max_batch_count {
                         // batch is full (count), start a new one
                         info!(count = %max_batch_count, "filled batch (count)");
                         *full = true;
 
-                        acc.push((false, vec![idx], vec![t]));
+                        acc.push((false, gapped, vec![idx], vec![t]));
                     } else {
                         // batch has room
                         idxs.push(idx);
@@ -226,16 +246,22 @@ impl PluginServer<Never, Never> for Module {
                     acc
                 })
                 .into_iter()
-                .partition::<Vec<_>, _>(|(full, _idxs, ts)| {
-                    *full
-                        || ts.iter().any(|t| {
-                            (get_first_seen_at(t) + max_wait_time.as_millis() as u64) < now
-                        })
+                .partition::<Vec<_>, _>(|(full, gapped, _idxs, ts)| {
+                    !*gapped
+                        && (*full
+                            || ts.iter().any(|t| {
+                                (get_first_seen_at(t) + max_wait_time.as_millis() as u64) < now
+                            }))
                 });
 
             (
-                ready.into_iter().map(|(_, idxs, ts)| (idxs, ts)).collect(),
-                wait.into_iter().map(|(_, idxs, ts)| (idxs, ts)).collect(),
+                ready
+                    .into_iter()
+                    .map(|(_, _, idxs, ts)| (idxs, ts))
+                    .collect(),
+                wait.into_iter()
+                    .map(|(_, _, idxs, ts)| (idxs, ts))
+                    .collect(),
             )
         }
 
@@ -244,6 +270,7 @@ impl PluginServer<Never, Never> for Module {
             now,
             |t| t.first_seen_at,
             |t| &t.packet,
+            |t| t.sequence,
             &self.max_batch_count,
             &self.max_batch_size_bytes,
             &self.max_wait_time,
@@ -253,6 +280,7 @@ impl PluginServer<Never, Never> for Module {
             now,
             |t| t.first_seen_at,
             |t| &t.packet,
+            |_| None,
             &self.max_batch_count,
             &self.max_batch_size_bytes,
             &self.max_wait_time,
@@ -285,8 +313,13 @@ impl PluginServer<Never, Never> for Module {
             ready: send_ready
                 .into_iter()
                 .map(|(idxs, d)| {
+                    // batches of ordered packets are already sorted by sequence
+                    let ordered = d.iter().any(|d| d.sequence.is_some());
+
                     let mut packets = d.into_iter().map(|d| d.packet).collect::<Vec<_>>();
-                    packets.sort_by_cached_key(|packet| packet.hash());
+                    if !ordered {
+                        packets.sort_by_cached_key(|packet| packet.hash());
+                    }
 
                     (
                         idxs,
@@ -339,6 +339,7 @@ impl Module {
                             },
                             timeout_timestamp: packet_response.packet.timeout_timestamp,
                         },
+                        sequence: None,
                     })),
                 })
             }
@@ -14,10 +14,10 @@ use voyager_sdk::{
         data::{EventProvableHeight, IbcDatagram},
         PluginMessage, VoyagerMessage,
     },
-    primitives::{ChainId, QueryHeight},
-    rpc::MISSING_STATE_ERROR_CODE,
+    primitives::{ChainId, IbcInterface, QueryHeight},
+    rpc::{FATAL_JSONRPC_ERROR_CODE, MISSING_STATE_ERROR_CODE},
     types::RawClientId,
-    vm::{data, now, promise, Op},
+    vm::{data, noop, now, promise, Op},
     VoyagerClient,
 };
 
@@ -467,6 +467,31 @@ impl MakeMsg<IbcUnion> {
             }
 
             EventUnion::ChannelOpenInit(event) => {
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                // the channel commitment of an ordered channel includes its ordering, which is only
+                // implemented by the cosmwasm stack
+                if event.ordering.is_ordered()
+                    && client_info.ibc_interface.as_str() != IbcInterface::IBC_COSMWASM
+                {
+                    return Err(ErrorObject::owned(
+                        FATAL_JSONRPC_ERROR_CODE,
+                        format!(
+                            "ordered channels are only supported between {} chains, \
+                            but the counterparty of channel {} is {}",
+                            IbcInterface::IBC_COSMWASM,
+                            event.channel_id,
+                            client_info.ibc_interface
+                        ),
+                        None::<()>,
+                    ));
+                }
+
                 let proof = voyager_client
                     .query_ibc_proof(
                         origin_chain_id,
@@ -478,13 +503,6 @@ impl MakeMsg<IbcUnion> {
                     .await?
                     .into_result()?;
 
-                let client_info = voyager_client
-                    .client_info::<IbcUnion>(
-                        target_chain_id,
-                        event.connection.counterparty_client_id,
-                    )
-                    .await?;
-
                 let encoded_proof = voyager_client
                     .encode_proof::<IbcUnion>(
                         client_info.client_type,
@@ -507,6 +525,7 @@ impl MakeMsg<IbcUnion> {
                             counterparty_version: event.version,
                             proof_init: encoded_proof,
                             proof_height: origin_chain_proof_height.height(),
+                            ordering: event.ordering,
                         },
                     ),
                 )))
@@ -590,6 +609,223 @@ impl MakeMsg<IbcUnion> {
                 )))
             }
 
+            EventUnion::ChannelUpgradeInit(event) => {
+                let proof = voyager_client
+                    .query_ibc_proof(
+                        origin_chain_id,
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelUpgradePath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .into_result()?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                let encoded_proof = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof.proof,
+                    )
+                    .await?;
+
+                Ok(data(IbcDatagram::new::<IbcUnion>(
+                    ibc_union_spec::datagram::Datagram::from(
+                        ibc_union_spec::datagram::MsgChannelUpgradeTry {
+                            channel_id: event.counterparty_channel_id,
+                            proposed_version: event.version,
+                            upgrade_sequence: event.upgrade_sequence,
+                            proof_init: encoded_proof,
+                            proof_height: origin_chain_proof_height.height(),
+                        },
+                    ),
+                )))
+            }
+
+            EventUnion::ChannelUpgradeTry(event) => {
+                let proof = voyager_client
+                    .query_ibc_proof(
+                        origin_chain_id,
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelUpgradePath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .into_result()?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                let encoded_proof = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof.proof,
+                    )
+                    .await?;
+
+                Ok(data(IbcDatagram::new::<IbcUnion>(
+                    ibc_union_spec::datagram::Datagram::from(
+                        ibc_union_spec::datagram::MsgChannelUpgradeAck {
+                            channel_id: event.counterparty_channel_id,
+                            proof_try: encoded_proof,
+                            proof_height: origin_chain_proof_height.height(),
+                        },
+                    ),
+                )))
+            }
+
+            EventUnion::ChannelUpgradeAck(event) => {
+                let proof = voyager_client
+                    .query_ibc_proof(
+                        origin_chain_id,
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelUpgradePath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .into_result()?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                let encoded_proof = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof.proof,
+                    )
+                    .await?;
+
+                Ok(data(IbcDatagram::new::<IbcUnion>(
+                    ibc_union_spec::datagram::Datagram::from(
+                        ibc_union_spec::datagram::MsgChannelUpgradeConfirm {
+                            channel_id: event.counterparty_channel_id,
+                            proof_ack: encoded_proof,
+                            proof_height: origin_chain_proof_height.height(),
+                        },
+                    ),
+                )))
+            }
+
+            EventUnion::ChannelUpgradeConfirm(event) => {
+                // the upgrade is deleted once it is confirmed, so the upgraded channel is proven to
+                // complete the upgrade on the proposer
+                let proof = voyager_client
+                    .query_ibc_proof(
+                        origin_chain_id,
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelPath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .into_result()?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                let encoded_proof = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof.proof,
+                    )
+                    .await?;
+
+                Ok(data(IbcDatagram::new::<IbcUnion>(
+                    ibc_union_spec::datagram::Datagram::from(
+                        ibc_union_spec::datagram::MsgChannelUpgradeOpen {
+                            channel_id: event.counterparty_channel_id,
+                            proof_channel: encoded_proof,
+                            proof_height: origin_chain_proof_height.height(),
+                        },
+                    ),
+                )))
+            }
+
+            EventUnion::ChannelUpgradeCancel(event) => {
+                // only a cancelled proposal has to be relayed, an upgrade that was cancelled on
+                // the counterparty of the proposer has already been cancelled on the proposer
+                let upgrade_error = voyager_client
+                    .maybe_query_ibc_state(
+                        origin_chain_id.clone(),
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelUpgradeErrorPath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .state;
+
+                if upgrade_error.is_none_or(|upgrade| upgrade.sequence != event.upgrade_sequence) {
+                    debug!(
+                        channel_id = %event.channel_id,
+                        upgrade_sequence = event.upgrade_sequence,
+                        "upgrade was not cancelled by the proposer"
+                    );
+
+                    return Ok(noop());
+                }
+
+                let proof = voyager_client
+                    .query_ibc_proof(
+                        origin_chain_id,
+                        QueryHeight::Specific(origin_chain_proof_height),
+                        ibc_union_spec::path::ChannelUpgradeErrorPath {
+                            channel_id: event.channel_id,
+                        },
+                    )
+                    .await?
+                    .into_result()?;
+
+                let client_info = voyager_client
+                    .client_info::<IbcUnion>(
+                        target_chain_id,
+                        event.connection.counterparty_client_id,
+                    )
+                    .await?;
+
+                let encoded_proof = voyager_client
+                    .encode_proof::<IbcUnion>(
+                        client_info.client_type,
+                        client_info.ibc_interface,
+                        proof.proof,
+                    )
+                    .await?;
+
+                Ok(data(IbcDatagram::new::<IbcUnion>(
+                    ibc_union_spec::datagram::Datagram::from(
+                        ibc_union_spec::datagram::MsgChannelUpgradeCancel {
+                            channel_id: event.counterparty_channel_id,
+                            proof_error: encoded_proof,
+                            proof_height: origin_chain_proof_height.height(),
+                        },
+                    ),
+                )))
+            }
+
             EventUnion::PacketSend(event) => {
                 let packet = event.packet();
 
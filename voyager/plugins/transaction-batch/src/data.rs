@@ -89,6 +89,12 @@ pub enum EventUnion {
     ChannelOpenTry(ibc_union_spec::event::ChannelOpenTry),
     ChannelOpenAck(ibc_union_spec::event::ChannelOpenAck),
 
+    ChannelUpgradeInit(ibc_union_spec::event::ChannelUpgradeInit),
+    ChannelUpgradeTry(ibc_union_spec::event::ChannelUpgradeTry),
+    ChannelUpgradeAck(ibc_union_spec::event::ChannelUpgradeAck),
+    ChannelUpgradeConfirm(ibc_union_spec::event::ChannelUpgradeConfirm),
+    ChannelUpgradeCancel(ibc_union_spec::event::ChannelUpgradeCancel),
+
     PacketSend(ibc_union_spec::event::PacketSend),
     BatchSend(ibc_union_spec::event::BatchSend),
     WriteAck(ibc_union_spec::event::WriteAck),
@@ -111,6 +117,21 @@ impl TryFrom<ibc_union_spec::event::FullEvent> for EventUnion {
             ibc_union_spec::event::FullEvent::ChannelOpenInit(e) => Ok(Self::ChannelOpenInit(e)),
             ibc_union_spec::event::FullEvent::ChannelOpenTry(e) => Ok(Self::ChannelOpenTry(e)),
             ibc_union_spec::event::FullEvent::ChannelOpenAck(e) => Ok(Self::ChannelOpenAck(e)),
+            ibc_union_spec::event::FullEvent::ChannelUpgradeInit(e) => {
+                Ok(Self::ChannelUpgradeInit(e))
+            }
+            ibc_union_spec::event::FullEvent::ChannelUpgradeTry(e) => {
+                Ok(Self::ChannelUpgradeTry(e))
+            }
+            ibc_union_spec::event::FullEvent::ChannelUpgradeAck(e) => {
+                Ok(Self::ChannelUpgradeAck(e))
+            }
+            ibc_union_spec::event::FullEvent::ChannelUpgradeConfirm(e) => {
+                Ok(Self::ChannelUpgradeConfirm(e))
+            }
+            ibc_union_spec::event::FullEvent::ChannelUpgradeCancel(e) => {
+                Ok(Self::ChannelUpgradeCancel(e))
+            }
             ibc_union_spec::event::FullEvent::PacketSend(e) => Ok(Self::PacketSend(e)),
             ibc_union_spec::event::FullEvent::BatchSend(e) => Ok(Self::BatchSend(e)),
             ibc_union_spec::event::FullEvent::WriteAck(e) => Ok(Self::WriteAck(e)),
@@ -147,6 +147,11 @@ impl IbcSpecExt for IbcUnion {
             EventUnion::ChannelOpenInit(_) => "channel_open_init",
             EventUnion::ChannelOpenTry(_) => "channel_open_try",
             EventUnion::ChannelOpenAck(_) => "channel_open_ack",
+            EventUnion::ChannelUpgradeInit(_) => "channel_upgrade_init",
+            EventUnion::ChannelUpgradeTry(_) => "channel_upgrade_try",
+            EventUnion::ChannelUpgradeAck(_) => "channel_upgrade_ack",
+            EventUnion::ChannelUpgradeConfirm(_) => "channel_upgrade_confirm",
+            EventUnion::ChannelUpgradeCancel(_) => "channel_upgrade_cancel",
             EventUnion::PacketSend(_) => "packet_send",
             EventUnion::BatchSend(_) => "batch_send",
             EventUnion::WriteAck(_) => "write_ack",
@@ -269,6 +274,21 @@ if ."@type" == "data" then
         ) or (
             $event_type == "channel_open_ack"
             and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
+        ) or (
+            $event_type == "channel_upgrade_init"
+            and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
+        ) or (
+            $event_type == "channel_upgrade_try"
+            and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
+        ) or (
+            $event_type == "channel_upgrade_ack"
+            and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
+        ) or (
+            $event_type == "channel_upgrade_confirm"
+            and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
+        ) or (
+            $event_type == "channel_upgrade_cancel"
+            and ($event_data.connection.counterparty_client_id as $client_id | {clients_filter})
         ) or (
             $event_type == "packet_send"
             and ($event_data.packet.destination_channel.connection.client_id as $client_id | {clients_filter})
@@ -10,6 +10,7 @@ use concurrent_keyring::{ConcurrentKeyring, KeyringConfig, KeyringEntry};
 use ibc_union_spec::{datagram::Datagram, ChannelId, IbcUnion};
 use jsonrpsee::{
     core::{async_trait, RpcResult},
+    types::{ErrorObject, ErrorObjectOwned},
     Extensions,
 };
 use move_core_types::{
@@ -26,7 +27,7 @@ use voyager_sdk::{
     message::{data::Data, PluginMessage, VoyagerMessage},
     plugin::Plugin,
     primitives::ChainId,
-    rpc::{types::PluginInfo, PluginServer},
+    rpc::{types::PluginInfo, PluginServer, FATAL_JSONRPC_ERROR_CODE},
     vm::{self, call, noop, pass::PassResult, Op, Visit},
     DefaultCmd,
 };
@@ -218,7 +219,7 @@ impl PluginServer<ModuleCall, Never> for Module {
                             msgs.clone(),
                             sender,
                         )
-                        .await;
+                        .await?;
 
                         let mut txs = vec![];
 
@@ -294,10 +295,17 @@ async fn process_msgs<
     client: &T,
     msgs: Vec<Datagram>,
     relayer: AccountAddress,
-) -> Vec<(Datagram, EntryFunction)> {
+) -> RpcResult<Vec<(Datagram, EntryFunction)>> {
     let mut data = vec![];
     for msg in msgs {
         let item = match msg.clone() {
+            // the move implementation only supports unordered channels
+            Datagram::ChannelOpenInit(data) if data.ordering.is_ordered() => {
+                return Err(unsupported(&msg));
+            }
+            Datagram::ChannelOpenTry(data) if data.ordering.is_ordered() => {
+                return Err(unsupported(&msg));
+            }
             Datagram::CreateClient(data) => (
                 msg,
                 client.create_client(
@@ -444,7 +452,8 @@ async fn process_msgs<
                                 p.destination_channel_id,
                                 (
                                     p.data.to_vec(),
-                                    (p.timeout_height, p.timeout_timestamp.as_nanos()),
+                                    // timeout heights are not supported by ibc-union
+                                    (0_u64, p.timeout_timestamp.as_nanos()),
                                 ),
                             ),
                         )
@@ -490,7 +499,8 @@ async fn process_msgs<
                                 p.destination_channel_id,
                                 (
                                     p.data.to_vec(),
-                                    (p.timeout_height, p.timeout_timestamp.as_nanos()),
+                                    // timeout heights are not supported by ibc-union
+                                    (0_u64, p.timeout_timestamp.as_nanos()),
                                 ),
                             ),
                         )
@@ -529,10 +539,21 @@ async fn process_msgs<
                 )
             }
 
-            _ => todo!(),
+            _ => return Err(unsupported(&msg)),
         };
         data.push(item);
     }
 
-    data
+    Ok(data)
+}
+
+fn unsupported(datagram: &Datagram) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        FATAL_JSONRPC_ERROR_CODE,
+        format!(
+            "datagram {} is not supported by the move implementation",
+            datagram.name()
+        ),
+        None::<()>,
+    )
 }
@@ -918,6 +918,40 @@ fn process_msgs(
                             funds: vec![],
                         })
                     }
+                    ibc_union_spec::datagram::Datagram::Misbehaviour(msg_misbehaviour) => {
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(
+                                &ibc_union_msg::msg::ExecuteMsg::SubmitMisbehaviour(
+                                    ibc_union_msg::msg::MsgSubmitMisbehaviour {
+                                        client_id: msg_misbehaviour.client_id,
+                                        client_message: msg_misbehaviour.client_message,
+                                        relayer: fee_recipient
+                                            .map_or(signer.to_string(), |s| s.to_string()),
+                                    },
+                                ),
+                            )
+                            .unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::RecoverClient(msg_recover_client) => {
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(
+                                &ibc_union_msg::msg::ExecuteMsg::RecoverClient(
+                                    ibc_union_msg::msg::MsgRecoverClient {
+                                        subject: msg_recover_client.subject,
+                                        substitute: msg_recover_client.substitute,
+                                    },
+                                ),
+                            )
+                            .unwrap(),
+                            funds: vec![],
+                        })
+                    }
                     ibc_union_spec::datagram::Datagram::ConnectionOpenInit(
                         msg_connection_open_init,
                     ) => mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
@@ -1001,6 +1035,7 @@ fn process_msgs(
                                 counterparty_port_id: msg_channel_open_init.counterparty_port_id,
                                 connection_id: msg_channel_open_init.connection_id,
                                 version: msg_channel_open_init.version,
+                                ordering: msg_channel_open_init.ordering,
                             },
                         );
 
@@ -1021,6 +1056,7 @@ fn process_msgs(
                                 proof_height: msg_channel_open_try.proof_height,
                                 relayer: fee_recipient
                                     .map_or(signer.to_string(), |s| s.to_string()),
+                                ordering: msg_channel_open_try.ordering,
                             },
                         );
 
@@ -1081,6 +1117,131 @@ fn process_msgs(
                     ) => {
                         todo!()
                     }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeInit(
+                        msg_channel_upgrade_init,
+                    ) => {
+                        let channel_upgrade_init =
+                            ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeInit(
+                                ibc_union_msg::msg::MsgChannelUpgradeInit {
+                                    channel_id: msg_channel_upgrade_init.channel_id,
+                                    proposed_version: msg_channel_upgrade_init.proposed_version,
+                                    relayer: fee_recipient
+                                        .map_or(signer.to_string(), |s| s.to_string()),
+                                },
+                            );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_init).unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeTry(
+                        msg_channel_upgrade_try,
+                    ) => {
+                        let channel_upgrade_try = ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeTry(
+                            ibc_union_msg::msg::MsgChannelUpgradeTry {
+                                channel_id: msg_channel_upgrade_try.channel_id,
+                                proposed_version: msg_channel_upgrade_try.proposed_version,
+                                upgrade_sequence: msg_channel_upgrade_try.upgrade_sequence,
+                                proof_init: msg_channel_upgrade_try.proof_init,
+                                proof_height: msg_channel_upgrade_try.proof_height,
+                                relayer: fee_recipient
+                                    .map_or(signer.to_string(), |s| s.to_string()),
+                            },
+                        );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_try).unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeAck(
+                        msg_channel_upgrade_ack,
+                    ) => {
+                        let channel_upgrade_ack = ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeAck(
+                            ibc_union_msg::msg::MsgChannelUpgradeAck {
+                                channel_id: msg_channel_upgrade_ack.channel_id,
+                                proof_try: msg_channel_upgrade_ack.proof_try,
+                                proof_height: msg_channel_upgrade_ack.proof_height,
+                                relayer: fee_recipient
+                                    .map_or(signer.to_string(), |s| s.to_string()),
+                            },
+                        );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_ack).unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeConfirm(
+                        msg_channel_upgrade_confirm,
+                    ) => {
+                        let channel_upgrade_confirm =
+                            ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeConfirm(
+                                ibc_union_msg::msg::MsgChannelUpgradeConfirm {
+                                    channel_id: msg_channel_upgrade_confirm.channel_id,
+                                    proof_ack: msg_channel_upgrade_confirm.proof_ack,
+                                    proof_height: msg_channel_upgrade_confirm.proof_height,
+                                    relayer: fee_recipient
+                                        .map_or(signer.to_string(), |s| s.to_string()),
+                                },
+                            );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_confirm).unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeOpen(
+                        msg_channel_upgrade_open,
+                    ) => {
+                        let channel_upgrade_open =
+                            ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeOpen(
+                                ibc_union_msg::msg::MsgChannelUpgradeOpen {
+                                    channel_id: msg_channel_upgrade_open.channel_id,
+                                    proof_channel: msg_channel_upgrade_open.proof_channel,
+                                    proof_height: msg_channel_upgrade_open.proof_height,
+                                    relayer: fee_recipient
+                                        .map_or(signer.to_string(), |s| s.to_string()),
+                                },
+                            );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_open).unwrap(),
+                            funds: vec![],
+                        })
+                    }
+                    ibc_union_spec::datagram::Datagram::ChannelUpgradeCancel(
+                        msg_channel_upgrade_cancel,
+                    ) => {
+                        let channel_upgrade_cancel =
+                            ibc_union_msg::msg::ExecuteMsg::ChannelUpgradeCancel(
+                                ibc_union_msg::msg::MsgChannelUpgradeCancel {
+                                    channel_id: msg_channel_upgrade_cancel.channel_id,
+                                    proof_error: msg_channel_upgrade_cancel.proof_error,
+                                    proof_height: msg_channel_upgrade_cancel.proof_height,
+                                    relayer: fee_recipient
+                                        .map_or(signer.to_string(), |s| s.to_string()),
+                                },
+                            );
+
+                        mk_any(&protos::cosmwasm::wasm::v1::MsgExecuteContract {
+                            sender: signer.to_string(),
+                            contract: ibc_host_contract_address.to_string(),
+                            msg: serde_json::to_vec(&channel_upgrade_cancel).unwrap(),
+                            funds: vec![],
+                        })
+                    }
                     ibc_union_spec::datagram::Datagram::PacketRecv(msg_packet_recv) => {
                         let packet_recv = ibc_union_msg::msg::ExecuteMsg::PacketRecv(
                             ibc_union_msg::msg::MsgPacketRecv {
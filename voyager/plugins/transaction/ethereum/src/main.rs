@@ -663,6 +663,16 @@ fn process_msgs<'a>(
                         })
                         .clear_decoder(),
                 ),
+                Datagram::Misbehaviour(data) => (
+                    msg,
+                    ibc_handler
+                        .misbehaviour(ibc_solidity::MsgMisbehaviour {
+                            client_id: data.client_id.raw(),
+                            client_message: data.client_message.into(),
+                            relayer: relayer.into(),
+                        })
+                        .clear_decoder(),
+                ),
                 Datagram::ConnectionOpenInit(data) => (
                     msg,
                     ibc_handler
@@ -7,12 +7,12 @@ use std::{
 
 use anyhow::{anyhow, Context};
 use clap::{self, Parser, Subcommand};
-use ibc_union_spec::IbcUnion;
+use ibc_union_spec::{ClientId, IbcUnion};
 use unionlabs::{self, bounded::BoundedI64, ibc::core::client::height::Height, result_unwrap};
 use voyager_message::VoyagerMessage;
 use voyager_primitives::{ChainId, ClientType, IbcInterface, IbcSpec, IbcSpecId, QueryHeight};
 use voyager_types::RawClientId;
-use voyager_vm::{BoxDynError, Op};
+use voyager_vm::{history::DEFAULT_MAX_DEPTH, BoxDynError, Op};
 
 use crate::config::Config;
 
@@ -122,7 +122,26 @@ pub enum Command {
         rest_url: Option<String>,
     },
     /// Run Voyager.
-    Start,
+    Start {
+        /// Record all requests made to plugins and modules, along with their responses, to this
+        /// file. The recording can later be used with `voyager replay`.
+        #[arg(long)]
+        record: Option<PathBuf>,
+    },
+    /// Re-execute an op against a recording made with `voyager start --record`, printing every
+    /// item that is processed as JSON.
+    ///
+    /// No plugins or modules are spawned; all requests to them are served from the recording. The
+    /// plugins and modules in the config must match the ones of the recorded run.
+    Replay {
+        /// The recording to replay against.
+        recording: PathBuf,
+        #[arg(value_parser(|s: &str| serde_json::from_str::<Op<VoyagerMessage>>(s)))]
+        op: Op<VoyagerMessage>,
+        /// The maximum amount of items to process before stopping.
+        #[arg(long, default_value_t = 1000)]
+        max_steps: usize,
+    },
     /// Query and interact with the queue.
     #[command(subcommand, alias = "q")]
     Queue(QueueCmd),
@@ -147,6 +166,14 @@ pub enum ConfigCmd {
     Default,
     /// Print the JSON Schema for the voyager config, to be used in the top-level `$schema` field.
     Schema,
+    /// Reload the plugin and module config of a running voyager instance from its config file.
+    ///
+    /// Only the plugins and modules whose config changed are restarted. This is equivalent to
+    /// sending SIGHUP to the voyager process.
+    Reload {
+        #[arg(long, short = 'r')]
+        rpc_url: Option<String>,
+    },
 }
 
 type Pg64 = BoundedI64<1, { i64::MAX }>;
@@ -188,36 +215,59 @@ pub enum QueueCmd {
         failed: bool,
     },
 
-    // History {
-    //     id: PgId,
-    //     #[arg(long, default_value_t = result_unwrap!(Pg32::new(10)))]
-    //     max_depth: Pg32,
-    // },
+    /// Query the lineage of an item in the queue of an already running voyager instance.
+    ///
+    /// This will print the tree of all ancestors and descendants of the item, up to `max_depth`
+    /// levels in either direction.
+    #[command(alias = "h")]
+    History {
+        id: BoundedI64<0, { i64::MAX }>,
+        #[arg(long, default_value_t = DEFAULT_MAX_DEPTH)]
+        max_depth: u32,
+        /// Print the full history as JSON instead of as a tree.
+        #[arg(long)]
+        json: bool,
+        #[arg(long)]
+        rpc_url: Option<String>,
+    },
     /// Query all failed messages.
     QueryFailed {
         #[arg(long, default_value_t = result_unwrap!(Pg64::new_const(1)))]
         page: Pg64,
         #[arg(long, default_value_t = result_unwrap!(Pg64::new_const(1)))]
         per_page: Pg64,
-        /// SQL filters for the item.
-        ///
-        /// These will be run on the stringified item (`item::text`), which is the *almost* fully compact JSON:
-        ///
-        /// ```psql
-        /// default=# select '{"a":{"b":"c"}}'::jsonb::text;
-        ///        text        
-        /// -------------------
-        ///  {"a": {"b": "c"}}
-        /// ````
-        ///
-        /// This can be specified multiple times to specify multiple filters.
-        #[arg(long = "item-filter", short = 'i')]
-        item_filters: Vec<String>,
-        /// SQL filters for failure message.
-        ///
-        /// This can be specified multiple times to specify multiple filters.
-        #[arg(long = "message-filter", short = 'm')]
-        message_filters: Vec<String>,
+        #[command(flatten)]
+        filters: FailedFilters,
+    },
+    /// Move all failed messages matching the filters back into the queue.
+    ///
+    /// The messages keep their original IDs, and will be picked up by any running voyager instance
+    /// using this queue.
+    RequeueFailed {
+        #[command(flatten)]
+        filters: FailedFilters,
+        /// Print the amount of matching messages and a sample of them instead of requeueing them.
+        #[arg(long)]
+        dry_run: bool,
+        /// The amount of messages to show with `--dry-run`.
+        #[arg(long, default_value_t = result_unwrap!(Pg64::new_const(5)))]
+        sample: Pg64,
+    },
+    /// Permanently delete all failed messages matching the filters.
+    ///
+    /// At least one filter is required, unless `--all` is passed.
+    DiscardFailed {
+        #[command(flatten)]
+        filters: FailedFilters,
+        /// Discard all failed messages. This is required to run without any filters.
+        #[arg(long, conflicts_with_all(["item_filters", "message_filters"]))]
+        all: bool,
+        /// Print the amount of matching messages and a sample of them instead of discarding them.
+        #[arg(long)]
+        dry_run: bool,
+        /// The amount of messages to show with `--dry-run`.
+        #[arg(long, default_value_t = result_unwrap!(Pg64::new_const(5)))]
+        sample: Pg64,
     },
     /// Query a failed message by it's ID.
     QueryFailedById {
@@ -229,6 +279,42 @@ pub enum QueueCmd {
     },
 }
 
+#[derive(Debug, clap::Args)]
+pub struct FailedFilters {
+    /// SQL filters for the item.
+    ///
+    /// These will be run on the stringified item (`item::text`), which is the *almost* fully compact JSON:
+    ///
+    /// ```psql
+    /// default=# select '{"a":{"b":"c"}}'::jsonb::text;
+    ///        text        
+    /// -------------------
+    ///  {"a": {"b": "c"}}
+    /// ````
+    ///
+    /// Note that the `sqlite` queue stores the fully compact JSON (`{"a":{"b":"c"}}`). Filters in the
+    /// `item::text` form are compacted before they are run there, so the same filter can be used
+    /// for both queues. Postgres also sorts object keys, so filters spanning multiple keys may
+    /// still differ between the queues.
+    ///
+    /// This can be specified multiple times to specify multiple filters.
+    #[arg(long = "item-filter", short = 'i')]
+    pub item_filters: Vec<String>,
+    /// SQL filters for failure message.
+    ///
+    /// This can be specified multiple times to specify multiple filters.
+    #[arg(long = "message-filter", short = 'm')]
+    pub message_filters: Vec<String>,
+}
+
+impl FailedFilters {
+    /// Whether no filters were specified, i.e. these filters match all failed messages.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.item_filters.is_empty() && self.message_filters.is_empty()
+    }
+}
+
 #[derive(Debug, Subcommand)]
 pub enum PluginCmd {
     /// Run the interest filter for the specified plugin on the provided JSON object.
@@ -472,6 +558,14 @@ pub enum MsgCmd {
         /// Automatically enqueue the op.
         #[arg(long, short = 'e', default_value_t = false)]
         enqueue: bool,
+        /// Print the op as a human readable plan, showing which plugin or module each message will
+        /// be handled by, instead of printing it as JSON.
+        #[arg(long, conflicts_with_all(["enqueue", "dry_run"]))]
+        explain: bool,
+        /// Run the op against the plugins and modules in the config without submitting any
+        /// transactions, and print the transactions that would have been submitted.
+        #[arg(long, conflicts_with = "enqueue")]
+        dry_run: bool,
         #[arg(long, global = true)]
         rest_url: Option<String>,
         #[arg(long, global = true)]
@@ -499,11 +593,53 @@ pub enum MsgCmd {
         /// Automatically enqueue the op.
         #[arg(long, short = 'e', default_value_t = false)]
         enqueue: bool,
+        /// Print the op as a human readable plan, showing which plugin or module each message will
+        /// be handled by, instead of printing it as JSON.
+        #[arg(long, conflicts_with_all(["enqueue", "dry_run"]))]
+        explain: bool,
+        /// Run the op against the plugins and modules in the config without submitting any
+        /// transactions, and print the transactions that would have been submitted.
+        #[arg(long, conflicts_with = "enqueue")]
+        dry_run: bool,
         #[arg(long, global = true)]
         rest_url: Option<String>,
         #[arg(long, global = true)]
         rpc_url: Option<String>,
     },
+    /// Recover an expired or frozen ibc-union client by replacing its state with the state of an
+    /// active substitute client of the same type.
+    ///
+    /// The transaction must be signed by the relayer admin of the IBC host on `on`, and the light
+    /// client must support recovering from the substitute.
+    RecoverClient {
+        #[arg(value_parser(|s: &str| ok(ChainId::new(s.to_owned()))))]
+        on: ChainId,
+        /// The expired or frozen client to recover.
+        subject: ClientId,
+        /// The active client to copy the state from. Usually this is a freshly created client
+        /// tracking the same chain as the subject.
+        substitute: ClientId,
+
+        /// Automatically enqueue the op.
+        #[arg(long, short = 'e', default_value_t = false)]
+        enqueue: bool,
+        /// Print the op as a human readable plan, showing which plugin or module each message will
+        /// be handled by, instead of printing it as JSON.
+        #[arg(long, conflicts_with_all(["enqueue", "dry_run"]))]
+        explain: bool,
+        /// Run the op against the plugins and modules in the config without submitting any
+        /// transactions, and print the transactions that would have been submitted.
+        #[arg(long, conflicts_with = "enqueue")]
+        dry_run: bool,
+        #[arg(long, global = true)]
+        rest_url: Option<String>,
+    },
+    /// Print an op as a human readable plan, showing which plugin or module each message will be
+    /// handled by.
+    Explain {
+        #[arg(value_parser(|s: &str| serde_json::from_str::<Op<VoyagerMessage>>(s)))]
+        op: Op<VoyagerMessage>,
+    },
 }
 
 #[allow(
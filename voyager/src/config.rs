@@ -7,7 +7,12 @@ use voyager_core::{
     default_ipc_client_request_timeout, default_metrics_endpoint,
     default_optimizer_delay_milliseconds, default_rest_laddr, default_rpc_laddr,
     equivalent_chain_ids::EquivalentChainIds,
+    filter::LaneConfig,
+    health::HealthConfig,
+    throttle::ChainLimitsConfig,
 };
+use voyager_message::VoyagerMessage;
+use voyager_vm::schedule::Schedule;
 
 use crate::queue::QueueConfig;
 
@@ -42,4 +47,21 @@ pub struct VoyagerConfig {
     #[serde(default = "default_ipc_client_request_timeout")]
     pub ipc_client_request_timeout: Duration,
     pub cache: voyager_core::cache::Config,
+    /// Ops to enqueue on a recurring basis. The state of each schedule is persisted in the queue,
+    /// and can be inspected with `voyager queue stats`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[schemars(with = "Vec<serde_json::Value>")]
+    pub schedules: Vec<Schedule<VoyagerMessage>>,
+    /// Lanes to split the queue into, in priority order. Ops that don't match any lane are queued
+    /// in the `default` lane, which is served with a weight of 1 unless configured here.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lanes: Vec<LaneConfig>,
+    /// Concurrency and rate limits for the requests made to the modules and plugins of each
+    /// chain. Requests to chains that are not listed here are not limited.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chain_limits: Vec<ChainLimitsConfig>,
+    /// Health checking of the plugins and modules. Plugins and modules that stop responding are
+    /// restarted, and calls to unhealthy plugins are deferred until they recover.
+    #[serde(default)]
+    pub health: HealthConfig,
 }
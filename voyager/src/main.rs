@@ -16,46 +16,56 @@ use std::{collections::HashMap, fmt::Write, iter, process::ExitCode, time::Durat
 use anyhow::{anyhow, Context as _};
 use clap::Parser;
 use ibc_classic_spec::IbcClassic;
-use ibc_union_spec::IbcUnion;
+use ibc_union_spec::{
+    datagram::{Datagram, MsgRecoverClient},
+    IbcUnion,
+};
 use pg_queue::{
     default_max_connections, default_min_connections, default_retryable_error_expo_backoff_max,
-    default_retryable_error_expo_backoff_multiplier, PgQueueConfig, Tables,
+    default_retryable_error_expo_backoff_multiplier, PgQueueConfig,
 };
 use reqwest::Url;
 use schemars::gen::{SchemaGenerator, SchemaSettings};
 use serde::Serialize;
 use serde_json::Value;
 use tikv_jemallocator::Jemalloc;
-use tracing::info;
+use tokio::signal::unix::{signal, SignalKind};
+use tracing::{error, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 use voyager_client::VoyagerClient;
 use voyager_core::{
     context::ModulesConfig,
     default_metrics_endpoint, default_rest_laddr, default_rpc_laddr,
     equivalent_chain_ids::EquivalentChainIds,
-    filter::{make_filter, run_filter, JaqFilterResult},
+    explain,
+    filter::{make_filter, run_filter, InterestFilters, JaqFilterResult},
     get_plugin_info,
+    health::HealthConfig,
     ibc_spec_handlers::IbcSpecHandler,
+    reload::ReloadableConfig,
     Engine,
 };
 use voyager_message::{
-    call::{FetchUpdateHeaders, Index, IndexRange, IndexRangeHeights},
+    call::{FetchUpdateHeaders, Index, IndexRange, IndexRangeHeights, SubmitTx},
     callback::AggregateSubmitTxFromOrderedHeaders,
+    data::IbcDatagram,
     VoyagerMessage,
 };
+use voyager_plugin_protocol::record::{Recorder, Recording};
 use voyager_primitives::{IbcSpec, QueryHeight};
-use voyager_rpc::{types::IbcStateResponse, VoyagerRpcClient};
-use voyager_vm::{call, promise, Op, Queue};
+use voyager_rpc::{types::IbcStateResponse, ConfigRpcClient, QueueRpcClient, VoyagerRpcClient};
+use voyager_vm::{call, promise, ItemId, Op, Queue};
 
 #[global_allocator]
 static GLOBAL: Jemalloc = Jemalloc;
 
 use crate::{
     cli::{
-        get_voyager_config, App, Command, ConfigCmd, LogFormat, MsgCmd, PluginCmd, QueueCmd, RpcCmd,
+        get_voyager_config, App, Command, ConfigCmd, FailedFilters, LogFormat, MsgCmd, PluginCmd,
+        QueueCmd, RpcCmd,
     },
     config::{Config, VoyagerConfig},
-    queue::{QueueConfig, QueueImpl},
+    queue::{PersistentQueue, QueueConfig, QueueImpl},
 };
 
 #[cfg(windows)]
@@ -174,6 +184,10 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                     optimizer_delay_milliseconds: 100,
                     ipc_client_request_timeout: Duration::new(60, 0),
                     cache: voyager_core::cache::Config::default(),
+                    schedules: vec![],
+                    lanes: vec![],
+                    chain_limits: vec![],
+                    health: HealthConfig::default(),
                 },
             }),
             ConfigCmd::Schema => print_json(
@@ -183,13 +197,37 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 }))
                 .into_root_schema_for::<Config>(),
             ),
+            ConfigCmd::Reload { rpc_url } => {
+                let voyager_client =
+                    jsonrpsee::http_client::HttpClient::builder().build(get_rpc_url(rpc_url))?;
+
+                print_json(&voyager_client.reload().await?);
+            }
         },
-        Command::Start => {
+        Command::Start { record } => {
             let config = get_voyager_config()?;
 
             metrics::init(&config.voyager.metrics_endpoint);
 
-            let voyager = Engine::builder()
+            let mut builder = Engine::builder();
+
+            if let Some(record) = record {
+                builder = builder.with_recorder(Recorder::open(&record).with_context(|| {
+                    format!("unable to open recording at `{}`", record.display())
+                })?);
+            }
+
+            let config_file_path = app.config_file_path.clone();
+
+            let voyager = builder
+                .with_config_loader(move || {
+                    let config = cli::get_voyager_config(config_file_path.as_deref())?;
+
+                    Ok(ReloadableConfig {
+                        plugins: config.plugins,
+                        modules: config.modules,
+                    })
+                })
                 .with_equivalent_chain_ids(config.equivalent_chain_ids)
                 .with_plugins(config.plugins)
                 .with_modules(config.modules)
@@ -200,6 +238,10 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 .with_rest_laddr(config.voyager.rest_laddr)
                 .with_rpc_laddr(config.voyager.rpc_laddr)
                 .with_optimizer_delay_milliseconds(config.voyager.optimizer_delay_milliseconds)
+                .with_schedules(config.voyager.schedules)
+                .with_lanes(config.voyager.lanes)
+                .with_chain_limits(config.voyager.chain_limits)
+                .with_health_config(config.voyager.health)
                 .with_queue::<QueueImpl>(config.voyager.queue)
                 .register_ibc_spec_handler::<IbcUnion>()
                 .register_ibc_spec_handler::<IbcClassic>()
@@ -208,7 +250,51 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
 
             info!("starting relay service");
 
-            voyager.run().await;
+            let mut sighup = signal(SignalKind::hangup())?;
+
+            tokio::select! {
+                () = voyager.run() => {}
+                () = async {
+                    while sighup.recv().await.is_some() {
+                        info!("received SIGHUP, reloading config");
+
+                        if let Err(error) = voyager.reload_config().await {
+                            error!("error reloading config: {error:#}");
+                        }
+                    }
+
+                    futures::future::pending().await
+                } => {}
+            }
+        }
+        Command::Replay {
+            recording,
+            op,
+            max_steps,
+        } => {
+            let config = get_voyager_config()?;
+
+            let recording = Recording::load(&recording).with_context(|| {
+                format!("unable to load recording at `{}`", recording.display())
+            })?;
+
+            let voyager = Engine::builder()
+                .with_equivalent_chain_ids(config.equivalent_chain_ids)
+                .with_plugins(config.plugins)
+                .with_modules(config.modules)
+                .with_cache_config(config.voyager.cache)
+                .with_lanes(config.voyager.lanes)
+                .with_replay(recording)
+                .register_ibc_spec_handler::<IbcUnion>()
+                .register_ibc_spec_handler::<IbcClassic>()
+                .build()
+                .await?;
+
+            voyager
+                .replay(op, max_steps, |step| print_json(&step))
+                .await?;
+
+            voyager.shutdown();
         }
         Command::Plugin(cmd) => match cmd {
             PluginCmd::Interest {
@@ -282,24 +368,7 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
             }
         },
         Command::Queue(cli_msg) => {
-            let db = || {
-                Ok(match get_voyager_config()?.voyager.queue {
-                    QueueConfig::PgQueue(cfg) => {
-                        pg_queue::PgQueue::<VoyagerMessage>::new(PgQueueConfig {
-                            // only one connection is needed for the queue commands
-                            min_connections: 1,
-                            max_connections: 1,
-                            ..cfg
-                        })
-                    }
-                    QueueConfig::InMemory => {
-                        return Err(anyhow!(
-                            "no database set in config, queue commands \
-                            require the `pg-queue` database backend"
-                        ))
-                    }
-                })
-            };
+            let db = async || PersistentQueue::new(get_voyager_config()?.voyager.queue).await;
 
             match cli_msg {
                 QueueCmd::Enqueue { op, rest_url } => {
@@ -307,71 +376,138 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
 
                     send_enqueue(&rest_url, op).await?;
                 }
-                QueueCmd::Stats => {
-                    let stats = db()?.await?.stats().await?;
-
-                    print_json(&stats);
-                }
+                QueueCmd::Stats => match db().await? {
+                    PersistentQueue::PgQueue(q) => print_json(&q.stats().await?),
+                    PersistentQueue::Sqlite(q) => print_json(&q.stats().await?),
+                },
                 QueueCmd::Truncate {
                     queue,
                     optimize,
                     done,
                     failed,
-                } => {
-                    db()?
-                        .await?
-                        .truncate(Tables {
+                } => match db().await? {
+                    PersistentQueue::PgQueue(q) => {
+                        q.truncate(pg_queue::Tables {
                             queue,
                             optimize,
                             done,
                             failed,
                         })
                         .await?;
-                }
+                    }
+                    PersistentQueue::Sqlite(q) => {
+                        q.truncate(sqlite_queue::Tables {
+                            queue,
+                            optimize,
+                            done,
+                            failed,
+                        })
+                        .await?;
+                    }
+                },
                 QueueCmd::Vacuum {
                     queue,
                     optimize,
                     done,
                     failed,
-                } => {
-                    db()?
-                        .await?
-                        .vacuum(Tables {
+                } => match db().await? {
+                    PersistentQueue::PgQueue(q) => {
+                        q.vacuum(pg_queue::Tables {
                             queue,
                             optimize,
                             done,
                             failed,
                         })
                         .await?;
+                    }
+                    PersistentQueue::Sqlite(q) => {
+                        q.vacuum(sqlite_queue::Tables {
+                            queue,
+                            optimize,
+                            done,
+                            failed,
+                        })
+                        .await?;
+                    }
+                },
+                QueueCmd::History {
+                    id,
+                    max_depth,
+                    json,
+                    rpc_url,
+                } => {
+                    let rpc_url = get_rpc_url(rpc_url);
+
+                    let voyager_client =
+                        jsonrpsee::http_client::HttpClient::builder().build(rpc_url)?;
+
+                    let history = voyager_client
+                        .history(ItemId::new(id.inner())?, Some(max_depth))
+                        .await?
+                        .ok_or_else(|| anyhow!("item {id} not found"))?;
+
+                    if json {
+                        print_json(&history);
+                    } else {
+                        print!("{}", history.render_tree());
+                    }
                 }
-                // NOTE: Temporarily disabled until i figure out a better way to implement this with the new queue design
-                // cli::QueueCmd::History { id, max_depth } => {
-                //     // let results = query_as!(
-                //     //     Record,
-                //     //     r#"SELECT id as "id!", parent, item as "item!: Item" FROM get_list($1, $2) ORDER BY id ASC"#,
-                //     //     id.inner(),
-                //     //     max_depth.inner()
-                //     // )
-                //     // .fetch_all(&db)
-                //     // .await
-                //     // .unwrap();
-
-                //     // println!("{}", serde_json::to_string_pretty(&results).unwrap());
-
-                //     todo!();
-                // }
                 QueueCmd::QueryFailed {
                     page,
                     per_page,
-                    item_filters,
-                    message_filters,
+                    filters:
+                        FailedFilters {
+                            item_filters,
+                            message_filters,
+                        },
+                } => match db().await? {
+                    PersistentQueue::PgQueue(q) => print_json(
+                        &q.query_failed(
+                            page.into(),
+                            per_page.into(),
+                            item_filters,
+                            message_filters,
+                        )
+                        .await?,
+                    ),
+                    PersistentQueue::Sqlite(q) => print_json(
+                        &q.query_failed(
+                            page.into(),
+                            per_page.into(),
+                            item_filters,
+                            message_filters,
+                        )
+                        .await?,
+                    ),
+                },
+                QueueCmd::RequeueFailed {
+                    filters,
+                    dry_run,
+                    sample,
                 } => {
-                    let record = db()?
-                        .await?
-                        .query_failed(page.into(), per_page.into(), item_filters, message_filters)
-                        .await?;
+                    let q = db().await?;
+
+                    if dry_run {
+                        print_json(&q.sample_failed(filters, sample.into()).await?);
+                    } else {
+                        let requeued = q.requeue_failed(filters).await?;
+                        print_json(&serde_json::json!({ "requeued": requeued }));
+                    }
+                }
+                QueueCmd::DiscardFailed {
+                    filters,
+                    all,
+                    dry_run,
+                    sample,
+                } => {
+                    let q = db().await?;
 
-                    print_json(&record);
+                    if dry_run {
+                        print_json(&q.sample_failed(filters, sample.into()).await?);
+                    } else {
+                        let discarded = q.discard_failed(filters, all).await?;
+                        print_json(&serde_json::json!({ "discarded": discarded }));
+                    }
                 }
                 QueueCmd::QueryFailedById {
                     id,
@@ -380,12 +516,25 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 } => {
                     let rest_url = get_rest_url(rest_url);
 
-                    let q = db()?.await?;
-
-                    let record = q.query_failed_by_id(id.inner()).await?;
+                    let (op, record) = match db().await? {
+                        PersistentQueue::PgQueue(q) => {
+                            let record = q.query_failed_by_id(id.inner()).await?;
+                            (
+                                record.as_ref().map(|r| r.item.0.clone()),
+                                serde_json::to_value(record)?,
+                            )
+                        }
+                        PersistentQueue::Sqlite(q) => {
+                            let record = q.query_failed_by_id(id.inner()).await?;
+                            (
+                                record.as_ref().map(|r| r.item.0.clone()),
+                                serde_json::to_value(record)?,
+                            )
+                        }
+                    };
 
                     if requeue {
-                        if let Some(op) = record.as_ref().map(|r| r.item.0.clone()) {
+                        if let Some(op) = op {
                             send_enqueue(&rest_url, op).await?;
                             println!("requeued");
                         }
@@ -690,6 +839,8 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 height,
                 metadata,
                 enqueue,
+                explain,
+                dry_run,
                 rest_url,
                 rpc_url,
                 client_state_config,
@@ -727,6 +878,13 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
 
                 if enqueue {
                     send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else if explain {
+                    print!(
+                        "{}",
+                        explain::explain(&op, &interest_filters(&get_voyager_config()?)?)
+                    );
+                } else if dry_run {
+                    print_dry_run(get_voyager_config()?, op).await?;
                 } else {
                     print_json(&op);
                 }
@@ -738,6 +896,8 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
                 update_to,
                 update_from,
                 enqueue,
+                explain,
+                dry_run,
                 rest_url,
                 rpc_url,
             } => {
@@ -791,16 +951,102 @@ async fn do_main(app: cli::App) -> anyhow::Result<()> {
 
                 if enqueue {
                     send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else if explain {
+                    print!(
+                        "{}",
+                        explain::explain(&op, &interest_filters(&get_voyager_config()?)?)
+                    );
+                } else if dry_run {
+                    print_dry_run(get_voyager_config()?, op).await?;
                 } else {
                     print_json(&op);
                 }
             }
+            MsgCmd::RecoverClient {
+                on,
+                subject,
+                substitute,
+                enqueue,
+                explain,
+                dry_run,
+                rest_url,
+            } => {
+                let op = call::<VoyagerMessage>(SubmitTx {
+                    chain_id: on,
+                    datagrams: vec![IbcDatagram::new::<IbcUnion>(Datagram::from(
+                        MsgRecoverClient {
+                            subject,
+                            substitute,
+                        },
+                    ))],
+                });
+
+                if enqueue {
+                    send_enqueue(&get_rest_url(rest_url), op).await?;
+                } else if explain {
+                    print!(
+                        "{}",
+                        explain::explain(&op, &interest_filters(&get_voyager_config()?)?)
+                    );
+                } else if dry_run {
+                    print_dry_run(get_voyager_config()?, op).await?;
+                } else {
+                    print_json(&op);
+                }
+            }
+            MsgCmd::Explain { op } => {
+                print!(
+                    "{}",
+                    explain::explain(&op, &interest_filters(&get_voyager_config()?)?)
+                );
+            }
         },
     }
 
     Ok(())
 }
 
+/// Load the interest filters of all enabled plugins in `config`.
+fn interest_filters(config: &Config) -> anyhow::Result<InterestFilters> {
+    InterestFilters::new(
+        config
+            .plugins
+            .iter()
+            .filter(|plugin_config| plugin_config.enabled)
+            .map(get_plugin_info)
+            .collect::<anyhow::Result<_>>()?,
+        config.voyager.lanes.clone(),
+    )
+}
+
+/// Run `op` against the plugins and modules in `config`, and print the transactions that it would
+/// submit.
+async fn print_dry_run(config: Config, op: Op<VoyagerMessage>) -> anyhow::Result<()> {
+    let voyager = Engine::builder()
+        .with_equivalent_chain_ids(config.equivalent_chain_ids)
+        .with_plugins(config.plugins)
+        .with_modules(config.modules)
+        .with_ipc_client_request_timeout(config.voyager.ipc_client_request_timeout)
+        .with_cache_config(config.voyager.cache)
+        .with_lanes(config.voyager.lanes)
+        .with_chain_limits(config.voyager.chain_limits)
+        .with_health_config(config.voyager.health)
+        .register_ibc_spec_handler::<IbcUnion>()
+        .register_ibc_spec_handler::<IbcClassic>()
+        .build()
+        .await?;
+
+    let res = voyager.dry_run(op).await;
+
+    voyager.shutdown();
+
+    for submit_tx in res? {
+        print_json(&submit_tx);
+    }
+
+    Ok(())
+}
+
 async fn send_enqueue(
     rest_laddr: &str,
     op: Op<VoyagerMessage>,
@@ -6,23 +6,33 @@ use futures::Future;
 use pg_queue::{PgQueue, PgQueueConfig};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlite_queue::{SqliteQueue, SqliteQueueConfig};
 use voyager_message::VoyagerMessage;
 use voyager_vm::{
-    filter::InterestFilter, in_memory::InMemoryQueue, pass::Pass, Captures, EnqueueResult, ItemId,
-    Op, Queue, QueueError,
+    filter::InterestFilter,
+    history::History,
+    in_memory::InMemoryQueue,
+    pass::Pass,
+    schedule::{ScheduleState, Trigger},
+    Captures, EnqueueResult, ItemId, Op, Queue, QueueError,
 };
 
+use crate::cli::FailedFilters;
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case", tag = "type")]
 pub enum QueueConfig {
     InMemory,
     PgQueue(PgQueueConfig),
+    Sqlite(SqliteQueueConfig),
 }
 
 #[derive(Debug, Clone)]
 pub enum QueueImpl {
     InMemory(InMemoryQueue<VoyagerMessage>),
     PgQueue(PgQueue<VoyagerMessage>),
+    Sqlite(SqliteQueue<VoyagerMessage>),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -30,6 +40,7 @@ pub enum QueueImpl {
 pub enum AnyQueueError {
     InMemory(std::convert::Infallible),
     PgQueue(sqlx::Error),
+    Sqlite(sqlx::Error),
 }
 
 impl Queue<VoyagerMessage> for QueueImpl {
@@ -46,6 +57,10 @@ impl Queue<VoyagerMessage> for QueueImpl {
                 .await
                 .map_err(AnyQueueError::PgQueue)
                 .map(Self::PgQueue),
+            QueueConfig::Sqlite(cfg) => SqliteQueue::new(cfg)
+                .await
+                .map_err(AnyQueueError::Sqlite)
+                .map(Self::Sqlite),
         }
     }
 
@@ -63,6 +78,10 @@ impl Queue<VoyagerMessage> for QueueImpl {
                 .enqueue(item, filter)
                 .await
                 .map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue
+                .enqueue(item, filter)
+                .await
+                .map_err(AnyQueueError::Sqlite),
         }
     }
 
@@ -86,6 +105,10 @@ impl Queue<VoyagerMessage> for QueueImpl {
                 .process(filter, f)
                 .await
                 .map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue
+                .process(filter, f)
+                .await
+                .map_err(AnyQueueError::Sqlite),
         }
     }
 
@@ -104,6 +127,193 @@ impl Queue<VoyagerMessage> for QueueImpl {
                 .optimize(tag, filter, optimizer)
                 .await
                 .map_err(|e| e.map_left(AnyQueueError::PgQueue)),
+            QueueImpl::Sqlite(queue) => queue
+                .optimize(tag, filter, optimizer)
+                .await
+                .map_err(|e| e.map_left(AnyQueueError::Sqlite)),
+        }
+    }
+
+    async fn history(
+        &self,
+        id: ItemId,
+        max_depth: u32,
+    ) -> Result<Option<History<VoyagerMessage>>, Self::Error> {
+        match self {
+            QueueImpl::InMemory(queue) => queue
+                .history(id, max_depth)
+                .await
+                .map_err(AnyQueueError::InMemory),
+            QueueImpl::PgQueue(queue) => queue
+                .history(id, max_depth)
+                .await
+                .map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue
+                .history(id, max_depth)
+                .await
+                .map_err(AnyQueueError::Sqlite),
+        }
+    }
+
+    async fn enqueue_scheduled<'a, Filter: InterestFilter<VoyagerMessage>>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        item: Op<VoyagerMessage>,
+        filter: &'a Filter,
+        next_run_at: u64,
+    ) -> Result<EnqueueResult, Self::Error> {
+        match self {
+            QueueImpl::InMemory(queue) => queue
+                .enqueue_scheduled(name, trigger, item, filter, next_run_at)
+                .await
+                .map_err(AnyQueueError::InMemory),
+            QueueImpl::PgQueue(queue) => queue
+                .enqueue_scheduled(name, trigger, item, filter, next_run_at)
+                .await
+                .map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue
+                .enqueue_scheduled(name, trigger, item, filter, next_run_at)
+                .await
+                .map_err(AnyQueueError::Sqlite),
         }
     }
+
+    async fn skip_scheduled<'a>(
+        &'a self,
+        name: &'a str,
+        trigger: &'a Trigger,
+        next_run_at: u64,
+    ) -> Result<(), Self::Error> {
+        match self {
+            QueueImpl::InMemory(queue) => queue
+                .skip_scheduled(name, trigger, next_run_at)
+                .await
+                .map_err(AnyQueueError::InMemory),
+            QueueImpl::PgQueue(queue) => queue
+                .skip_scheduled(name, trigger, next_run_at)
+                .await
+                .map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue
+                .skip_scheduled(name, trigger, next_run_at)
+                .await
+                .map_err(AnyQueueError::Sqlite),
+        }
+    }
+
+    async fn schedules(&self) -> Result<Vec<ScheduleState>, Self::Error> {
+        match self {
+            QueueImpl::InMemory(queue) => queue.schedules().await.map_err(AnyQueueError::InMemory),
+            QueueImpl::PgQueue(queue) => queue.schedules().await.map_err(AnyQueueError::PgQueue),
+            QueueImpl::Sqlite(queue) => queue.schedules().await.map_err(AnyQueueError::Sqlite),
+        }
+    }
+}
+
+/// A queue backend that persists it's state outside of the voyager process, and can therefore be
+/// inspected by the `voyager queue` subcommands.
+pub enum PersistentQueue {
+    PgQueue(PgQueue<VoyagerMessage>),
+    Sqlite(SqliteQueue<VoyagerMessage>),
+}
+
+impl PersistentQueue {
+    pub async fn new(cfg: QueueConfig) -> anyhow::Result<Self> {
+        match cfg {
+            QueueConfig::PgQueue(cfg) => Ok(Self::PgQueue(
+                PgQueue::new(PgQueueConfig {
+                    // only one connection is needed for the queue commands
+                    min_connections: 1,
+                    max_connections: 1,
+                    ..cfg
+                })
+                .await?,
+            )),
+            QueueConfig::Sqlite(cfg) => Ok(Self::Sqlite(
+                SqliteQueue::new(SqliteQueueConfig {
+                    max_connections: 1,
+                    ..cfg
+                })
+                .await?,
+            )),
+            QueueConfig::InMemory => Err(anyhow::anyhow!(
+                "no database set in config, queue commands require \
+                either the `pg-queue` or `sqlite` queue backend"
+            )),
+        }
+    }
+    /// The amount of failed items matching the filters, along with up to `sample_size` of the most
+    /// recent ones.
+    pub async fn sample_failed(
+        &self,
+        filters: FailedFilters,
+        sample_size: i64,
+    ) -> anyhow::Result<FailedSample> {
+        let FailedFilters {
+            item_filters,
+            message_filters,
+        } = filters;
+
+        Ok(match self {
+            Self::PgQueue(q) => FailedSample {
+                count: q
+                    .count_failed(item_filters.clone(), message_filters.clone())
+                    .await?,
+                sample: serde_json::to_value(
+                    q.query_failed(1, sample_size, item_filters, message_filters)
+                        .await?,
+                )?,
+            },
+            Self::Sqlite(q) => FailedSample {
+                count: q
+                    .count_failed(item_filters.clone(), message_filters.clone())
+                    .await?,
+                sample: serde_json::to_value(
+                    q.query_failed(1, sample_size, item_filters, message_filters)
+                        .await?,
+                )?,
+            },
+        })
+    }
+
+    pub async fn requeue_failed(&self, filters: FailedFilters) -> anyhow::Result<u64> {
+        Ok(match self {
+            Self::PgQueue(q) => {
+                q.requeue_failed(filters.item_filters, filters.message_filters)
+                    .await?
+            }
+            Self::Sqlite(q) => {
+                q.requeue_failed(filters.item_filters, filters.message_filters)
+                    .await?
+            }
+        })
+    }
+
+    /// Permanently delete all failed items matching the filters. Empty filters match every failed
+    /// item, so this requires `all` to be set if no filters are specified.
+    pub async fn discard_failed(&self, filters: FailedFilters, all: bool) -> anyhow::Result<u64> {
+        if filters.is_empty() && !all {
+            return Err(anyhow::anyhow!(
+                "refusing to discard all failed items without any filters, \
+                pass `--all` to discard them anyway"
+            ));
+        }
+
+        Ok(match self {
+            Self::PgQueue(q) => {
+                q.discard_failed(filters.item_filters, filters.message_filters)
+                    .await?
+            }
+            Self::Sqlite(q) => {
+                q.discard_failed(filters.item_filters, filters.message_filters)
+                    .await?
+            }
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FailedSample {
+    pub count: i64,
+    pub sample: Value,
 }